@@ -0,0 +1,73 @@
+//! Benchmarks comparing curve arithmetic in affine and projective coordinates,
+//! and plain scalar multiplication with the GLV method.
+//!
+//! Run with `cargo bench --bench curves`.
+
+use ark_algebra_intro::bench::{affine_add, affine_double};
+use ark_algebra_intro::curves::glv_mul;
+use ark_bls12_381::{Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_std::UniformRand;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+macro_rules! bench_group {
+    ($c:expr, $name:expr, $affine:ty, $projective:ty) => {{
+        let mut rng = ark_std::rand::thread_rng();
+        let a_proj = <$projective>::rand(&mut rng);
+        let b_proj = <$projective>::rand(&mut rng);
+        let a: $affine = a_proj.into_affine();
+        let b: $affine = b_proj.into_affine();
+        let scalar = Fr::rand(&mut rng).into_repr();
+
+        let mut group = $c.benchmark_group($name);
+        group.bench_function("affine addition", |bench| {
+            bench.iter(|| affine_add(&black_box(a), &black_box(b)))
+        });
+        group.bench_function("projective addition", |bench| {
+            bench.iter(|| black_box(a_proj) + black_box(b_proj))
+        });
+        group.bench_function("mixed addition", |bench| {
+            bench.iter(|| black_box(a_proj).add_mixed(&black_box(b)))
+        });
+        group.bench_function("affine doubling", |bench| {
+            bench.iter(|| affine_double(&black_box(a)))
+        });
+        group.bench_function("projective doubling", |bench| {
+            bench.iter(|| black_box(a_proj).double())
+        });
+        group.bench_function("scalar multiplication (affine base)", |bench| {
+            bench.iter(|| black_box(a).mul(black_box(scalar)))
+        });
+        group.bench_function("scalar multiplication (projective base)", |bench| {
+            bench.iter(|| black_box(a_proj).mul(black_box(scalar)))
+        });
+        group.finish();
+    }};
+}
+
+fn bench_g1(c: &mut Criterion) {
+    bench_group!(c, "BLS12-381 G1", G1Affine, G1Projective);
+}
+
+fn bench_g2(c: &mut Criterion) {
+    bench_group!(c, "BLS12-381 G2", G2Affine, G2Projective);
+}
+
+fn bench_glv(c: &mut Criterion) {
+    let mut rng = ark_std::rand::thread_rng();
+    let p = G1Projective::rand(&mut rng).into_affine();
+    let scalar = Fr::rand(&mut rng);
+
+    let mut group = c.benchmark_group("BLS12-381 G1 scalar multiplication");
+    group.bench_function("plain", |bench| {
+        bench.iter(|| black_box(p).mul(black_box(scalar)))
+    });
+    group.bench_function("GLV", |bench| {
+        bench.iter(|| glv_mul(&black_box(p), &black_box(scalar)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_g1, bench_g2, bench_glv);
+criterion_main!(benches);
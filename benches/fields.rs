@@ -0,0 +1,106 @@
+//! Benchmarks of field arithmetic: the basic operations, inversion (including Fermat's
+//! and batch inversion), and square roots.
+//!
+//! Run with `cargo bench --bench fields`.
+
+use ark_algebra_intro::fields::{batch_inverse, demo_inverse, sqrt_tonelli_shanks};
+use ark_bls12_381::{Fq, Fq2, Fr};
+use ark_ff::{Field, PrimeField, SquareRootField};
+use ark_std::UniformRand;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+macro_rules! bench_field {
+    ($c:expr, $name:expr, $field:ty) => {{
+        let mut rng = ark_std::rand::thread_rng();
+        let a = <$field>::rand(&mut rng);
+        let b = <$field>::rand(&mut rng);
+        let square = a.square();
+
+        let mut group = $c.benchmark_group($name);
+        group.bench_function("addition", |bench| {
+            bench.iter(|| black_box(a) + black_box(b))
+        });
+        group.bench_function("multiplication", |bench| {
+            bench.iter(|| black_box(a) * black_box(b))
+        });
+        group.bench_function("squaring", |bench| bench.iter(|| black_box(a).square()));
+        group.bench_function("inversion", |bench| bench.iter(|| black_box(a).inverse()));
+        group.bench_function("square root", |bench| {
+            bench.iter(|| black_box(square).sqrt())
+        });
+        group.finish();
+    }};
+}
+
+fn bench_fr(c: &mut Criterion) {
+    bench_field!(c, "BLS12-381 Fr", Fr);
+}
+
+fn bench_fq(c: &mut Criterion) {
+    bench_field!(c, "BLS12-381 Fq", Fq);
+}
+
+fn bench_fq2(c: &mut Criterion) {
+    bench_field!(c, "BLS12-381 Fq2", Fq2);
+}
+
+/// Compares the teaching implementations in `fields` with the `arkworks` ones.
+fn bench_algorithms<F: PrimeField + SquareRootField>(c: &mut Criterion, name: &str) {
+    let mut rng = ark_std::rand::thread_rng();
+    let a = F::rand(&mut rng);
+    let square = a.square();
+
+    let mut group = c.benchmark_group(name);
+    group.bench_function("inversion (arkworks)", |bench| {
+        bench.iter(|| black_box(a).inverse())
+    });
+    group.bench_function("inversion (Fermat)", |bench| {
+        bench.iter(|| demo_inverse(&black_box(a)))
+    });
+    group.bench_function("square root (arkworks)", |bench| {
+        bench.iter(|| black_box(square).sqrt())
+    });
+    group.bench_function("square root (Tonelli-Shanks)", |bench| {
+        bench.iter(|| sqrt_tonelli_shanks(black_box(square)))
+    });
+    group.finish();
+}
+
+fn bench_fr_algorithms(c: &mut Criterion) {
+    // Fr has 2-adicity 32, so Tonelli-Shanks has plenty of work to do.
+    bench_algorithms::<Fr>(c, "BLS12-381 Fr algorithms");
+}
+
+fn bench_batch_inverse(c: &mut Criterion) {
+    let mut rng = ark_std::rand::thread_rng();
+    let mut group = c.benchmark_group("BLS12-381 Fr inversion of many elements");
+    for &size in &[16, 256, 4096] {
+        let elements: Vec<_> = (0..size).map(|_| Fr::rand(&mut rng)).collect();
+        group.bench_with_input(BenchmarkId::new("one by one", size), &size, |bench, _| {
+            bench.iter(|| {
+                elements
+                    .iter()
+                    .map(|a| a.inverse().unwrap())
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("batch", size), &size, |bench, _| {
+            bench.iter(|| {
+                let mut elements = elements.clone();
+                batch_inverse(&mut elements);
+                elements
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_fr,
+    bench_fq,
+    bench_fq2,
+    bench_fr_algorithms,
+    bench_batch_inverse
+);
+criterion_main!(benches);
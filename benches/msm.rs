@@ -0,0 +1,61 @@
+//! Benchmarks comparing naive MSM, Pippenger's bucket method, and `arkworks`' MSM,
+//! as well as fixed-base scalar multiplication with precomputed tables.
+//!
+//! Run with `cargo bench --bench msm`.
+
+use ark_algebra_intro::msm;
+use ark_bls12_381::{Fr, G1Projective};
+use ark_ec::ProjectiveCurve;
+use ark_ff::PrimeField;
+use ark_std::UniformRand;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+fn bench_msm(c: &mut Criterion) {
+    let mut rng = ark_std::rand::thread_rng();
+    let mut group = c.benchmark_group("BLS12-381 G1 MSM");
+    group.sample_size(10);
+    for log_size in [8, 10, 12, 14, 16] {
+        let size = 1 << log_size;
+        let bases: Vec<_> = (0..size)
+            .map(|_| G1Projective::rand(&mut rng).into_affine())
+            .collect();
+        let scalars: Vec<_> = (0..size).map(|_| Fr::rand(&mut rng)).collect();
+
+        // The naive method is already far behind at 2^10 terms.
+        if log_size <= 10 {
+            group.bench_with_input(BenchmarkId::new("naive", size), &size, |b, _| {
+                b.iter(|| msm::naive(&bases, &scalars))
+            });
+        }
+        group.bench_with_input(BenchmarkId::new("pippenger", size), &size, |b, _| {
+            b.iter(|| msm::pippenger(&bases, &scalars))
+        });
+        group.bench_with_input(BenchmarkId::new("arkworks", size), &size, |b, _| {
+            b.iter(|| msm::arkworks(&bases, &scalars))
+        });
+    }
+    group.finish();
+}
+
+fn bench_fixed_base(c: &mut Criterion) {
+    let mut rng = ark_std::rand::thread_rng();
+    let g = G1Projective::prime_subgroup_generator();
+    let s = Fr::rand(&mut rng);
+
+    let mut group = c.benchmark_group("BLS12-381 G1 fixed-base");
+    group.sample_size(10);
+    group.bench_function("variable-base mul", |b| b.iter(|| g.mul(s.into_repr())));
+    for window in [4, 8, 12] {
+        group.bench_with_input(BenchmarkId::new("table setup", window), &window, |b, &w| {
+            b.iter(|| msm::FixedBaseTable::new(g, w))
+        });
+        let table = msm::FixedBaseTable::new(g, window);
+        group.bench_with_input(BenchmarkId::new("table mul", window), &window, |b, _| {
+            b.iter(|| table.mul(&s))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_msm, bench_fixed_base);
+criterion_main!(benches);
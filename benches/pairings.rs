@@ -0,0 +1,68 @@
+//! Benchmarks of pairings, their Miller loops and final exponentiations (naive and
+//! optimized), and of a product of pairings computed separately versus with a single
+//! shared final exponentiation.
+//!
+//! Run with `cargo bench --bench pairings`.
+
+use ark_algebra_intro::pairings::{final_exp_parts, miller_loop_naive, multi_pairing};
+use ark_bls12_381::{Bls12_381, Fq12, G1Projective, G2Projective};
+use ark_ec::{PairingEngine, ProjectiveCurve};
+use ark_std::UniformRand;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+fn bench_pairing(c: &mut Criterion) {
+    let mut rng = ark_std::rand::thread_rng();
+    let p = G1Projective::rand(&mut rng).into_affine();
+    let q = G2Projective::rand(&mut rng).into_affine();
+    let pair = [(p.into(), q.into())];
+    let f = Fq12::rand(&mut rng);
+
+    let mut group = c.benchmark_group("BLS12-381 pairing");
+    group.sample_size(20);
+    group.bench_function("pairing", |bench| {
+        bench.iter(|| Bls12_381::pairing(black_box(p), black_box(q)))
+    });
+    group.bench_function("Miller loop", |bench| {
+        bench.iter(|| Bls12_381::miller_loop(black_box(&pair)))
+    });
+    group.bench_function("Miller loop (naive)", |bench| {
+        bench.iter(|| miller_loop_naive(&black_box(p), &black_box(q)))
+    });
+    group.bench_function("final exponentiation", |bench| {
+        bench.iter(|| Bls12_381::final_exponentiation(black_box(&f)))
+    });
+    group.bench_function("final exponentiation (by parts)", |bench| {
+        bench.iter(|| final_exp_parts(black_box(f)))
+    });
+    group.finish();
+}
+
+fn bench_multi_pairing(c: &mut Criterion) {
+    let mut rng = ark_std::rand::thread_rng();
+    let mut group = c.benchmark_group("BLS12-381 product of pairings");
+    for &size in &[1, 2, 4, 8] {
+        let a: Vec<_> = (0..size)
+            .map(|_| G1Projective::rand(&mut rng).into_affine())
+            .collect();
+        let b: Vec<_> = (0..size)
+            .map(|_| G2Projective::rand(&mut rng).into_affine())
+            .collect();
+        group.bench_with_input(BenchmarkId::new("separately", size), &size, |bench, _| {
+            bench.iter(|| {
+                a.iter()
+                    .zip(&b)
+                    .map(|(p, q)| Bls12_381::pairing(black_box(*p), black_box(*q)))
+                    .product::<Fq12>()
+            })
+        });
+        group.bench_with_input(
+            BenchmarkId::new("multi_pairing", size),
+            &size,
+            |bench, _| bench.iter(|| multi_pairing::<Bls12_381>(black_box(&a), black_box(&b))),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_pairing, bench_multi_pairing);
+criterion_main!(benches);
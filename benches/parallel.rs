@@ -0,0 +1,53 @@
+//! Benchmarks comparing the serial and parallel versions of Pippenger's MSM and the
+//! iterative FFT.
+//!
+//! Run with `cargo bench --bench parallel --features parallel`. Set `RAYON_NUM_THREADS`
+//! to change the number of threads.
+
+use ark_algebra_intro::{msm, poly};
+use ark_bls12_381::{Fr, G1Projective};
+use ark_ec::ProjectiveCurve;
+use ark_std::UniformRand;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+fn bench_msm(c: &mut Criterion) {
+    let mut rng = ark_std::rand::thread_rng();
+    let mut group = c.benchmark_group("BLS12-381 G1 MSM, serial vs parallel");
+    group.sample_size(10);
+    for log_size in [8, 12, 16] {
+        let size = 1 << log_size;
+        let bases: Vec<_> = (0..size)
+            .map(|_| G1Projective::rand(&mut rng).into_affine())
+            .collect();
+        let scalars: Vec<_> = (0..size).map(|_| Fr::rand(&mut rng)).collect();
+
+        group.bench_with_input(BenchmarkId::new("serial", size), &size, |b, _| {
+            b.iter(|| msm::pippenger(&bases, &scalars))
+        });
+        group.bench_with_input(BenchmarkId::new("parallel", size), &size, |b, _| {
+            b.iter(|| msm::pippenger_parallel(&bases, &scalars))
+        });
+    }
+    group.finish();
+}
+
+fn bench_fft(c: &mut Criterion) {
+    let mut rng = ark_std::rand::thread_rng();
+    let mut group = c.benchmark_group("BLS12-381 Fr FFT, serial vs parallel");
+    group.sample_size(10);
+    for log_size in [8, 12, 16, 20] {
+        let size = 1 << log_size;
+        let coeffs: Vec<_> = (0..size).map(|_| Fr::rand(&mut rng)).collect();
+
+        group.bench_with_input(BenchmarkId::new("serial", size), &size, |b, _| {
+            b.iter(|| poly::fft_iterative(black_box(&coeffs)))
+        });
+        group.bench_with_input(BenchmarkId::new("parallel", size), &size, |b, _| {
+            b.iter(|| poly::fft_parallel(black_box(&coeffs)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_msm, bench_fft);
+criterion_main!(benches);
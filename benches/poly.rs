@@ -0,0 +1,59 @@
+//! Benchmarks comparing the naive DFT with the recursive, iterative and `arkworks` FFTs,
+//! and interpolation over an FFT domain with Lagrange interpolation.
+//!
+//! Run with `cargo bench --bench poly`.
+
+use ark_algebra_intro::poly::domains::{
+    interpolate_on_domain, EvaluationDomain, Radix2EvaluationDomain,
+};
+use ark_algebra_intro::poly::{dft_naive, fft_iterative, fft_recursive, lagrange_interpolate};
+use ark_bls12_381::Fr;
+use ark_std::UniformRand;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+fn bench_fft(c: &mut Criterion) {
+    let mut rng = ark_std::rand::thread_rng();
+    let mut group = c.benchmark_group("BLS12-381 Fr FFT");
+    group.sample_size(10);
+    for log_size in [8, 12, 16] {
+        let size = 1 << log_size;
+        let domain = Radix2EvaluationDomain::<Fr>::new(size).unwrap();
+        let coeffs: Vec<_> = (0..size).map(|_| Fr::rand(&mut rng)).collect();
+        // The naive DFT takes n^2 multiplications, which is too slow beyond 2^8.
+        if log_size <= 8 {
+            group.bench_with_input(BenchmarkId::new("naive DFT", size), &size, |bench, _| {
+                bench.iter(|| dft_naive(black_box(&coeffs)))
+            });
+        }
+        group.bench_with_input(BenchmarkId::new("recursive", size), &size, |bench, _| {
+            bench.iter(|| fft_recursive(black_box(&coeffs)))
+        });
+        group.bench_with_input(BenchmarkId::new("iterative", size), &size, |bench, _| {
+            bench.iter(|| fft_iterative(black_box(&coeffs)))
+        });
+        group.bench_with_input(BenchmarkId::new("arkworks", size), &size, |bench, _| {
+            bench.iter(|| domain.fft(black_box(&coeffs)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_interpolation(c: &mut Criterion) {
+    let mut rng = ark_std::rand::thread_rng();
+    let mut group = c.benchmark_group("BLS12-381 Fr interpolation");
+    for &size in &[16, 64, 256] {
+        let domain = Radix2EvaluationDomain::<Fr>::new(size).unwrap();
+        let evals: Vec<_> = (0..size).map(|_| Fr::rand(&mut rng)).collect();
+        let points: Vec<_> = domain.elements().zip(evals.iter().copied()).collect();
+        group.bench_with_input(BenchmarkId::new("FFT", size), &size, |bench, _| {
+            bench.iter(|| interpolate_on_domain(black_box(&evals)))
+        });
+        group.bench_with_input(BenchmarkId::new("Lagrange", size), &size, |bench, _| {
+            bench.iter(|| lagrange_interpolate(black_box(&points)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_fft, bench_interpolation);
+criterion_main!(benches);
@@ -0,0 +1,51 @@
+//! Arithmetic in a prime field: BLS12-381's scalar field `Fr`.
+//!
+//! Run with `cargo run --example 01_prime_fields`. Next: `02_extension_fields`.
+
+use ark_algebra_intro::display::FieldFmt;
+use ark_algebra_intro::fields::{
+    characteristic, demo_inverse, roots_of_unity, sqrt_tonelli_shanks, two_adicity,
+};
+use ark_algebra_intro::Error;
+use ark_bls12_381::Fr;
+use ark_ff::{Field, One, PrimeField, Zero};
+
+fn main() -> Result<(), Error> {
+    println!("r = {}", characteristic::<Fr>());
+
+    // Arithmetic wraps around modulo r, so -1 is r - 1.
+    let minus_one = -Fr::one();
+    println!(
+        "-1 = {} (balanced: {})",
+        FieldFmt(minus_one),
+        FieldFmt(minus_one).balanced()
+    );
+    assert_eq!(minus_one + Fr::one(), Fr::zero());
+
+    // Every nonzero element has an inverse, by Fermat's little theorem: a^(r - 2).
+    let a = Fr::from(7u64);
+    let inverse = demo_inverse(&a)?;
+    assert_eq!(Some(inverse), a.inverse());
+    assert_eq!(a * inverse, Fr::one());
+    println!("1/7 = {}", FieldFmt(inverse));
+
+    // Half of the nonzero elements are squares; Tonelli–Shanks finds their roots.
+    let (root, trace) = sqrt_tonelli_shanks(Fr::from(4u64))?;
+    assert!(root == Fr::from(2u64) || root == -Fr::from(2u64));
+    println!(
+        "sqrt(4) = {} after {} Tonelli–Shanks steps",
+        FieldFmt(root).balanced(),
+        trace.steps.len()
+    );
+
+    // r - 1 is divisible by a large power of two, which is what makes FFTs possible.
+    println!("r - 1 = 2^{} · odd", two_adicity::<Fr>());
+    let roots = roots_of_unity::<Fr>(3).unwrap();
+    assert!(roots.iter().all(|w| w.pow([8u64]).is_one()));
+    println!("a primitive 8th root of unity: {}", FieldFmt(roots[1]));
+
+    // Elements are stored in Montgomery form, but `into_repr` returns the canonical integer.
+    println!("7 as limbs: {}", FieldFmt(a).limbs());
+    assert_eq!(a.into_repr().0[0], 7);
+    Ok(())
+}
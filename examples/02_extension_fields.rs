@@ -0,0 +1,44 @@
+//! Extension fields: `Fq2 = Fq[u]/(u² + 1)`, and the tower up to `Fq12`.
+//!
+//! Run with `cargo run --example 02_extension_fields`. Next: `03_curve_points`.
+
+use ark_algebra_intro::display::FieldFmt;
+use ark_algebra_intro::extensions::{
+    fq2_from_coeffs, frobenius_orbit, lift_to_fq12, lift_to_fq2, lift_to_fq6,
+};
+use ark_algebra_intro::fields::{extension_degree, field_order};
+use ark_bls12_381::{Fq, Fq12, Fq2};
+use ark_ff::{Field, One};
+
+fn main() {
+    // An element of Fq2 is c0 + c1·u, with u² = -1.
+    let u = fq2_from_coeffs(Fq::from(0u64), Fq::from(1u64));
+    assert_eq!(u.square(), -Fq2::one());
+    let a = fq2_from_coeffs(Fq::from(3u64), Fq::from(4u64));
+    let a_squared = a.square();
+    // (3 + 4u)² = 9 - 16 + 24u
+    println!(
+        "(3 + 4u)² = {} + {}·u",
+        FieldFmt(a_squared.c0).balanced(),
+        FieldFmt(a_squared.c1)
+    );
+
+    // The Frobenius map x ↦ x^q is conjugation in Fq2: it swaps the two roots ±u.
+    let orbit = frobenius_orbit(a);
+    assert_eq!(orbit.len(), 2);
+    assert_eq!(orbit[1], fq2_from_coeffs(Fq::from(3u64), -Fq::from(4u64)));
+    println!("Frobenius orbit of 3 + 4u has {} elements", orbit.len());
+
+    // Fq12 has degree 12 over Fq, and q^12 elements.
+    println!(
+        "[Fq12 : Fq] = {}, |Fq12| has {} bits",
+        extension_degree::<Fq12>(),
+        field_order::<Fq12>().bits()
+    );
+
+    // Elements of subfields embed in the tower, and keep their arithmetic.
+    let seven = lift_to_fq12(lift_to_fq6(lift_to_fq2(Fq::from(7u64))));
+    assert_eq!(seven.inverse().unwrap() * seven, Fq12::one());
+    // Elements of Fq are fixed by Frobenius.
+    assert!(frobenius_orbit(seven).iter().all(|x| *x == seven));
+}
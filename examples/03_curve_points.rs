@@ -0,0 +1,44 @@
+//! Points on BLS12-381's G1: the curve equation, the group law and subgroups.
+//!
+//! Run with `cargo run --example 03_curve_points`. Next: `04_scalar_multiplication`.
+
+use ark_algebra_intro::curves::{
+    compress, decompress, find_low_order_point, is_in_correct_subgroup, is_on_curve, point_from_xy,
+};
+use ark_algebra_intro::display::FieldFmt;
+use ark_algebra_intro::Error;
+use ark_bls12_381::{Fq, Fr, G1Affine};
+use ark_ec::AffineCurve;
+use ark_ff::{FpParameters, PrimeField};
+use ark_std::Zero;
+
+fn main() -> Result<(), Error> {
+    let mut rng = ark_std::rand::thread_rng();
+
+    // The curve is y² = x³ + 4 over Fq.
+    let g = G1Affine::prime_subgroup_generator();
+    println!("G = ({}, {})", FieldFmt(g.x), FieldFmt(g.y));
+    assert!(is_on_curve(&g));
+    assert!(point_from_xy::<G1Affine>(Fq::from(1u64), Fq::from(1u64)).is_err());
+
+    // Adding a point to its negation gives the point at infinity, the identity.
+    assert!((g + (-g)).is_zero());
+    // The group has order h · r: G generates the subgroup of prime order r.
+    assert!(g.mul(<Fr as PrimeField>::Params::MODULUS).is_zero());
+    assert!(is_in_correct_subgroup(&g));
+
+    // The cofactor h is divisible by 3, so there are points of order 3 too, like (0, 2).
+    let p: G1Affine = point_from_xy(Fq::from(0u64), Fq::from(2u64))?;
+    assert!(!is_in_correct_subgroup(&p));
+    assert!((p + p + p).is_zero());
+    println!("(0, 2) has order 3, and is not in the prime-order subgroup");
+    let q: G1Affine = find_low_order_point(3, &mut rng).unwrap();
+    assert!(!q.is_zero() && (q + q + q).is_zero());
+
+    // A point is determined by x and the sign of y, which is how points are compressed.
+    let (x, greatest) = compress(&g).unwrap();
+    assert_eq!(decompress(x, greatest), Ok(g));
+    assert_eq!(decompress(x, !greatest), Ok(-g));
+    println!("G compresses to x and the bit {}", greatest);
+    Ok(())
+}
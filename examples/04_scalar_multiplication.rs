@@ -0,0 +1,58 @@
+//! Scalar multiplication and multi-scalar multiplication (MSM).
+//!
+//! Run with `cargo run --release --example 04_scalar_multiplication`. Next: `05_pairings`.
+
+use ark_algebra_intro::curves::{scalar_mul_double_and_add, wnaf_cost, Step};
+use ark_algebra_intro::msm::{self, FixedBaseTable};
+use ark_bls12_381::{Fr, G1Affine, G1Projective};
+use ark_ec::ProjectiveCurve;
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::time::Instant;
+use ark_std::UniformRand;
+
+fn main() {
+    let mut rng = ark_std::rand::thread_rng();
+    let g = G1Projective::prime_subgroup_generator();
+
+    // Double-and-add reads the bits of the scalar: one doubling per bit, and one addition
+    // per set bit.
+    let s = Fr::rand(&mut rng);
+    let (result, steps) = scalar_mul_double_and_add(g, &s.into_repr().to_bits_be());
+    assert_eq!(result, g.mul(s.into_repr()));
+    let additions = steps.iter().filter(|s| matches!(s, Step::Add(_))).count();
+    println!(
+        "double-and-add: {} doublings, {} additions",
+        steps.len() - additions,
+        additions
+    );
+
+    // Signed digits (wNAF) trade a small table of multiples for far fewer additions.
+    for window in 3..=6 {
+        let cost = wnaf_cost(s.into_repr(), window);
+        println!(
+            "wNAF with window {}: {} doublings, {} additions",
+            window, cost.doublings, cost.additions
+        );
+    }
+
+    // When the base is fixed, a precomputed table removes the doublings altogether.
+    let table = FixedBaseTable::new(g, 4);
+    assert_eq!(table.mul(&s), result);
+
+    // An MSM Σ s_i · P_i shares work between the terms: Pippenger's bucket method beats
+    // computing each product separately.
+    let n = 1 << 10;
+    let bases: Vec<G1Affine> = (0..n)
+        .map(|_| G1Projective::rand(&mut rng).into_affine())
+        .collect();
+    let scalars: Vec<Fr> = (0..n).map(|_| Fr::rand(&mut rng)).collect();
+
+    let start = Instant::now();
+    let naive = msm::naive(&bases, &scalars);
+    println!("naive MSM of size {}: {:?}", n, start.elapsed());
+    let start = Instant::now();
+    let pippenger = msm::pippenger(&bases, &scalars);
+    println!("Pippenger MSM of size {}: {:?}", n, start.elapsed());
+    assert_eq!(naive, pippenger);
+    assert_eq!(pippenger, msm::arkworks(&bases, &scalars));
+}
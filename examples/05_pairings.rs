@@ -0,0 +1,58 @@
+//! The pairing `e: G1 × G2 → Gt` and its bilinearity.
+//!
+//! Run with `cargo run --release --example 05_pairings`. Next: `06_polynomials`.
+
+use ark_algebra_intro::pairings::{
+    check_bilinearity, miller_loop_naive, multi_pairing, pairing_ratio_check, Gt,
+};
+use ark_bls12_381::{Bls12_381, Fr, G1Affine, G2Affine};
+use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_std::time::Instant;
+use ark_std::{One, UniformRand};
+
+fn main() {
+    let mut rng = ark_std::rand::thread_rng();
+    let g1 = G1Affine::prime_subgroup_generator();
+    let g2 = G2Affine::prime_subgroup_generator();
+
+    // e(a · P, b · Q) = e(P, Q)^(a · b): scalars move freely through the pairing.
+    let (a, b) = (Fr::rand(&mut rng), Fr::rand(&mut rng));
+    let lhs = Bls12_381::pairing(g1.mul(a), g2.mul(b));
+    let rhs = Gt::<Bls12_381>::generator().mul_scalar(&(a * b));
+    assert_eq!(lhs, rhs.into_inner());
+    assert!(check_bilinearity::<Bls12_381, _>(&mut rng));
+    println!("e(aP, bQ) = e(P, Q)^(ab)");
+
+    // Checking e(A, B) = e(C, D) takes one product of pairings, compared with 1.
+    let c = g1.mul(a * b).into_affine();
+    let d = g2;
+    assert!(pairing_ratio_check::<Bls12_381>(
+        (g1.mul(a).into_affine(), g2.mul(b).into_affine()),
+        (c, d)
+    ));
+
+    // The pairing of the generators is not 1: the pairing is non-degenerate.
+    assert!(!Bls12_381::pairing(g1, g2).is_one());
+
+    // A pairing is a Miller loop followed by a final exponentiation; a product of n
+    // pairings needs n Miller loops but a single final exponentiation.
+    let (_, steps) = miller_loop_naive(&g1, &g2);
+    println!("the Miller loop of BLS12-381 takes {} steps", steps.len());
+    let n = 8;
+    let ps: Vec<_> = (0..n)
+        .map(|_| g1.mul(Fr::rand(&mut rng)).into_affine())
+        .collect();
+    let qs: Vec<_> = (0..n)
+        .map(|_| g2.mul(Fr::rand(&mut rng)).into_affine())
+        .collect();
+    let start = Instant::now();
+    let separately: <Bls12_381 as PairingEngine>::Fqk = ps
+        .iter()
+        .zip(&qs)
+        .map(|(p, q)| Bls12_381::pairing(*p, *q))
+        .product();
+    println!("{} pairings: {:?}", n, start.elapsed());
+    let start = Instant::now();
+    assert_eq!(multi_pairing::<Bls12_381>(&ps, &qs), separately);
+    println!("a product of {} pairings: {:?}", n, start.elapsed());
+}
@@ -0,0 +1,49 @@
+//! Polynomials: evaluation, interpolation, division and the FFT.
+//!
+//! Run with `cargo run --release --example 06_polynomials`. Next: `07_hash_to_curve`.
+
+use ark_algebra_intro::poly::domains::{EvaluationDomain, Radix2EvaluationDomain};
+use ark_algebra_intro::poly::{
+    dft_naive, divide_with_remainder, evaluate, fft_recursive, from_roots, lagrange_interpolate,
+    vanishing_polynomial, DensePolynomial, Polynomial, UVPolynomial,
+};
+use ark_bls12_381::Fr;
+use ark_std::time::Instant;
+use ark_std::{UniformRand, Zero};
+
+fn main() {
+    let mut rng = ark_std::rand::thread_rng();
+
+    // 3 + 2X + X², evaluated with Horner's rule, and recovered from three evaluations.
+    let coeffs = vec![Fr::from(3u64), Fr::from(2u64), Fr::from(1u64)];
+    let points: Vec<_> = (1..=3u64)
+        .map(|x| (Fr::from(x), evaluate(&coeffs, Fr::from(x))))
+        .collect();
+    assert_eq!(lagrange_interpolate(&points), coeffs);
+    println!("3 + 2X + X² is determined by its values at 1, 2 and 3");
+
+    // p(z) = 0 exactly when (X - z) divides p: this is what KZG openings prove.
+    let p = DensePolynomial::from_coefficients_vec(coeffs);
+    let z = Fr::rand(&mut rng);
+    let shifted = &p - &DensePolynomial::from_coefficients_vec(vec![p.evaluate(&z)]);
+    let (_, remainder) = divide_with_remainder(&shifted, &from_roots(&[z]));
+    assert!(remainder.is_zero());
+
+    // X^n - 1 vanishes on the n-th roots of unity.
+    let n = 1 << 10;
+    let domain = Radix2EvaluationDomain::<Fr>::new(n).unwrap();
+    let vanishing = vanishing_polynomial::<Fr>(n);
+    assert!(domain.elements().all(|w| vanishing.evaluate(&w).is_zero()));
+
+    // Evaluating on all the roots of unity is the DFT; the FFT computes it in O(n log n)
+    // instead of O(n²).
+    let p: Vec<Fr> = (0..n).map(|_| Fr::rand(&mut rng)).collect();
+    let start = Instant::now();
+    let slow = dft_naive(&p);
+    println!("naive DFT of size {}: {:?}", n, start.elapsed());
+    let start = Instant::now();
+    let fast = fft_recursive(&p);
+    println!("recursive FFT of size {}: {:?}", n, start.elapsed());
+    assert_eq!(slow, fast);
+    assert_eq!(fast, domain.fft(&p));
+}
@@ -0,0 +1,44 @@
+//! Hashing messages to points of G1, with no known discrete logarithm.
+//!
+//! Run with `cargo run --example 07_hash_to_curve`. Next: `08_pedersen_commitments`.
+
+use ark_algebra_intro::curves::is_in_correct_subgroup;
+use ark_algebra_intro::hash_to_curve::{
+    hash_to_field, hash_to_g1, map_to_curve, try_and_increment,
+};
+use ark_algebra_intro::interop::zcash_format::encode_g1;
+
+fn main() {
+    let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+    let hex = |bytes: &[u8]| {
+        bytes
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<String>()
+    };
+
+    // Try-and-increment hashes (message, counter) to an x-coordinate until one is on the
+    // curve: simple, but the number of attempts depends on the message.
+    for msg in [&b"hello"[..], b"world", b"abc"] {
+        let (p, counter) = try_and_increment(msg);
+        assert!(is_in_correct_subgroup(&p));
+        println!(
+            "try-and-increment({:?}) stopped at counter {}",
+            String::from_utf8_lossy(msg),
+            counter
+        );
+    }
+
+    // RFC 9380 hashes to two field elements, maps each to the curve with the simplified
+    // SWU map (which never fails), adds the results and clears the cofactor.
+    let u = hash_to_field(b"abc", dst, 2);
+    let p = hash_to_g1(b"abc", dst);
+    assert!(is_in_correct_subgroup(&p));
+    println!("hash_to_g1(\"abc\") = {}", hex(&encode_g1(&p)));
+    // The map itself doesn't land in the prime-order subgroup.
+    let q = map_to_curve(u[0]);
+    println!(
+        "map_to_curve(u0) is in the subgroup: {}",
+        is_in_correct_subgroup(&q)
+    );
+}
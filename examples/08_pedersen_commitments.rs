@@ -0,0 +1,55 @@
+//! Pedersen commitments: hiding, binding, and additively homomorphic.
+//!
+//! Run with `cargo run --example 08_pedersen_commitments`. Next: `09_kzg_commitments`.
+
+use ark_algebra_intro::commitments::pedersen::{
+    add, broken_trapdoor, commit, open, rerandomize, CommitmentKey,
+};
+use ark_bls12_381::Fr;
+use ark_ff::Field;
+use ark_std::UniformRand;
+
+fn main() {
+    let mut rng = ark_std::rand::thread_rng();
+    let key = CommitmentKey::new(2, b"08_pedersen_commitments");
+
+    // C = m_1 · G_1 + m_2 · G_2 + r · H hides (m_1, m_2) behind the random r...
+    let values = [Fr::from(3u64), Fr::from(4u64)];
+    let blinding = Fr::rand(&mut rng);
+    let c = commit(&key, &values, blinding);
+    assert!(open(&key, &c, &values, blinding));
+    // ...and binds the committer to them.
+    assert!(!open(&key, &c, &[Fr::from(3u64), Fr::from(5u64)], blinding));
+    println!("committed to (3, 4)");
+
+    // Commitments add up like the values they hide.
+    let other = [Fr::from(10u64), Fr::from(20u64)];
+    let other_blinding = Fr::rand(&mut rng);
+    let sum = add(&c, &commit(&key, &other, other_blinding));
+    assert!(open(
+        &key,
+        &sum,
+        &[Fr::from(13u64), Fr::from(24u64)],
+        blinding + other_blinding
+    ));
+    println!("C(3, 4) + C(10, 20) opens to (13, 24)");
+
+    // Rerandomizing hides the link between two commitments to the same values.
+    let delta = Fr::rand(&mut rng);
+    let fresh = rerandomize(&key, &c, delta);
+    assert_ne!(fresh, c);
+    assert!(open(&key, &fresh, &values, blinding + delta));
+
+    // Binding relies on nobody knowing the discrete logarithms between generators. With
+    // G_1 = a · G and H = b · G, any value opens a commitment.
+    let broken = CommitmentKey::new_broken(1, b"08_pedersen_commitments");
+    let c = commit(&broken, &[Fr::from(1u64)], blinding);
+    let (a, b) = (
+        broken_trapdoor(b"08_pedersen_commitments", 1),
+        broken_trapdoor(b"08_pedersen_commitments", 0),
+    );
+    let forged = Fr::from(1_000_000u64);
+    let forged_blinding = blinding + (Fr::from(1u64) - forged) * a * b.inverse().unwrap();
+    assert!(open(&broken, &c, &[forged], forged_blinding));
+    println!("with a broken key, a commitment to 1 also opens to 1000000");
+}
@@ -0,0 +1,34 @@
+//! KZG polynomial commitments, from a structured reference string.
+//!
+//! Run with `cargo run --release --example 09_kzg_commitments`. Next: `10_fiat_shamir`.
+
+use ark_algebra_intro::commitments::kzg::{commit, open, open_batch, verify, verify_batch, Srs};
+use ark_algebra_intro::poly::{DensePolynomial, Polynomial, UVPolynomial};
+use ark_bls12_381::{Bls12_381, Fr};
+use ark_std::UniformRand;
+
+fn main() {
+    let mut rng = ark_std::rand::thread_rng();
+
+    // The setup publishes [τ^i]_1 for a secret τ, which must then be forgotten.
+    let srs = Srs::<Bls12_381>::setup(16, &mut rng);
+
+    // A commitment is [p(τ)]_1: a single point, whatever the degree of p.
+    let p = DensePolynomial::<Fr>::rand(10, &mut rng);
+    let c = commit(&srs, &p);
+
+    // An opening at z proves p(z) = v with a commitment to (p(X) - v) / (X - z), checked
+    // with one pairing equation.
+    let z = Fr::rand(&mut rng);
+    let (v, proof) = open(&srs, &p, z);
+    assert_eq!(v, p.evaluate(&z));
+    assert!(verify(&srs, &c, z, v, &proof));
+    assert!(!verify(&srs, &c, z, v + Fr::from(1u64), &proof));
+    println!("p(z) = v verifies, p(z) = v + 1 doesn't");
+
+    // Several evaluations can share a single proof.
+    let points: Vec<Fr> = (0..4).map(|_| Fr::rand(&mut rng)).collect();
+    let (values, proof) = open_batch(&srs, &p, &points);
+    assert!(verify_batch(&srs, &c, &points, &values, &proof));
+    println!("{} evaluations verified with one proof", points.len());
+}
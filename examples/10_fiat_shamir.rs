@@ -0,0 +1,45 @@
+//! Fiat–Shamir: making Schnorr's protocol non-interactive with a transcript.
+//!
+//! Run with `cargo run --example 10_fiat_shamir`. Next: `11_bls_signatures`.
+
+use ark_algebra_intro::protocols::schnorr::{
+    keygen, prove_knowledge, recover_key_from_nonce_reuse, sign_with_nonce, verify,
+    verify_knowledge,
+};
+use ark_algebra_intro::transcript::Transcript;
+use ark_bls12_381::{Fr, G1Affine};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_std::UniformRand;
+
+fn main() {
+    let mut rng = ark_std::rand::thread_rng();
+    let g = G1Affine::prime_subgroup_generator();
+
+    // The prover and the verifier derive the same challenge from the same messages.
+    let mut prover = Transcript::new(b"10_fiat_shamir");
+    prover.append_point(b"commitment", &g);
+    let mut verifier = prover.clone();
+    assert_eq!(
+        prover.challenge_scalar::<Fr>(b"challenge"),
+        verifier.challenge_scalar::<Fr>(b"challenge")
+    );
+
+    // A proof of knowledge of x with X = x · G: commit to R = k · G, derive e from the
+    // transcript, and respond with s = k + e · x.
+    let x = Fr::rand(&mut rng);
+    let statement = g.mul(x).into_affine();
+    let proof = prove_knowledge(&g, &x, &mut rng);
+    assert!(verify_knowledge(&g, &statement, &proof));
+    println!("proved knowledge of the discrete logarithm of X");
+
+    // Signatures are the same protocol with the message in the transcript. Reusing the
+    // nonce k for two messages gives away the secret key.
+    let (sk, pk) = keygen(&mut rng);
+    let nonce = Fr::rand(&mut rng);
+    let first = sign_with_nonce(&sk, b"first", nonce);
+    let second = sign_with_nonce(&sk, b"second", nonce);
+    assert!(verify(&pk, b"first", &first) && verify(&pk, b"second", &second));
+    let recovered = recover_key_from_nonce_reuse(&pk, (b"first", &first), (b"second", &second));
+    assert_eq!(recovered, Ok(sk));
+    println!("two signatures with the same nonce reveal the secret key");
+}
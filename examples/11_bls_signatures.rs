@@ -0,0 +1,54 @@
+//! BLS signatures: one pairing equation, and aggregation for free.
+//!
+//! Run with `cargo run --release --example 11_bls_signatures`. Next: `12_secret_sharing`.
+
+use ark_algebra_intro::protocols::bls::{
+    aggregate_signatures, aggregate_verify, keygen, pop, sign, verify, PublicKey, Signature,
+};
+
+fn main() {
+    let mut rng = ark_std::rand::thread_rng();
+
+    // A signature is σ = sk · H(m) in G1, checked with e(σ, g2) = e(H(m), pk).
+    let (sk, pk) = keygen(&mut rng);
+    let signature = sign(&sk, b"hello");
+    assert!(verify(&pk, b"hello", &signature));
+    assert!(!verify(&pk, b"goodbye", &signature));
+    println!("signed and verified \"hello\"");
+
+    // Signatures on different messages aggregate into a single point.
+    let keys: Vec<_> = (0..4).map(|_| keygen(&mut rng)).collect();
+    let messages: Vec<Vec<u8>> = (0..4)
+        .map(|i| format!("message {}", i).into_bytes())
+        .collect();
+    let signatures: Vec<Signature> = keys
+        .iter()
+        .zip(&messages)
+        .map(|((sk, _), msg)| sign(sk, msg))
+        .collect();
+    let aggregate = aggregate_signatures(&signatures);
+    let public_keys: Vec<PublicKey> = keys.iter().map(|(_, pk)| *pk).collect();
+    let message_refs: Vec<&[u8]> = messages.iter().map(|m| m.as_slice()).collect();
+    assert!(aggregate_verify(&public_keys, &message_refs, &aggregate));
+    println!("{} signatures aggregated into one", signatures.len());
+
+    // When everyone signs the same message, proofs of possession stop rogue-key attacks,
+    // and checking the aggregate takes just two pairings.
+    let proofs: Vec<_> = keys
+        .iter()
+        .map(|(sk, _)| pop::prove_possession(sk))
+        .collect();
+    assert!(public_keys
+        .iter()
+        .zip(&proofs)
+        .all(|(pk, proof)| pop::verify_possession(pk, proof)));
+    let same: Vec<_> = keys
+        .iter()
+        .map(|(sk, _)| pop::sign(sk, b"block 42"))
+        .collect();
+    assert!(pop::fast_aggregate_verify(
+        &public_keys,
+        b"block 42",
+        &aggregate_signatures(&same)
+    ));
+}
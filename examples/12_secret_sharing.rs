@@ -0,0 +1,25 @@
+//! Shamir secret sharing: polynomial interpolation as threshold cryptography.
+//!
+//! Run with `cargo run --example 12_secret_sharing`. Next: `13_qap` (needs `--features r1cs`).
+
+use ark_algebra_intro::protocols::shamir::{reconstruct, split};
+use ark_bls12_381::Fr;
+use ark_std::UniformRand;
+
+fn main() {
+    let mut rng = ark_std::rand::thread_rng();
+
+    // The shares are evaluations f(1), ..., f(5) of a random polynomial of degree 2 with
+    // f(0) = secret.
+    let secret = Fr::rand(&mut rng);
+    let shares = split(secret, 3, 5, &mut rng);
+
+    // Any three of them determine f, and so f(0)...
+    assert_eq!(reconstruct(&shares[..3]), secret);
+    assert_eq!(reconstruct(&[shares[0], shares[2], shares[4]]), secret);
+    println!("any 3 of the 5 shares recover the secret");
+
+    // ...but two are consistent with every possible secret.
+    assert_ne!(reconstruct(&shares[..2]), secret);
+    println!("2 shares reveal nothing about it");
+}
@@ -0,0 +1,44 @@
+//! From a circuit to a polynomial identity: R1CS and quadratic arithmetic programs.
+//!
+//! Run with `cargo run --example 13_qap --features r1cs`. Next: `14_groth16`.
+
+use ark_algebra_intro::poly::Polynomial;
+use ark_algebra_intro::r1cs::{cubic_assignment, cubic_r1cs};
+use ark_algebra_intro::snark::qap::Qap;
+use ark_bls12_381::Fr;
+use ark_std::UniformRand;
+
+fn main() {
+    let mut rng = ark_std::rand::thread_rng();
+
+    // x³ + x + 5 = out, flattened into constraints <a_i, z> · <b_i, z> = <c_i, z>.
+    let r1cs = cubic_r1cs::<Fr>();
+    println!(
+        "x³ + x + 5 = out: {} constraints over {} variables",
+        r1cs.num_constraints(),
+        r1cs.num_variables()
+    );
+    let z = cubic_assignment(Fr::from(3u64));
+    assert!(r1cs.is_satisfied(&z));
+
+    // Interpolating the columns of the matrices turns the constraints into a single
+    // identity A(X) · B(X) - C(X) = H(X) · Z(X).
+    let qap = Qap::from_r1cs(&r1cs);
+    let h = qap
+        .quotient(&z)
+        .expect("the assignment satisfies the constraints");
+    let (a, b, c) = qap.combine(&z);
+
+    // Checking the identity at one random point is enough, by Schwartz–Zippel.
+    let tau = Fr::rand(&mut rng);
+    assert_eq!(
+        a.evaluate(&tau) * b.evaluate(&tau) - c.evaluate(&tau),
+        h.evaluate(&tau) * qap.target.evaluate(&tau)
+    );
+    println!("A(τ) · B(τ) - C(τ) = H(τ) · Z(τ) at a random τ");
+
+    // A wrong assignment leaves a remainder.
+    let mut wrong = cubic_assignment(Fr::from(4u64));
+    wrong[1] = Fr::from(35u64);
+    assert!(qap.quotient(&wrong).is_err());
+}
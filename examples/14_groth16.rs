@@ -0,0 +1,28 @@
+//! A toy SNARK end to end: Groth16 proofs that `x³ + x + 5 = 35`.
+//!
+//! Run with `cargo run --release --example 14_groth16 --features groth16`.
+
+use ark_algebra_intro::snark::groth16_demo::{
+    corrupt, prove, setup, verify, KeySummary, ProofElement,
+};
+use ark_bls12_381::Fr;
+
+fn main() {
+    let mut rng = ark_std::rand::thread_rng();
+
+    // The trusted setup produces keys for this one circuit.
+    let (pk, vk) = setup(&mut rng);
+    println!("{}", KeySummary::new(&pk));
+
+    // The proof shows that the prover knows x = 3, without revealing it, in 192 bytes
+    // whatever the size of the circuit.
+    let proof = prove(&pk, Fr::from(3u64), Fr::from(35u64), &mut rng).unwrap();
+    assert!(verify(&vk, Fr::from(35u64), &proof));
+
+    // It is bound to the public input, and to each of its three elements.
+    assert!(!verify(&vk, Fr::from(36u64), &proof));
+    for element in [ProofElement::A, ProofElement::B, ProofElement::C] {
+        assert!(!verify(&vk, Fr::from(35u64), &corrupt(&proof, element)));
+    }
+    println!("changing the input or any element of the proof makes it invalid");
+}
@@ -0,0 +1,27 @@
+//! Prints a table comparing the results of the latest `cargo bench` run.
+//!
+//! Run with `cargo run --example bench_report`, after `cargo bench`. Pass a directory to
+//! read criterion's results from somewhere other than `target/criterion`.
+
+use ark_algebra_intro::bench::{criterion_results, ComparisonTable};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let dir = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "target/criterion".to_string());
+    match criterion_results(&dir) {
+        Ok(results) if results.is_empty() => {
+            eprintln!("no benchmark results in {}; run `cargo bench` first", dir);
+            ExitCode::FAILURE
+        }
+        Ok(results) => {
+            print!("{}", ComparisonTable(results));
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: cannot read {}: {}", dir, e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Moving a Jubjub Pedersen commitment over to BLS12-381.
+//!
+//! Run with `cargo run --example embedded_curves --features ed-on-bls12-381`.
+
+use ark_algebra_intro::embedded::{commit_to_point, is_embedded, verify_point_commitment};
+use ark_bls12_381::{Fr, G1Affine, G1Projective};
+use ark_ec::ProjectiveCurve;
+use ark_ed_on_bls12_381::{EdwardsAffine, EdwardsProjective, Fr as JubjubScalar};
+use ark_ff::PrimeField;
+use ark_std::UniformRand;
+
+fn main() {
+    let mut rng = ark_std::rand::thread_rng();
+    assert!(is_embedded::<EdwardsAffine, G1Affine>());
+
+    // A Pedersen commitment `m · G + r · H` to a message `m`, computed on Jubjub.
+    let g = EdwardsProjective::rand(&mut rng);
+    let h = EdwardsProjective::rand(&mut rng);
+    let message = JubjubScalar::from(42u64);
+    let randomness = JubjubScalar::rand(&mut rng);
+    let inner = (g.mul(message.into_repr()) + h.mul(randomness.into_repr())).into_affine();
+    println!("Jubjub commitment: ({}, {})", inner.x, inner.y);
+
+    // Its coordinates are BLS12-381 scalars, so we can commit to it on G1...
+    let bases: Vec<G1Affine> = (0..3)
+        .map(|_| G1Projective::rand(&mut rng).into_affine())
+        .collect();
+    let blinding = Fr::rand(&mut rng);
+    let outer = commit_to_point(&inner, &bases, blinding);
+    println!("BLS12-381 commitment: {}", outer.into_affine());
+
+    // ...and a verifier over BLS12-381's scalar field can open it.
+    assert!(verify_point_commitment(&outer, &inner, &bases, blinding));
+    println!("The outer commitment opens to the inner one.");
+}
@@ -0,0 +1,31 @@
+//! Checks your solutions to the exercises in `src/exercises.rs`.
+//!
+//! Run with `cargo run --example exercises`, or with `--features solutions` to check the
+//! reference solutions instead.
+
+use ark_algebra_intro::exercises::{run_all, Outcome};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let results = run_all();
+    let mut passed = 0;
+    for (exercise, outcome) in &results {
+        match outcome {
+            Outcome::Passed => {
+                passed += 1;
+                println!("  passed        {}", exercise.name);
+            }
+            Outcome::Failed(reason) => println!("  FAILED        {}: {}", exercise.name, reason),
+            Outcome::NotAttempted => println!("  not attempted {}", exercise.name),
+        }
+    }
+    println!("\n{} of {} exercises passed.", passed, results.len());
+    if results
+        .iter()
+        .any(|(_, outcome)| matches!(outcome, Outcome::Failed(_)))
+    {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,15 @@
+"""The KZG commitment scheme, from Python."""
+
+from algebra_intro import Fr, G1, G2, KzgSrs, pairing
+
+g, h = G1.generator(), G2.generator()
+a, b = Fr(6), Fr(7)
+print("e(6g, 7h) == e(g, h)^42:", pairing(a * g, b * h) == pairing(g, h) ** Fr(42))
+
+srs = KzgSrs.setup(8, seed=0)
+p = [Fr(1), Fr(2), Fr(3)]  # 1 + 2X + 3X^2
+commitment = srs.commit(p)
+value, proof = srs.open(p, Fr(2))
+print("p(2) =", value)
+print("proof verifies:", srs.verify(commitment, Fr(2), value, proof))
+print("wrong value verifies:", srs.verify(commitment, Fr(2), value + Fr(1), proof))
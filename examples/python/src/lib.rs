@@ -0,0 +1,10 @@
+//! The `python` bindings of `ark-algebra-intro`, packaged as an extension module.
+//!
+//! Build with `maturin develop`, then `import algebra_intro`.
+
+use pyo3::prelude::*;
+
+#[pymodule]
+fn algebra_intro(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    ark_algebra_intro::python::register(m)
+}
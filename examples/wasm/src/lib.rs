@@ -0,0 +1,6 @@
+//! The `wasm` bindings of `ark-algebra-intro`, packaged for the browser.
+//!
+//! Build with `wasm-pack build --target web`, then serve this directory and open
+//! `index.html`.
+
+pub use ark_algebra_intro::wasm::*;
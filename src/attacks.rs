@@ -0,0 +1,10 @@
+//! Attacks on deliberately weak parameters.
+//!
+//! Each attack here breaks a discrete logarithm that should be infeasible, because of a
+//! mistake in choosing or checking a group, on the toy groups of
+//! [`toy_curves`](crate::toy_curves) and [`discrete_log`](crate::discrete_log). They
+//! show why real curves have (nearly) prime order, and why implementations validate the
+//! points they receive.
+
+pub mod invalid_curve;
+pub mod pohlig_hellman;
@@ -0,0 +1,201 @@
+//! Invalid-curve and small-subgroup attacks on Diffie–Hellman.
+//!
+//! In elliptic-curve Diffie–Hellman, a responder with the secret key `k` receives a
+//! point `P` and computes the shared secret `k · P`. The point is meant to be in the
+//! prime-order subgroup of the curve, where `k · P` reveals nothing about `k`. A
+//! responder that doesn't check this can be sent other points:
+//!
+//! - *Invalid-curve attack*: the addition formulas never use the coefficient `b`, so a
+//!   point of a different curve `y² = x³ + a · x + b'` is multiplied as if it was on
+//!   that curve. If it has a small prime order `q` there, `k · P` is one of only `q`
+//!   points, and gives `k mod q` by a discrete log in a group of order `q`. A few
+//!   curves with small factors in their orders give `k` modulo enough primes to
+//!   recover it with the Chinese remainder theorem.
+//! - *Small-subgroup attack*: if the curve itself has a cofactor `h`, points of order
+//!   dividing `h` pass a check that the point is on the curve, and give `k mod h`.
+//!
+//! A [`Responder`] does the responder's side with a given level of [`Checks`], and
+//! [`recover_key`] runs the attack against it:
+//!
+//! ```rust
+//! use ark_algebra_intro::attacks::invalid_curve::{
+//!     invalid_points, point_of_order, recover_key, Checks, Responder,
+//! };
+//! use ark_algebra_intro::dynamic::DynPrimeField;
+//! use ark_algebra_intro::toy_curves::ToyCurve;
+//! use ark_algebra_intro::Error;
+//! use num_bigint::BigUint;
+//!
+//! // y² = x³ + 2x + 9 over F65537 has 65524 = 4 · 16381 points, so its keys are the
+//! // integers modulo the prime 16381.
+//! let f = DynPrimeField::new(BigUint::from(65537u64)).unwrap();
+//! let curve = ToyCurve::new(f.element(2u64), f.element(9u64)).unwrap();
+//! let (order, subgroup_order) = (65524, 16381);
+//! assert_eq!(curve.order(), order);
+//! let secret = 12_345;
+//!
+//! // Points of small order on curves with other b', whose orders multiply to more than
+//! // 16381, recover the key from a responder that checks nothing...
+//! let points = invalid_points(&curve, subgroup_order, 100);
+//! assert!(points.iter().all(|(_, p)| !curve.is_on_curve(p)));
+//! let naive = Responder::new(curve.clone(), subgroup_order, secret, Checks::Nothing);
+//! assert_eq!(recover_key(&naive, &points), Ok(secret));
+//! // ...but a responder that checks that points are on the curve rejects them.
+//! let careful = Responder::new(curve.clone(), subgroup_order, secret, Checks::OnCurve);
+//! assert_eq!(recover_key(&careful, &points), Err(Error::NotOnCurve));
+//!
+//! // A point of order 2 *is* on the curve, and still leaks the parity of the key...
+//! let two_torsion = point_of_order(&curve, order, 2).unwrap();
+//! assert_eq!(recover_key(&careful, &[(2, two_torsion.clone())]), Ok(secret % 2));
+//! // ...unless the responder also checks that points are in the prime-order subgroup.
+//! let validating = Responder::new(curve, subgroup_order, secret, Checks::OnCurveAndSubgroup);
+//! assert_eq!(
+//!     recover_key(&validating, &[(2, two_torsion)]),
+//!     Err(Error::NotInSubgroup)
+//! );
+//! ```
+//!
+//! The same checks protect `arkworks` code: [`is_on_curve`](crate::curves::is_on_curve)
+//! and [`is_in_correct_subgroup`](crate::curves::is_in_correct_subgroup), which
+//! deserialization runs unless it is told not to. Here, the responder returns `k · P`
+//! itself; a real one only uses it to derive a key, so the attacker has to try each of
+//! the `q` candidates against, say, an encrypted reply, which is slower but works the
+//! same way.
+
+use crate::attacks::pohlig_hellman::factor;
+use crate::discrete_log::{bsgs, DlogGroup};
+use crate::number_theory::crt;
+use crate::toy_curves::{ToyCurve, ToyPoint};
+use crate::Error;
+use ark_std::convert::TryFrom;
+use ark_std::vec::Vec;
+use num_bigint::BigUint;
+
+/// Which points a [`Responder`] checks before multiplying them by its key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Checks {
+    /// Multiply any point.
+    Nothing,
+    /// Reject points that are not on the curve.
+    OnCurve,
+    /// Reject points that are not on the curve, or not in the prime-order subgroup.
+    OnCurveAndSubgroup,
+}
+
+/// The responder in a Diffie–Hellman key exchange, which multiplies the points it
+/// receives by its secret key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Responder {
+    curve: ToyCurve,
+    subgroup_order: u64,
+    secret: u64,
+    checks: Checks,
+}
+
+impl Responder {
+    /// Returns a responder with the key `secret` for the subgroup of prime order
+    /// `subgroup_order` of `curve`, making the given `checks`.
+    pub fn new(curve: ToyCurve, subgroup_order: u64, secret: u64, checks: Checks) -> Self {
+        Self {
+            curve,
+            subgroup_order,
+            secret,
+            checks,
+        }
+    }
+
+    /// Returns the shared secret `k · point`, or [`Error::NotOnCurve`] or
+    /// [`Error::NotInSubgroup`] if one of the checks fails.
+    pub fn respond(&self, point: &ToyPoint) -> Result<ToyPoint, Error> {
+        if self.checks != Checks::Nothing && !self.curve.is_on_curve(point) {
+            return Err(Error::NotOnCurve);
+        }
+        if self.checks == Checks::OnCurveAndSubgroup
+            && !point.times(self.subgroup_order).is_infinity()
+        {
+            return Err(Error::NotInSubgroup);
+        }
+        Ok(point.times(self.secret))
+    }
+}
+
+/// Returns a point of the prime order `q` on `curve`, which has `curve_order` points, or
+/// [`Error::NoSuchPoint`] if there is none.
+///
+/// With `curve_order = q^e · m` for `m` not divisible by `q`, the order of
+/// `m · R` is a power of `q` for any point `R`, so this tries points `R` until one of
+/// the multiples `q^i · m · R` has order `q`.
+pub fn point_of_order(curve: &ToyCurve, curve_order: u64, q: u64) -> Result<ToyPoint, Error> {
+    if q < 2 || !curve_order.is_multiple_of(q) {
+        return Err(Error::NoSuchPoint);
+    }
+    let mut m = curve_order;
+    while m.is_multiple_of(q) {
+        m /= q;
+    }
+    let p = u64::try_from(curve.field().modulus()).expect("the modulus fits in a u64");
+    (0..p)
+        .filter_map(|x| curve.lift_x(x).ok())
+        .find_map(|r| {
+            let mut point = r.times(m);
+            if point.is_infinity() {
+                return None;
+            }
+            loop {
+                let next = point.times(q);
+                if next.is_infinity() {
+                    return Some(point);
+                }
+                point = next;
+            }
+        })
+        .ok_or(Error::NoSuchPoint)
+}
+
+/// Returns points `(q, P)` with `P` of distinct prime orders `q ≤ max_prime`, on curves
+/// `y² = x³ + a · x + b'` with the `a` of `curve` and other `b'`, such that the product
+/// of the `q` exceeds `bound`.
+///
+/// This counts the points of the curves with `b' = 0, 1, 2, ...` with
+/// [`ToyCurve::order`], and takes a point of each small prime order. It returns fewer
+/// points if their orders never multiply to more than `bound`.
+pub fn invalid_points(curve: &ToyCurve, bound: u64, max_prime: u64) -> Vec<(u64, ToyPoint)> {
+    let f = curve.field();
+    let p = u64::try_from(f.modulus()).expect("the modulus fits in a u64");
+    let mut points: Vec<(u64, ToyPoint)> = Vec::new();
+    let mut product = 1u64;
+    for b in (0..p).map(|b| f.element(b)).filter(|b| b != curve.b()) {
+        let related = match ToyCurve::new(curve.a().clone(), b) {
+            Ok(related) => related,
+            Err(_) => continue,
+        };
+        let order = related.order();
+        for (q, _) in factor(order, &[]) {
+            if q > max_prime || points.iter().any(|(known, _)| *known == q) {
+                continue;
+            }
+            let point = point_of_order(&related, order, q).expect("q divides the order");
+            points.push((q, point));
+            product = product.saturating_mul(q);
+            if product > bound {
+                return points;
+            }
+        }
+    }
+    points
+}
+
+/// Recovers the key of `responder` modulo the product of the `q`, by sending it each
+/// point `P` of order `q` in `points` and computing `k mod q` from its answer `k · P`.
+///
+/// Returns the error of the first point that the responder rejects.
+pub fn recover_key(responder: &Responder, points: &[(u64, ToyPoint)]) -> Result<u64, Error> {
+    let mut residues = Vec::new();
+    for (q, point) in points {
+        let shared = responder.respond(point)?;
+        let residue = bsgs(point, &shared, q - 1).expect("k · P is a multiple of P");
+        residues.push((BigUint::from(residue), BigUint::from(*q)));
+    }
+    // k mod the product of the `q` is at most k, so it fits in a u64.
+    Ok(u64::try_from(crt(&residues)).expect("the key fits in a u64"))
+}
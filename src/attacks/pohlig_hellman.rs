@@ -0,0 +1,156 @@
+//! The Pohlig–Hellman attack on groups of composite order.
+//!
+//! In a group of order `n = q₁^e₁ · ... · q_k^e_k`, a discrete logarithm `x` is
+//! determined by its residues modulo each `q_i^e_i`, and each of those only needs
+//! discrete logarithms in the subgroup of order `q_i`. So the cost of a discrete log is
+//! about `√q` for the *largest prime factor* `q` of `n`, not `√n`. On a curve whose
+//! order has no large prime factor, a key that [`pollard_rho`] would take a million
+//! steps to find falls in a few dozen:
+//!
+//! ```rust
+//! use ark_algebra_intro::attacks::pohlig_hellman::{factor, pohlig_hellman};
+//! use ark_algebra_intro::discrete_log::DlogGroup;
+//! use ark_algebra_intro::dynamic::DynPrimeField;
+//! use ark_algebra_intro::toy_curves::ToyCurve;
+//! use num_bigint::BigUint;
+//!
+//! // y² = x³ + x + 253 over a 40-bit prime field, whose group of points has order
+//! // n = 3³ · 7³ · 41 · 127 · 151², and is generated by g.
+//! let f = DynPrimeField::new(BigUint::from(1_099_511_627_689u64)).unwrap();
+//! let curve = ToyCurve::new(f.element(1u64), f.element(253u64)).unwrap();
+//! let g = curve.point(5u64, 256_079_645_572u64).unwrap();
+//! let n = 1_099_510_437_627u64;
+//! assert!(g.times(n).is_infinity());
+//! assert_eq!(factor(n, &[]), vec![(3, 3), (7, 3), (41, 1), (127, 1), (151, 2)]);
+//!
+//! let secret = 777_777_777_777u64;
+//! let public = g.times(secret);
+//! assert_eq!(pohlig_hellman(&g, &public, n, &[]), Ok(secret));
+//! ```
+//!
+//! This is why the groups used in cryptography have prime order, or a prime-order
+//! subgroup with a small cofactor, like the groups of BLS12-381.
+//!
+//! [`pollard_rho`]: crate::discrete_log::pollard_rho
+
+use crate::discrete_log::{bsgs, DlogGroup};
+use crate::number_theory::{crt, is_probable_prime};
+use crate::Error;
+use ark_std::convert::TryFrom;
+use ark_std::vec::Vec;
+use num_bigint::BigUint;
+
+/// The largest trial divisor that [`factor`] tries.
+pub const TRIAL_DIVISION_BOUND: u64 = 1 << 20;
+
+/// Returns the factorization of `n` as pairs `(q, e)` of a prime `q` and its exponent
+/// `e`, in increasing order of `q`.
+///
+/// The primes in `known_factors` are divided out first, and then the ones up to
+/// [`TRIAL_DIVISION_BOUND`] by trial division. If what remains has no factor below the
+/// bound but is not prime, it is returned as a single factor with exponent `1`: pass
+/// its factors in `known_factors` to split it.
+///
+/// # Panics
+///
+/// Panics if `n` is zero, or one of `known_factors` is not prime.
+///
+/// ```rust
+/// use ark_algebra_intro::attacks::pohlig_hellman::factor;
+///
+/// let (p, q) = (2_147_483_647u64, 2_147_483_629u64); // Two 31-bit primes.
+/// assert_eq!(factor(360, &[]), vec![(2, 3), (3, 2), (5, 1)]);
+/// assert_eq!(factor(4 * p * q, &[]), vec![(2, 2), (p * q, 1)]);
+/// assert_eq!(factor(4 * p * q, &[p]), vec![(2, 2), (q, 1), (p, 1)]);
+/// ```
+pub fn factor(mut n: u64, known_factors: &[u64]) -> Vec<(u64, u32)> {
+    // Every prime divides 0, so dividing them out would never end.
+    assert!(n > 0, "0 has no factorization");
+    let mut factors = Vec::new();
+    let mut divide_out = |n: &mut u64, q: u64| {
+        let mut e = 0;
+        while n.is_multiple_of(q) {
+            *n /= q;
+            e += 1;
+        }
+        if e > 0 {
+            factors.push((q, e));
+        }
+    };
+
+    for &q in known_factors {
+        assert!(
+            is_probable_prime(&BigUint::from(q)),
+            "the known factor {} is not prime",
+            q
+        );
+        divide_out(&mut n, q);
+    }
+    let mut q = 2;
+    while q <= TRIAL_DIVISION_BOUND && q * q <= n {
+        divide_out(&mut n, q);
+        q += 1;
+    }
+    if n > 1 {
+        factors.push((n, 1));
+    }
+    factors.sort_unstable();
+    factors
+}
+
+/// Finds `x < order` such that `target = x · base`, by the Pohlig–Hellman algorithm, or
+/// returns [`Error::DiscreteLogNotFound`] if there is none.
+///
+/// `order` is the order of `base`, or a multiple of it such as the order of the group,
+/// and is factored by [`factor`] with the help of `known_factors`. For each prime power
+/// `q^e` dividing `order`, the digits of `x` in base `q` are found one at a time with
+/// [`bsgs`] in the subgroup of order `q`, which gives `x mod q^e`. The Chinese remainder
+/// theorem then combines these residues into `x mod order`.
+///
+/// # Panics
+///
+/// Panics if `order` is zero, or one of `known_factors` is not prime.
+///
+/// ```rust
+/// use ark_algebra_intro::attacks::pohlig_hellman::pohlig_hellman;
+/// use ark_algebra_intro::discrete_log::{DlogGroup, Multiplicative};
+/// use ark_algebra_intro::toy_fields::F101;
+/// use ark_algebra_intro::Error;
+///
+/// // The multiplicative group of F101 has order 100 = 2² · 5², and 2 generates it.
+/// let g = Multiplicative(F101::from(2u64));
+/// assert_eq!(pohlig_hellman(&g, &g.times(73), 100, &[]), Ok(73));
+/// // 4 is only a multiple of 2.
+/// let four = Multiplicative(F101::from(4u64));
+/// assert_eq!(pohlig_hellman(&four, &g, 100, &[]), Err(Error::DiscreteLogNotFound));
+/// ```
+pub fn pohlig_hellman<G: DlogGroup>(
+    base: &G,
+    target: &G,
+    order: u64,
+    known_factors: &[u64],
+) -> Result<u64, Error> {
+    let mut residues = Vec::new();
+    for (q, e) in factor(order, known_factors) {
+        // `q_base` generates the subgroup of order `q` (or is `0`, if `q` doesn't divide
+        // the order of `base`).
+        let q_base = base.times(order / q);
+        let (mut x, mut q_power) = (0u64, 1u64);
+        for _ in 0..e {
+            // With x = x mod q^k + q^k · (d + q · ...), multiplying target - (x mod q^k) ·
+            // base by order / q^(k + 1) leaves d · q_base.
+            let rest = target.combine(&base.times(x).negate());
+            let digit = bsgs(&q_base, &rest.times(order / (q_power * q)), q - 1)?;
+            x += digit * q_power;
+            q_power *= q;
+        }
+        residues.push((BigUint::from(x), BigUint::from(q_power)));
+    }
+
+    let x = u64::try_from(crt(&residues)).expect("x is less than the order");
+    if base.times(x) == *target {
+        Ok(x)
+    } else {
+        Err(Error::DiscreteLogNotFound)
+    }
+}
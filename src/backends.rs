@@ -0,0 +1,203 @@
+//! Uniform names for the types of every supported curve.
+//!
+//! This tutorial uses BLS12-381 throughout, but `arkworks` supports many other
+//! curves, and everything in this crate that is generic over [`Field`](ark_ff::Field),
+//! [`ProjectiveCurve`], or [`PairingEngine`] works with them too. To make it easy to
+//! switch between curves, each submodule here re-exports the types of one curve under
+//! the same names:
+//!
+//! * pairing-friendly curves (`bls12_381`, `bn254`, `bls12_377`) provide
+//!   `Engine`, `Fq`, `Fq2`, `Fq12`, `Fr`, `G1Affine`, `G1Projective`, `G2Affine`,
+//!   and `G2Projective`;
+//! * other curves (`pallas`, `vesta`, `ed_on_bls12_381`, `ed25519`) provide `Fq`,
+//!   `Fr`, `Affine`, and `Projective`.
+//!
+//! Every curve except BLS12-381 is behind a Cargo feature of the same name
+//! (with `-` instead of `_`), and the `all-curves` feature enables all of them.
+//!
+//! ```rust
+//! use ark_algebra_intro::backends;
+//! use ark_ec::{PairingEngine, ProjectiveCurve};
+//! use ark_ff::{Field, PrimeField};
+//! use ark_std::UniformRand;
+//!
+//! // A generic function runs unchanged on any pairing-friendly curve...
+//! fn check_bilinearity<E: PairingEngine>() {
+//!     let mut rng = ark_std::rand::thread_rng();
+//!     let a = E::Fr::rand(&mut rng);
+//!     let g1 = E::G1Projective::prime_subgroup_generator();
+//!     let g2 = E::G2Projective::prime_subgroup_generator();
+//!     let left = E::pairing(g1.mul(a.into_repr()), g2);
+//!     let right = E::pairing(g1, g2).pow(a.into_repr());
+//!     assert_eq!(left, right);
+//! }
+//!
+//! // ...and the concrete curve is just a type parameter.
+//! check_bilinearity::<backends::bls12_381::Engine>();
+//! #[cfg(feature = "bn254")]
+//! check_bilinearity::<backends::bn254::Engine>();
+//! #[cfg(feature = "bls12-377")]
+//! check_bilinearity::<backends::bls12_377::Engine>();
+//! ```
+//!
+//! The same goes for curves that don't support pairings:
+//!
+//! ```rust
+//! use ark_algebra_intro::backends;
+//! use ark_ec::ProjectiveCurve;
+//! use ark_ff::PrimeField;
+//! use ark_std::{UniformRand, Zero};
+//!
+//! fn check_scalar_mul<G: ProjectiveCurve>() {
+//!     let mut rng = ark_std::rand::thread_rng();
+//!     let a = G::ScalarField::rand(&mut rng);
+//!     let g = G::rand(&mut rng);
+//!     assert_eq!(g.mul(a.into_repr()) + g.mul((-a).into_repr()), G::zero());
+//! }
+//!
+//! check_scalar_mul::<backends::bls12_381::G1Projective>();
+//! #[cfg(feature = "pallas")]
+//! check_scalar_mul::<backends::pallas::Projective>();
+//! #[cfg(feature = "vesta")]
+//! check_scalar_mul::<backends::vesta::Projective>();
+//! #[cfg(feature = "ed-on-bls12-381")]
+//! check_scalar_mul::<backends::ed_on_bls12_381::Projective>();
+//! #[cfg(feature = "ed25519")]
+//! check_scalar_mul::<backends::ed25519::Projective>();
+//! ```
+//!
+//! [`ProjectiveCurve`]: ark_ec::ProjectiveCurve
+//! [`PairingEngine`]: ark_ec::PairingEngine
+
+/// The BLS12-381 pairing-friendly curve, used throughout this tutorial.
+pub mod bls12_381 {
+    pub use ark_bls12_381::{Fq, Fq12, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+
+    /// The pairing engine for this curve.
+    pub type Engine = ark_bls12_381::Bls12_381;
+}
+
+/// The BN254 (a.k.a. alt-bn128) pairing-friendly curve, which is supported by
+/// Ethereum's precompiles.
+#[cfg(feature = "bn254")]
+pub mod bn254 {
+    pub use ark_bn254::{Fq, Fq12, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+
+    /// The pairing engine for this curve.
+    pub type Engine = ark_bn254::Bn254;
+}
+
+/// The BLS12-377 pairing-friendly curve, whose scalar field has high two-adicity
+/// and which forms a two-chain with BW6-761.
+#[cfg(feature = "bls12-377")]
+pub mod bls12_377 {
+    pub use ark_bls12_377::{Fq, Fq12, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+
+    /// The pairing engine for this curve.
+    pub type Engine = ark_bls12_377::Bls12_377;
+}
+
+/// The Pallas curve, which forms a cycle with [Vesta](crate::backends::vesta).
+#[cfg(feature = "pallas")]
+pub mod pallas {
+    pub use ark_pallas::{Affine, Fq, Fr, Projective};
+}
+
+/// The Vesta curve, which forms a cycle with [Pallas](crate::backends::pallas):
+/// the base field of each is the scalar field of the other.
+#[cfg(feature = "vesta")]
+pub mod vesta {
+    pub use ark_vesta::{Affine, Fq, Fr, Projective};
+}
+
+/// The Jubjub twisted Edwards curve, which is defined over the scalar field of BLS12-381.
+#[cfg(feature = "ed-on-bls12-381")]
+pub mod ed_on_bls12_381 {
+    pub use ark_ed_on_bls12_381::{
+        EdwardsAffine as Affine, EdwardsProjective as Projective, Fq, Fr,
+    };
+}
+
+/// The Ed25519 twisted Edwards curve `-x² + y² = 1 - (121665/121666) x²y²` over the
+/// field of order `2^255 - 19`, which is birationally equivalent to the Montgomery
+/// curve Curve25519 and is used by the Ed25519 signature scheme and X25519.
+///
+/// `arkworks` 0.3 doesn't ship this curve, so its fields are defined here with
+/// [`define_prime_field!`](crate::define_prime_field), and `Affine` and `Projective`
+/// are the `arkworks` twisted Edwards points over them.
+///
+/// ```rust
+/// use ark_algebra_intro::backends::ed25519::{Affine, FrParameters};
+/// use ark_ec::AffineCurve;
+/// use ark_ff::{FpParameters, Zero};
+///
+/// // The base point generates the subgroup of prime order ℓ, a 253-bit prime.
+/// let g = Affine::prime_subgroup_generator();
+/// assert!(g.is_on_curve() && !g.is_zero());
+/// assert!(g.mul(FrParameters::MODULUS).is_zero());
+/// assert_eq!(FrParameters::MODULUS_BITS, 253);
+/// ```
+#[cfg(feature = "ed25519")]
+pub mod ed25519 {
+    use ark_ec::models::{ModelParameters, MontgomeryModelParameters, TEModelParameters};
+    use ark_ec::twisted_edwards_extended::{GroupAffine, GroupProjective};
+    use ark_ff::field_new;
+
+    crate::define_prime_field!(
+        pub Fq,
+        "57896044618658097711785492504343953926634992332820282019728792003956564819949",
+        Fp256
+    );
+    crate::define_prime_field!(
+        pub Fr,
+        "7237005577332262213973186563042994240857116359379907606001950938285454250989",
+        Fp256
+    );
+
+    /// A point of Ed25519 in affine coordinates.
+    pub type Affine = GroupAffine<EdwardsParameters>;
+    /// A point of Ed25519 in extended twisted Edwards coordinates.
+    pub type Projective = GroupProjective<EdwardsParameters>;
+
+    /// The parameters of Ed25519, and of Curve25519 as its Montgomery form.
+    #[derive(Clone, Default, PartialEq, Eq)]
+    pub struct EdwardsParameters;
+
+    impl ModelParameters for EdwardsParameters {
+        type BaseField = Fq;
+        type ScalarField = Fr;
+    }
+
+    impl TEModelParameters for EdwardsParameters {
+        const COEFF_A: Fq = field_new!(Fq, "-1");
+        /// `-121665/121666`.
+        #[rustfmt::skip]
+        const COEFF_D: Fq = field_new!(Fq, "37095705934669439343138083508754565189542113879843219016388785533085940283555");
+        const COFACTOR: &'static [u64] = &[8];
+        #[rustfmt::skip]
+        const COFACTOR_INV: Fr = field_new!(Fr, "2713877091499598330239944961141122840321418634767465352250731601857045344121");
+        /// The base point of RFC 8032.
+        #[rustfmt::skip]
+        const AFFINE_GENERATOR_COEFFS: (Fq, Fq) = (
+            field_new!(Fq, "15112221349535400772501151409588531511454012693041857206046113283949847762202"),
+            field_new!(Fq, "46316835694926478169428394003475163141307993866256225615783033603165251855960"),
+        );
+
+        type MontgomeryModelParameters = EdwardsParameters;
+
+        #[inline(always)]
+        fn mul_by_a(elem: &Fq) -> Fq {
+            -*elem
+        }
+    }
+
+    /// Curve25519 is `v² = u³ + 486662 u² + u`. The Montgomery form that maps directly
+    /// to Ed25519 has `B = -486664` instead of `1`, which is isomorphic to it because
+    /// `-486664` is a square.
+    impl MontgomeryModelParameters for EdwardsParameters {
+        const COEFF_A: Fq = field_new!(Fq, "486662");
+        const COEFF_B: Fq = field_new!(Fq, "-486664");
+
+        type TEModelParameters = EdwardsParameters;
+    }
+}
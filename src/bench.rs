@@ -0,0 +1,345 @@
+//! A tiny harness for timing curve arithmetic in affine and projective coordinates.
+//!
+//! The crate documentation claims that "most group operations are slower in affine
+//! coordinates". This module lets you check that claim (and get a feel for the
+//! relative costs of the basic operations) on your own machine:
+//!
+//! ```rust
+//! use ark_algebra_intro::bench::compare_representations;
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! for measurement in compare_representations::<ark_bls12_381::g1::Parameters, _>(10, &mut rng) {
+//!     println!("{}", measurement);
+//! }
+//! ```
+//!
+//! The numbers above come from a handful of iterations in an unoptimized test
+//! build, so they're only a rough guide. For statistically meaningful results, run
+//! the benchmarks in the `benches` directory with `cargo bench`.
+//!
+//! The reason for the difference is that adding two affine points requires a
+//! field *inversion* (to compute the slope of the line through them), and a field
+//! inversion costs about as much as a hundred multiplications. Projective coordinates
+//! avoid inversions entirely, at the cost of a few extra multiplications.
+//!
+//! The benchmarks cover the claims made throughout the crate documentation:
+//!
+//! * `fields`: addition, multiplication, squaring, inversion (including
+//!   [Fermat's](crate::fields::demo_inverse) and [batch](crate::fields::batch_inverse)
+//!   inversion) and square roots in `Fr`, `Fq` and `Fq2`;
+//! * `curves`: affine, projective and mixed addition, and plain versus GLV scalar
+//!   multiplication;
+//! * `msm`: [naive, Pippenger and `arkworks`](crate::msm) MSMs of `2^8` to `2^16` terms,
+//!   and fixed-base tables;
+//! * `pairings`: pairings, Miller loops and final exponentiations, both [naive and
+//!   optimized](crate::pairings::miller_loop_naive), and products of pairings;
+//! * `poly`: [naive, recursive and iterative](crate::poly::fft_recursive) FFTs, and
+//!   interpolation;
+//! * `parallel` (with the `parallel` feature): serial versus parallel MSMs and FFTs.
+//!
+//! Criterion's own reports are in `target/criterion`. For a single table comparing the
+//! benchmarks in each group, run `cargo run --example bench_report` afterwards, which
+//! reads them with [`criterion_results`].
+
+use ark_ec::{
+    models::SWModelParameters, short_weierstrass_jacobian::GroupAffine, AffineCurve,
+    ProjectiveCurve,
+};
+use ark_ff::{Field, PrimeField, UniformRand};
+use ark_std::fmt;
+use ark_std::rand::Rng;
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// The average running time of an operation, as measured by [`time`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Measurement {
+    /// A description of the operation that was timed.
+    pub name: String,
+    /// The number of times the operation was run.
+    pub iterations: u32,
+    /// The average time taken by a single run.
+    pub per_iteration: Duration,
+}
+
+impl fmt::Display for Measurement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:<40} {:>12?} ({} iterations)",
+            self.name, self.per_iteration, self.iterations
+        )
+    }
+}
+
+/// Runs `operation` `iterations` times, and returns the average running time.
+///
+/// The result of each run is passed through [`black_box`] to prevent the
+/// compiler from optimizing the computation away.
+///
+/// # Panics
+///
+/// Panics if `iterations` is zero.
+///
+/// ```rust
+/// use ark_algebra_intro::bench::time;
+/// use ark_bls12_381::Fq;
+/// use ark_ff::Field;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let a = Fq::rand(&mut rng);
+/// let inversion = time("inversion", 100, || a.inverse());
+/// let multiplication = time("multiplication", 100, || a * a);
+/// println!("{}\n{}", inversion, multiplication);
+/// assert_eq!(inversion.iterations, 100);
+/// ```
+pub fn time<T>(name: &str, iterations: u32, mut operation: impl FnMut() -> T) -> Measurement {
+    assert!(iterations > 0, "cannot time zero iterations");
+    let start = Instant::now();
+    for _ in 0..iterations {
+        black_box(operation());
+    }
+    Measurement {
+        name: name.to_string(),
+        iterations,
+        per_iteration: start.elapsed() / iterations,
+    }
+}
+
+/// Times addition, doubling, mixed addition, and scalar multiplication on the
+/// short Weierstrass curve with parameters `P`, in both affine and projective
+/// coordinates, running each operation `iterations` times.
+///
+/// Affine addition and doubling use the textbook chord-and-tangent formulas,
+/// with one field inversion each.
+///
+/// ```rust
+/// use ark_algebra_intro::bench::compare_representations;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let measurements = compare_representations::<ark_bls12_381::g2::Parameters, _>(5, &mut rng);
+/// assert_eq!(measurements.len(), 7);
+/// assert_eq!(measurements[0].name, "affine addition");
+/// ```
+pub fn compare_representations<P: SWModelParameters, R: Rng + ?Sized>(
+    iterations: u32,
+    rng: &mut R,
+) -> Vec<Measurement> {
+    let a_proj = GroupAffine::<P>::prime_subgroup_generator().mul(P::ScalarField::rand(rng));
+    let b_proj = GroupAffine::<P>::prime_subgroup_generator().mul(P::ScalarField::rand(rng));
+    let (a, b) = (a_proj.into_affine(), b_proj.into_affine());
+    let scalar = P::ScalarField::rand(rng).into_repr();
+
+    // Make sure we're timing correct formulas.
+    assert_eq!(affine_add(&a, &b), (a_proj + b_proj).into_affine());
+    assert_eq!(affine_double(&a), a_proj.double().into_affine());
+
+    vec![
+        time("affine addition", iterations, || affine_add(&a, &b)),
+        time("projective addition", iterations, || a_proj + b_proj),
+        time("mixed addition (projective + affine)", iterations, || {
+            a_proj.add_mixed(&b)
+        }),
+        time("affine doubling", iterations, || affine_double(&a)),
+        time("projective doubling", iterations, || a_proj.double()),
+        time("scalar multiplication (affine base)", iterations, || {
+            a.mul(scalar)
+        }),
+        time(
+            "scalar multiplication (projective base)",
+            iterations,
+            || a_proj.mul(scalar),
+        ),
+    ]
+}
+
+/// Adds two affine points with the chord rule, assuming that neither is the
+/// point at infinity and that `a != ±b`.
+///
+/// This is the formula that [`compare_representations`] times as "affine addition".
+/// The `+` of `arkworks` affine points doesn't use it: it converts to projective
+/// coordinates, adds, and converts back.
+///
+/// # Panics
+///
+/// Panics if `a` and `b` have the same x-coordinate.
+///
+/// ```rust
+/// use ark_algebra_intro::bench::affine_add;
+/// use ark_bls12_381::G1Projective;
+/// use ark_ec::ProjectiveCurve;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let (a, b) = (G1Projective::rand(&mut rng), G1Projective::rand(&mut rng));
+/// assert_eq!(affine_add(&a.into_affine(), &b.into_affine()), (a + b).into_affine());
+/// ```
+pub fn affine_add<P: SWModelParameters>(a: &GroupAffine<P>, b: &GroupAffine<P>) -> GroupAffine<P> {
+    let slope = (b.y - a.y) * (b.x - a.x).inverse().unwrap();
+    let x = slope.square() - a.x - b.x;
+    let y = slope * (a.x - x) - a.y;
+    GroupAffine::new(x, y, false)
+}
+
+/// Doubles an affine point with the tangent rule, assuming that it has
+/// non-zero y-coordinate.
+///
+/// # Panics
+///
+/// Panics if the y-coordinate of `a` is zero.
+pub fn affine_double<P: SWModelParameters>(a: &GroupAffine<P>) -> GroupAffine<P> {
+    let x_squared = a.x.square();
+    let slope = (x_squared.double() + x_squared + P::COEFF_A) * a.y.double().inverse().unwrap();
+    let x = slope.square() - a.x.double();
+    let y = slope * (a.x - x) - a.y;
+    GroupAffine::new(x, y, false)
+}
+
+/// The mean running time of a benchmark, as estimated by criterion.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchResult {
+    /// The benchmark group, e.g., `BLS12-381 G1 MSM`.
+    pub group: String,
+    /// The benchmark's name within the group, including its parameter if it has one,
+    /// e.g., `pippenger/1024`.
+    pub name: String,
+    /// The mean running time of one iteration.
+    pub mean: Duration,
+}
+
+/// Reads the results of the latest `cargo bench` run from criterion's output directory
+/// (usually `target/criterion`), sorted by group, then by parameter (numerically, so
+/// that `msm/256` comes before `msm/1024`), then by name.
+///
+/// Criterion stores each benchmark in a directory of its own, with its identity in
+/// `new/benchmark.json` and its statistics in `new/estimates.json`. Directories without
+/// both files (such as criterion's HTML reports) are skipped, and files that don't
+/// have the fields this needs are an [`InvalidData`](io::ErrorKind::InvalidData) error.
+pub fn criterion_results(dir: impl AsRef<Path>) -> io::Result<Vec<BenchResult>> {
+    let mut results = Vec::new();
+    collect_results(dir.as_ref(), &mut results)?;
+    let parameter = |r: &BenchResult| -> Option<u64> { r.name.rsplit('/').next()?.parse().ok() };
+    results
+        .sort_by(|a, b| (&a.group, parameter(a), &a.name).cmp(&(&b.group, parameter(b), &b.name)));
+    Ok(results)
+}
+
+fn collect_results(dir: &Path, results: &mut Vec<BenchResult>) -> io::Result<()> {
+    let new = dir.join("new");
+    if let (Ok(benchmark), Ok(estimates)) = (
+        fs::read_to_string(new.join("benchmark.json")),
+        fs::read_to_string(new.join("estimates.json")),
+    ) {
+        let invalid = |what: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} in {}", what, new.display()),
+            )
+        };
+        let benchmark: serde_json::Value =
+            serde_json::from_str(&benchmark).map_err(|_| invalid("malformed benchmark.json"))?;
+        let estimates: serde_json::Value =
+            serde_json::from_str(&estimates).map_err(|_| invalid("malformed estimates.json"))?;
+        let group = benchmark["group_id"]
+            .as_str()
+            .ok_or_else(|| invalid("no group_id"))?;
+        let mean = estimates["mean"]["point_estimate"]
+            .as_f64()
+            .ok_or_else(|| invalid("no mean point_estimate"))?;
+        // Either of these is `null` for a benchmark without a function name or parameter.
+        let function = benchmark["function_id"].as_str();
+        let value = benchmark["value_str"].as_str();
+        let name = match (function, value) {
+            (Some(function), Some(value)) => format!("{}/{}", function, value),
+            (Some(name), None) | (None, Some(name)) => name.to_string(),
+            (None, None) => String::new(),
+        };
+        results.push(BenchResult {
+            group: group.to_string(),
+            name,
+            mean: Duration::from_secs_f64(mean / 1e9),
+        });
+        return Ok(());
+    }
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_results(&path, results)?;
+        }
+    }
+    Ok(())
+}
+
+/// A table comparing benchmarks within each group, relative to the fastest one.
+///
+/// ```rust
+/// use ark_algebra_intro::bench::{BenchResult, ComparisonTable};
+/// use std::time::Duration;
+///
+/// let result = |name: &str, micros| BenchResult {
+///     group: "G1 scalar multiplication".to_string(),
+///     name: name.to_string(),
+///     mean: Duration::from_micros(micros),
+/// };
+/// let table = ComparisonTable(vec![result("plain", 150), result("GLV", 100)]);
+/// let output = table.to_string();
+/// assert!(output.contains("plain"));
+/// assert!(output.contains("1.50x"));
+/// println!("{}", output);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComparisonTable(pub Vec<BenchResult>);
+
+impl fmt::Display for ComparisonTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.0.iter().map(|r| r.name.len()).max().unwrap_or(0);
+        let mut groups: Vec<&str> = self.0.iter().map(|r| r.group.as_str()).collect();
+        groups.dedup();
+        for (i, group) in groups.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            let rows: Vec<_> = self.0.iter().filter(|r| r.group == *group).collect();
+            // Compare benchmarks with the same parameter, e.g., MSMs of the same size.
+            let fastest = |row: &BenchResult| {
+                let parameter = row.name.rsplit('/').next();
+                rows.iter()
+                    .filter(|r| !row.name.contains('/') || r.name.rsplit('/').next() == parameter)
+                    .map(|r| r.mean)
+                    .min()
+                    .unwrap_or(row.mean)
+            };
+            writeln!(f, "{}", group)?;
+            for row in &rows {
+                let ratio = row.mean.as_secs_f64() / fastest(row).as_secs_f64();
+                writeln!(
+                    f,
+                    "  {:<width$}  {:>10}  {:>8.2}x",
+                    row.name,
+                    format_duration(row.mean),
+                    ratio,
+                    width = width
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Formats `d` with three significant digits or so, in the largest unit below it.
+fn format_duration(d: Duration) -> String {
+    let nanos = d.as_secs_f64() * 1e9;
+    if nanos < 1e3 {
+        format!("{:.0} ns", nanos)
+    } else if nanos < 1e6 {
+        format!("{:.2} µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.2} ms", nanos / 1e6)
+    } else {
+        format!("{:.2} s", nanos / 1e9)
+    }
+}
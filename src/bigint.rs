@@ -0,0 +1,167 @@
+//! Conversions between `arkworks` [`BigInteger`]s, [`BigUint`]s, and byte arrays.
+//!
+//! `arkworks` represents the integers underlying prime field elements with
+//! fixed-width [`BigInteger`] types (e.g., `BigInteger256` for BLS12-381's `Fr`),
+//! which store their value as little-endian 64-bit limbs. These are convenient
+//! inside the library, but interacting with the outside world usually requires
+//! arbitrary-precision integers or byte strings.
+//!
+//! The most common pitfall here is *endianness*. `arkworks` serializes integers
+//! little-endian (least significant byte first), while most other tools (hex
+//! strings, Ethereum, Python's `int.to_bytes` default conventions, etc.) use big-endian.
+//! Mixing the two up doesn't produce an error; it silently produces a different number:
+//!
+//! ```rust
+//! use ark_algebra_intro::bigint::{repr_from_bytes_be, repr_from_bytes_le, repr_to_bytes_le};
+//! use ark_ff::{biginteger::BigInteger256, BigInteger};
+//!
+//! let n = BigInteger256::from(1u64);
+//! let bytes = repr_to_bytes_le(&n);
+//! assert_eq!(bytes[0], 1);
+//! // Reading the bytes back with the right endianness recovers `n`...
+//! assert_eq!(repr_from_bytes_le::<BigInteger256>(&bytes), Ok(n));
+//! // ...but reading them as big-endian yields 2^248 instead!
+//! let mut expected = BigInteger256::from(1u64);
+//! expected.muln(248);
+//! assert_eq!(repr_from_bytes_be::<BigInteger256>(&bytes), Ok(expected));
+//! ```
+
+use crate::Error;
+use ark_ff::{BigInteger, FpParameters, PrimeField};
+use ark_std::convert::TryFrom;
+use ark_std::string::String;
+use ark_std::vec::Vec;
+use num_bigint::BigUint;
+
+/// Converts `n` into the [`BigInteger`] type underlying `F`, returning
+/// [`Error::OutOfRange`] if `n` is not smaller than the modulus of `F`.
+///
+/// The result can be turned into a field element via [`PrimeField::from_repr`].
+///
+/// ```rust
+/// use ark_algebra_intro::bigint::biguint_to_repr;
+/// use ark_algebra_intro::Error;
+/// use ark_bls12_381::Fr;
+/// use ark_ff::{FpParameters, PrimeField};
+/// use num_bigint::BigUint;
+///
+/// let n = BigUint::from(12345u64);
+/// let repr = biguint_to_repr::<Fr>(&n).unwrap();
+/// assert_eq!(Fr::from_repr(repr), Some(Fr::from(12345u64)));
+///
+/// // The modulus itself is not a valid representative:
+/// let r: BigUint = <Fr as PrimeField>::Params::MODULUS.into();
+/// assert_eq!(biguint_to_repr::<Fr>(&r), Err(Error::OutOfRange));
+/// ```
+pub fn biguint_to_repr<F: PrimeField>(n: &BigUint) -> Result<F::BigInt, Error> {
+    let modulus: BigUint = F::Params::MODULUS.into();
+    if *n >= modulus {
+        return Err(Error::OutOfRange);
+    }
+    F::BigInt::try_from(n.clone()).map_err(|_| Error::OutOfRange)
+}
+
+/// Converts a [`BigInteger`] into a [`BigUint`].
+///
+/// ```rust
+/// use ark_algebra_intro::bigint::repr_to_biguint;
+/// use ark_bls12_381::Fr;
+/// use ark_ff::PrimeField;
+/// use num_bigint::BigUint;
+///
+/// let repr = Fr::from(42u64).into_repr();
+/// assert_eq!(repr_to_biguint(&repr), BigUint::from(42u64));
+/// ```
+pub fn repr_to_biguint<B: BigInteger>(repr: &B) -> BigUint {
+    (*repr).into()
+}
+
+/// Formats a [`BigInteger`] as a `0x`-prefixed, big-endian hexadecimal string,
+/// zero-padded to the full width of `B`.
+///
+/// This is the format in which most specifications and other libraries print
+/// field elements and curve parameters.
+///
+/// ```rust
+/// use ark_algebra_intro::bigint::repr_to_hex;
+/// use ark_bls12_381::Fr;
+/// use ark_ff::{FpParameters, PrimeField};
+///
+/// assert_eq!(
+///     repr_to_hex(&<Fr as PrimeField>::Params::MODULUS),
+///     "0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+/// );
+/// assert_eq!(repr_to_hex(&Fr::from(255u64).into_repr()).len(), 2 + 64);
+/// ```
+pub fn repr_to_hex<B: BigInteger>(repr: &B) -> String {
+    let mut hex = String::from("0x");
+    for byte in repr.to_bytes_be() {
+        hex.push_str(&format!("{:02x}", byte));
+    }
+    hex
+}
+
+/// Returns the little-endian (least significant byte first) encoding of
+/// `repr`, padded to the full width of `B`.
+///
+/// ```rust
+/// use ark_algebra_intro::bigint::repr_to_bytes_le;
+/// use ark_ff::biginteger::BigInteger256;
+///
+/// let bytes = repr_to_bytes_le(&BigInteger256::from(0x0102u64));
+/// assert_eq!(bytes.len(), 32);
+/// assert_eq!(&bytes[..3], &[0x02, 0x01, 0x00]);
+/// ```
+pub fn repr_to_bytes_le<B: BigInteger>(repr: &B) -> Vec<u8> {
+    repr.to_bytes_le()
+}
+
+/// Returns the big-endian (most significant byte first) encoding of
+/// `repr`, padded to the full width of `B`.
+///
+/// ```rust
+/// use ark_algebra_intro::bigint::repr_to_bytes_be;
+/// use ark_ff::biginteger::BigInteger256;
+///
+/// let bytes = repr_to_bytes_be(&BigInteger256::from(0x0102u64));
+/// assert_eq!(bytes.len(), 32);
+/// assert_eq!(&bytes[29..], &[0x00, 0x01, 0x02]);
+/// ```
+pub fn repr_to_bytes_be<B: BigInteger>(repr: &B) -> Vec<u8> {
+    repr.to_bytes_be()
+}
+
+/// Parses a little-endian byte string into a [`BigInteger`], returning
+/// [`Error::OutOfRange`] if the integer does not fit into `B`.
+///
+/// The input may be shorter than the width of `B`.
+///
+/// ```rust
+/// use ark_algebra_intro::bigint::repr_from_bytes_le;
+/// use ark_algebra_intro::Error;
+/// use ark_ff::biginteger::BigInteger64;
+///
+/// assert_eq!(repr_from_bytes_le::<BigInteger64>(&[0x02, 0x01]), Ok(BigInteger64::from(0x0102)));
+/// // Nine non-zero bytes don't fit into a single limb:
+/// assert_eq!(repr_from_bytes_le::<BigInteger64>(&[1; 9]), Err(Error::OutOfRange));
+/// // ...but trailing zeros are fine:
+/// assert!(repr_from_bytes_le::<BigInteger64>(&[1, 0, 0, 0, 0, 0, 0, 0, 0]).is_ok());
+/// ```
+pub fn repr_from_bytes_le<B: BigInteger>(bytes: &[u8]) -> Result<B, Error> {
+    B::try_from(BigUint::from_bytes_le(bytes)).map_err(|_| Error::OutOfRange)
+}
+
+/// Parses a big-endian byte string into a [`BigInteger`], returning
+/// [`Error::OutOfRange`] if the integer does not fit into `B`.
+///
+/// The input may be shorter than the width of `B`.
+///
+/// ```rust
+/// use ark_algebra_intro::bigint::repr_from_bytes_be;
+/// use ark_ff::biginteger::BigInteger64;
+///
+/// assert_eq!(repr_from_bytes_be::<BigInteger64>(&[0x01, 0x02]), Ok(BigInteger64::from(0x0102)));
+/// ```
+pub fn repr_from_bytes_be<B: BigInteger>(bytes: &[u8]) -> Result<B, Error> {
+    B::try_from(BigUint::from_bytes_be(bytes)).map_err(|_| Error::OutOfRange)
+}
@@ -0,0 +1,146 @@
+//! Command-line tools: conversions of BLS12-381 values (see [`ark_algebra_intro::convert`]),
+//! and starter crates for puzzles (see [`ark_algebra_intro::scaffold`]).
+//!
+//! ```text
+//! $ cargo run --features cli --bin algebra-intro -- convert point-decompress --group g1 97f1d3a7...
+//! x:             3685416753713387016781088315183077757961620795782546409894578378688607592378376318836054947676345821548104185464507
+//! y:             1339506544944476473020471379941921221584933875938349620426543736416511423956333506472724655353366534992391756441569
+//! in subgroup:   yes
+//! ...
+//! ```
+//!
+//! Hexadecimal inputs to `convert` are read from the arguments, or from standard input if omitted.
+
+use ark_algebra_intro::convert::{self, ConvertError, FieldName, Group, PointFormat, Report};
+use ark_algebra_intro::scaffold;
+use clap::{Parser, Subcommand};
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// Tools for inspecting BLS12-381 values, and for writing puzzles.
+#[derive(Parser)]
+#[command(name = "algebra-intro")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Converts between the encodings of field elements and points.
+    #[command(subcommand)]
+    Convert(Convert),
+    /// Creates a starter crate for a puzzle.
+    NewPuzzle {
+        /// The name of the crate.
+        name: String,
+        /// The directory to create the crate in.
+        #[arg(long, default_value = ".")]
+        dir: PathBuf,
+        /// Depends on the copy of this crate at this path, instead of the one on GitHub.
+        #[arg(long)]
+        local: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum Convert {
+    /// Parses a big-endian hexadecimal field element.
+    FieldFromHex {
+        /// The field: `fr` or `fq`.
+        #[arg(long, default_value = "fr")]
+        field: FieldName,
+        hex: Option<String>,
+    },
+    /// Decompresses a point, and checks whether it is in the prime-order subgroup.
+    PointDecompress {
+        /// The group: `g1` or `g2`.
+        #[arg(long, default_value = "g1")]
+        group: Group,
+        /// The encoding: `zcash` or `arkworks`.
+        #[arg(long, default_value = "zcash")]
+        format: PointFormat,
+        hex: Option<String>,
+    },
+    /// Reduces bytes of any length modulo the scalar field's order.
+    ScalarReduce {
+        /// Reads the bytes as a little-endian integer.
+        #[arg(long)]
+        little_endian: bool,
+        hex: Option<String>,
+    },
+    /// Hashes a message to base field elements, as in RFC 9380.
+    HashToField {
+        /// The domain separation tag.
+        #[arg(long)]
+        dst: String,
+        /// The number of elements.
+        #[arg(long, default_value_t = 2)]
+        count: usize,
+        /// The message, in hexadecimal.
+        message: Option<String>,
+    },
+    /// Computes the pairing of ZCash-encoded G1 and G2 points.
+    Pairing { g1: String, g2: String },
+}
+
+/// Returns `hex`, or reads it from standard input.
+fn input(hex: Option<String>) -> io::Result<String> {
+    match hex {
+        Some(hex) => Ok(hex),
+        None => {
+            let mut hex = String::new();
+            io::stdin().read_to_string(&mut hex)?;
+            Ok(hex.trim().to_string())
+        }
+    }
+}
+
+fn run(command: Convert) -> io::Result<Result<Report, ConvertError>> {
+    Ok(match command {
+        Convert::FieldFromHex { field, hex } => convert::field_from_hex(field, &input(hex)?),
+        Convert::PointDecompress { group, format, hex } => {
+            convert::point_decompress(group, format, &input(hex)?)
+        }
+        Convert::ScalarReduce { little_endian, hex } => {
+            convert::scalar_reduce(&input(hex)?, little_endian)
+        }
+        Convert::HashToField {
+            dst,
+            count,
+            message,
+        } => convert::hash_to_field(&input(message)?, &dst, count),
+        Convert::Pairing { g1, g2 } => convert::pairing(&g1, &g2),
+    })
+}
+
+fn main() -> io::Result<ExitCode> {
+    match Cli::parse().command {
+        Command::Convert(command) => match run(command)? {
+            Ok(report) => {
+                println!("{}", report);
+                Ok(ExitCode::SUCCESS)
+            }
+            Err(e) => {
+                eprintln!("error: {}", e);
+                Ok(ExitCode::FAILURE)
+            }
+        },
+        Command::NewPuzzle { name, dir, local } => {
+            let mut puzzle = match scaffold::new_puzzle(&name) {
+                Ok(puzzle) => puzzle,
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    return Ok(ExitCode::FAILURE);
+                }
+            };
+            if let Some(path) = local {
+                puzzle = puzzle.with_local_dependency(path);
+            }
+            let root = puzzle.write(dir)?;
+            println!("Created {}", root.display());
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
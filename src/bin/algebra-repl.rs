@@ -0,0 +1,37 @@
+//! An interactive calculator for BLS12-381; see [`ark_algebra_intro::repl`].
+//!
+//! ```text
+//! $ cargo run --bin algebra-repl
+//! > a = 6
+//! 6
+//! > pairing(a * g1, g2) == pairing(g1, g2)^6
+//! true
+//! ```
+
+use ark_algebra_intro::repl::Repl;
+use std::io::{self, BufRead, Write};
+
+fn main() -> io::Result<()> {
+    let mut repl = Repl::new();
+    let stdin = io::stdin();
+    let mut stdout = io::stdout();
+    println!("BLS12-381 calculator; type :help for help, :quit to exit.");
+    loop {
+        print!("> ");
+        stdout.flush()?;
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line)? == 0 {
+            // End of input.
+            println!();
+            return Ok(());
+        }
+        match line.trim() {
+            ":quit" | ":q" => return Ok(()),
+            line => match repl.handle(line) {
+                Ok(output) if output.is_empty() => {}
+                Ok(output) => println!("{}", output),
+                Err(e) => println!("error: {}", e),
+            },
+        }
+    }
+}
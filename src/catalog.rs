@@ -0,0 +1,153 @@
+//! A runtime-queryable catalog of the curves supported by this crate.
+//!
+//! The [`backends`](crate::backends) module makes the types of each curve available
+//! at compile time. Sometimes, however, it is more convenient to look at curves
+//! as *data*: to print a comparison table, to pick a curve by name from a
+//! command-line flag, or simply to see at a glance which curves support pairings.
+//! This module describes each curve with a [`CurveInfo`]:
+//!
+//! ```rust
+//! use ark_algebra_intro::catalog::supported_curves;
+//!
+//! for info in supported_curves() {
+//!     println!(
+//!         "{:<16} |Fq| = {:>3} bits, |Fr| = {:>3} bits, ~{} bits of security",
+//!         info.name, info.base_field_bits, info.scalar_field_bits, info.security_bits,
+//!     );
+//! }
+//!
+//! // BLS12-381 is always available; the other curves depend on the enabled features.
+//! assert!(supported_curves().iter().any(|info| info.name == "BLS12-381"));
+//! ```
+//!
+//! Field sizes and two-adicities are read off the `arkworks` parameters of each
+//! curve, so they are guaranteed to match the implementation. Security levels
+//! are estimates from the literature, and should be treated as such.
+
+use ark_ff::{FftParameters, FpParameters, PrimeField};
+use ark_std::vec::Vec;
+
+/// Metadata describing an elliptic curve supported by this crate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CurveInfo {
+    /// The conventional name of the curve, e.g. `"BLS12-381"`.
+    pub name: &'static str,
+    /// The bit length of the modulus of the base field `Fq`, over which the curve is defined.
+    pub base_field_bits: u32,
+    /// The bit length of the modulus of the scalar field `Fr`, i.e., of the order
+    /// of the prime-order subgroup.
+    pub scalar_field_bits: u32,
+    /// The (estimated) security level of the curve in bits, taking into account
+    /// the best known attacks on its group *and*, for pairing-friendly curves,
+    /// on discrete logarithms in the target group.
+    pub security_bits: u32,
+    /// The embedding degree of the curve, i.e., the smallest `k` such that
+    /// `|Fr|` divides `|Fq|^k - 1`.
+    ///
+    /// This is `None` for curves that are not pairing-friendly, whose embedding
+    /// degrees are astronomically large.
+    pub embedding_degree: Option<u32>,
+    /// Whether the curve supports an efficiently computable pairing.
+    pub pairing_friendly: bool,
+    /// The two-adicity of the scalar field, i.e., the largest `s` such that
+    /// `2^s` divides `|Fr| - 1`. This bounds the size of the FFTs
+    /// (and hence circuits) that the curve supports; see
+    /// [`max_fft_size`](crate::fields::max_fft_size).
+    pub scalar_two_adicity: u32,
+}
+
+impl CurveInfo {
+    /// Builds the entry for a curve with base field `Fq` and scalar field `Fr`.
+    fn new<Fq: PrimeField, Fr: PrimeField>(
+        name: &'static str,
+        security_bits: u32,
+        embedding_degree: Option<u32>,
+    ) -> Self {
+        CurveInfo {
+            name,
+            base_field_bits: Fq::Params::MODULUS_BITS,
+            scalar_field_bits: Fr::Params::MODULUS_BITS,
+            security_bits,
+            embedding_degree,
+            pairing_friendly: embedding_degree.is_some(),
+            scalar_two_adicity: <Fr::Params as FftParameters>::TWO_ADICITY,
+        }
+    }
+}
+
+/// Returns the metadata of every curve supported by this crate, given the
+/// enabled Cargo features.
+///
+/// ```rust
+/// use ark_algebra_intro::catalog::supported_curves;
+///
+/// let pairing_friendly: Vec<_> = supported_curves()
+///     .into_iter()
+///     .filter(|info| info.pairing_friendly)
+///     .map(|info| info.name)
+///     .collect();
+/// assert!(pairing_friendly.contains(&"BLS12-381"));
+/// ```
+pub fn supported_curves() -> Vec<CurveInfo> {
+    // Security estimates follow Guillevic (2020) for pairing-friendly curves,
+    // and Pollard's rho (half the subgroup size) for the others.
+    #[allow(unused_mut)]
+    let mut curves = vec![CurveInfo::new::<ark_bls12_381::Fq, ark_bls12_381::Fr>(
+        "BLS12-381",
+        126,
+        Some(12),
+    )];
+    #[cfg(feature = "bn254")]
+    curves.push(CurveInfo::new::<ark_bn254::Fq, ark_bn254::Fr>(
+        "BN254",
+        103,
+        Some(12),
+    ));
+    #[cfg(feature = "bls12-377")]
+    curves.push(CurveInfo::new::<ark_bls12_377::Fq, ark_bls12_377::Fr>(
+        "BLS12-377",
+        125,
+        Some(12),
+    ));
+    #[cfg(feature = "pallas")]
+    curves.push(CurveInfo::new::<ark_pallas::Fq, ark_pallas::Fr>(
+        "Pallas", 127, None,
+    ));
+    #[cfg(feature = "vesta")]
+    curves.push(CurveInfo::new::<ark_vesta::Fq, ark_vesta::Fr>(
+        "Vesta", 127, None,
+    ));
+    #[cfg(feature = "ed-on-bls12-381")]
+    curves.push(CurveInfo::new::<
+        ark_ed_on_bls12_381::Fq,
+        ark_ed_on_bls12_381::Fr,
+    >("Jubjub", 126, None));
+    #[cfg(feature = "ed25519")]
+    curves.push(CurveInfo::new::<
+        crate::backends::ed25519::Fq,
+        crate::backends::ed25519::Fr,
+    >("Ed25519", 126, None));
+    curves
+}
+
+/// Looks up a supported curve by name, ignoring ASCII case.
+///
+/// Returns `None` if no such curve exists, or if the feature enabling it is off.
+///
+/// ```rust
+/// use ark_algebra_intro::catalog::curve_info;
+/// use ark_algebra_intro::constants::bls12_381;
+///
+/// let info = curve_info("bls12-381").unwrap();
+/// assert_eq!(info.base_field_bits, bls12_381::FQ_MODULUS_BITS);
+/// assert_eq!(info.scalar_field_bits, bls12_381::FR_MODULUS_BITS);
+/// assert_eq!(info.scalar_two_adicity, bls12_381::FR_TWO_ADICITY);
+/// assert_eq!(info.embedding_degree, Some(bls12_381::EMBEDDING_DEGREE));
+///
+/// assert_eq!(curve_info("secp256k1"), None);
+/// ```
+pub fn curve_info(name: &str) -> Option<CurveInfo> {
+    supported_curves()
+        .into_iter()
+        .find(|info| info.name.eq_ignore_ascii_case(name))
+}
@@ -0,0 +1,8 @@
+//! Error-correcting codes.
+//!
+//! A code adds redundancy to data so that it survives losing or corrupting some of it.
+//! The codes here are *linear* over a field: codewords form a subspace, so they're
+//! described by matrices and polynomials, which is what makes them useful in proof
+//! systems (see [FRI](crate::commitments::fri)).
+
+pub mod reed_solomon;
@@ -0,0 +1,153 @@
+//! Reed–Solomon codes: messages as polynomials, codewords as their evaluations.
+//!
+//! A message of `k` field elements is read as the coefficients of a polynomial `f` of
+//! degree less than `k`, and encoded as its values `f(1), ..., f(n)` at `n ≥ k` points
+//! (here, like in [Shamir secret sharing](crate::protocols::shamir), the points
+//! `1, ..., n`). Two distinct polynomials of degree less than `k` agree on fewer than `k`
+//! points, so two distinct codewords differ in at least `n - k + 1` positions.
+//!
+//! In particular, any `k` of the `n` values determine `f` by interpolation, so the
+//! message survives the loss of any `n - k` of them (*erasures*, where the positions of
+//! the missing values are known):
+//!
+//! ```rust
+//! use ark_algebra_intro::codes::reed_solomon::{decode_erasures, encode};
+//! use ark_algebra_intro::Error;
+//! use ark_bls12_381::Fr;
+//!
+//! let message = vec![Fr::from(4u64), Fr::from(8u64), Fr::from(15u64)];
+//! let codeword = encode(&message, 6);
+//!
+//! // Lose half of the shares.
+//! let mut received: Vec<_> = codeword.into_iter().map(Some).collect();
+//! received[0] = None;
+//! received[2] = None;
+//! received[3] = None;
+//! assert_eq!(decode_erasures(&received, 3), Ok(message));
+//!
+//! // One more loss is too many.
+//! received[5] = None;
+//! assert_eq!(decode_erasures(&received, 3), Err(Error::Undecodable));
+//! ```
+//!
+//! This is how data-availability schemes spread data across many nodes, and the code
+//! whose proximity [FRI](crate::commitments::fri) tests, with the points of a
+//! multiplicative subgroup instead of `1, ..., n`.
+//!
+//! # Syndromes
+//!
+//! A received word can be checked without decoding it, with a *parity-check matrix* `H`
+//! whose kernel is exactly the code. For Reed–Solomon codes, `H` has `n - k` rows, and
+//! the row `j` is `(v_1 · x_1^j, ..., v_n · x_n^j)` with `v_i = 1 / ∏_{l ≠ i} (x_i - x_l)`:
+//! the product `H · c` collects the leading coefficients of the interpolations of
+//! `X^j · f(X)` over the `n` points, which vanish when `deg f < k`. These products are
+//! the [`syndromes`], and they are all zero exactly for codewords:
+//!
+//! ```rust
+//! use ark_algebra_intro::codes::reed_solomon::{encode, is_codeword, syndromes};
+//! use ark_bls12_381::Fr;
+//! use ark_ff::Zero;
+//!
+//! let message = vec![Fr::from(4u64), Fr::from(8u64), Fr::from(15u64)];
+//! let mut word = encode(&message, 6);
+//! assert!(syndromes(&word, 3).iter().all(Zero::is_zero));
+//!
+//! word[1] += Fr::from(1u64);
+//! assert!(!is_codeword(&word, 3));
+//! ```
+//!
+//! Correcting errors at unknown positions (up to `(n - k) / 2` of them) also starts from
+//! the syndromes, with algorithms such as Berlekamp–Welch or Berlekamp–Massey, which are
+//! beyond this module.
+
+use crate::poly::{evaluate, lagrange_interpolate};
+use crate::Error;
+use ark_ff::PrimeField;
+use ark_std::vec::Vec;
+
+/// Returns the evaluation point of the position `i` (counting from zero), `x_i = i + 1`.
+pub fn evaluation_point<F: PrimeField>(i: usize) -> F {
+    F::from(i as u64 + 1)
+}
+
+/// Encodes the `k` elements of `message` as the values at `1, ..., n` of the polynomial
+/// with coefficients `message`.
+///
+/// # Panics
+///
+/// Panics if `n` is less than the length of `message`.
+pub fn encode<F: PrimeField>(message: &[F], n: usize) -> Vec<F> {
+    assert!(
+        n >= message.len(),
+        "the codeword is shorter than the message"
+    );
+    (0..n)
+        .map(|i| evaluate(message, evaluation_point(i)))
+        .collect()
+}
+
+/// Recovers the message of length `k` from a codeword with erasures, marked as `None`.
+///
+/// Returns [`Error::Undecodable`] if fewer than `k` values are left, or if they are not
+/// consistent with any message (i.e., some of them are corrupted, not just missing).
+pub fn decode_erasures<F: PrimeField>(received: &[Option<F>], k: usize) -> Result<Vec<F>, Error> {
+    let known: Vec<_> = received
+        .iter()
+        .enumerate()
+        .filter_map(|(i, value)| value.map(|v| (evaluation_point::<F>(i), v)))
+        .collect();
+    if known.len() < k {
+        return Err(Error::Undecodable);
+    }
+    let message = lagrange_interpolate(&known[..k]);
+    let consistent = known[k..].iter().all(|(x, y)| evaluate(&message, *x) == *y);
+    if consistent {
+        Ok(message)
+    } else {
+        Err(Error::Undecodable)
+    }
+}
+
+/// Returns the `n - k` syndromes `Σ_i v_i · x_i^j · word_i` of `word`, for `j` from `0` to
+/// `n - k - 1`, which are all zero exactly when `word` is a codeword for messages of
+/// length `k`.
+///
+/// This takes `O(n²)` field operations.
+///
+/// # Panics
+///
+/// Panics if `word` is shorter than `k`.
+pub fn syndromes<F: PrimeField>(word: &[F], k: usize) -> Vec<F> {
+    let n = word.len();
+    assert!(n >= k, "the word is shorter than the message");
+    let points: Vec<F> = (0..n).map(evaluation_point).collect();
+    // The weighted values v_i · word_i.
+    let weighted: Vec<F> = points
+        .iter()
+        .zip(word)
+        .map(|(x_i, c_i)| {
+            let denominator: F = points
+                .iter()
+                .filter(|x_l| *x_l != x_i)
+                .map(|x_l| *x_i - x_l)
+                .product();
+            *c_i * denominator.inverse().expect("the points are distinct")
+        })
+        .collect();
+    let mut powers = vec![F::one(); n];
+    (0..n - k)
+        .map(|_| {
+            let syndrome = weighted.iter().zip(&powers).map(|(w, p)| *w * p).sum();
+            for (p, x) in powers.iter_mut().zip(&points) {
+                *p *= x;
+            }
+            syndrome
+        })
+        .collect()
+}
+
+/// Checks whether `word` is a codeword for messages of length `k`, i.e., whether all its
+/// [`syndromes`] vanish.
+pub fn is_codeword<F: PrimeField>(word: &[F], k: usize) -> bool {
+    syndromes(word, k).iter().all(|s| s.is_zero())
+}
@@ -0,0 +1,10 @@
+//! Commitment schemes.
+//!
+//! A commitment scheme lets someone fix a value now and reveal it later: the commitment
+//! is *hiding* (it says nothing about the value) and *binding* (it can only be opened to
+//! the value it was computed from).
+
+pub mod fri;
+pub mod ipa;
+pub mod kzg;
+pub mod pedersen;
@@ -0,0 +1,295 @@
+//! FRI, the hash-based low-degree test behind STARKs.
+//!
+//! [KZG](super::kzg) and the [IPA](super::ipa) rely on elliptic curves. FRI (Fast
+//! Reed–Solomon Interactive Oracle Proof of Proximity) only needs a hash function: the
+//! prover commits to the values of a function on a large domain with a [Merkle
+//! tree](crate::merkle), and convinces the verifier that this function is (close to) a
+//! polynomial of low degree, by opening a few random positions.
+//!
+//! # Folding
+//!
+//! A polynomial `p` of degree less than `d` splits into its even and odd parts,
+//! `p(X) = p_e(X²) + X · p_o(X²)`, which both have degree less than `d / 2`. For a
+//! challenge `α`, the *folded* polynomial `p'(Y) = p_e(Y) + α · p_o(Y)` has half the
+//! degree, and its values on the squares of the domain follow from two values of `p`:
+//!
+//! ```text
+//! p'(x²) = (p(x) + p(-x)) / 2 + α · (p(x) - p(-x)) / (2x).
+//! ```
+//!
+//! The domain is a multiplicative subgroup `L` of size `N = d · ρ⁻¹` for a *blowup factor*
+//! `ρ⁻¹`, so `-x` is in `L` whenever `x` is, and squaring maps `L` onto a subgroup of half
+//! the size. The prover folds and commits to each layer in turn, until the degree bound
+//! is small enough to send the last polynomial in the clear.
+//!
+//! # Queries
+//!
+//! The verifier then picks random positions `x` in the first domain, and for each one,
+//! checks the Merkle paths of `p_j(x_j)` and `p_j(-x_j)` in every layer `j`, and that
+//! folding them gives the value opened in the next layer (and finally, the value of the
+//! last polynomial). A function far from every low-degree polynomial fails each query
+//! with constant probability, so a few dozen queries give high confidence.
+//!
+//! ```rust
+//! use ark_algebra_intro::commitments::fri::{prove, verify, FriParameters};
+//! use ark_algebra_intro::poly::{DensePolynomial, UVPolynomial};
+//! use ark_bls12_381::Fr;
+//!
+//! let params = FriParameters::<Fr>::new(16, 4, 8);
+//! assert_eq!(params.domain_size(), 64);
+//!
+//! let coeffs = |n: u64| (1..=n).map(Fr::from).collect::<Vec<_>>();
+//! let p = DensePolynomial::from_coefficients_vec(coeffs(16));
+//! let proof = prove(&params, &p);
+//! assert!(verify(&params, &proof));
+//!
+//! // A polynomial of degree 31 is too large: some folded value won't match.
+//! let q = DensePolynomial::from_coefficients_vec(coeffs(32));
+//! assert!(!verify(&params, &prove(&params, &q)));
+//!
+//! // And every opened value is bound to the Merkle root of its layer.
+//! let mut tampered = proof.clone();
+//! tampered.queries[0][1].value += Fr::from(1u64);
+//! assert!(!verify(&params, &tampered));
+//! ```
+//!
+//! This is the textbook protocol, without the optimizations of production STARKs
+//! (cosets, folding by more than two, grinding, and batched Merkle openings).
+
+use crate::hashes::poseidon::PoseidonParameters;
+use crate::merkle::{self, MerkleProof, MerkleTree};
+use crate::poly::domains::{EvaluationDomain, Radix2EvaluationDomain};
+use crate::poly::{DensePolynomial, Polynomial, UVPolynomial};
+use crate::transcript::Transcript;
+use ark_ff::{Field, PrimeField};
+use ark_std::vec::Vec;
+
+/// The transcript label.
+const PROOF_LABEL: &[u8] = b"ark-algebra-intro/fri";
+
+/// The parameters of the protocol.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FriParameters<F: PrimeField> {
+    /// The degree bound `d`: the committed polynomial has degree less than `d`.
+    pub degree_bound: usize,
+    /// The blowup factor `ρ⁻¹`, the ratio of the domain size to the degree bound.
+    pub blowup_factor: usize,
+    /// The number of queries.
+    pub num_queries: usize,
+    /// The degree bound of the last polynomial, which is sent in the clear.
+    pub final_degree_bound: usize,
+    /// The hash for the Merkle trees.
+    pub hasher: PoseidonParameters<F>,
+}
+
+impl<F: PrimeField> FriParameters<F> {
+    /// Returns parameters for polynomials of degree less than `degree_bound`, folding down
+    /// to a constant, with Poseidon as the hash.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `degree_bound` or `blowup_factor` is not a power of two, or if
+    /// `blowup_factor` is less than 2.
+    pub fn new(degree_bound: usize, blowup_factor: usize, num_queries: usize) -> Self {
+        assert!(
+            degree_bound.is_power_of_two() && blowup_factor.is_power_of_two(),
+            "the degree bound and blowup factor must be powers of two"
+        );
+        assert!(blowup_factor >= 2, "the blowup factor must be at least 2");
+        Self {
+            degree_bound,
+            blowup_factor,
+            num_queries,
+            final_degree_bound: 1,
+            hasher: PoseidonParameters::recommended(),
+        }
+    }
+
+    /// Returns the size `N` of the first domain.
+    pub fn domain_size(&self) -> usize {
+        self.degree_bound * self.blowup_factor
+    }
+
+    /// Returns the number of folding rounds, `log₂(d / final_degree_bound)`.
+    pub fn num_rounds(&self) -> usize {
+        (self.degree_bound / self.final_degree_bound).trailing_zeros() as usize
+    }
+
+    /// Returns the conjectured security level in bits, `num_queries · log₂(ρ⁻¹)`.
+    ///
+    /// (Each query of a far-from-low-degree function is conjectured to pass with
+    /// probability about `ρ`; the proven bounds are weaker.)
+    pub fn conjectured_security_bits(&self) -> usize {
+        self.num_queries * self.blowup_factor.trailing_zeros() as usize
+    }
+}
+
+/// The openings of `p_j(x)` and `p_j(-x)` in one layer, for one query.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LayerOpening<F> {
+    /// The value `p_j(x)`.
+    pub value: F,
+    /// The value `p_j(-x)`.
+    pub sibling: F,
+    /// The Merkle proof of `value`.
+    pub value_proof: MerkleProof<F>,
+    /// The Merkle proof of `sibling`.
+    pub sibling_proof: MerkleProof<F>,
+}
+
+/// A FRI proof.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FriProof<F> {
+    /// The Merkle roots of the layers; the first is the commitment to the polynomial.
+    pub roots: Vec<F>,
+    /// The coefficients of the last polynomial.
+    pub final_coeffs: Vec<F>,
+    /// For each query, the openings in every layer.
+    pub queries: Vec<Vec<LayerOpening<F>>>,
+}
+
+/// Commits to the values of `p` on the domain, and proves that they have low degree.
+///
+/// If `p` does not satisfy the degree bound, the proof is still produced, but the
+/// verifier will (with high probability) reject it.
+pub fn prove<F: PrimeField>(params: &FriParameters<F>, p: &DensePolynomial<F>) -> FriProof<F> {
+    let domain = Radix2EvaluationDomain::<F>::new(params.domain_size())
+        .expect("the field has no domain of this size");
+    let mut evals = domain.fft(&p.coeffs);
+    let mut transcript = Transcript::new(PROOF_LABEL);
+    let (mut trees, mut roots) = (Vec::new(), Vec::new());
+
+    let mut generator = domain.group_gen;
+    for _ in 0..params.num_rounds() {
+        let tree = MerkleTree::new(&params.hasher, &evals);
+        transcript.append_field(b"root", &tree.root());
+        roots.push(tree.root());
+        trees.push((tree, evals.clone()));
+        let alpha = transcript.challenge_scalar(b"alpha");
+        evals = fold(&evals, generator, alpha);
+        generator.square_in_place();
+    }
+
+    // The last layer should be the evaluations of a polynomial of low degree.
+    let last_domain = Radix2EvaluationDomain::<F>::new(evals.len()).unwrap();
+    let mut final_coeffs = last_domain.ifft(&evals);
+    final_coeffs.truncate(params.final_degree_bound);
+    for coeff in &final_coeffs {
+        transcript.append_field(b"final", coeff);
+    }
+
+    let queries = query_indices(&mut transcript, params)
+        .into_iter()
+        .map(|index| {
+            trees
+                .iter()
+                .map(|(tree, evals)| {
+                    let half = evals.len() / 2;
+                    let i = index % evals.len();
+                    let sibling = (i + half) % evals.len();
+                    LayerOpening {
+                        value: evals[i],
+                        sibling: evals[sibling],
+                        value_proof: tree.prove(i),
+                        sibling_proof: tree.prove(sibling),
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    FriProof {
+        roots,
+        final_coeffs,
+        queries,
+    }
+}
+
+/// Checks that the function committed to by the first root of `proof` is (close to) a
+/// polynomial of degree less than the degree bound.
+pub fn verify<F: PrimeField>(params: &FriParameters<F>, proof: &FriProof<F>) -> bool {
+    if proof.roots.len() != params.num_rounds()
+        || proof.final_coeffs.len() > params.final_degree_bound
+        || proof.queries.len() != params.num_queries
+    {
+        return false;
+    }
+    let domain = match Radix2EvaluationDomain::<F>::new(params.domain_size()) {
+        Some(domain) => domain,
+        None => return false,
+    };
+    let mut transcript = Transcript::new(PROOF_LABEL);
+    let alphas: Vec<F> = proof
+        .roots
+        .iter()
+        .map(|root| {
+            transcript.append_field(b"root", root);
+            transcript.challenge_scalar(b"alpha")
+        })
+        .collect();
+    for coeff in &proof.final_coeffs {
+        transcript.append_field(b"final", coeff);
+    }
+    let final_poly = DensePolynomial::from_coefficients_slice(&proof.final_coeffs);
+
+    let indices = query_indices(&mut transcript, params);
+    indices.iter().zip(&proof.queries).all(|(&index, layers)| {
+        if layers.len() != params.num_rounds() {
+            return false;
+        }
+        let (mut size, mut generator) = (params.domain_size(), domain.group_gen);
+        let mut expected = None;
+        for ((root, alpha), layer) in proof.roots.iter().zip(&alphas).zip(layers) {
+            let i = index % size;
+            let sibling = (i + size / 2) % size;
+            let opened = layer.value_proof.index == i
+                && layer.sibling_proof.index == sibling
+                && merkle::verify(&params.hasher, root, &layer.value, &layer.value_proof)
+                && merkle::verify(&params.hasher, root, &layer.sibling, &layer.sibling_proof);
+            if !opened || expected.is_some_and(|v| v != layer.value) {
+                return false;
+            }
+            let x = generator.pow([i as u64]);
+            expected = Some(fold_pair(layer.value, layer.sibling, x, *alpha));
+            size /= 2;
+            generator.square_in_place();
+        }
+        expected == Some(final_poly.evaluate(&generator.pow([(index % size) as u64])))
+    })
+}
+
+/// Folds the values of `p` on the domain generated by `generator` into the values of
+/// `p'` on the domain of squares.
+fn fold<F: Field>(evals: &[F], generator: F, alpha: F) -> Vec<F> {
+    let half = evals.len() / 2;
+    let mut x = F::one();
+    (0..half)
+        .map(|i| {
+            let folded = fold_pair(evals[i], evals[i + half], x, alpha);
+            x *= generator;
+            folded
+        })
+        .collect()
+}
+
+/// Computes `p'(x²)` from `p(x)` and `p(-x)`.
+fn fold_pair<F: Field>(at_x: F, at_minus_x: F, x: F, alpha: F) -> F {
+    let two_inv = F::from(2u64).inverse().unwrap();
+    let even = (at_x + at_minus_x) * two_inv;
+    let odd = (at_x - at_minus_x) * (x.double()).inverse().unwrap();
+    even + alpha * odd
+}
+
+/// Squeezes the query positions in the first domain.
+fn query_indices<F: PrimeField>(
+    transcript: &mut Transcript,
+    params: &FriParameters<F>,
+) -> Vec<usize> {
+    (0..params.num_queries)
+        .map(|_| {
+            let bytes = transcript.challenge_bytes(b"query", 8);
+            let mut word = [0u8; 8];
+            word.copy_from_slice(&bytes);
+            (u64::from_be_bytes(word) % params.domain_size() as u64) as usize
+        })
+        .collect()
+}
@@ -0,0 +1,313 @@
+//! Polynomial commitments from inner-product arguments.
+//!
+//! [KZG](super::kzg) needs a pairing and a trusted setup. The inner-product argument
+//! (IPA) of Bulletproofs needs neither: it works over any prime-order group where
+//! discrete logarithms are hard, such as Pallas, and its parameters are just random
+//! generators `G_0, ..., G_{n-1}, U` derived by hashing, so there is no toxic waste.
+//! This is the commitment scheme of Halo 2.
+//!
+//! # Commitments and openings
+//!
+//! A polynomial `p(X) = Σ a_i X^i` of degree less than `n` is committed to as
+//! `C = Σ a_i · G_i` (a [Pedersen vector commitment](super::pedersen), without blinding).
+//! Its value at `z` is the inner product `v = <a, b>` with `b = (1, z, ..., z^{n-1})`, so
+//! proving `p(z) = v` amounts to proving that `C + v · U` has the form
+//! `<a, G> + <a, b> · U` for some vector `a`.
+//!
+//! Sending `a` would take `n` scalars. Instead, the prover *folds* the problem in half,
+//! `log₂ n` times: in each round, with a challenge `x`, it replaces
+//!
+//! ```text
+//! a ← a_lo · x + a_hi · x⁻¹,   b ← b_lo · x⁻¹ + b_hi · x,   G ← G_lo · x⁻¹ + G_hi · x,
+//! ```
+//!
+//! and sends the two cross terms `L = <a_lo, G_hi> + <a_lo, b_hi> · U` and
+//! `R = <a_hi, G_lo> + <a_hi, b_lo> · U`, since the new claim is
+//! `P ← x² · L + P + x⁻² · R`. After the last round, `a` is a single scalar, which the
+//! prover sends.
+//!
+//! ```rust
+//! use ark_algebra_intro::commitments::ipa::{commit, open, verify, Parameters};
+//! use ark_algebra_intro::poly::{DensePolynomial, Polynomial, UVPolynomial};
+//! use ark_bls12_381::{Fr, G1Affine};
+//! use ark_std::UniformRand;
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let params = Parameters::<G1Affine>::new(16, b"example");
+//! let p = DensePolynomial::<Fr>::rand(15, &mut rng);
+//! let commitment = commit(&params, &p);
+//!
+//! let z = Fr::rand(&mut rng);
+//! let (value, proof) = open(&params, &p, z);
+//! assert_eq!(value, p.evaluate(&z));
+//! assert!(verify(&params, &commitment, z, value, &proof));
+//! assert!(!verify(&params, &commitment, z, value + Fr::from(1u64), &proof));
+//!
+//! // A proof with the wrong number of rounds is rejected, however many it has.
+//! let mut long_proof = proof.clone();
+//! long_proof.left.resize(64, long_proof.left[0]);
+//! long_proof.right.resize(64, long_proof.right[0]);
+//! assert!(!verify(&params, &commitment, z, value, &long_proof));
+//! ```
+//!
+//! # Costs
+//!
+//! Compared to KZG, the proof is logarithmic rather than constant in size: `2 log₂ n`
+//! group elements and one scalar. Verification is *linear*: the verifier has to fold
+//! the generators too, which it does with one multi-scalar multiplication of size `n`.
+//! (Halo's accumulation schemes defer this cost across many proofs.)
+//!
+//! ```rust
+//! # #[cfg(feature = "pallas")]
+//! # {
+//! use ark_algebra_intro::commitments::ipa::{commit, open, verify, Parameters};
+//! use ark_algebra_intro::poly::{DensePolynomial, UVPolynomial};
+//! use ark_pallas::{Affine, Fr};
+//! use ark_std::UniformRand;
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let params = Parameters::<Affine>::new(64, b"example");
+//! let p = DensePolynomial::<Fr>::rand(63, &mut rng);
+//! let z = Fr::rand(&mut rng);
+//! let (value, proof) = open(&params, &p, z);
+//! assert!(verify(&params, &commit(&params, &p), z, value, &proof));
+//!
+//! // For 64 coefficients: 6 rounds, so 12 points and a scalar.
+//! assert_eq!(proof.left.len(), 6);
+//! assert_eq!(proof.right.len(), 6);
+//! # }
+//! ```
+
+use crate::hash_to_curve::hash_to_group;
+use crate::poly::DensePolynomial;
+use crate::transcript::Transcript;
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{batch_inversion, Field, One, Zero};
+use ark_std::vec::Vec;
+
+/// The Fiat–Shamir transcript label.
+const PROOF_LABEL: &[u8] = b"ark-algebra-intro/ipa";
+
+/// The public parameters: generators `G_0, ..., G_{n-1}` and `U`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Parameters<G: AffineCurve> {
+    /// The generators `G_i` for the coefficients.
+    pub generators: Vec<G>,
+    /// The generator `U` for the inner product.
+    pub u: G,
+}
+
+/// An evaluation proof.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proof<G: AffineCurve> {
+    /// The cross terms `L` of each round.
+    pub left: Vec<G>,
+    /// The cross terms `R` of each round.
+    pub right: Vec<G>,
+    /// The fully folded vector `a`, which is a single scalar.
+    pub a: G::ScalarField,
+}
+
+impl<G: AffineCurve> Parameters<G> {
+    /// Derives parameters for polynomials with up to `size` coefficients, by hashing
+    /// `label` and an index to the curve for each generator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is not a power of two.
+    pub fn new(size: usize, label: &[u8]) -> Self {
+        assert!(size.is_power_of_two(), "the size must be a power of two");
+        Self {
+            generators: (0..size as u64)
+                .map(|i| hash_to_group(label, i + 1))
+                .collect(),
+            u: hash_to_group(label, 0),
+        }
+    }
+
+    /// Returns the maximum number of coefficients of polynomials that can be committed to.
+    pub fn size(&self) -> usize {
+        self.generators.len()
+    }
+}
+
+/// Commits to `p`.
+///
+/// # Panics
+///
+/// Panics if `p` has more coefficients than the parameters support.
+pub fn commit<G: AffineCurve>(params: &Parameters<G>, p: &DensePolynomial<G::ScalarField>) -> G {
+    assert!(
+        p.coeffs.len() <= params.size(),
+        "the parameters only support {} coefficients",
+        params.size()
+    );
+    crate::msm::arkworks(&params.generators[..p.coeffs.len()], &p.coeffs).into_affine()
+}
+
+/// Evaluates `p` at `point`, and proves that evaluation.
+///
+/// # Panics
+///
+/// Panics if `p` has more coefficients than the parameters support.
+pub fn open<G: AffineCurve>(
+    params: &Parameters<G>,
+    p: &DensePolynomial<G::ScalarField>,
+    point: G::ScalarField,
+) -> (G::ScalarField, Proof<G>) {
+    let commitment = commit(params, p);
+    let mut a = p.coeffs.clone();
+    a.resize(params.size(), G::ScalarField::zero());
+    let mut b = powers(point, params.size());
+    let value = inner_product(&a, &b);
+    let mut g: Vec<_> = params
+        .generators
+        .iter()
+        .map(|g| g.into_projective())
+        .collect();
+
+    let mut transcript = start_transcript(params, &commitment, point, value);
+    let (mut left, mut right) = (Vec::new(), Vec::new());
+    while a.len() > 1 {
+        let half = a.len() / 2;
+        let (a_lo, a_hi) = a.split_at(half);
+        let (b_lo, b_hi) = b.split_at(half);
+        let (g_lo, g_hi) = g.split_at(half);
+        let g_lo = G::Projective::batch_normalization_into_affine(g_lo);
+        let g_hi = G::Projective::batch_normalization_into_affine(g_hi);
+
+        let l = crate::msm::arkworks(&g_hi, a_lo) + params.u.mul(inner_product(a_lo, b_hi));
+        let r = crate::msm::arkworks(&g_lo, a_hi) + params.u.mul(inner_product(a_hi, b_lo));
+        let (l, r) = (l.into_affine(), r.into_affine());
+        let x = challenge(&mut transcript, &l, &r);
+        let x_inv = x.inverse().expect("challenges are nonzero");
+        left.push(l);
+        right.push(r);
+
+        a = fold(a_lo, a_hi, x, x_inv);
+        b = fold(b_lo, b_hi, x_inv, x);
+        g = g_lo
+            .iter()
+            .zip(&g_hi)
+            .map(|(lo, hi)| lo.mul(x_inv) + hi.mul(x))
+            .collect();
+    }
+    let proof = Proof {
+        left,
+        right,
+        a: a[0],
+    };
+    (value, proof)
+}
+
+/// Checks that `proof` proves that the polynomial committed to in `commitment` takes the
+/// value `value` at `point`.
+///
+/// The verifier replays the challenges `x_1, ..., x_k`, folds the claim
+/// `P = C + v · U` into `Σ (x_j² · L_j + x_j⁻² · R_j) + P`, and checks it against
+/// `a · G' + a · b' · U`. The folded generator `G' = Σ s_i · G_i` and the folded vector
+/// `b' = Σ s_i · z^i` use the same coefficients `s_i`: the product, over all rounds `j`,
+/// of `x_j` if bit `k - j` of `i` is set (`G_i` was in the upper half), and `x_j⁻¹`
+/// otherwise.
+pub fn verify<G: AffineCurve>(
+    params: &Parameters<G>,
+    commitment: &G,
+    point: G::ScalarField,
+    value: G::ScalarField,
+    proof: &Proof<G>,
+) -> bool {
+    // `rounds` comes from the proof, so it is compared to log₂ n rather than shifted,
+    // which would overflow for a proof of 64 rounds or more.
+    let rounds = proof.left.len();
+    if !params.size().is_power_of_two()
+        || rounds != params.size().trailing_zeros() as usize
+        || proof.right.len() != rounds
+    {
+        return false;
+    }
+
+    let mut transcript = start_transcript(params, commitment, point, value);
+    let challenges: Vec<_> = proof
+        .left
+        .iter()
+        .zip(&proof.right)
+        .map(|(l, r)| challenge(&mut transcript, l, r))
+        .collect();
+    if challenges.iter().any(|x| x.is_zero()) {
+        return false;
+    }
+    let mut inverses = challenges.clone();
+    batch_inversion(&mut inverses);
+
+    let mut claim = commitment.into_projective() + params.u.mul(value);
+    for ((l, r), (x, x_inv)) in proof
+        .left
+        .iter()
+        .zip(&proof.right)
+        .zip(challenges.iter().zip(&inverses))
+    {
+        claim += l.mul(x.square()) + r.mul(x_inv.square());
+    }
+
+    let s: Vec<_> = (0..params.size())
+        .map(|i| {
+            (0..rounds).fold(G::ScalarField::one(), |s, j| {
+                let in_upper_half = (i >> (rounds - 1 - j)) & 1 == 1;
+                s * if in_upper_half {
+                    challenges[j]
+                } else {
+                    inverses[j]
+                }
+            })
+        })
+        .collect();
+    let g = crate::msm::arkworks(&params.generators, &s);
+    let b = inner_product(&s, &powers(point, params.size()));
+    claim == g.into_affine().mul(proof.a) + params.u.mul(proof.a * b)
+}
+
+/// Starts the transcript with the statement: the parameters, the commitment, the point
+/// and the claimed value.
+fn start_transcript<G: AffineCurve>(
+    params: &Parameters<G>,
+    commitment: &G,
+    point: G::ScalarField,
+    value: G::ScalarField,
+) -> Transcript {
+    let mut transcript = Transcript::new(PROOF_LABEL);
+    transcript.append_serializable(b"generators", &params.generators);
+    transcript.append_point(b"u", &params.u);
+    transcript.append_point(b"commitment", commitment);
+    transcript.append_field(b"point", &point);
+    transcript.append_field(b"value", &value);
+    transcript
+}
+
+/// Absorbs the cross terms of a round, and squeezes its challenge.
+fn challenge<G: AffineCurve>(transcript: &mut Transcript, l: &G, r: &G) -> G::ScalarField {
+    transcript.append_point(b"L", l);
+    transcript.append_point(b"R", r);
+    transcript.challenge_scalar(b"x")
+}
+
+fn fold<F: Field>(lo: &[F], hi: &[F], x_lo: F, x_hi: F) -> Vec<F> {
+    lo.iter()
+        .zip(hi)
+        .map(|(l, h)| *l * x_lo + *h * x_hi)
+        .collect()
+}
+
+fn inner_product<F: Field>(a: &[F], b: &[F]) -> F {
+    a.iter().zip(b).map(|(a, b)| *a * b).sum()
+}
+
+/// Returns `(1, z, ..., z^{n-1})`.
+fn powers<F: Field>(z: F, n: usize) -> Vec<F> {
+    let mut powers = Vec::with_capacity(n);
+    let mut power = F::one();
+    for _ in 0..n {
+        powers.push(power);
+        power *= z;
+    }
+    powers
+}
@@ -0,0 +1,297 @@
+//! KZG polynomial commitments.
+//!
+//! The KZG (Kate–Zaverucha–Goldberg) scheme commits to a polynomial `p` of degree at
+//! most `d` with a single G1 point, and later proves the value `p(z)` at any point `z`
+//! with another single G1 point, whatever the degree.
+//!
+//! # Setup
+//!
+//! A trusted party samples a secret `τ` and publishes the *structured reference string*
+//! `[τ^i]_1 = τ^i · G` for `i ≤ d`, together with `[τ^i]_2 = τ^i · H` in G2. It must
+//! then forget `τ`: anyone who knows it can open commitments to arbitrary values (see
+//! [`Srs::from_tau`]).
+//!
+//! # Commitments and openings
+//!
+//! The commitment to `p(X) = Σ p_i X^i` is `C = [p(τ)]_1 = Σ p_i · [τ^i]_1`, a
+//! multi-scalar multiplication with the SRS. To prove that `p(z) = v`, the prover uses
+//! that `X - z` divides `p(X) - v` exactly when `p(z) = v`, and commits to the quotient
+//! `q(X) = (p(X) - v) / (X - z)`: the proof is `π = [q(τ)]_1`.
+//!
+//! The verifier can't compute `q(τ)` itself, but it can check the identity
+//! `p(τ) - v = q(τ) · (τ - z)` "in the exponent", with a pairing:
+//!
+//! ```text
+//! e(C - [v]_1, H) = e(π, [τ]_2 - [z]_2).
+//! ```
+//!
+//! Both sides equal `e(G, H)^{(p(τ) - v)}`, and by the bilinearity of the pairing each
+//! side only needs the points the verifier has. A cheating prover would need
+//! `q(τ) = (p(τ) - v') / (τ - z)` for some `v' ≠ v`, which is infeasible without knowing
+//! `τ` (under the `q`-strong Diffie–Hellman assumption).
+//!
+//! ```rust
+//! use ark_algebra_intro::commitments::kzg::{commit, open, verify, Srs};
+//! use ark_algebra_intro::poly::{DensePolynomial, Polynomial, UVPolynomial};
+//! use ark_bls12_381::{Bls12_381, Fr};
+//! use ark_std::UniformRand;
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let srs = Srs::<Bls12_381>::setup(8, &mut rng);
+//!
+//! let p = DensePolynomial::<Fr>::rand(8, &mut rng);
+//! let commitment = commit(&srs, &p);
+//!
+//! let z = Fr::rand(&mut rng);
+//! let (value, proof) = open(&srs, &p, z);
+//! assert_eq!(value, p.evaluate(&z));
+//! assert!(verify(&srs, &commitment, z, value, &proof));
+//! assert!(!verify(&srs, &commitment, z, value + Fr::from(1u64), &proof));
+//! ```
+//!
+//! The `09_kzg_commitments` example walks through this module:
+//! `cargo run --release --example 09_kzg_commitments`.
+
+use crate::pairings::pairing_ratio_check;
+use crate::poly::{
+    divide_with_remainder, from_roots, lagrange_interpolate, DensePolynomial, Polynomial,
+    UVPolynomial,
+};
+use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::{One, UniformRand, Zero};
+use ark_std::rand::Rng;
+use ark_std::vec::Vec;
+
+/// A structured reference string for polynomials of degree at most [`Srs::max_degree`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Srs<E: PairingEngine> {
+    /// The powers `[τ^i]_1` for `0 ≤ i ≤ d`.
+    pub powers_of_g: Vec<E::G1Affine>,
+    /// The powers `[τ^i]_2` for `0 ≤ i ≤ d`, which are used to check
+    /// [batch openings](open_batch).
+    pub powers_of_h: Vec<E::G2Affine>,
+}
+
+/// A commitment `[p(τ)]_1` to a polynomial.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Commitment<E: PairingEngine>(pub E::G1Affine);
+
+/// A proof `[q(τ)]_1` of the value of a committed polynomial at one or several points.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Proof<E: PairingEngine>(pub E::G1Affine);
+
+impl<E: PairingEngine> Srs<E> {
+    /// Samples a fresh secret `τ`, computes the SRS for polynomials of degree at most
+    /// `max_degree`, and throws `τ` away.
+    pub fn setup<R: Rng + ?Sized>(max_degree: usize, rng: &mut R) -> Self {
+        Self::from_tau(max_degree, E::Fr::rand(rng))
+    }
+
+    /// Computes the SRS for the given secret `τ`.
+    ///
+    /// This is deterministic, which makes it useful for test vectors: a commitment is
+    /// just `p(τ) · G`.
+    ///
+    /// ```rust
+    /// use ark_algebra_intro::commitments::kzg::{commit, Srs};
+    /// use ark_algebra_intro::poly::{DensePolynomial, UVPolynomial};
+    /// use ark_bls12_381::{Bls12_381, Fr, G1Affine};
+    /// use ark_ec::{AffineCurve, ProjectiveCurve};
+    /// use ark_serialize::CanonicalSerialize;
+    ///
+    /// let srs = Srs::<Bls12_381>::from_tau(2, Fr::from(42u64));
+    /// // 1 + 2X + 3X^2 at 42 is 5377.
+    /// let p = DensePolynomial::from_coefficients_vec(vec![Fr::from(1u64), Fr::from(2u64), Fr::from(3u64)]);
+    /// let commitment = commit(&srs, &p);
+    /// let g = G1Affine::prime_subgroup_generator();
+    /// assert_eq!(commitment.0, g.mul(5377u64).into_affine());
+    ///
+    /// let mut bytes = Vec::new();
+    /// commitment.0.serialize(&mut bytes).unwrap();
+    /// let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    /// assert_eq!(hex, "b31cb96f14a031836b7684081f37906ac5f88cf773415b360a3aa2a45281c8dad303e8f8c5973f51abfbcfdc45926483");
+    /// ```
+    ///
+    /// But the SRS must never be generated from a *known* `τ` in practice: knowing `τ`,
+    /// one can "prove" any value `v'` at `z` with `π = ((p(τ) - v') / (τ - z)) · G`.
+    ///
+    /// ```rust
+    /// use ark_algebra_intro::commitments::kzg::{commit, verify, Proof, Srs};
+    /// use ark_algebra_intro::poly::{DensePolynomial, Polynomial, UVPolynomial};
+    /// use ark_bls12_381::{Bls12_381, Fr, G1Affine};
+    /// use ark_ec::{AffineCurve, ProjectiveCurve};
+    /// use ark_ff::Field;
+    /// use ark_std::UniformRand;
+    ///
+    /// let mut rng = ark_std::rand::thread_rng();
+    /// let tau = Fr::rand(&mut rng);
+    /// let srs = Srs::<Bls12_381>::from_tau(4, tau);
+    /// let p = DensePolynomial::<Fr>::rand(4, &mut rng);
+    /// let commitment = commit(&srs, &p);
+    ///
+    /// let (z, forged) = (Fr::from(3u64), Fr::from(1234u64));
+    /// assert_ne!(p.evaluate(&z), forged);
+    /// let q_at_tau = (p.evaluate(&tau) - forged) * (tau - z).inverse().unwrap();
+    /// let proof = Proof(G1Affine::prime_subgroup_generator().mul(q_at_tau).into_affine());
+    /// assert!(verify(&srs, &commitment, z, forged, &proof));
+    /// ```
+    pub fn from_tau(max_degree: usize, tau: E::Fr) -> Self {
+        let mut powers = Vec::with_capacity(max_degree + 1);
+        let mut power = E::Fr::one();
+        for _ in 0..=max_degree {
+            powers.push(power);
+            power *= tau;
+        }
+        let g = E::G1Affine::prime_subgroup_generator();
+        let h = E::G2Affine::prime_subgroup_generator();
+        let powers_of_g: Vec<_> = powers.iter().map(|p| g.mul(*p)).collect();
+        let powers_of_h: Vec<_> = powers.iter().map(|p| h.mul(*p)).collect();
+        Self {
+            powers_of_g: E::G1Projective::batch_normalization_into_affine(&powers_of_g),
+            powers_of_h: E::G2Projective::batch_normalization_into_affine(&powers_of_h),
+        }
+    }
+
+    /// Returns the maximum degree of polynomials that can be committed to.
+    pub fn max_degree(&self) -> usize {
+        self.powers_of_g.len() - 1
+    }
+}
+
+/// Commits to `p`.
+///
+/// # Panics
+///
+/// Panics if the degree of `p` exceeds the maximum degree of the SRS.
+pub fn commit<E: PairingEngine>(srs: &Srs<E>, p: &DensePolynomial<E::Fr>) -> Commitment<E> {
+    Commitment(commit_g1(&srs.powers_of_g, p))
+}
+
+/// Evaluates `p` at `point`, and proves that evaluation.
+///
+/// # Panics
+///
+/// Panics if the degree of `p` exceeds the maximum degree of the SRS.
+pub fn open<E: PairingEngine>(
+    srs: &Srs<E>,
+    p: &DensePolynomial<E::Fr>,
+    point: E::Fr,
+) -> (E::Fr, Proof<E>) {
+    let (values, proof) = open_batch(srs, p, &[point]);
+    (values[0], proof)
+}
+
+/// Checks that `proof` proves that the polynomial committed to in `commitment` takes the
+/// value `value` at `point`, by checking `e(C - [v]_1, H) = e(π, [τ]_2 - [z]_2)`.
+pub fn verify<E: PairingEngine>(
+    srs: &Srs<E>,
+    commitment: &Commitment<E>,
+    point: E::Fr,
+    value: E::Fr,
+    proof: &Proof<E>,
+) -> bool {
+    verify_batch(srs, commitment, &[point], &[value], proof)
+}
+
+/// Evaluates `p` at all of `points`, and proves all the evaluations with a single proof.
+///
+/// This generalizes [`open`] from one point to several: with `I` the polynomial that
+/// [interpolates](lagrange_interpolate) the values at the points, and `Z` the polynomial
+/// that [vanishes](from_roots) at them, `Z` divides `p - I` exactly when `p` agrees with
+/// `I` at all the points. The proof is a commitment to the quotient `(p - I) / Z`, and the
+/// verifier checks
+///
+/// ```text
+/// e(C - [I(τ)]_1, H) = e(π, [Z(τ)]_2),
+/// ```
+///
+/// which is why the SRS also contains powers of `τ` in G2.
+///
+/// ```rust
+/// use ark_algebra_intro::commitments::kzg::{commit, open_batch, verify_batch, Srs};
+/// use ark_algebra_intro::poly::{DensePolynomial, UVPolynomial};
+/// use ark_bls12_381::{Bls12_381, Fr};
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let srs = Srs::<Bls12_381>::setup(8, &mut rng);
+/// let p = DensePolynomial::<Fr>::rand(8, &mut rng);
+/// let commitment = commit(&srs, &p);
+///
+/// let points: Vec<_> = (0..3).map(|_| Fr::rand(&mut rng)).collect();
+/// let (mut values, proof) = open_batch(&srs, &p, &points);
+/// assert!(verify_batch(&srs, &commitment, &points, &values, &proof));
+///
+/// values[1] += Fr::from(1u64);
+/// assert!(!verify_batch(&srs, &commitment, &points, &values, &proof));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the degree of `p` exceeds the maximum degree of the SRS, or if the points
+/// are not distinct.
+pub fn open_batch<E: PairingEngine>(
+    srs: &Srs<E>,
+    p: &DensePolynomial<E::Fr>,
+    points: &[E::Fr],
+) -> (Vec<E::Fr>, Proof<E>) {
+    let values: Vec<_> = points.iter().map(|z| p.evaluate(z)).collect();
+    let interpolant = interpolant(points, &values);
+    let (quotient, remainder) = divide_with_remainder(&(p - &interpolant), &from_roots(points));
+    debug_assert!(remainder.is_zero());
+    (values, Proof(commit_g1(&srs.powers_of_g, &quotient)))
+}
+
+/// Checks a proof produced by [`open_batch`].
+///
+/// Returns `false` if there are more points than the maximum degree of the SRS, or if the
+/// points are not distinct.
+///
+/// # Panics
+///
+/// Panics if `points` and `values` have different lengths.
+pub fn verify_batch<E: PairingEngine>(
+    srs: &Srs<E>,
+    commitment: &Commitment<E>,
+    points: &[E::Fr],
+    values: &[E::Fr],
+    proof: &Proof<E>,
+) -> bool {
+    assert_eq!(
+        points.len(),
+        values.len(),
+        "there must be one value per point"
+    );
+    let mut sorted = points.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+    if points.is_empty() || points.len() > srs.max_degree() || sorted.len() != points.len() {
+        return false;
+    }
+    let interpolant = commit_g1(&srs.powers_of_g, &interpolant(points, values));
+    let vanishing = from_roots(points);
+    let vanishing = crate::msm::arkworks(
+        &srs.powers_of_h[..vanishing.coeffs.len()],
+        &vanishing.coeffs,
+    );
+    let lhs = (commitment.0.into_projective() - interpolant.into_projective()).into_affine();
+    let h = srs.powers_of_h[0];
+    pairing_ratio_check::<E>((lhs, h), (proof.0, vanishing.into_affine()))
+}
+
+/// Returns the polynomial of degree less than `points.len()` taking the given values.
+fn interpolant<F: ark_ff::Field>(points: &[F], values: &[F]) -> DensePolynomial<F> {
+    let pairs: Vec<_> = points.iter().copied().zip(values.iter().copied()).collect();
+    DensePolynomial::from_coefficients_vec(lagrange_interpolate(&pairs))
+}
+
+/// Computes `[p(τ)]` from the powers `[τ^i]`.
+fn commit_g1<G: AffineCurve>(powers: &[G], p: &DensePolynomial<G::ScalarField>) -> G {
+    assert!(
+        p.coeffs.len() <= powers.len(),
+        "the polynomial has degree {}, but the SRS only supports degree {}",
+        p.degree(),
+        powers.len() - 1
+    );
+    crate::msm::arkworks(&powers[..p.coeffs.len()], &p.coeffs).into_affine()
+}
@@ -0,0 +1,192 @@
+//! Pedersen commitments over BLS12-381's G1.
+//!
+//! A Pedersen commitment to a vector of scalars `m_1, ..., m_n` with *blinding factor*
+//! `r` is the point
+//!
+//! ```text
+//! C = m_1 · G_1 + ... + m_n · G_n + r · H
+//! ```
+//!
+//! for fixed generators `G_1, ..., G_n, H`, which together form the [`CommitmentKey`].
+//! Committing to a single scalar is the special case `n = 1`.
+//!
+//! * The commitment is *perfectly hiding*: for a uniformly random `r`, `r · H` is a
+//!   uniformly random point, so `C` is too, whatever the `m_i` are.
+//! * It is *computationally binding*, as long as nobody knows a linear relation between
+//!   the generators. Opening `C` to two different vectors would give such a relation, so
+//!   the generators must be derived such that nobody knows their discrete logarithms
+//!   with respect to each other. [`CommitmentKey::new`] does this by
+//!   [hashing to the curve](crate::hash_to_curve), and [`CommitmentKey::new_broken`]
+//!   shows what goes wrong otherwise.
+//!
+//! ```rust
+//! use ark_algebra_intro::commitments::pedersen::{commit, open, CommitmentKey};
+//! use ark_bls12_381::Fr;
+//! use ark_std::UniformRand;
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let key = CommitmentKey::new(3, b"example");
+//! let values = [Fr::from(1u64), Fr::from(2u64), Fr::from(3u64)];
+//! let blinding = Fr::rand(&mut rng);
+//!
+//! let commitment = commit(&key, &values, blinding);
+//! assert!(open(&key, &commitment, &values, blinding));
+//! // The commitment does not open to anything else...
+//! assert!(!open(&key, &commitment, &[Fr::from(1u64), Fr::from(2u64), Fr::from(4u64)], blinding));
+//! // ...and without the blinding factor, equal values give unrelated commitments.
+//! assert_ne!(commitment, commit(&key, &values, Fr::rand(&mut rng)));
+//! ```
+//!
+//! Pedersen commitments are also *additively homomorphic*: adding two commitments gives a
+//! commitment to the sum of the values, with the sum of the blinding factors.
+//!
+//! ```rust
+//! use ark_algebra_intro::commitments::pedersen::{add, commit, open, CommitmentKey};
+//! use ark_bls12_381::Fr;
+//!
+//! let key = CommitmentKey::new(1, b"example");
+//! let a = commit(&key, &[Fr::from(20u64)], Fr::from(1u64));
+//! let b = commit(&key, &[Fr::from(22u64)], Fr::from(2u64));
+//! assert!(open(&key, &add(&a, &b), &[Fr::from(42u64)], Fr::from(3u64)));
+//! ```
+//!
+//! The `08_pedersen_commitments` example walks through this module:
+//! `cargo run --example 08_pedersen_commitments`.
+
+use crate::hash_to_curve::hash_to_g1;
+use crate::protocols::hash_to_scalar;
+use ark_bls12_381::{Fr, G1Affine, G1Projective};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_std::vec::Vec;
+
+/// The generators used to compute Pedersen commitments.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommitmentKey {
+    /// The generators `G_1, ..., G_n` for the committed values.
+    pub generators: Vec<G1Affine>,
+    /// The generator `H` for the blinding factor.
+    pub blinding_generator: G1Affine,
+}
+
+/// A Pedersen commitment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Commitment(pub G1Affine);
+
+impl CommitmentKey {
+    /// Derives a key for vectors of up to `size` values, by hashing `label` and the index
+    /// of each generator to the curve.
+    ///
+    /// Since the generators are outputs of a hash function, nobody knows a relation
+    /// between them, and anyone can recompute them from `label` to check that.
+    pub fn new(size: usize, label: &[u8]) -> Self {
+        let dst = b"ARK-ALGEBRA-INTRO-PEDERSEN-GENERATORS";
+        let generator = |index: u64| hash_to_g1(&[label, &index.to_be_bytes()].concat(), dst);
+        Self {
+            generators: (1..=size as u64).map(generator).collect(),
+            blinding_generator: generator(0),
+        }
+    }
+
+    /// Derives a key the *wrong* way, as `hash(label, i) · G` for the generator `G` of G1.
+    ///
+    /// These generators look just as random as those of [`CommitmentKey::new`], but
+    /// anyone can recompute their discrete logarithms with [`broken_trapdoor`] and use
+    /// them to open commitments to arbitrary values:
+    ///
+    /// ```rust
+    /// use ark_algebra_intro::commitments::pedersen::{
+    ///     broken_trapdoor, commit, open, CommitmentKey,
+    /// };
+    /// use ark_bls12_381::Fr;
+    /// use ark_ff::Field;
+    ///
+    /// let key = CommitmentKey::new_broken(1, b"example");
+    /// let commitment = commit(&key, &[Fr::from(1u64)], Fr::from(5u64));
+    ///
+    /// // With `G_1 = a · G` and `H = b · G`, the commitment is `(m · a + r · b) · G`, so
+    /// // for any other value `m'`, the blinding factor `r' = r + (m - m') · a / b` also
+    /// // opens it.
+    /// let (a, b) = (broken_trapdoor(b"example", 1), broken_trapdoor(b"example", 0));
+    /// let forged = Fr::from(1_000_000u64);
+    /// let blinding = Fr::from(5u64) + (Fr::from(1u64) - forged) * a * b.inverse().unwrap();
+    /// assert!(open(&key, &commitment, &[forged], blinding));
+    /// ```
+    pub fn new_broken(size: usize, label: &[u8]) -> Self {
+        let g = G1Affine::prime_subgroup_generator();
+        let generator = |index: u64| g.mul(broken_trapdoor(label, index)).into_affine();
+        Self {
+            generators: (1..=size as u64).map(generator).collect(),
+            blinding_generator: generator(0),
+        }
+    }
+
+    /// Returns the maximum number of values that can be committed to with this key.
+    pub fn size(&self) -> usize {
+        self.generators.len()
+    }
+}
+
+/// Returns the discrete logarithm of the generator with the given `index` in
+/// [`CommitmentKey::new_broken`], where index 0 is the blinding generator.
+pub fn broken_trapdoor(label: &[u8], index: u64) -> Fr {
+    hash_to_scalar(
+        b"ARK-ALGEBRA-INTRO-PEDERSEN-BROKEN",
+        &[label, &index.to_be_bytes()],
+    )
+}
+
+/// Commits to `values` with the blinding factor `blinding`.
+///
+/// If there are fewer values than generators in `key`, the remaining values are zero.
+///
+/// # Panics
+///
+/// Panics if there are more values than generators in `key`.
+pub fn commit(key: &CommitmentKey, values: &[Fr], blinding: Fr) -> Commitment {
+    assert!(
+        values.len() <= key.size(),
+        "the key only supports {} values",
+        key.size()
+    );
+    let bases = &key.generators[..values.len()];
+    let point = crate::msm::arkworks(bases, values) + key.blinding_generator.mul(blinding);
+    Commitment(point.into_affine())
+}
+
+/// Checks that `commitment` is a commitment to `values` with the blinding factor `blinding`.
+pub fn open(key: &CommitmentKey, commitment: &Commitment, values: &[Fr], blinding: Fr) -> bool {
+    values.len() <= key.size() && *commitment == commit(key, values, blinding)
+}
+
+/// Adds two commitments, yielding a commitment to the sum of their values with the sum of
+/// their blinding factors.
+pub fn add(first: &Commitment, second: &Commitment) -> Commitment {
+    Commitment(first.0 + second.0)
+}
+
+/// Adds `delta · H` to a commitment, which changes its blinding factor from `r` to
+/// `r + delta` without changing the committed values.
+///
+/// The result is unlinkable to the original commitment for anyone who doesn't know
+/// `delta`:
+///
+/// ```rust
+/// use ark_algebra_intro::commitments::pedersen::{commit, open, rerandomize, CommitmentKey};
+/// use ark_bls12_381::Fr;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let key = CommitmentKey::new(2, b"example");
+/// let values = [Fr::from(7u64), Fr::from(8u64)];
+/// let blinding = Fr::rand(&mut rng);
+/// let commitment = commit(&key, &values, blinding);
+///
+/// let delta = Fr::rand(&mut rng);
+/// let fresh = rerandomize(&key, &commitment, delta);
+/// assert_ne!(fresh, commitment);
+/// assert!(open(&key, &fresh, &values, blinding + delta));
+/// ```
+pub fn rerandomize(key: &CommitmentKey, commitment: &Commitment, delta: Fr) -> Commitment {
+    let point: G1Projective = commitment.0.into_projective() + key.blinding_generator.mul(delta);
+    Commitment(point.into_affine())
+}
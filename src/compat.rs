@@ -0,0 +1,179 @@
+//! One set of names for the `arkworks` 0.3, 0.4 and 0.5 APIs.
+//!
+//! This crate, and its documentation, use `arkworks` 0.3. Version 0.4 renamed most of
+//! the traits and methods that the tutorial relies on, and 0.5 moved a few more, so
+//! code copied from a tutorial written for one version fails to compile against another
+//! with "method not found" errors. The renamings are:
+//!
+//! | 0.3                                        | 0.4 and 0.5                              |
+//! |--------------------------------------------|------------------------------------------|
+//! | `ProjectiveCurve`                          | `CurveGroup` (and `Group` for the group law) |
+//! | `AffineCurve`                              | `AffineRepr`                             |
+//! | `PairingEngine`                            | `Pairing`                                |
+//! | `prime_subgroup_generator()`               | `generator()`                            |
+//! | `into_projective()`                        | `into_group()`                           |
+//! | `g.mul(s.into_repr())`                     | `g * s`                                  |
+//! | `batch_normalization_into_affine(&points)` | `normalize_batch(&points)`               |
+//! | `PrimeField::into_repr`, `from_repr`       | `PrimeField::into_bigint`, `from_bigint` |
+//! | `F::Params::MODULUS`                       | `F::MODULUS`                             |
+//! | `VariableBaseMSM::multi_scalar_mul(&bases, &bigints)` | `G::msm(&bases, &scalars)`    |
+//! | `E::pairing(p, q)`, of type `E::Fqk`       | `E::pairing(p, q).0`, of type `E::TargetField` |
+//! | `E::product_of_pairings(&pairs)`           | `E::multi_pairing(ps, qs).0`             |
+//!
+//! and, in 0.5 only, `Group` was split into `PrimeGroup` (which has `generator`) and
+//! `AdditiveGroup` (which has `double` and `ZERO`).
+//!
+//! The traits here use the 0.4 names, and are implemented for the BLS12-381 types of
+//! each version, so that code written against them compiles whichever version it is
+//! used with:
+//!
+//! ```rust
+//! use ark_algebra_intro::compat::{AffineRepr, CurveGroup, Group, Pairing, PrimeField};
+//!
+//! /// Checks that `e(a · P, Q) = e(P, a · Q)`, written once for every version.
+//! fn bilinear<E: Pairing>(a: E::ScalarField) -> bool {
+//!     let (p, q) = (E::G1Affine::generator(), E::G2Affine::generator());
+//!     let ap = p.into_group().mul_scalar(&a).into_affine();
+//!     let aq = q.into_group().mul_scalar(&a).into_affine();
+//!     E::pairing(ap, q) == E::pairing(p, aq)
+//! }
+//!
+//! // With the 0.3 types that the rest of this crate uses...
+//! assert!(bilinear::<ark_bls12_381::Bls12_381>(ark_bls12_381::Fr::from(5u64)));
+//! let five = ark_bls12_381::Fr::from(5u64).into_bigint();
+//! assert_eq!(ark_bls12_381::Fr::from_bigint(five), Some(ark_bls12_381::Fr::from(5u64)));
+//!
+//! // ...and with those of later versions, behind the `arkworks-04` and `arkworks-05`
+//! // features.
+//! #[cfg(feature = "arkworks-04")]
+//! assert!(bilinear::<ark_bls12_381_04::Bls12_381>(ark_bls12_381_04::Fr::from(5u64)));
+//! #[cfg(feature = "arkworks-05")]
+//! assert!(bilinear::<ark_bls12_381_05::Bls12_381>(ark_bls12_381_05::Fr::from(5u64)));
+//! ```
+//!
+//! A few names (such as `into_affine` and `pairing`) exist both here and in the
+//! traits of each version, so import the traits from here *instead of* those of
+//! `ark-ec`, or calls to these methods will be ambiguous. Only the BLS12-381 types
+//! implement these traits; for other curves, follow the table above.
+
+use ark_std::fmt::Debug;
+use ark_std::vec::Vec;
+
+mod v0_3;
+#[cfg(feature = "arkworks-04")]
+mod v0_4;
+#[cfg(feature = "arkworks-05")]
+mod v0_5;
+
+/// A prime field, which converts to and from the integers below its modulus.
+pub trait PrimeField: Copy + Debug + Eq {
+    /// The integers below the modulus, as arrays of 64-bit limbs.
+    type BigInt: Copy + Debug + Eq + AsRef<[u64]>;
+
+    /// Returns the integer that `self` represents, in `[0, p)`.
+    fn into_bigint(self) -> Self::BigInt;
+
+    /// Returns the field element that `repr` represents, or `None` if `repr` is not
+    /// smaller than the modulus.
+    fn from_bigint(repr: Self::BigInt) -> Option<Self>;
+
+    /// Returns the modulus `p`.
+    fn modulus() -> Self::BigInt;
+}
+
+/// A group of prime order, written additively.
+pub trait Group: Copy + Debug + Eq {
+    /// The field of scalars, of the same order as the group.
+    type ScalarField: PrimeField;
+
+    /// Returns the generator of the group.
+    fn generator() -> Self;
+
+    /// Returns `self + self`.
+    fn double(&self) -> Self;
+
+    /// Returns `scalar · self`.
+    fn mul_scalar(&self, scalar: &Self::ScalarField) -> Self;
+}
+
+/// The points of an elliptic curve group, in the fast (projective) representation.
+pub trait CurveGroup: Group {
+    /// The affine representation of the points.
+    type Affine: AffineRepr<Group = Self, ScalarField = Self::ScalarField>;
+
+    /// Converts `self` to affine coordinates.
+    fn into_affine(self) -> Self::Affine;
+
+    /// Converts all of `points` to affine coordinates, with a single inversion.
+    fn normalize_batch(points: &[Self]) -> Vec<Self::Affine>;
+}
+
+/// The points of an elliptic curve group, in affine coordinates.
+pub trait AffineRepr: Copy + Debug + Eq {
+    /// The field of the coordinates.
+    type BaseField: Copy + Debug + Eq;
+    /// The field of scalars.
+    type ScalarField: PrimeField;
+    /// The projective representation of the points.
+    type Group: CurveGroup<Affine = Self, ScalarField = Self::ScalarField>;
+
+    /// Returns the generator of the group.
+    fn generator() -> Self;
+
+    /// Converts `self` to projective coordinates.
+    fn into_group(self) -> Self::Group;
+
+    /// Returns the coordinates of `self`, or `None` for the point at infinity.
+    fn xy(&self) -> Option<(Self::BaseField, Self::BaseField)>;
+}
+
+/// Multiplications of many points or scalars at once.
+///
+/// ```rust
+/// use ark_algebra_intro::compat::{CurveGroup, Group, ScalarMul};
+/// use ark_bls12_381::{Fr, G1Projective};
+///
+/// let g = G1Projective::generator();
+/// let scalars = [Fr::from(1u64), Fr::from(2u64), Fr::from(3u64)];
+/// let points = g.batch_mul(&scalars);
+/// assert_eq!(points[2], g.mul_scalar(&Fr::from(3u64)).into_affine());
+///
+/// // 1 · g + 2 · (2 · g) + 3 · (3 · g) = 14 · g
+/// let sum = G1Projective::msm(&points, &scalars).unwrap();
+/// assert_eq!(sum, g.mul_scalar(&Fr::from(14u64)));
+/// assert_eq!(G1Projective::msm(&points, &scalars[..2]), None);
+/// ```
+pub trait ScalarMul: CurveGroup {
+    /// Returns `[s · self for s in scalars]`, in affine coordinates.
+    fn batch_mul(&self, scalars: &[Self::ScalarField]) -> Vec<Self::Affine>;
+
+    /// Returns the multi-scalar multiplication `Σ scalars[i] · bases[i]`, or `None` if
+    /// the two slices have different lengths.
+    fn msm(bases: &[Self::Affine], scalars: &[Self::ScalarField]) -> Option<Self>;
+}
+
+/// A pairing `e: G1 × G2 → GT`.
+pub trait Pairing {
+    /// The field of scalars of G1 and G2.
+    type ScalarField: PrimeField;
+    /// The group G1.
+    type G1: CurveGroup<Affine = Self::G1Affine, ScalarField = Self::ScalarField>;
+    /// The points of G1, in affine coordinates.
+    type G1Affine: AffineRepr<Group = Self::G1, ScalarField = Self::ScalarField>;
+    /// The group G2.
+    type G2: CurveGroup<Affine = Self::G2Affine, ScalarField = Self::ScalarField>;
+    /// The points of G2, in affine coordinates.
+    type G2Affine: AffineRepr<Group = Self::G2, ScalarField = Self::ScalarField>;
+    /// The field that contains the target group GT.
+    type TargetField: Copy + Debug + Eq;
+
+    /// Returns `e(p, q)`.
+    fn pairing(p: Self::G1Affine, q: Self::G2Affine) -> Self::TargetField;
+
+    /// Returns `Π e(ps[i], qs[i])`, with a single final exponentiation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ps` and `qs` have different lengths.
+    fn multi_pairing(ps: &[Self::G1Affine], qs: &[Self::G2Affine]) -> Self::TargetField;
+}
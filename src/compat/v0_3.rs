@@ -0,0 +1,137 @@
+//! The facade for `arkworks` 0.3, the version that the rest of this crate uses.
+
+use super::{AffineRepr, CurveGroup, Group, Pairing, PrimeField, ScalarMul};
+use ark_bls12_381::{g1, g2, Bls12_381, Fq, Fq12, Fq2, Fr, G1Affine, G2Affine};
+use ark_ec::msm::VariableBaseMSM;
+use ark_ec::short_weierstrass_jacobian::{GroupAffine, GroupProjective};
+use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::{FpParameters, PrimeField as _};
+use ark_std::vec::Vec;
+
+macro_rules! impl_prime_field {
+    ($field:ty) => {
+        impl PrimeField for $field {
+            type BigInt = <$field as ark_ff::PrimeField>::BigInt;
+
+            fn into_bigint(self) -> Self::BigInt {
+                self.into_repr()
+            }
+
+            fn from_bigint(repr: Self::BigInt) -> Option<Self> {
+                Self::from_repr(repr)
+            }
+
+            fn modulus() -> Self::BigInt {
+                <$field as ark_ff::PrimeField>::Params::MODULUS
+            }
+        }
+    };
+}
+
+impl_prime_field!(Fr);
+impl_prime_field!(Fq);
+
+macro_rules! impl_curve {
+    ($projective:ty, $affine:ty, $base:ty) => {
+        impl Group for $projective {
+            type ScalarField = Fr;
+
+            fn generator() -> Self {
+                Self::prime_subgroup_generator()
+            }
+
+            fn double(&self) -> Self {
+                ProjectiveCurve::double(self)
+            }
+
+            fn mul_scalar(&self, scalar: &Fr) -> Self {
+                ProjectiveCurve::mul(*self, scalar.into_repr())
+            }
+        }
+
+        impl CurveGroup for $projective {
+            type Affine = $affine;
+
+            fn into_affine(self) -> $affine {
+                ProjectiveCurve::into_affine(&self)
+            }
+
+            fn normalize_batch(points: &[Self]) -> Vec<$affine> {
+                Self::batch_normalization_into_affine(points)
+            }
+        }
+
+        impl AffineRepr for $affine {
+            type BaseField = $base;
+            type ScalarField = Fr;
+            type Group = $projective;
+
+            fn generator() -> Self {
+                Self::prime_subgroup_generator()
+            }
+
+            fn into_group(self) -> $projective {
+                self.into_projective()
+            }
+
+            fn xy(&self) -> Option<($base, $base)> {
+                if self.infinity {
+                    None
+                } else {
+                    Some((self.x, self.y))
+                }
+            }
+        }
+
+        impl ScalarMul for $projective {
+            fn batch_mul(&self, scalars: &[Fr]) -> Vec<$affine> {
+                let points: Vec<_> = scalars.iter().map(|s| self.mul_scalar(s)).collect();
+                Self::normalize_batch(&points)
+            }
+
+            fn msm(bases: &[$affine], scalars: &[Fr]) -> Option<Self> {
+                if bases.len() != scalars.len() {
+                    return None;
+                }
+                let scalars: Vec<_> = scalars.iter().map(|s| s.into_repr()).collect();
+                Some(VariableBaseMSM::multi_scalar_mul(bases, &scalars))
+            }
+        }
+    };
+}
+
+// The aliases `G1Projective` and `G2Projective` are projections out of the BLS12
+// parameters, which coherence can't tell apart, so name the types directly.
+impl_curve!(
+    GroupProjective<g1::Parameters>,
+    GroupAffine<g1::Parameters>,
+    Fq
+);
+impl_curve!(
+    GroupProjective<g2::Parameters>,
+    GroupAffine<g2::Parameters>,
+    Fq2
+);
+
+impl Pairing for Bls12_381 {
+    type ScalarField = Fr;
+    type G1 = ark_bls12_381::G1Projective;
+    type G1Affine = G1Affine;
+    type G2 = ark_bls12_381::G2Projective;
+    type G2Affine = G2Affine;
+    type TargetField = Fq12;
+
+    fn pairing(p: G1Affine, q: G2Affine) -> Fq12 {
+        <Bls12_381 as PairingEngine>::pairing(p, q)
+    }
+
+    fn multi_pairing(ps: &[G1Affine], qs: &[G2Affine]) -> Fq12 {
+        assert_eq!(ps.len(), qs.len(), "one G2 point is needed per G1 point");
+        let pairs: Vec<_> = ps
+            .iter()
+            .zip(qs)
+            .map(|(p, q)| ((*p).into(), (*q).into()))
+            .collect();
+        Bls12_381::product_of_pairings(&pairs)
+    }
+}
@@ -0,0 +1,117 @@
+//! The facade for `arkworks` 0.5.
+
+use super::{AffineRepr, CurveGroup, Group, Pairing, PrimeField, ScalarMul};
+use ark_bls12_381_05::{g1, g2, Bls12_381, Fq, Fq12, Fq2, Fr, G1Affine, G2Affine};
+use ark_ec_05::short_weierstrass::{Affine, Projective};
+use ark_ec_05::VariableBaseMSM;
+use ark_std::vec::Vec;
+
+macro_rules! impl_prime_field {
+    ($field:ty) => {
+        impl PrimeField for $field {
+            type BigInt = <$field as ark_ff_05::PrimeField>::BigInt;
+
+            fn into_bigint(self) -> Self::BigInt {
+                ark_ff_05::PrimeField::into_bigint(self)
+            }
+
+            fn from_bigint(repr: Self::BigInt) -> Option<Self> {
+                ark_ff_05::PrimeField::from_bigint(repr)
+            }
+
+            fn modulus() -> Self::BigInt {
+                <$field as ark_ff_05::PrimeField>::MODULUS
+            }
+        }
+    };
+}
+
+impl_prime_field!(Fr);
+impl_prime_field!(Fq);
+
+macro_rules! impl_curve {
+    ($projective:ty, $affine:ty, $base:ty) => {
+        impl Group for $projective {
+            type ScalarField = Fr;
+
+            fn generator() -> Self {
+                ark_ec_05::PrimeGroup::generator()
+            }
+
+            fn double(&self) -> Self {
+                ark_ff_05::AdditiveGroup::double(self)
+            }
+
+            fn mul_scalar(&self, scalar: &Fr) -> Self {
+                *self * scalar
+            }
+        }
+
+        impl CurveGroup for $projective {
+            type Affine = $affine;
+
+            fn into_affine(self) -> $affine {
+                ark_ec_05::CurveGroup::into_affine(self)
+            }
+
+            fn normalize_batch(points: &[Self]) -> Vec<$affine> {
+                ark_ec_05::CurveGroup::normalize_batch(points)
+            }
+        }
+
+        impl AffineRepr for $affine {
+            type BaseField = $base;
+            type ScalarField = Fr;
+            type Group = $projective;
+
+            fn generator() -> Self {
+                ark_ec_05::AffineRepr::generator()
+            }
+
+            fn into_group(self) -> $projective {
+                ark_ec_05::AffineRepr::into_group(self)
+            }
+
+            fn xy(&self) -> Option<($base, $base)> {
+                ark_ec_05::AffineRepr::xy(self)
+            }
+        }
+
+        impl ScalarMul for $projective {
+            fn batch_mul(&self, scalars: &[Fr]) -> Vec<$affine> {
+                let points: Vec<_> = scalars.iter().map(|s| *self * s).collect();
+                ark_ec_05::CurveGroup::normalize_batch(&points)
+            }
+
+            fn msm(bases: &[$affine], scalars: &[Fr]) -> Option<Self> {
+                VariableBaseMSM::msm(bases, scalars).ok()
+            }
+        }
+    };
+}
+
+// As for 0.3, name the types directly rather than through the aliases.
+impl_curve!(Projective<g1::Config>, Affine<g1::Config>, Fq);
+impl_curve!(Projective<g2::Config>, Affine<g2::Config>, Fq2);
+
+impl Pairing for Bls12_381 {
+    type ScalarField = Fr;
+    type G1 = ark_bls12_381_05::G1Projective;
+    type G1Affine = G1Affine;
+    type G2 = ark_bls12_381_05::G2Projective;
+    type G2Affine = G2Affine;
+    type TargetField = Fq12;
+
+    fn pairing(p: G1Affine, q: G2Affine) -> Fq12 {
+        <Bls12_381 as ark_ec_05::pairing::Pairing>::pairing(p, q).0
+    }
+
+    fn multi_pairing(ps: &[G1Affine], qs: &[G2Affine]) -> Fq12 {
+        assert_eq!(ps.len(), qs.len(), "one G2 point is needed per G1 point");
+        <Bls12_381 as ark_ec_05::pairing::Pairing>::multi_pairing(
+            ps.iter().copied(),
+            qs.iter().copied(),
+        )
+        .0
+    }
+}
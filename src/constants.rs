@@ -0,0 +1,8 @@
+//! Named constants for the curves used in this tutorial.
+//!
+//! The parameters of `arkworks` curves are spread across several parameter
+//! structs and traits (`FpParameters`, `SWModelParameters`, `Bls12Parameters`, ...).
+//! The submodules here collect the most important ones in a single place,
+//! with their values spelled out in the documentation.
+
+pub mod bls12_381;
@@ -0,0 +1,251 @@
+//! Constants for the BLS12-381 pairing-friendly curve.
+//!
+//! Each constant's documentation includes a doctest checking it against the
+//! corresponding value in `ark-bls12-381`.
+
+use ark_bls12_381::{Fq, Fq2};
+use ark_ff::{
+    biginteger::{BigInteger256, BigInteger384},
+    field_new,
+};
+
+/// The modulus `q` of the base field `Fq`:
+/// 4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559787.
+///
+/// ```rust
+/// use ark_algebra_intro::constants::bls12_381::FQ_MODULUS;
+/// use ark_bls12_381::Fq;
+/// use ark_ff::{FpParameters, PrimeField};
+///
+/// assert_eq!(FQ_MODULUS, <Fq as PrimeField>::Params::MODULUS);
+/// ```
+#[rustfmt::skip]
+pub const FQ_MODULUS: BigInteger384 = BigInteger384([
+    0xb9feffffffffaaab,
+    0x1eabfffeb153ffff,
+    0x6730d2a0f6b0f624,
+    0x64774b84f38512bf,
+    0x4b1ba7b6434bacd7,
+    0x1a0111ea397fe69a,
+]);
+
+/// The number of bits in [`FQ_MODULUS`].
+///
+/// ```rust
+/// use ark_algebra_intro::constants::bls12_381::FQ_MODULUS_BITS;
+/// use ark_bls12_381::Fq;
+/// use ark_ff::PrimeField;
+///
+/// assert_eq!(FQ_MODULUS_BITS as usize, Fq::size_in_bits());
+/// ```
+pub const FQ_MODULUS_BITS: u32 = 381;
+
+/// The modulus `r` of the scalar field `Fr`, which is also the order of the groups G1, G2, and GT:
+/// 52435875175126190479447740508185965837690552500527637822603658699938581184513.
+///
+/// ```rust
+/// use ark_algebra_intro::constants::bls12_381::FR_MODULUS;
+/// use ark_bls12_381::{Fr, G1Projective};
+/// use ark_ec::ProjectiveCurve;
+/// use ark_ff::{FpParameters, PrimeField};
+/// use ark_std::Zero;
+///
+/// assert_eq!(FR_MODULUS, <Fr as PrimeField>::Params::MODULUS);
+/// assert!(G1Projective::prime_subgroup_generator().mul(FR_MODULUS).is_zero());
+/// ```
+#[rustfmt::skip]
+pub const FR_MODULUS: BigInteger256 = BigInteger256([
+    0xffffffff00000001,
+    0x53bda402fffe5bfe,
+    0x3339d80809a1d805,
+    0x73eda753299d7d48,
+]);
+
+/// The number of bits in [`FR_MODULUS`].
+///
+/// ```rust
+/// use ark_algebra_intro::constants::bls12_381::FR_MODULUS_BITS;
+/// use ark_bls12_381::Fr;
+/// use ark_ff::PrimeField;
+///
+/// assert_eq!(FR_MODULUS_BITS as usize, Fr::size_in_bits());
+/// ```
+pub const FR_MODULUS_BITS: u32 = 255;
+
+/// The two-adicity of `r - 1`, i.e., the largest `s` such that `2^s` divides `r - 1`.
+/// This is what makes `Fr` suitable for FFTs over domains of size up to `2^32`.
+///
+/// ```rust
+/// use ark_algebra_intro::constants::bls12_381::FR_TWO_ADICITY;
+/// use ark_bls12_381::Fr;
+/// use ark_ff::{FftParameters, PrimeField};
+///
+/// assert_eq!(FR_TWO_ADICITY, <Fr as PrimeField>::Params::TWO_ADICITY);
+/// ```
+pub const FR_TWO_ADICITY: u32 = 32;
+
+/// The absolute value of the curve parameter `x` from which all other BLS12-381
+/// parameters are derived: `x = -0xd201000000010000`.
+///
+/// ```rust
+/// use ark_algebra_intro::constants::bls12_381::{X, X_IS_NEGATIVE};
+/// use ark_bls12_381::Parameters;
+/// use ark_ec::bls12::Bls12Parameters;
+///
+/// assert_eq!(&[X], Parameters::X);
+/// assert_eq!(X_IS_NEGATIVE, Parameters::X_IS_NEGATIVE);
+/// ```
+pub const X: u64 = 0xd201000000010000;
+
+/// Whether the curve parameter [`X`] is negative.
+pub const X_IS_NEGATIVE: bool = true;
+
+/// The embedding degree `k` of BLS12-381: the smallest `k` such that `r` divides `q^k - 1`.
+/// This is why pairings land in `Fq12`.
+///
+/// ```rust
+/// use ark_algebra_intro::constants::bls12_381::EMBEDDING_DEGREE;
+/// use ark_algebra_intro::fields::characteristic;
+/// use ark_bls12_381::{Fq, Fr};
+/// use num_bigint::BigUint;
+///
+/// let q = characteristic::<Fq>();
+/// let r = characteristic::<Fr>();
+/// let one = BigUint::from(1u64);
+/// assert!((1..EMBEDDING_DEGREE).all(|k| (q.pow(k) - &one) % &r != BigUint::from(0u64)));
+/// assert_eq!((q.pow(EMBEDDING_DEGREE) - &one) % &r, BigUint::from(0u64));
+/// ```
+pub const EMBEDDING_DEGREE: u32 = 12;
+
+/// The coefficient `b = 4` of the G1 curve equation `y^2 = x^3 + 4` over `Fq`.
+///
+/// ```rust
+/// use ark_algebra_intro::constants::bls12_381::G1_COEFF_B;
+/// use ark_bls12_381::g1::Parameters;
+/// use ark_ec::SWModelParameters;
+///
+/// assert_eq!(G1_COEFF_B, Parameters::COEFF_B);
+/// ```
+pub const G1_COEFF_B: Fq = field_new!(Fq, "4");
+
+/// The coefficient `b = 4(u + 1)` of the G2 curve equation `y^2 = x^3 + 4(u + 1)` over `Fq2`.
+///
+/// ```rust
+/// use ark_algebra_intro::constants::bls12_381::G2_COEFF_B;
+/// use ark_bls12_381::g2::Parameters;
+/// use ark_ec::SWModelParameters;
+///
+/// assert_eq!(G2_COEFF_B, Parameters::COEFF_B);
+/// ```
+pub const G2_COEFF_B: Fq2 = field_new!(Fq2, field_new!(Fq, "4"), field_new!(Fq, "4"));
+
+/// The cofactor of G1, i.e., the number of points on the G1 curve divided by `r`:
+/// 76329603384216526031706109802092473003 (= 0x396c8c005555e1568c00aaab0000aaab).
+///
+/// ```rust
+/// use ark_algebra_intro::constants::bls12_381::G1_COFACTOR;
+/// use ark_bls12_381::g1::Parameters;
+/// use ark_ec::SWModelParameters;
+///
+/// assert_eq!(G1_COFACTOR, Parameters::COFACTOR);
+/// ```
+pub const G1_COFACTOR: &[u64] = &[0x8c00aaab0000aaab, 0x396c8c005555e156];
+
+/// The cofactor of G2, i.e., the number of points on the G2 curve divided by `r`:
+/// 305502333931268344200999753193121504214466019254188142667664032982267604182971884026507427359259977847832272839041616661285803823378372096355777062779109.
+///
+/// ```rust
+/// use ark_algebra_intro::constants::bls12_381::G2_COFACTOR;
+/// use ark_bls12_381::g2::Parameters;
+/// use ark_ec::SWModelParameters;
+///
+/// assert_eq!(G2_COFACTOR, Parameters::COFACTOR);
+/// ```
+#[rustfmt::skip]
+pub const G2_COFACTOR: &[u64] = &[
+    0xcf1c38e31c7238e5,
+    0x1616ec6e786f0c70,
+    0x21537e293a6691ae,
+    0xa628f1cb4d9e82ef,
+    0xa68a205b2e5a7ddf,
+    0xcd91de4547085aba,
+    0x91d50792876a202,
+    0x5d543a95414e7f1,
+];
+
+/// The x-coordinate of the standard generator of G1.
+///
+/// ```rust
+/// use ark_algebra_intro::constants::bls12_381::{G1_GENERATOR_X, G1_GENERATOR_Y};
+/// use ark_bls12_381::G1Affine;
+/// use ark_ec::AffineCurve;
+///
+/// let g = G1Affine::prime_subgroup_generator();
+/// assert_eq!((g.x, g.y), (G1_GENERATOR_X, G1_GENERATOR_Y));
+/// ```
+#[rustfmt::skip]
+pub const G1_GENERATOR_X: Fq = field_new!(Fq, "3685416753713387016781088315183077757961620795782546409894578378688607592378376318836054947676345821548104185464507");
+
+/// The y-coordinate of the standard generator of G1.
+#[rustfmt::skip]
+pub const G1_GENERATOR_Y: Fq = field_new!(Fq, "1339506544944476473020471379941921221584933875938349620426543736416511423956333506472724655353366534992391756441569");
+
+/// The x-coordinate of the standard generator of G2.
+///
+/// ```rust
+/// use ark_algebra_intro::constants::bls12_381::{G2_GENERATOR_X, G2_GENERATOR_Y};
+/// use ark_bls12_381::G2Affine;
+/// use ark_ec::AffineCurve;
+///
+/// let g = G2Affine::prime_subgroup_generator();
+/// assert_eq!((g.x, g.y), (G2_GENERATOR_X, G2_GENERATOR_Y));
+/// ```
+#[rustfmt::skip]
+pub const G2_GENERATOR_X: Fq2 = field_new!(
+    Fq2,
+    field_new!(Fq, "352701069587466618187139116011060144890029952792775240219908644239793785735715026873347600343865175952761926303160"),
+    field_new!(Fq, "3059144344244213709971259814753781636986470325476647558659373206291635324768958432433509563104347017837885763365758")
+);
+
+/// The y-coordinate of the standard generator of G2.
+#[rustfmt::skip]
+pub const G2_GENERATOR_Y: Fq2 = field_new!(
+    Fq2,
+    field_new!(Fq, "1985150602287291935568054521177171638300868978215655730859378665066344726373823718423869104263333984641494340347905"),
+    field_new!(Fq, "927553665492332455747201965776037880757740193453592970025027978793976877002675564980949289727957565575433344219582")
+);
+
+/// A primitive cube root of unity `β` in `Fq`, chosen so that the endomorphism
+/// `φ(x, y) = (β · x, y)` of G1 acts as multiplication by [`GLV_LAMBDA`].
+///
+/// ```rust
+/// use ark_algebra_intro::constants::bls12_381::{GLV_BETA, GLV_LAMBDA};
+/// use ark_bls12_381::{Fq, Fr, G1Affine};
+/// use ark_ec::{AffineCurve, ProjectiveCurve};
+/// use ark_ff::Field;
+/// use ark_std::One;
+///
+/// assert_eq!(GLV_BETA.pow([3]), Fq::one());
+/// assert_ne!(GLV_BETA, Fq::one());
+///
+/// let g = G1Affine::prime_subgroup_generator();
+/// let lambda_g = g.mul(Fr::from(GLV_LAMBDA)).into_affine();
+/// assert_eq!((lambda_g.x, lambda_g.y), (GLV_BETA * g.x, g.y));
+/// ```
+#[rustfmt::skip]
+pub const GLV_BETA: Fq = field_new!(Fq, "4002409555221667392624310435006688643935503118305586438271171395842971157480381377015405980053539358417135540939436");
+
+/// The eigenvalue `λ = X^2 - 1` of the endomorphism of G1 with `φ(P) = λ · P`,
+/// which is a cube root of unity in `Fr`: `λ^2 + λ + 1 = r`.
+///
+/// ```rust
+/// use ark_algebra_intro::constants::bls12_381::{GLV_LAMBDA, X};
+/// use ark_bls12_381::Fr;
+/// use ark_ff::Field;
+/// use ark_std::{One, Zero};
+///
+/// assert_eq!(GLV_LAMBDA, (X as u128) * (X as u128) - 1);
+/// let lambda = Fr::from(GLV_LAMBDA);
+/// assert!((lambda.square() + lambda + Fr::one()).is_zero());
+/// ```
+pub const GLV_LAMBDA: u128 = 0xac45a4010001a40200000000ffffffff;
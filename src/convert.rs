@@ -0,0 +1,367 @@
+//! Conversions between the encodings of BLS12-381 values, behind `algebra-intro convert`.
+//!
+//! Puzzles and specifications hand out values as byte blobs, in hexadecimal. Each function
+//! here parses one kind of blob and returns a [`Report`] of what it contains, in the
+//! canonical encodings of this crate: decimal integers, big-endian hexadecimal,
+//! [arkworks](crate::serialize) bytes and [ZCash](crate::interop::zcash_format) bytes.
+//! Hexadecimal inputs may omit the `0x` prefix.
+//!
+//! ```rust
+//! use ark_algebra_intro::convert::{field_from_hex, point_decompress, FieldName, Group, PointFormat};
+//!
+//! let report = field_from_hex(FieldName::Fr, "0xff").unwrap();
+//! assert_eq!(report.get("decimal"), Some("255"));
+//! assert_eq!(
+//!     report.get("arkworks"),
+//!     Some("0xff00000000000000000000000000000000000000000000000000000000000000"),
+//! );
+//!
+//! // The generator of G1, in the ZCash encoding.
+//! let g = "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+//! let report = point_decompress(Group::G1, PointFormat::ZCash, g).unwrap();
+//! assert_eq!(report.get("in subgroup"), Some("yes"));
+//! assert!(report.get("y").unwrap().starts_with("1339506544944"));
+//! // Decompressing the arkworks encoding gives the same point.
+//! let arkworks = report.get("arkworks").unwrap();
+//! let again = point_decompress(Group::G1, PointFormat::Arkworks, arkworks).unwrap();
+//! assert_eq!(again, report);
+//! ```
+//!
+//! This module is always available; the `algebra-intro` binary needs the `cli` feature:
+//!
+//! ```text
+//! $ cargo run --features cli --bin algebra-intro -- convert scalar-reduce ffff...ff
+//! ```
+
+use crate::curves::decompress;
+use crate::display::FieldFmt;
+use crate::encoding::{
+    from_be_bytes_mod_order, from_hex, from_le_bytes_mod_order, to_bytes_be, to_hex,
+};
+use crate::fields::{parse_hex, ParseError};
+use crate::hash_to_curve;
+use crate::interop::{check_length, zcash_format, DecodeError};
+use crate::serialize::{to_bytes_compressed, to_bytes_uncompressed};
+use ark_bls12_381::{Bls12_381, Fq, Fq2, Fr};
+use ark_ec::models::SWModelParameters;
+use ark_ec::short_weierstrass_jacobian::GroupAffine;
+use ark_ec::PairingEngine;
+use ark_ff::{PrimeField, Zero};
+use ark_serialize::{CanonicalDeserializeWithFlags, SWFlags};
+use ark_std::fmt;
+use ark_std::str::FromStr;
+use ark_std::string::{String, ToString};
+use ark_std::vec::Vec;
+
+/// The labelled lines that describe a converted value.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    /// The lines, as `(label, value)` pairs.
+    pub lines: Vec<(String, String)>,
+}
+
+impl Report {
+    /// Returns the value of the line labelled `label`.
+    pub fn get(&self, label: &str) -> Option<&str> {
+        self.lines
+            .iter()
+            .find(|(l, _)| l == label)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn push(&mut self, label: impl Into<String>, value: impl Into<String>) {
+        self.lines.push((label.into(), value.into()));
+    }
+}
+
+impl fmt::Display for Report {
+    /// Prints one `label: value` line per entry, with the values aligned.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.lines.iter().map(|(l, _)| l.len()).max().unwrap_or(0);
+        for (i, (label, value)) in self.lines.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(
+                f,
+                "{:width$}  {}",
+                format!("{}:", label),
+                value,
+                width = width + 1
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The error returned when an input cannot be converted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConvertError {
+    /// The input is not a hexadecimal string with an even number of digits.
+    InvalidHex,
+    /// The input is not a field element.
+    Field(ParseError),
+    /// The input is not an encoding of a point.
+    Point(DecodeError),
+}
+
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConvertError::InvalidHex => write!(f, "invalid hexadecimal string"),
+            ConvertError::Field(e) => write!(f, "invalid field element: {}", e),
+            ConvertError::Point(e) => write!(f, "invalid point: {}", e),
+        }
+    }
+}
+
+impl ark_std::error::Error for ConvertError {}
+
+impl From<ParseError> for ConvertError {
+    fn from(e: ParseError) -> Self {
+        ConvertError::Field(e)
+    }
+}
+
+impl From<DecodeError> for ConvertError {
+    fn from(e: DecodeError) -> Self {
+        ConvertError::Point(e)
+    }
+}
+
+/// One of BLS12-381's prime fields.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldName {
+    /// The scalar field.
+    Fr,
+    /// The base field.
+    Fq,
+}
+
+impl FromStr for FieldName {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fr" => Ok(FieldName::Fr),
+            "fq" => Ok(FieldName::Fq),
+            _ => Err(format!("unknown field `{}`, expected `fr` or `fq`", s)),
+        }
+    }
+}
+
+/// One of BLS12-381's source groups.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Group {
+    /// The group over `F_q`.
+    G1,
+    /// The group over `F_{q^2}`.
+    G2,
+}
+
+impl FromStr for Group {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "g1" => Ok(Group::G1),
+            "g2" => Ok(Group::G2),
+            _ => Err(format!("unknown group `{}`, expected `g1` or `g2`", s)),
+        }
+    }
+}
+
+/// A compressed point encoding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PointFormat {
+    /// The [ZCash encoding](crate::interop::zcash_format): big-endian, flags in the first
+    /// byte.
+    ZCash,
+    /// The [arkworks encoding](crate::serialize): little-endian, flags in the last byte.
+    Arkworks,
+}
+
+impl FromStr for PointFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "zcash" => Ok(PointFormat::ZCash),
+            "arkworks" => Ok(PointFormat::Arkworks),
+            _ => Err(format!(
+                "unknown format `{}`, expected `zcash` or `arkworks`",
+                s
+            )),
+        }
+    }
+}
+
+/// Parses hexadecimal bytes, with or without a `0x` prefix.
+pub fn bytes_from_hex(hex: &str) -> Result<Vec<u8>, ConvertError> {
+    let hex = hex.trim();
+    let digits = hex.strip_prefix("0x").unwrap_or(hex);
+    from_hex(&format!("0x{}", digits)).ok_or(ConvertError::InvalidHex)
+}
+
+/// Describes a field element: its decimal and balanced values, its big-endian hexadecimal,
+/// and its arkworks encoding.
+fn field_report<F: PrimeField>(a: F) -> Report {
+    let mut report = Report::default();
+    report.push("decimal", FieldFmt(a).to_string());
+    report.push("balanced", FieldFmt(a).balanced().to_string());
+    report.push("hex", to_hex(&to_bytes_be(&a)));
+    report.push("arkworks", to_hex(&to_bytes_compressed(&a)));
+    report
+}
+
+/// Parses a big-endian hexadecimal integer (optionally negative) as an element of
+/// `field`, which must be smaller than the modulus.
+pub fn field_from_hex(field: FieldName, hex: &str) -> Result<Report, ConvertError> {
+    let hex = hex.trim();
+    Ok(match field {
+        FieldName::Fr => field_report(parse_hex::<Fr>(hex)?),
+        FieldName::Fq => field_report(parse_hex::<Fq>(hex)?),
+    })
+}
+
+/// Reduces bytes of any length, read as a big-endian integer (or little-endian, if
+/// `little_endian` is set), modulo the order `r` of the scalar field.
+///
+/// This is how most protocols turn a hash into a scalar:
+///
+/// ```rust
+/// use ark_algebra_intro::convert::scalar_reduce;
+///
+/// let r_plus_one = "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000002";
+/// assert_eq!(scalar_reduce(r_plus_one, false).unwrap().get("decimal"), Some("1"));
+/// let leading_zeros = "00000000ffffffff";
+/// assert_eq!(scalar_reduce(leading_zeros, false).unwrap().get("decimal"), Some("4294967295"));
+/// ```
+pub fn scalar_reduce(hex: &str, little_endian: bool) -> Result<Report, ConvertError> {
+    let bytes = bytes_from_hex(hex)?;
+    let scalar: Fr = if little_endian {
+        from_le_bytes_mod_order(&bytes)
+    } else {
+        from_be_bytes_mod_order(&bytes)
+    };
+    Ok(field_report(scalar))
+}
+
+/// Decodes a compressed arkworks encoding, checking that the point is on the curve but not
+/// that it is in the prime-order subgroup.
+fn decode_arkworks_unchecked<P: SWModelParameters>(
+    bytes: &[u8],
+    width: usize,
+) -> Result<GroupAffine<P>, DecodeError> {
+    check_length(bytes, width)?;
+    let (x, flags) = P::BaseField::deserialize_with_flags::<_, SWFlags>(bytes)
+        .map_err(|_| DecodeError::NonCanonical)?;
+    match flags.is_positive() {
+        Some(greatest) => decompress(x, greatest).map_err(|_| DecodeError::NotOnCurve),
+        None if x.is_zero() => Ok(GroupAffine::zero()),
+        None => Err(DecodeError::NonCanonical),
+    }
+}
+
+/// Describes a point: its coordinates, whether it is in the prime-order subgroup, and its
+/// encodings.
+fn point_report<P: SWModelParameters>(
+    point: GroupAffine<P>,
+    coordinate: fn(&P::BaseField) -> String,
+    zcash: Vec<u8>,
+) -> Report {
+    let mut report = Report::default();
+    if point.infinity {
+        report.push("point", "infinity");
+    } else {
+        report.push("x", coordinate(&point.x));
+        report.push("y", coordinate(&point.y));
+    }
+    let in_subgroup = point.is_in_correct_subgroup_assuming_on_curve();
+    report.push("in subgroup", if in_subgroup { "yes" } else { "no" });
+    report.push("zcash", to_hex(&zcash));
+    report.push("arkworks", to_hex(&to_bytes_compressed(&point)));
+    report.push("uncompressed", to_hex(&to_bytes_uncompressed(&point)));
+    report
+}
+
+/// Formats an element of `F_{q^2}` as `c0 + c1*u`.
+fn fq2(a: &Fq2) -> String {
+    format!("{} + {}*u", FieldFmt(a.c0), FieldFmt(a.c1))
+}
+
+/// Decompresses a point, checking that it is on the curve; the report says whether it is
+/// in the prime-order subgroup.
+pub fn point_decompress(
+    group: Group,
+    format: PointFormat,
+    hex: &str,
+) -> Result<Report, ConvertError> {
+    let bytes = bytes_from_hex(hex)?;
+    Ok(match (group, format) {
+        (Group::G1, PointFormat::ZCash) => {
+            let point = zcash_format::decode_g1_unchecked(&bytes)?;
+            point_report(
+                point,
+                |x| FieldFmt(*x).to_string(),
+                zcash_format::encode_g1(&point),
+            )
+        }
+        (Group::G1, PointFormat::Arkworks) => {
+            let point = decode_arkworks_unchecked::<ark_bls12_381::g1::Parameters>(&bytes, 48)?;
+            point_report(
+                point,
+                |x| FieldFmt(*x).to_string(),
+                zcash_format::encode_g1(&point),
+            )
+        }
+        (Group::G2, PointFormat::ZCash) => {
+            let point = zcash_format::decode_g2_unchecked(&bytes)?;
+            point_report(point, fq2, zcash_format::encode_g2(&point))
+        }
+        (Group::G2, PointFormat::Arkworks) => {
+            let point = decode_arkworks_unchecked::<ark_bls12_381::g2::Parameters>(&bytes, 96)?;
+            point_report(point, fq2, zcash_format::encode_g2(&point))
+        }
+    })
+}
+
+/// [Hashes](hash_to_curve::hash_to_field) a message, given in hexadecimal, to `count`
+/// elements of `F_q` with the domain separation tag `dst`.
+///
+/// ```rust
+/// use ark_algebra_intro::convert::hash_to_field;
+///
+/// // From the test vectors of RFC 9380, for the empty message.
+/// let dst = "QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+/// let report = hash_to_field("", dst, 2).unwrap();
+/// assert_eq!(
+///     report.get("u0"),
+///     Some("0x0ba14bd907ad64a016293ee7c2d276b8eae71f25a4b941eece7b0d89f17f75cb3ae5438a614fb61d6835ad59f29c564f"),
+/// );
+/// assert_eq!(report.lines.len(), 2);
+/// ```
+pub fn hash_to_field(message_hex: &str, dst: &str, count: usize) -> Result<Report, ConvertError> {
+    let message = bytes_from_hex(message_hex)?;
+    let mut report = Report::default();
+    for (i, u) in hash_to_curve::hash_to_field(&message, dst.as_bytes(), count)
+        .iter()
+        .enumerate()
+    {
+        report.push(format!("u{}", i), to_hex(&to_bytes_be(u)));
+    }
+    Ok(report)
+}
+
+/// Computes the pairing of a G1 and a G2 point, given in the ZCash encoding, and returns
+/// the arkworks encoding of the result (there is no standard encoding of `F_{q^12}`).
+pub fn pairing(p_hex: &str, q_hex: &str) -> Result<Report, ConvertError> {
+    let p = zcash_format::decode_g1(&bytes_from_hex(p_hex)?)?;
+    let q = zcash_format::decode_g2(&bytes_from_hex(q_hex)?)?;
+    let mut report = Report::default();
+    report.push(
+        "arkworks",
+        to_hex(&to_bytes_compressed(&Bls12_381::pairing(p, q))),
+    );
+    Ok(report)
+}
@@ -0,0 +1,515 @@
+//! What a `ProjectiveCurve` point actually stores.
+//!
+//! Adding two affine points `(x, y)` takes a field inversion to compute the slope of
+//! the line through them. *Projective* coordinates avoid this by representing a point
+//! as a triple `(X, Y, Z)` and postponing all divisions until the very end. There are two
+//! common ways to do so:
+//!
+//! * [`HomogeneousPoint`]: `(X : Y : Z)` stands for the affine point `(X / Z, Y / Z)`;
+//! * [`JacobianPoint`]: `(X : Y : Z)` stands for the affine point `(X / Z^2, Y / Z^3)`.
+//!
+//! In both cases, the point at infinity is the one with `Z = 0`, and the triples
+//! `(X, Y, Z)` and their rescalings (`(λX, λY, λZ)` and `(λ^2 X, λ^3 Y, λZ)`
+//! respectively) represent the same point, which is why two projective points can be
+//! equal without having equal coordinates.
+//!
+//! `arkworks` uses Jacobian coordinates for short Weierstrass curves: the `x`, `y`, and `z`
+//! fields of `short_weierstrass_jacobian::GroupProjective` are exactly the `X`, `Y`, and
+//! `Z` of a [`JacobianPoint`]. The types here spell out the addition and doubling
+//! formulas for both systems, so that they can be compared against `arkworks`:
+//!
+//! ```rust
+//! use ark_algebra_intro::coords::{HomogeneousPoint, JacobianPoint};
+//! use ark_bls12_381::G1Projective;
+//! use ark_ec::ProjectiveCurve;
+//! use ark_std::UniformRand;
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let a = G1Projective::rand(&mut rng);
+//! let b = G1Projective::rand(&mut rng);
+//!
+//! // The arkworks representation *is* a Jacobian point...
+//! let (ja, jb) = (JacobianPoint::from(a), JacobianPoint::from(b));
+//! assert_eq!((ja.x, ja.y, ja.z), (a.x, a.y, a.z));
+//! assert_eq!(G1Projective::from(ja + jb), a + b);
+//! assert_eq!(G1Projective::from(ja.double()), a.double());
+//!
+//! // ...and homogeneous coordinates give the same group law.
+//! let (ha, hb) = (HomogeneousPoint::from(a), HomogeneousPoint::from(b));
+//! assert_eq!((ha + hb).to_affine(), (a + b).into_affine());
+//! assert_eq!(ha.double().to_affine(), a.double().into_affine());
+//! ```
+
+use ark_ec::{
+    models::SWModelParameters,
+    short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+};
+use ark_ff::{Field, One, Zero};
+use ark_std::{fmt, ops};
+
+/// A point in Jacobian coordinates: `(X : Y : Z)` represents the affine point
+/// `(X / Z^2, Y / Z^3)`, or the point at infinity if `Z = 0`.
+///
+/// Substituting `x = X / Z^2` and `y = Y / Z^3` into `y^2 = x^3 + a x + b` and clearing
+/// denominators gives the curve equation `Y^2 = X^3 + a X Z^4 + b Z^6`.
+///
+/// ```rust
+/// use ark_algebra_intro::coords::JacobianPoint;
+/// use ark_bls12_381::{g1::Parameters, Fq, G1Affine};
+/// use ark_ec::AffineCurve;
+///
+/// let g = G1Affine::prime_subgroup_generator();
+/// let p = JacobianPoint::<Parameters>::from_affine(&g);
+///
+/// // Rescaling by λ = 2 gives a different triple for the same point.
+/// let two = Fq::from(2u64);
+/// let q = JacobianPoint::<Parameters>::new(g.x * two * two, g.y * two * two * two, two);
+/// assert_ne!((p.x, p.y, p.z), (q.x, q.y, q.z));
+/// assert_eq!(p, q);
+/// assert_eq!(q.to_affine(), g);
+/// ```
+pub struct JacobianPoint<P: SWModelParameters> {
+    /// The `X` coordinate.
+    pub x: P::BaseField,
+    /// The `Y` coordinate.
+    pub y: P::BaseField,
+    /// The `Z` coordinate.
+    pub z: P::BaseField,
+}
+
+impl<P: SWModelParameters> JacobianPoint<P> {
+    /// Creates the point `(x : y : z)`, without checking that it lies on the curve.
+    pub fn new(x: P::BaseField, y: P::BaseField, z: P::BaseField) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Returns the point at infinity, `(1 : 1 : 0)`.
+    pub fn identity() -> Self {
+        Self::new(
+            P::BaseField::one(),
+            P::BaseField::one(),
+            P::BaseField::zero(),
+        )
+    }
+
+    /// Returns whether this is the point at infinity.
+    pub fn is_identity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// Converts an affine point `(x, y)` into `(x : y : 1)`.
+    pub fn from_affine(point: &GroupAffine<P>) -> Self {
+        if point.infinity {
+            Self::identity()
+        } else {
+            Self::new(point.x, point.y, P::BaseField::one())
+        }
+    }
+
+    /// Converts this point into affine coordinates `(X / Z^2, Y / Z^3)`, at the cost of
+    /// one field inversion.
+    pub fn to_affine(&self) -> GroupAffine<P> {
+        if self.is_identity() {
+            return GroupAffine::zero();
+        }
+        let z_inv = self.z.inverse().unwrap();
+        let z_inv_squared = z_inv.square();
+        GroupAffine::new(
+            self.x * z_inv_squared,
+            self.y * z_inv_squared * z_inv,
+            false,
+        )
+    }
+
+    /// Doubles this point, following the tangent rule without any divisions.
+    ///
+    /// In affine coordinates, the tangent at `(x, y)` has slope `(3x^2 + a) / 2y`.
+    /// Substituting `x = X / Z^2` and `y = Y / Z^3`, the slope becomes `M / (2 Y Z)`
+    /// with `M = 3X^2 + a Z^4`; the result is then `(X3 : Y3 : Z3)` with `Z3 = 2 Y Z`,
+    /// which keeps all denominators out of the formulas below.
+    ///
+    /// ```rust
+    /// use ark_algebra_intro::coords::JacobianPoint;
+    /// use ark_bls12_381::G1Projective;
+    /// use ark_ec::ProjectiveCurve;
+    /// use ark_std::{UniformRand, Zero};
+    ///
+    /// let mut rng = ark_std::rand::thread_rng();
+    /// let a = G1Projective::rand(&mut rng);
+    /// assert_eq!(G1Projective::from(JacobianPoint::from(a).double()), a.double());
+    /// assert!(JacobianPoint::from(G1Projective::zero()).double().is_identity());
+    /// ```
+    pub fn double(&self) -> Self {
+        // Points with y = 0 have order 2, so doubling them gives the point at infinity.
+        if self.is_identity() || self.y.is_zero() {
+            return Self::identity();
+        }
+        let xx = self.x.square();
+        let yy = self.y.square();
+        let zz = self.z.square();
+        // S = 4 X Y^2 and M = 3 X^2 + a Z^4.
+        let s = (self.x * yy).double().double();
+        let m = xx.double() + xx + P::mul_by_a(&zz.square());
+        // X3 = M^2 - 2S
+        let x3 = m.square() - s.double();
+        // Y3 = M (S - X3) - 8 Y^4
+        let y3 = m * (s - x3) - yy.square().double().double().double();
+        // Z3 = 2 Y Z
+        let z3 = (self.y * self.z).double();
+        Self::new(x3, y3, z3)
+    }
+}
+
+/// Adds two points in Jacobian coordinates with the chord rule.
+///
+/// Bringing both points to the common denominators `Z1^2 Z2^2` and `Z1^3 Z2^3`
+/// gives `U1 = X1 Z2^2`, `U2 = X2 Z1^2` and `S1 = Y1 Z2^3`, `S2 = Y2 Z1^3`. The points
+/// have equal x-coordinates exactly when `U1 = U2`, and otherwise the chord through
+/// them has slope `R / (Z1 Z2 H)`, with `H = U2 - U1` and `R = S2 - S1`.
+///
+/// ```rust
+/// use ark_algebra_intro::coords::JacobianPoint;
+/// use ark_bls12_381::G1Projective;
+/// use ark_ec::ProjectiveCurve;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let a = G1Projective::rand(&mut rng);
+/// let b = G1Projective::rand(&mut rng);
+/// let (ja, jb) = (JacobianPoint::from(a), JacobianPoint::from(b));
+/// assert_eq!(G1Projective::from(ja + jb), a + b);
+///
+/// // The special cases: doubling, inverses, and the identity.
+/// assert_eq!(ja + ja, ja.double());
+/// assert!((ja + (-ja)).is_identity());
+/// assert_eq!(ja + JacobianPoint::identity(), ja);
+/// ```
+impl<P: SWModelParameters> ops::Add for JacobianPoint<P> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        if self.is_identity() {
+            return other;
+        }
+        if other.is_identity() {
+            return self;
+        }
+        let z1z1 = self.z.square();
+        let z2z2 = other.z.square();
+        let u1 = self.x * z2z2;
+        let u2 = other.x * z1z1;
+        let s1 = self.y * other.z * z2z2;
+        let s2 = other.y * self.z * z1z1;
+        if u1 == u2 {
+            // Same x-coordinate: either the same point, or inverses.
+            return if s1 == s2 {
+                self.double()
+            } else {
+                Self::identity()
+            };
+        }
+        let h = u2 - u1;
+        let r = s2 - s1;
+        let hh = h.square();
+        let hhh = hh * h;
+        let u1hh = u1 * hh;
+        // X3 = R^2 - H^3 - 2 U1 H^2
+        let x3 = r.square() - hhh - u1hh.double();
+        // Y3 = R (U1 H^2 - X3) - S1 H^3
+        let y3 = r * (u1hh - x3) - s1 * hhh;
+        // Z3 = Z1 Z2 H
+        let z3 = self.z * other.z * h;
+        Self::new(x3, y3, z3)
+    }
+}
+
+impl<P: SWModelParameters> ops::Neg for JacobianPoint<P> {
+    type Output = Self;
+
+    /// Negates the point, by negating `Y`.
+    fn neg(self) -> Self {
+        Self::new(self.x, -self.y, self.z)
+    }
+}
+
+/// Two Jacobian points are equal if they represent the same affine point, i.e., if
+/// `X1 Z2^2 = X2 Z1^2` and `Y1 Z2^3 = Y2 Z1^3`.
+impl<P: SWModelParameters> PartialEq for JacobianPoint<P> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_identity() || other.is_identity() {
+            return self.is_identity() && other.is_identity();
+        }
+        let z1z1 = self.z.square();
+        let z2z2 = other.z.square();
+        self.x * z2z2 == other.x * z1z1 && self.y * z2z2 * other.z == other.y * z1z1 * self.z
+    }
+}
+
+impl<P: SWModelParameters> Eq for JacobianPoint<P> {}
+
+impl<P: SWModelParameters> Clone for JacobianPoint<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: SWModelParameters> Copy for JacobianPoint<P> {}
+
+impl<P: SWModelParameters> fmt::Debug for JacobianPoint<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({} : {} : {})", self.x, self.y, self.z)
+    }
+}
+
+impl<P: SWModelParameters> From<GroupProjective<P>> for JacobianPoint<P> {
+    /// Copies the coordinates of an `arkworks` point, which are already Jacobian.
+    fn from(point: GroupProjective<P>) -> Self {
+        Self::new(point.x, point.y, point.z)
+    }
+}
+
+impl<P: SWModelParameters> From<JacobianPoint<P>> for GroupProjective<P> {
+    fn from(point: JacobianPoint<P>) -> Self {
+        if point.is_identity() {
+            return GroupProjective::zero();
+        }
+        GroupProjective::new(point.x, point.y, point.z)
+    }
+}
+
+/// A point in homogeneous projective coordinates: `(X : Y : Z)` represents the affine
+/// point `(X / Z, Y / Z)`, or the point at infinity if `Z = 0`.
+///
+/// Substituting `x = X / Z` and `y = Y / Z` into `y^2 = x^3 + a x + b` and clearing
+/// denominators gives the curve equation `Y^2 Z = X^3 + a X Z^2 + b Z^3`. This is the
+/// classical "projective plane" view of the curve, in which the point at infinity
+/// `(0 : 1 : 0)` is just another solution of the equation.
+///
+/// ```rust
+/// use ark_algebra_intro::coords::HomogeneousPoint;
+/// use ark_bls12_381::{g1::Parameters, Fq, G1Affine};
+/// use ark_ec::AffineCurve;
+///
+/// let g = G1Affine::prime_subgroup_generator();
+/// let p = HomogeneousPoint::<Parameters>::from_affine(&g);
+///
+/// // Rescaling by λ = 2 gives a different triple for the same point.
+/// let two = Fq::from(2u64);
+/// let q = HomogeneousPoint::<Parameters>::new(g.x * two, g.y * two, two);
+/// assert_eq!(p, q);
+/// assert_eq!(q.to_affine(), g);
+/// ```
+pub struct HomogeneousPoint<P: SWModelParameters> {
+    /// The `X` coordinate.
+    pub x: P::BaseField,
+    /// The `Y` coordinate.
+    pub y: P::BaseField,
+    /// The `Z` coordinate.
+    pub z: P::BaseField,
+}
+
+impl<P: SWModelParameters> HomogeneousPoint<P> {
+    /// Creates the point `(x : y : z)`, without checking that it lies on the curve.
+    pub fn new(x: P::BaseField, y: P::BaseField, z: P::BaseField) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Returns the point at infinity, `(0 : 1 : 0)`.
+    pub fn identity() -> Self {
+        Self::new(
+            P::BaseField::zero(),
+            P::BaseField::one(),
+            P::BaseField::zero(),
+        )
+    }
+
+    /// Returns whether this is the point at infinity.
+    pub fn is_identity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// Converts an affine point `(x, y)` into `(x : y : 1)`.
+    pub fn from_affine(point: &GroupAffine<P>) -> Self {
+        if point.infinity {
+            Self::identity()
+        } else {
+            Self::new(point.x, point.y, P::BaseField::one())
+        }
+    }
+
+    /// Converts this point into affine coordinates `(X / Z, Y / Z)`, at the cost of
+    /// one field inversion.
+    pub fn to_affine(&self) -> GroupAffine<P> {
+        if self.is_identity() {
+            return GroupAffine::zero();
+        }
+        let z_inv = self.z.inverse().unwrap();
+        GroupAffine::new(self.x * z_inv, self.y * z_inv, false)
+    }
+
+    /// Doubles this point, following the tangent rule without any divisions.
+    ///
+    /// Substituting `x = X / Z` and `y = Y / Z`, the slope `(3x^2 + a) / 2y` of the
+    /// tangent becomes `W / 2S`, with `W = 3X^2 + a Z^2` and `S = Y Z`.
+    ///
+    /// ```rust
+    /// use ark_algebra_intro::coords::HomogeneousPoint;
+    /// use ark_bls12_381::G2Projective;
+    /// use ark_ec::ProjectiveCurve;
+    /// use ark_std::UniformRand;
+    ///
+    /// let mut rng = ark_std::rand::thread_rng();
+    /// let a = G2Projective::rand(&mut rng);
+    /// assert_eq!(HomogeneousPoint::from(a).double().to_affine(), a.double().into_affine());
+    /// ```
+    pub fn double(&self) -> Self {
+        if self.is_identity() || self.y.is_zero() {
+            return Self::identity();
+        }
+        let xx = self.x.square();
+        // W = 3 X^2 + a Z^2, S = Y Z, and B = X Y S.
+        let w = xx.double() + xx + P::mul_by_a(&self.z.square());
+        let s = self.y * self.z;
+        let b = self.x * self.y * s;
+        // H = W^2 - 8B
+        let h = w.square() - b.double().double().double();
+        let s_squared = s.square();
+        // X3 = 2 H S
+        let x3 = (h * s).double();
+        // Y3 = W (4B - H) - 8 Y^2 S^2
+        let y3 = w * (b.double().double() - h)
+            - (self.y.square() * s_squared).double().double().double();
+        // Z3 = 8 S^3
+        let z3 = (s_squared * s).double().double().double();
+        Self::new(x3, y3, z3)
+    }
+}
+
+/// Adds two points in homogeneous coordinates with the chord rule.
+///
+/// The chord through `(X1 / Z1, Y1 / Z1)` and `(X2 / Z2, Y2 / Z2)` has slope `u / v`,
+/// with `u = Y2 Z1 - Y1 Z2` and `v = X2 Z1 - X1 Z2`; the points have equal x-coordinates
+/// exactly when `v = 0`.
+///
+/// ```rust
+/// use ark_algebra_intro::coords::HomogeneousPoint;
+/// use ark_bls12_381::G1Projective;
+/// use ark_ec::ProjectiveCurve;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let a = G1Projective::rand(&mut rng);
+/// let b = G1Projective::rand(&mut rng);
+/// let (ha, hb) = (HomogeneousPoint::from(a), HomogeneousPoint::from(b));
+/// assert_eq!((ha + hb).to_affine(), (a + b).into_affine());
+///
+/// assert_eq!(ha + ha, ha.double());
+/// assert!((ha + (-ha)).is_identity());
+/// assert_eq!(HomogeneousPoint::identity() + ha, ha);
+/// ```
+impl<P: SWModelParameters> ops::Add for HomogeneousPoint<P> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        if self.is_identity() {
+            return other;
+        }
+        if other.is_identity() {
+            return self;
+        }
+        let y1z2 = self.y * other.z;
+        let x1z2 = self.x * other.z;
+        let z1z2 = self.z * other.z;
+        let u = other.y * self.z - y1z2;
+        let v = other.x * self.z - x1z2;
+        if v.is_zero() {
+            return if u.is_zero() {
+                self.double()
+            } else {
+                Self::identity()
+            };
+        }
+        let vv = v.square();
+        let vvv = vv * v;
+        let r = vv * x1z2;
+        // A = u^2 Z1 Z2 - v^3 - 2 v^2 X1 Z2
+        let a = u.square() * z1z2 - vvv - r.double();
+        // X3 = v A
+        let x3 = v * a;
+        // Y3 = u (v^2 X1 Z2 - A) - v^3 Y1 Z2
+        let y3 = u * (r - a) - vvv * y1z2;
+        // Z3 = v^3 Z1 Z2
+        let z3 = vvv * z1z2;
+        Self::new(x3, y3, z3)
+    }
+}
+
+impl<P: SWModelParameters> ops::Neg for HomogeneousPoint<P> {
+    type Output = Self;
+
+    /// Negates the point, by negating `Y`.
+    fn neg(self) -> Self {
+        Self::new(self.x, -self.y, self.z)
+    }
+}
+
+/// Two homogeneous points are equal if they represent the same affine point, i.e., if
+/// `X1 Z2 = X2 Z1` and `Y1 Z2 = Y2 Z1`.
+impl<P: SWModelParameters> PartialEq for HomogeneousPoint<P> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_identity() || other.is_identity() {
+            return self.is_identity() && other.is_identity();
+        }
+        self.x * other.z == other.x * self.z && self.y * other.z == other.y * self.z
+    }
+}
+
+impl<P: SWModelParameters> Eq for HomogeneousPoint<P> {}
+
+impl<P: SWModelParameters> Clone for HomogeneousPoint<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: SWModelParameters> Copy for HomogeneousPoint<P> {}
+
+impl<P: SWModelParameters> fmt::Debug for HomogeneousPoint<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({} : {} : {})", self.x, self.y, self.z)
+    }
+}
+
+impl<P: SWModelParameters> From<JacobianPoint<P>> for HomogeneousPoint<P> {
+    /// Converts `(X : Y : Z)` in Jacobian coordinates into `(X Z : Y : Z^3)`, which
+    /// represents the same affine point `(X / Z^2, Y / Z^3)` without needing an inversion.
+    fn from(point: JacobianPoint<P>) -> Self {
+        if point.is_identity() {
+            return Self::identity();
+        }
+        Self::new(point.x * point.z, point.y, point.z.square() * point.z)
+    }
+}
+
+impl<P: SWModelParameters> From<HomogeneousPoint<P>> for JacobianPoint<P> {
+    /// Converts `(X : Y : Z)` in homogeneous coordinates into `(X Z : Y Z^2 : Z)`, which
+    /// represents the same affine point `(X / Z, Y / Z)` without needing an inversion.
+    fn from(point: HomogeneousPoint<P>) -> Self {
+        if point.is_identity() {
+            return Self::identity();
+        }
+        Self::new(point.x * point.z, point.y * point.z.square(), point.z)
+    }
+}
+
+impl<P: SWModelParameters> From<GroupProjective<P>> for HomogeneousPoint<P> {
+    fn from(point: GroupProjective<P>) -> Self {
+        JacobianPoint::from(point).into()
+    }
+}
+
+impl<P: SWModelParameters> From<HomogeneousPoint<P>> for GroupProjective<P> {
+    fn from(point: HomogeneousPoint<P>) -> Self {
+        JacobianPoint::from(point).into()
+    }
+}
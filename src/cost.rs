@@ -0,0 +1,298 @@
+//! Predicting the cost of a computation before writing it.
+//!
+//! Provers spend their time in a handful of expensive operations: [multi-scalar
+//! multiplications](crate::msm), [FFTs](crate::poly), and [pairings](crate::pairings).
+//! Given the cost of the basic operations, a back-of-the-envelope estimate of a
+//! composition of these is just a sum, which [`estimate`] does for you:
+//!
+//! ```rust
+//! use ark_algebra_intro::cost::{estimate, CostTable, Pipeline, Step};
+//!
+//! // An MSM of size 2^16 in G1, plus 3 pairings.
+//! let pipeline = Pipeline::new().then(Step::G1Msm(1 << 16)).then(Step::Pairings(3));
+//! let cost = estimate(&pipeline, &CostTable::DEFAULT);
+//! println!("{}", cost);
+//!
+//! // The MSM dominates: about 25 million Fq multiplications, compared to 87 thousand.
+//! let msm = cost.breakdown[0].1;
+//! assert!(2e7 < msm && msm < 3e7);
+//! assert_eq!(cost.breakdown[1].1, 3.0 * (12_000.0 + 17_000.0));
+//!
+//! // Sharing the final exponentiation between the pairings saves a little.
+//! let product = Pipeline::new().then(Step::G1Msm(1 << 16)).then(Step::PairingProduct(3));
+//! assert!(estimate(&product, &CostTable::DEFAULT).total < cost.total);
+//! ```
+//!
+//! [`CostTable::DEFAULT`] counts in multiplications in BLS12-381's base field `Fq`
+//! (written `M`), which makes estimates comparable across machines.
+//! [`CostTable::measure`] instead times the basic operations on this machine, which makes
+//! estimates come out in nanoseconds. Either way, an estimate ignores memory traffic,
+//! additions in the field, and parallelism, so only trust it to within a factor of two.
+
+use crate::bench::time;
+use ark_bls12_381::{Bls12_381, Fq, Fq12, Fr, G1Projective, G2Projective};
+use ark_ec::{PairingEngine, ProjectiveCurve};
+use ark_ff::{Field, FpParameters, PrimeField};
+use ark_std::{fmt, UniformRand};
+use std::hint::black_box;
+
+/// The cost of each basic operation, in a common unit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CostTable {
+    /// The unit that the costs are given in.
+    pub unit: &'static str,
+    /// A multiplication in `Fq`.
+    pub fq_mul: f64,
+    /// A squaring in `Fq`.
+    pub fq_square: f64,
+    /// An inversion in `Fq`.
+    pub fq_inverse: f64,
+    /// A multiplication in `Fr`.
+    pub fr_mul: f64,
+    /// An addition of projective points of G1.
+    pub g1_add: f64,
+    /// A doubling of a projective point of G1.
+    pub g1_double: f64,
+    /// An addition of projective points of G2.
+    pub g2_add: f64,
+    /// A doubling of a projective point of G2.
+    pub g2_double: f64,
+    /// A Miller loop.
+    pub miller_loop: f64,
+    /// A final exponentiation.
+    pub final_exp: f64,
+}
+
+impl CostTable {
+    /// Costs in `Fq` multiplications (`M`).
+    ///
+    /// The costs of curve operations are the operation counts of the Jacobian formulas
+    /// that `arkworks` uses: `11M + 5S` per addition and `2M + 5S` per doubling, with
+    /// `S = 0.8M`, and with `Fq2` multiplications and squarings costing `3M` and `2M`
+    /// in G2. Inversion costs about 100M. The Miller loop and final exponentiation have
+    /// too many special cases to count by hand, so their costs are measurements of
+    /// `arkworks`, rounded.
+    pub const DEFAULT: CostTable = CostTable {
+        unit: "M",
+        fq_mul: 1.0,
+        fq_square: 0.8,
+        fq_inverse: 100.0,
+        fr_mul: 0.5,
+        g1_add: 15.0,
+        g1_double: 6.0,
+        g2_add: 43.0,
+        g2_double: 16.0,
+        miller_loop: 12_000.0,
+        final_exp: 17_000.0,
+    };
+
+    /// Times each operation on this machine, and returns costs in nanoseconds.
+    ///
+    /// The Miller loop and final exponentiation run `iterations` times, and the other
+    /// operations 100 times as often. Run this in a release build, as debug builds are
+    /// many times slower.
+    ///
+    /// ```rust
+    /// use ark_algebra_intro::cost::CostTable;
+    ///
+    /// let table = CostTable::measure(1);
+    /// assert_eq!(table.unit, "ns");
+    /// let costs = [
+    ///     table.fq_mul, table.fq_square, table.fq_inverse, table.fr_mul, table.g1_add,
+    ///     table.g1_double, table.g2_add, table.g2_double, table.miller_loop, table.final_exp,
+    /// ];
+    /// // Timings vary from run to run, so only check that every operation was timed.
+    /// assert!(costs.iter().all(|&cost| cost > 0.0));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `iterations` is zero.
+    pub fn measure(iterations: u32) -> CostTable {
+        let mut rng = ark_std::rand::thread_rng();
+        let (a, b) = (Fq::rand(&mut rng), Fq::rand(&mut rng));
+        let (r, s) = (Fr::rand(&mut rng), Fr::rand(&mut rng));
+        let (p1, q1) = (G1Projective::rand(&mut rng), G1Projective::rand(&mut rng));
+        let (p2, q2) = (G2Projective::rand(&mut rng), G2Projective::rand(&mut rng));
+        let pair = [(p1.into_affine().into(), p2.into_affine().into())];
+        let f = Fq12::rand(&mut rng);
+
+        let cheap = iterations * 100;
+        let nanos =
+            |measurement: crate::bench::Measurement| measurement.per_iteration.as_nanos() as f64;
+        // `black_box` the inputs too, so that the operations aren't hoisted out of the loop.
+        CostTable {
+            unit: "ns",
+            fq_mul: nanos(time("", cheap, || black_box(a) * black_box(b))),
+            fq_square: nanos(time("", cheap, || black_box(a).square())),
+            fq_inverse: nanos(time("", cheap, || black_box(a).inverse())),
+            fr_mul: nanos(time("", cheap, || black_box(r) * black_box(s))),
+            g1_add: nanos(time("", cheap, || black_box(p1) + black_box(q1))),
+            g1_double: nanos(time("", cheap, || black_box(p1).double())),
+            g2_add: nanos(time("", cheap, || black_box(p2) + black_box(q2))),
+            g2_double: nanos(time("", cheap, || black_box(p2).double())),
+            miller_loop: nanos(time("", iterations, || {
+                Bls12_381::miller_loop(black_box(&pair))
+            })),
+            final_exp: nanos(time("", iterations, || {
+                Bls12_381::final_exponentiation(black_box(&f))
+            })),
+        }
+    }
+}
+
+/// One stage of a computation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step {
+    /// This many multiplications in `Fq`.
+    FqMuls(usize),
+    /// This many inversions in `Fq`.
+    FqInverses(usize),
+    /// This many multiplications in `Fr`.
+    FrMuls(usize),
+    /// This many independent scalar multiplications in G1, by double-and-add.
+    G1ScalarMuls(usize),
+    /// This many independent scalar multiplications in G2, by double-and-add.
+    G2ScalarMuls(usize),
+    /// A multi-scalar multiplication of this size in G1, with [Pippenger's
+    /// method](crate::msm::pippenger).
+    G1Msm(usize),
+    /// A multi-scalar multiplication of this size in G2, with [Pippenger's
+    /// method](crate::msm::pippenger).
+    G2Msm(usize),
+    /// An FFT over `Fr` of this size, which should be a power of two.
+    Fft(usize),
+    /// This many independent pairings.
+    Pairings(usize),
+    /// A product of this many pairings, which shares one final exponentiation.
+    PairingProduct(usize),
+}
+
+/// The number of bits of a scalar, i.e., of an element of `Fr`.
+fn scalar_bits() -> f64 {
+    <Fr as PrimeField>::Params::MODULUS_BITS as f64
+}
+
+/// The cost of a double-and-add scalar multiplication: a doubling per bit, and an
+/// addition per set bit, which is half of them on average.
+fn scalar_mul(add: f64, double: f64) -> f64 {
+    scalar_bits() * (double + add / 2.0)
+}
+
+/// The cost of Pippenger's method, as in [`crate::msm::pippenger_with_window`]:
+/// `(b / c) · (n + 2^(c + 1))` additions and `b` doublings for `b`-bit scalars and
+/// windows of `c` bits.
+fn msm(n: usize, add: f64, double: f64) -> f64 {
+    if n == 0 {
+        return 0.0;
+    }
+    let window = crate::msm::default_window_size(n);
+    let windows = (scalar_bits() / window as f64).ceil();
+    let additions = windows * (n as f64 + 2f64.powi(window as i32 + 1));
+    additions * add + scalar_bits() * double
+}
+
+impl Step {
+    /// Returns the cost of this step, according to `table`.
+    pub fn cost(&self, table: &CostTable) -> f64 {
+        match *self {
+            Step::FqMuls(n) => n as f64 * table.fq_mul,
+            Step::FqInverses(n) => n as f64 * table.fq_inverse,
+            Step::FrMuls(n) => n as f64 * table.fr_mul,
+            Step::G1ScalarMuls(n) => n as f64 * scalar_mul(table.g1_add, table.g1_double),
+            Step::G2ScalarMuls(n) => n as f64 * scalar_mul(table.g2_add, table.g2_double),
+            Step::G1Msm(n) => msm(n, table.g1_add, table.g1_double),
+            Step::G2Msm(n) => msm(n, table.g2_add, table.g2_double),
+            // An FFT of size n has log2(n) layers of n / 2 butterflies, each with a
+            // multiplication by a root of unity.
+            Step::Fft(n) => {
+                let log_n = (n as f64).log2().max(0.0);
+                n as f64 / 2.0 * log_n * table.fr_mul
+            }
+            Step::Pairings(n) => n as f64 * (table.miller_loop + table.final_exp),
+            Step::PairingProduct(0) => 0.0,
+            Step::PairingProduct(n) => n as f64 * table.miller_loop + table.final_exp,
+        }
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self {
+            Step::FqMuls(n) => format!("{} Fq multiplications", n),
+            Step::FqInverses(n) => format!("{} Fq inversions", n),
+            Step::FrMuls(n) => format!("{} Fr multiplications", n),
+            Step::G1ScalarMuls(n) => format!("{} G1 scalar multiplications", n),
+            Step::G2ScalarMuls(n) => format!("{} G2 scalar multiplications", n),
+            Step::G1Msm(n) => format!("G1 MSM of size {}", n),
+            Step::G2Msm(n) => format!("G2 MSM of size {}", n),
+            Step::Fft(n) => format!("FFT of size {}", n),
+            Step::Pairings(n) => format!("{} pairings", n),
+            Step::PairingProduct(n) => format!("product of {} pairings", n),
+        };
+        f.pad(&description)
+    }
+}
+
+/// A computation, as a sequence of steps.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Pipeline {
+    /// The steps, in order.
+    pub steps: Vec<Step>,
+}
+
+impl Pipeline {
+    /// Returns an empty pipeline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `step` to the pipeline.
+    pub fn then(mut self, step: Step) -> Self {
+        self.steps.push(step);
+        self
+    }
+}
+
+/// The estimated cost of a [`Pipeline`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Estimate {
+    /// The unit of the costs, from the [`CostTable`].
+    pub unit: &'static str,
+    /// The cost of each step.
+    pub breakdown: Vec<(Step, f64)>,
+    /// The total cost.
+    pub total: f64,
+}
+
+impl fmt::Display for Estimate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (step, cost) in &self.breakdown {
+            let share = if self.total > 0.0 {
+                100.0 * cost / self.total
+            } else {
+                0.0
+            };
+            writeln!(
+                f,
+                "{:<32} {:>14.0} {} ({:>5.1}%)",
+                step, cost, self.unit, share
+            )?;
+        }
+        write!(f, "{:<32} {:>14.0} {}", "total", self.total, self.unit)
+    }
+}
+
+/// Estimates the cost of `pipeline`, with the costs of basic operations from `table`.
+pub fn estimate(pipeline: &Pipeline, table: &CostTable) -> Estimate {
+    let breakdown: Vec<_> = pipeline
+        .steps
+        .iter()
+        .map(|step| (*step, step.cost(table)))
+        .collect();
+    Estimate {
+        unit: table.unit,
+        total: breakdown.iter().map(|(_, cost)| cost).sum(),
+        breakdown,
+    }
+}
@@ -0,0 +1,627 @@
+//! Defining your own prime fields.
+//!
+//! The fields that `arkworks` ships are those of its curves, but the arithmetic of
+//! `Fp64`, `Fp256`, `Fp384` and friends works for any prime modulus; all it needs is a
+//! type implementing `FpParameters`, with the modulus and a dozen constants derived from
+//! it for Montgomery multiplication, square roots and FFTs. [`toy_fields`] spells these
+//! constants out by hand. [`define_prime_field!`] computes them at compile time from
+//! the modulus alone, so a prime from a paper or a puzzle becomes a field in one line:
+//!
+//! ```rust
+//! use ark_algebra_intro::define_prime_field;
+//! use ark_ff::{FftField, FftParameters, Field, One, PrimeField, SquareRootField};
+//!
+//! // The "Goldilocks" prime 2^64 - 2^32 + 1, popular in STARKs.
+//! define_prime_field!(Goldilocks, "18446744069414584321");
+//!
+//! let a = Goldilocks::from(3u64);
+//! assert_eq!(a.pow([18446744069414584320u64]), Goldilocks::one());
+//! assert_eq!(a.inverse().unwrap() * a, Goldilocks::one());
+//! assert_eq!((a * a).sqrt().map(|r| r == a || r == -a), Some(true));
+//! // p - 1 = 2^32 · (2^32 - 1), so there are roots of unity of order up to 2^32.
+//! assert_eq!(<Goldilocks as PrimeField>::Params::TWO_ADICITY, 32);
+//! // The smallest generator of the multiplicative group.
+//! assert_eq!(Goldilocks::multiplicative_generator(), Goldilocks::from(7u64));
+//! ```
+//!
+//! The resulting type is an ordinary `arkworks` field, next to a parameters type named
+//! after it (here `GoldilocksParameters`), so everything in this crate works with it,
+//! from [`laws::check_field`](crate::laws::check_field) to polynomials and FFTs:
+//!
+//! ```rust
+//! use ark_algebra_intro::define_prime_field;
+//! use ark_algebra_intro::laws::check_field;
+//! use ark_algebra_intro::rng::seeded;
+//! use ark_poly::{EvaluationDomain, Radix2EvaluationDomain};
+//!
+//! // 998244353 = 119 · 2^23 + 1, the usual prime for number-theoretic transforms.
+//! define_prime_field!(pub Ntt, "998244353", Fp64);
+//!
+//! assert_eq!(check_field::<Ntt, _>(100, &mut seeded(0)), Ok(()));
+//! let domain = Radix2EvaluationDomain::<Ntt>::new(1 << 20).unwrap();
+//! let values: Vec<Ntt> = (0..1u64 << 20).map(Ntt::from).collect();
+//! assert_eq!(domain.ifft(&domain.fft(&values)), values);
+//! ```
+//!
+//! The optional last argument picks the representation, from `Fp64` (one 64-bit limb)
+//! through `Fp256`, `Fp320`, `Fp384`, `Fp448` and `Fp768` to `Fp832` (13 limbs). It
+//! defaults to `Fp384`, which fits the fields of every curve in this crate; smaller
+//! primes are faster with fewer limbs. The arithmetic of `arkworks` 0.3 needs the top
+//! bit of the representation to be free, so the modulus must have fewer bits than the
+//! limbs do: Goldilocks, with its 64 bits, doesn't fit in `Fp64`. A modulus that isn't a
+//! decimal integer, doesn't fit, or isn't prime is a compile error:
+//!
+//! ```compile_fail
+//! // 1000000016000000063 = 1000000007 · 1000000009.
+//! ark_algebra_intro::define_prime_field!(NotAField, "1000000016000000063");
+//! ```
+//!
+//! ```compile_fail
+//! // 318665857834031151167461 = 399165290221 · 798330580441 passes the Miller-Rabin
+//! // test for every base up to 37.
+//! ark_algebra_intro::define_prime_field!(NotAField, "318665857834031151167461", Fp256);
+//! ```
+//!
+//! ```compile_fail
+//! ark_algebra_intro::define_prime_field!(TooBig, "18446744069414584321", Fp64);
+//! ```
+//!
+//! The constants are in [`FieldParameters`]. Their one weakness is `GENERATOR`, which
+//! should generate the whole multiplicative group: checking that requires the prime
+//! factors of `p - 1`, and the macro only finds those below 1000 (and a cofactor that
+//! is itself prime). When that isn't enough, `GENERATOR` is the smallest quadratic
+//! non-residue without those factors in its order, which still makes square roots and
+//! FFTs work; [`FieldParameters::generator_has_full_order`] says which happened.
+//!
+//! All of this happens in the compiler's interpreter for constants, which is slow: a
+//! 381-bit modulus takes a few seconds to compile, and one of 800 bits about half a
+//! minute.
+//!
+//! [`toy_fields`]: crate::toy_fields
+//! [`define_prime_field!`]: crate::define_prime_field
+
+#[doc(hidden)]
+pub use ark_ff as __ark_ff;
+#[doc(hidden)]
+pub use paste as __paste;
+
+/// Defines a prime field type with the given decimal modulus, computing its parameters
+/// at compile time.
+///
+/// `define_prime_field!(MyField, "modulus")` defines `MyField` and
+/// `MyFieldParameters`, with the visibility given before the name (as in
+/// `define_prime_field!(pub MyField, "modulus")`). An optional third argument,
+/// one of `Fp64`, `Fp256`, `Fp320`, `Fp384` (the default), `Fp448`, `Fp768` and
+/// `Fp832`, picks the representation. See the
+/// [module documentation](crate::custom_fields) for examples.
+#[macro_export]
+macro_rules! define_prime_field {
+    (@define $vis:vis $name:ident, $modulus:literal, $fp:ident, $bigint:ident, $limbs:tt) => {
+        $crate::custom_fields::__paste::paste! {
+            #[doc = concat!("The prime field of order ", $modulus, ".")]
+            $vis type $name =
+                $crate::custom_fields::__ark_ff::fields::$fp<[<$name Parameters>]>;
+
+            #[doc = concat!("Parameters for [`", stringify!($name), "`].")]
+            $vis struct [<$name Parameters>];
+
+            impl [<$name Parameters>] {
+                // Large moduli take more than the default budget for constant evaluation.
+                #[allow(long_running_const_eval)]
+                const PARAMS: $crate::custom_fields::FieldParameters<$limbs> =
+                    $crate::custom_fields::FieldParameters::new($modulus);
+            }
+
+            const _: () = {
+                use $crate::custom_fields::__ark_ff::biginteger::$bigint;
+                use $crate::custom_fields::__ark_ff::fields::{
+                    FftParameters, FpParameters, [<$fp Parameters>],
+                };
+
+                impl [<$fp Parameters>] for [<$name Parameters>] {}
+
+                impl FftParameters for [<$name Parameters>] {
+                    type BigInt = $bigint;
+
+                    const TWO_ADICITY: u32 = Self::PARAMS.two_adicity;
+                    const TWO_ADIC_ROOT_OF_UNITY: $bigint =
+                        $bigint(Self::PARAMS.montgomery_two_adic_root_of_unity);
+                }
+
+                impl FpParameters for [<$name Parameters>] {
+                    const MODULUS: $bigint = $bigint(Self::PARAMS.modulus);
+                    const MODULUS_BITS: u32 = Self::PARAMS.modulus_bits;
+                    const CAPACITY: u32 = Self::PARAMS.modulus_bits - 1;
+                    const REPR_SHAVE_BITS: u32 = 64 * $limbs - Self::PARAMS.modulus_bits;
+                    const R: $bigint = $bigint(Self::PARAMS.r);
+                    const R2: $bigint = $bigint(Self::PARAMS.r2);
+                    const INV: u64 = Self::PARAMS.inv;
+                    const GENERATOR: $bigint = $bigint(Self::PARAMS.montgomery_generator);
+                    const MODULUS_MINUS_ONE_DIV_TWO: $bigint =
+                        $bigint(Self::PARAMS.modulus_minus_one_div_two);
+                    const T: $bigint = $bigint(Self::PARAMS.t);
+                    const T_MINUS_ONE_DIV_TWO: $bigint = $bigint(Self::PARAMS.t_minus_one_div_two);
+                }
+            };
+        }
+    };
+    ($vis:vis $name:ident, $modulus:literal) => {
+        $crate::define_prime_field!($vis $name, $modulus, Fp384);
+    };
+    ($vis:vis $name:ident, $modulus:literal, Fp64) => {
+        $crate::define_prime_field!(@define $vis $name, $modulus, Fp64, BigInteger64, 1);
+    };
+    ($vis:vis $name:ident, $modulus:literal, Fp256) => {
+        $crate::define_prime_field!(@define $vis $name, $modulus, Fp256, BigInteger256, 4);
+    };
+    ($vis:vis $name:ident, $modulus:literal, Fp320) => {
+        $crate::define_prime_field!(@define $vis $name, $modulus, Fp320, BigInteger320, 5);
+    };
+    ($vis:vis $name:ident, $modulus:literal, Fp384) => {
+        $crate::define_prime_field!(@define $vis $name, $modulus, Fp384, BigInteger384, 6);
+    };
+    ($vis:vis $name:ident, $modulus:literal, Fp448) => {
+        $crate::define_prime_field!(@define $vis $name, $modulus, Fp448, BigInteger448, 7);
+    };
+    ($vis:vis $name:ident, $modulus:literal, Fp768) => {
+        $crate::define_prime_field!(@define $vis $name, $modulus, Fp768, BigInteger768, 12);
+    };
+    ($vis:vis $name:ident, $modulus:literal, Fp832) => {
+        $crate::define_prime_field!(@define $vis $name, $modulus, Fp832, BigInteger832, 13);
+    };
+}
+
+/// The constants that `FpParameters` needs for a prime modulus, as `N` little-endian
+/// 64-bit limbs.
+///
+/// Those marked "Montgomery" are in Montgomery form: `x` is stored as `x · R mod p`.
+///
+/// ```rust
+/// use ark_algebra_intro::custom_fields::FieldParameters;
+///
+/// const F7: FieldParameters<1> = FieldParameters::new("7");
+/// assert_eq!(F7.r, [2]); // 2^64 = 2 (mod 7)
+/// assert_eq!(F7.inv.wrapping_mul(7), u64::MAX); // -1 / 7 (mod 2^64)
+/// assert_eq!((F7.two_adicity, F7.t), (1, [3])); // 7 - 1 = 2^1 · 3
+/// assert_eq!(F7.generator, 3);
+/// assert!(F7.generator_has_full_order);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FieldParameters<const N: usize> {
+    /// The modulus `p`.
+    pub modulus: [u64; N],
+    /// The number of bits of `p`.
+    pub modulus_bits: u32,
+    /// `R = 2^(64 · N) mod p`, i.e., 1 in Montgomery form.
+    pub r: [u64; N],
+    /// `R^2 mod p`.
+    pub r2: [u64; N],
+    /// `-1 / p mod 2^64`.
+    pub inv: u64,
+    /// The largest `s` such that `2^s` divides `p - 1`.
+    pub two_adicity: u32,
+    /// `t = (p - 1) / 2^s`.
+    pub t: [u64; N],
+    /// `(t - 1) / 2`.
+    pub t_minus_one_div_two: [u64; N],
+    /// `(p - 1) / 2`.
+    pub modulus_minus_one_div_two: [u64; N],
+    /// The generator of the multiplicative group (see
+    /// [`generator_has_full_order`](Self::generator_has_full_order)).
+    pub generator: u64,
+    /// Whether [`generator`](Self::generator) is known to have order `p - 1`. If not, it
+    /// is a quadratic non-residue whose order has no prime factors below 1000 missing.
+    pub generator_has_full_order: bool,
+    /// The generator, in Montgomery form.
+    pub montgomery_generator: [u64; N],
+    /// `generator^t`, a root of unity of order `2^s`, in Montgomery form.
+    pub montgomery_two_adic_root_of_unity: [u64; N],
+}
+
+/// The odd numbers below this are tried as factors of `p - 1`.
+const TRIAL_DIVISION_BOUND: u64 = 1000;
+
+/// The bases of the Miller-Rabin test. They make it exact below `3.3 · 10^24`; above
+/// that, no composite passes them by accident, only those built to fool the test do.
+const MILLER_RABIN_BASES: [u64; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+impl<const N: usize> FieldParameters<N> {
+    /// Computes the parameters of the prime field with the decimal `modulus`.
+    ///
+    /// # Panics
+    ///
+    /// Panics (which, in a constant, is a compile error) if `modulus` is not a decimal
+    /// integer, does not fit in `N` limbs with the top bit free, is smaller than 3, or is
+    /// not prime.
+    pub const fn new(modulus: &str) -> Self {
+        let p = parse::<N>(modulus);
+        if !geq(&p, &small(3)) {
+            panic!("the modulus must be at least 3");
+        }
+        if bits(&p) == 64 * N as u32 {
+            panic!("the modulus must leave the top bit of the representation free");
+        }
+        if !is_probable_prime(&p) {
+            panic!("the modulus is not prime");
+        }
+        let mont = Montgomery::new(p);
+        let p_minus_one = sub(&p, &small(1));
+        let two_adicity = trailing_zeros(&p_minus_one);
+        let t = shr(&p_minus_one, two_adicity);
+        let (generator, generator_has_full_order) = find_generator(&mont, &p_minus_one, &t);
+        let montgomery_generator = mont.to_montgomery(&small(generator));
+        FieldParameters {
+            modulus: p,
+            modulus_bits: bits(&p),
+            r: mont.r,
+            r2: mont.r2,
+            inv: mont.inv,
+            two_adicity,
+            t,
+            t_minus_one_div_two: shr(&t, 1),
+            modulus_minus_one_div_two: shr(&p_minus_one, 1),
+            generator,
+            generator_has_full_order,
+            montgomery_generator,
+            montgomery_two_adic_root_of_unity: mont.pow(&montgomery_generator, &t),
+        }
+    }
+}
+
+/// Parses the decimal `s` (with optional `_` separators) into `N` limbs.
+const fn parse<const N: usize>(s: &str) -> [u64; N] {
+    let bytes = s.as_bytes();
+    if bytes.is_empty() {
+        panic!("the modulus must be a decimal integer");
+    }
+    let mut result = [0u64; N];
+    let mut i = 0;
+    while i < bytes.len() {
+        let byte = bytes[i];
+        i += 1;
+        if byte == b'_' {
+            continue;
+        }
+        if !byte.is_ascii_digit() {
+            panic!("the modulus must be a decimal integer");
+        }
+        let mut carry = (byte - b'0') as u128;
+        let mut j = 0;
+        while j < N {
+            let x = result[j] as u128 * 10 + carry;
+            result[j] = x as u64;
+            carry = x >> 64;
+            j += 1;
+        }
+        if carry != 0 {
+            panic!("the modulus does not fit in the chosen representation");
+        }
+    }
+    result
+}
+
+const fn small<const N: usize>(x: u64) -> [u64; N] {
+    let mut result = [0u64; N];
+    result[0] = x;
+    result
+}
+
+const fn eq<const N: usize>(a: &[u64; N], b: &[u64; N]) -> bool {
+    let mut i = 0;
+    while i < N {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns whether `a >= b`.
+const fn geq<const N: usize>(a: &[u64; N], b: &[u64; N]) -> bool {
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+    }
+    true
+}
+
+/// Returns `a - b mod 2^(64 · N)`.
+const fn sub<const N: usize>(a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+    let mut result = [0u64; N];
+    let mut borrow = 0u64;
+    let mut i = 0;
+    while i < N {
+        let (x, b1) = a[i].overflowing_sub(b[i]);
+        let (x, b2) = x.overflowing_sub(borrow);
+        result[i] = x;
+        borrow = (b1 | b2) as u64;
+        i += 1;
+    }
+    result
+}
+
+/// Returns `a + b mod m`, for `a, b < m`.
+const fn add_mod<const N: usize>(a: &[u64; N], b: &[u64; N], m: &[u64; N]) -> [u64; N] {
+    let mut sum = [0u64; N];
+    let mut carry = 0u64;
+    let mut i = 0;
+    while i < N {
+        let x = a[i] as u128 + b[i] as u128 + carry as u128;
+        sum[i] = x as u64;
+        carry = (x >> 64) as u64;
+        i += 1;
+    }
+    if carry != 0 || geq(&sum, m) {
+        sub(&sum, m)
+    } else {
+        sum
+    }
+}
+
+/// Returns `a >> shift`, for `shift < 64 · N`.
+const fn shr<const N: usize>(a: &[u64; N], shift: u32) -> [u64; N] {
+    let (limbs, shift) = ((shift / 64) as usize, shift % 64);
+    let mut result = [0u64; N];
+    let mut i = 0;
+    while i + limbs < N {
+        result[i] = a[i + limbs] >> shift;
+        if shift > 0 && i + limbs + 1 < N {
+            result[i] |= a[i + limbs + 1] << (64 - shift);
+        }
+        i += 1;
+    }
+    result
+}
+
+/// Returns `(a / d, a % d)`.
+const fn div_small<const N: usize>(a: &[u64; N], d: u64) -> ([u64; N], u64) {
+    let mut quotient = [0u64; N];
+    let mut remainder = 0u128;
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        let x = (remainder << 64) | a[i] as u128;
+        quotient[i] = (x / d as u128) as u64;
+        remainder = x % d as u128;
+    }
+    (quotient, remainder as u64)
+}
+
+/// Returns the number of bits of `a`.
+const fn bits<const N: usize>(a: &[u64; N]) -> u32 {
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        if a[i] != 0 {
+            return 64 * i as u32 + 64 - a[i].leading_zeros();
+        }
+    }
+    0
+}
+
+/// Returns the number of trailing zero bits of the nonzero `a`.
+const fn trailing_zeros<const N: usize>(a: &[u64; N]) -> u32 {
+    let mut i = 0;
+    while a[i] == 0 {
+        i += 1;
+    }
+    64 * i as u32 + a[i].trailing_zeros()
+}
+
+/// Montgomery arithmetic modulo an odd `m`.
+struct Montgomery<const N: usize> {
+    m: [u64; N],
+    inv: u64,
+    r: [u64; N],
+    r2: [u64; N],
+}
+
+impl<const N: usize> Montgomery<N> {
+    const fn new(m: [u64; N]) -> Self {
+        // m^(2^63 - 1) = 1 / m (mod 2^64), since the odd residues have order 2^62.
+        let mut inv = 1u64;
+        let mut i = 0;
+        while i < 63 {
+            inv = inv.wrapping_mul(inv).wrapping_mul(m[0]);
+            i += 1;
+        }
+        // Doubling 1 modulo m, 64 · N times for R and as many again for R^2.
+        let mut r = small(1);
+        let mut i = 0;
+        while i < 64 * N {
+            r = add_mod(&r, &r, &m);
+            i += 1;
+        }
+        let mut r2 = r;
+        while i < 128 * N {
+            r2 = add_mod(&r2, &r2, &m);
+            i += 1;
+        }
+        Montgomery {
+            m,
+            inv: inv.wrapping_neg(),
+            r,
+            r2,
+        }
+    }
+
+    /// Returns `a · b / R mod m`, by the CIOS method.
+    const fn mul(&self, a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+        let mut t = [0u64; N];
+        let mut top = 0u64;
+        let mut i = 0;
+        while i < N {
+            // t += a · b[i]
+            let mut carry = 0u64;
+            let mut j = 0;
+            while j < N {
+                let x = t[j] as u128 + a[j] as u128 * b[i] as u128 + carry as u128;
+                t[j] = x as u64;
+                carry = (x >> 64) as u64;
+                j += 1;
+            }
+            let x = top as u128 + carry as u128;
+            top = x as u64;
+            let overflow = (x >> 64) as u64;
+            // t = (t + k · m) / 2^64, where k makes the division exact.
+            let k = t[0].wrapping_mul(self.inv);
+            let x = t[0] as u128 + k as u128 * self.m[0] as u128;
+            let mut carry = (x >> 64) as u64;
+            let mut j = 1;
+            while j < N {
+                let x = t[j] as u128 + k as u128 * self.m[j] as u128 + carry as u128;
+                t[j - 1] = x as u64;
+                carry = (x >> 64) as u64;
+                j += 1;
+            }
+            let x = top as u128 + carry as u128;
+            t[N - 1] = x as u64;
+            top = overflow + (x >> 64) as u64;
+            i += 1;
+        }
+        if top != 0 || geq(&t, &self.m) {
+            sub(&t, &self.m)
+        } else {
+            t
+        }
+    }
+
+    /// Returns `a` in Montgomery form, for `a < m`.
+    const fn to_montgomery(&self, a: &[u64; N]) -> [u64; N] {
+        self.mul(a, &self.r2)
+    }
+
+    /// Returns `base^exponent`, for `base` in Montgomery form.
+    const fn pow(&self, base: &[u64; N], exponent: &[u64; N]) -> [u64; N] {
+        let mut result = self.r;
+        let mut i = bits(exponent);
+        while i > 0 {
+            i -= 1;
+            result = self.mul(&result, &result);
+            if (exponent[i as usize / 64] >> (i % 64)) & 1 == 1 {
+                result = self.mul(&result, base);
+            }
+        }
+        result
+    }
+
+    /// Returns `-1` in Montgomery form.
+    const fn minus_one(&self) -> [u64; N] {
+        sub(&self.m, &self.r)
+    }
+}
+
+/// Returns whether `n` passes the Miller-Rabin test for the bases in
+/// [`MILLER_RABIN_BASES`] below `n`.
+const fn is_probable_prime<const N: usize>(n: &[u64; N]) -> bool {
+    if !geq(n, &small(2)) {
+        return false;
+    }
+    if n[0] & 1 == 0 {
+        return eq(n, &small(2));
+    }
+    let mont = Montgomery::new(*n);
+    let n_minus_one = sub(n, &small(1));
+    let s = trailing_zeros(&n_minus_one);
+    let d = shr(&n_minus_one, s);
+    let minus_one = mont.minus_one();
+    let mut i = 0;
+    while i < MILLER_RABIN_BASES.len() {
+        let base = small(MILLER_RABIN_BASES[i]);
+        i += 1;
+        if geq(&base, n) {
+            break;
+        }
+        let mut x = mont.pow(&mont.to_montgomery(&base), &d);
+        if eq(&x, &mont.r) || eq(&x, &minus_one) {
+            continue;
+        }
+        let mut j = 1;
+        while j < s && !eq(&x, &minus_one) {
+            x = mont.mul(&x, &x);
+            j += 1;
+        }
+        if !eq(&x, &minus_one) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Returns the smallest `g` such that `g^((p - 1) / q) != 1` for every known prime
+/// factor `q` of `p - 1`, and whether all of them are known.
+const fn find_generator<const N: usize>(
+    mont: &Montgomery<N>,
+    p_minus_one: &[u64; N],
+    t: &[u64; N],
+) -> (u64, bool) {
+    let mut candidate = 2;
+    loop {
+        let g = mont.to_montgomery(&small(candidate));
+        // The prime factor 2, for which the check is that g is not a square.
+        let mut is_generator = !eq(&mont.pow(&g, &shr(p_minus_one, 1)), &mont.r);
+        // The odd prime factors below `TRIAL_DIVISION_BOUND`.
+        let mut cofactor = *t;
+        let mut q = 3;
+        while is_generator && q < TRIAL_DIVISION_BOUND {
+            let (quotient, remainder) = div_small(&cofactor, q);
+            if remainder == 0 {
+                let (exponent, _) = div_small(p_minus_one, q);
+                is_generator = !eq(&mont.pow(&g, &exponent), &mont.r);
+                cofactor = quotient;
+                while let (quotient, 0) = div_small(&cofactor, q) {
+                    cofactor = quotient;
+                }
+            }
+            q += 2;
+        }
+        // The rest of `t`, if it is a prime.
+        let mut has_full_order = eq(&cofactor, &small(1));
+        if is_generator && !has_full_order && is_probable_prime(&cofactor) {
+            let exponent = div_exact(p_minus_one, &cofactor);
+            is_generator = !eq(&mont.pow(&g, &exponent), &mont.r);
+            has_full_order = true;
+        }
+        if is_generator {
+            return (candidate, has_full_order);
+        }
+        candidate += 1;
+    }
+}
+
+/// Returns `a / b`, for `b` dividing `a`, by long division.
+const fn div_exact<const N: usize>(a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+    let mut result = [0u64; N];
+    let mut remainder = *a;
+    let shift = bits(a) - bits(b);
+    let mut i = shift + 1;
+    while i > 0 {
+        i -= 1;
+        let shifted = shl(b, i);
+        // `shl` drops the bits shifted out, so check that none were.
+        if eq(&shr(&shifted, i), b) && geq(&remainder, &shifted) {
+            remainder = sub(&remainder, &shifted);
+            result[i as usize / 64] |= 1 << (i % 64);
+        }
+    }
+    result
+}
+
+/// Returns `a << shift mod 2^(64 · N)`, for `shift < 64 · N`.
+const fn shl<const N: usize>(a: &[u64; N], shift: u32) -> [u64; N] {
+    let (limbs, shift) = ((shift / 64) as usize, shift % 64);
+    let mut result = [0u64; N];
+    let mut i = limbs;
+    while i < N {
+        result[i] = a[i - limbs] << shift;
+        if shift > 0 && i > limbs {
+            result[i] |= a[i - limbs - 1] >> (64 - shift);
+        }
+        i += 1;
+    }
+    result
+}
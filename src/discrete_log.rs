@@ -0,0 +1,322 @@
+//! Computing discrete logarithms in small groups.
+//!
+//! The security of Schnorr signatures, ElGamal, BLS and most of the rest of this crate
+//! rests on the *discrete logarithm problem*: given `base` and `target = x · base`,
+//! finding `x` is infeasible. The best generic algorithms, [`bsgs`] (baby-step
+//! giant-step) and [`pollard_rho`], take about `√n` group operations in a group of
+//! order `n`. That is `2^127` operations in the 255-bit group of BLS12-381, but only
+//! about a million in a 40-bit group, which a laptop does in a second:
+//!
+//! ```rust
+//! use ark_algebra_intro::define_prime_field;
+//! use ark_algebra_intro::discrete_log::{bsgs, pollard_rho, DlogGroup, Multiplicative};
+//! use ark_algebra_intro::rng::seeded;
+//! use ark_ff::Field;
+//!
+//! // p = 2q + 1 for the 40-bit prime q, so the nonzero squares modulo p form a group of
+//! // order q, generated by any of them but 1.
+//! define_prime_field!(Toy, "1099511628443", Fp64);
+//! let q = 549_755_814_221u64;
+//! let g = Multiplicative(Toy::from(4u64));
+//!
+//! // An account's key pair: a secret exponent, and the public key g^x.
+//! let secret = 123_456_789_012u64;
+//! let public = g.times(secret);
+//! assert_eq!(public, Multiplicative(Toy::from(4u64).pow([secret])));
+//!
+//! // Either algorithm recovers the secret key from the public key alone.
+//! assert_eq!(pollard_rho(&g, &public, q, &mut seeded(0)), Ok(secret));
+//! assert_eq!(bsgs(&g, &public, q - 1), Ok(secret));
+//! ```
+//!
+//! [`bsgs`] needs a table of `√n` elements, but can also search a smaller range: a key
+//! that was sampled from the first `2^20` integers instead of the whole group falls in
+//! a thousand steps, however large the group is. That is why the scalars of a key must
+//! be uniformly random, not merely unique:
+//!
+//! ```rust
+//! use ark_algebra_intro::discrete_log::bsgs;
+//! use ark_bls12_381::G1Projective;
+//! use ark_ec::ProjectiveCurve;
+//!
+//! let g = G1Projective::prime_subgroup_generator();
+//! let public = g.mul([654_321u64]);
+//! assert_eq!(bsgs(&g, &public, 1 << 20), Ok(654_321));
+//! ```
+//!
+//! Both work in any [`DlogGroup`]: the points of an `arkworks` curve or of a
+//! [`ToyCurve`](crate::toy_curves::ToyCurve), and the [`Multiplicative`] groups of
+//! `arkworks` fields and of [`DynField`]s.
+
+use crate::dynamic::DynField;
+use crate::Error;
+use ark_ec::ProjectiveCurve;
+use ark_ff::Field;
+use ark_serialize::CanonicalSerialize;
+use ark_std::collections::BTreeMap;
+use ark_std::rand::Rng;
+use ark_std::vec::Vec;
+
+/// A finite group, written additively, in which to compute discrete logarithms.
+pub trait DlogGroup: Clone + Eq {
+    /// Returns `self + other`.
+    fn combine(&self, other: &Self) -> Self;
+
+    /// Returns `-self`.
+    fn negate(&self) -> Self;
+
+    /// Returns an encoding of `self`, which is the same for equal elements and different
+    /// for different ones.
+    fn encode(&self) -> Vec<u8>;
+
+    /// Returns the identity `0`.
+    fn identity(&self) -> Self {
+        self.combine(&self.negate())
+    }
+
+    /// Returns `k · self`, by double-and-add.
+    fn times(&self, k: u64) -> Self {
+        let mut result = self.identity();
+        for i in (0..64 - k.leading_zeros()).rev() {
+            result = result.combine(&result);
+            if (k >> i) & 1 == 1 {
+                result = result.combine(self);
+            }
+        }
+        result
+    }
+}
+
+impl<G: ProjectiveCurve> DlogGroup for G {
+    fn combine(&self, other: &Self) -> Self {
+        *self + other
+    }
+
+    fn negate(&self) -> Self {
+        -*self
+    }
+
+    /// Returns the affine coordinates of `self`, since the projective ones aren't unique.
+    fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        self.into_affine()
+            .serialize_uncompressed(&mut bytes)
+            .expect("serializing to a vector never fails");
+        bytes
+    }
+}
+
+/// A nonzero element of a field, as an element of its multiplicative group.
+///
+/// The group operation is multiplication, so `k · g` is `g^k`, and the discrete
+/// logarithm of `h` to the base `g` is the `x` with `g^x = h`.
+///
+/// # Panics
+///
+/// [`DlogGroup::negate`] panics if the element is `0`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Multiplicative<F>(pub F);
+
+impl<F: Field> DlogGroup for Multiplicative<F> {
+    fn combine(&self, other: &Self) -> Self {
+        Multiplicative(self.0 * other.0)
+    }
+
+    fn negate(&self) -> Self {
+        Multiplicative(
+            self.0
+                .inverse()
+                .expect("0 is not in the multiplicative group"),
+        )
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        self.0
+            .serialize(&mut bytes)
+            .expect("serializing to a vector never fails");
+        bytes
+    }
+}
+
+impl DlogGroup for Multiplicative<DynField> {
+    fn combine(&self, other: &Self) -> Self {
+        Multiplicative(&self.0 * &other.0)
+    }
+
+    fn negate(&self) -> Self {
+        Multiplicative(
+            self.0
+                .inverse()
+                .expect("0 is not in the multiplicative group"),
+        )
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.0.value().to_bytes_le()
+    }
+}
+
+/// Finds the smallest `x ≤ bound` such that `target = x · base` by baby-step giant-step,
+/// or returns [`Error::DiscreteLogNotFound`] if there is none.
+///
+/// Writing `x = i · s + j` with `s = ⌈√(bound + 1)⌉` and `0 ≤ i, j < s`, this stores
+/// the "baby steps" `j · base` in a table, and then walks the "giant steps"
+/// `target - i · (s · base)` until one of them is in the table. This takes `O(√bound)`
+/// time and memory, instead of the `O(bound)` time of trying every `x`.
+///
+/// ```rust
+/// use ark_algebra_intro::discrete_log::{bsgs, DlogGroup, Multiplicative};
+/// use ark_algebra_intro::toy_fields::F101;
+/// use ark_algebra_intro::Error;
+///
+/// // 2 generates the multiplicative group of F101, of order 100.
+/// let g = Multiplicative(F101::from(2u64));
+/// assert_eq!(bsgs(&g, &g.times(70), 99), Ok(70));
+/// assert_eq!(bsgs(&g, &g.times(70), 50), Err(Error::DiscreteLogNotFound));
+/// // Exponents are only defined modulo the order of the group.
+/// assert_eq!(bsgs(&g, &g.times(170), 1000), Ok(70));
+/// ```
+pub fn bsgs<G: DlogGroup>(base: &G, target: &G, bound: u64) -> Result<u64, Error> {
+    let s = ceil_sqrt(bound.saturating_add(1));
+
+    let mut baby_steps = BTreeMap::new();
+    let mut point = base.identity();
+    for j in 0..s {
+        baby_steps.entry(point.encode()).or_insert(j);
+        point = point.combine(base);
+    }
+
+    // `point` is now `s · base`.
+    let giant_step = point.negate();
+    let mut point = target.clone();
+    for i in 0..s {
+        if let Some(j) = baby_steps.get(&point.encode()) {
+            let x = i * s + j;
+            return if x <= bound {
+                Ok(x)
+            } else {
+                Err(Error::DiscreteLogNotFound)
+            };
+        }
+        point = point.combine(&giant_step);
+    }
+    Err(Error::DiscreteLogNotFound)
+}
+
+/// Finds `x < order` such that `target = x · base`, by Pollard's rho algorithm, or
+/// returns [`Error::DiscreteLogNotFound`] if there is none.
+///
+/// `order` must be the order of `base`, and prime. The algorithm walks a pseudorandom
+/// sequence of elements `a · base + b · target` from a random start, where each element
+/// determines the next, until the sequence repeats itself; after about `√order` steps,
+/// the birthday paradox says it will. Two ways of writing the same element give an
+/// equation `a + b · x = a' + b' · x`, which determines `x`. This takes the same
+/// `O(√order)` time as [`bsgs`], but only constant memory.
+///
+/// ```rust
+/// use ark_algebra_intro::discrete_log::{pollard_rho, DlogGroup, Multiplicative};
+/// use ark_algebra_intro::dynamic::DynPrimeField;
+/// use ark_algebra_intro::rng::seeded;
+/// use ark_algebra_intro::Error;
+/// use num_bigint::BigUint;
+///
+/// // 4 has order 1031 modulo the prime 2063 = 2 · 1031 + 1.
+/// let f = DynPrimeField::new(BigUint::from(2063u64)).unwrap();
+/// let g = Multiplicative(f.element(4u64));
+/// assert_eq!(pollard_rho(&g, &g.times(777), 1031, &mut seeded(0)), Ok(777));
+/// // 5 is not a square modulo 2063, so it's not a power of 4.
+/// let five = Multiplicative(f.element(5u64));
+/// assert_eq!(
+///     pollard_rho(&g, &five, 1031, &mut seeded(0)),
+///     Err(Error::DiscreteLogNotFound)
+/// );
+/// ```
+pub fn pollard_rho<G: DlogGroup, R: Rng + ?Sized>(
+    base: &G,
+    target: &G,
+    order: u64,
+    rng: &mut R,
+) -> Result<u64, Error> {
+    // The walk needs a few steps to become random, so tiny groups are searched directly.
+    if order < 1 << 10 {
+        return bsgs(base, target, order.saturating_sub(1));
+    }
+    let n = u128::from(order);
+    let add = |x: u64, y: u64| ((u128::from(x) + u128::from(y)) % n) as u64;
+    let mul = |x: u64, y: u64| (u128::from(x) * u128::from(y) % n) as u64;
+
+    // Splits the elements into three classes, which add `base`, double, or add `target`.
+    let step = |(point, a, b): &(G, u64, u64)| match fingerprint(&point.encode()) % 3 {
+        0 => (point.combine(base), add(*a, 1), *b),
+        1 => (point.combine(point), add(*a, *a), add(*b, *b)),
+        _ => (point.combine(target), *a, add(*b, 1)),
+    };
+
+    // A random start rarely fails, so a few attempts are plenty.
+    for _ in 0..16 {
+        let (a, b) = (rng.gen_range(0..order), rng.gen_range(0..order));
+        let start = base.times(a).combine(&target.times(b));
+
+        // Brent's cycle detection: the tortoise waits at each power of two for the hare.
+        let mut tortoise = (start, a, b);
+        let mut hare = step(&tortoise);
+        let (mut power, mut length) = (1u64, 1u64);
+        while tortoise.0 != hare.0 {
+            if power == length {
+                tortoise = hare.clone();
+                power *= 2;
+                length = 0;
+            }
+            hare = step(&hare);
+            length += 1;
+        }
+
+        // a + b · x = a' + b' · x, so x = (a - a') / (b' - b).
+        let (_, a, b) = tortoise;
+        let (_, a2, b2) = hare;
+        let db = add(b2, order - b);
+        if db == 0 {
+            continue;
+        }
+        let x = mul(add(a, order - a2), pow(db, order - 2, n));
+        if base.times(x) == *target {
+            return Ok(x);
+        }
+    }
+    Err(Error::DiscreteLogNotFound)
+}
+
+/// Returns `⌈√n⌉`, by binary search.
+fn ceil_sqrt(n: u64) -> u64 {
+    let (mut low, mut high) = (0u64, 1 << 32);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if mid.saturating_mul(mid) < n {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+/// Returns `base^exponent mod n`.
+fn pow(base: u64, mut exponent: u64, n: u128) -> u64 {
+    let (mut base, mut result) = (u128::from(base), 1u128);
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % n;
+        }
+        base = base * base % n;
+        exponent >>= 1;
+    }
+    result as u64
+}
+
+/// Returns the 64-bit FNV-1a hash of `bytes`, which scatters similar encodings.
+fn fingerprint(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
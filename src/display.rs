@@ -0,0 +1,140 @@
+//! Human-readable formatting for prime field elements.
+//!
+//! The `Debug` output of an `arkworks` field element shows its internal
+//! limbs in [Montgomery form](crate::fields::montgomery_view), which is not
+//! much help when comparing against a calculation done by hand. The wrappers in
+//! this module instead print the canonical integer value of an element, in any of
+//! the usual radices:
+//!
+//! ```rust
+//! use ark_algebra_intro::display::FieldFmt;
+//! use ark_bls12_381::Fr;
+//!
+//! let a = FieldFmt(Fr::from(255u64));
+//! assert_eq!(format!("{}", a), "255");
+//! assert_eq!(format!("{:x}", a), "ff");
+//! assert_eq!(format!("{:#X}", a), "0xFF");
+//! assert_eq!(format!("{:b}", a), "11111111");
+//! assert_eq!(format!("{:>6}", a), "   255");
+//! ```
+
+pub mod latex;
+
+use ark_ff::{FpParameters, PrimeField};
+use ark_std::fmt;
+use num_bigint::BigUint;
+
+/// A wrapper around a prime field element that formats its canonical integer value.
+///
+/// Supports [`Display`](fmt::Display) (decimal), [`LowerHex`](fmt::LowerHex),
+/// [`UpperHex`](fmt::UpperHex), and [`Binary`](fmt::Binary) formatting, including
+/// the usual width, fill, and `#` flags. See the [module documentation](self) for examples.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FieldFmt<F>(pub F);
+
+impl<F: PrimeField> FieldFmt<F> {
+    /// Formats the element in *balanced* representation, i.e., as an integer in the
+    /// range `[-(p - 1)/2, (p - 1)/2]`.
+    ///
+    /// This makes small negative values readable:
+    ///
+    /// ```rust
+    /// use ark_algebra_intro::display::FieldFmt;
+    /// use ark_bls12_381::Fr;
+    ///
+    /// let a = -Fr::from(5u64);
+    /// // The canonical representation of -5 is r - 5...
+    /// assert_eq!(
+    ///     format!("{}", FieldFmt(a)),
+    ///     "52435875175126190479447740508185965837690552500527637822603658699938581184508",
+    /// );
+    /// // ...but the balanced representation is much more helpful:
+    /// assert_eq!(format!("{}", FieldFmt(a).balanced()), "-5");
+    /// assert_eq!(format!("{:#x}", FieldFmt(a).balanced()), "-0x5");
+    /// assert_eq!(format!("{}", FieldFmt(Fr::from(5u64)).balanced()), "5");
+    /// ```
+    pub fn balanced(self) -> Balanced<F> {
+        Balanced(self.0)
+    }
+
+    /// Formats the little-endian 64-bit limbs of the element's canonical
+    /// representation, which is what [`PrimeField::into_repr`] returns.
+    ///
+    /// ```rust
+    /// use ark_algebra_intro::display::FieldFmt;
+    /// use ark_bls12_381::Fr;
+    ///
+    /// let a = Fr::from(u64::MAX) + Fr::from(2u64);
+    /// assert_eq!(
+    ///     format!("{}", FieldFmt(a).limbs()),
+    ///     "[0x0000000000000001, 0x0000000000000001, 0x0000000000000000, 0x0000000000000000]",
+    /// );
+    /// ```
+    pub fn limbs(self) -> Limbs<F> {
+        Limbs(self.0)
+    }
+}
+
+/// Formats a prime field element in balanced representation.
+///
+/// Constructed via [`FieldFmt::balanced`]; supports the same formatting traits
+/// as [`FieldFmt`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Balanced<F>(pub F);
+
+impl<F: PrimeField> Balanced<F> {
+    /// Returns whether the balanced representative is non-negative, along with its magnitude.
+    fn sign_and_magnitude(&self) -> (bool, BigUint) {
+        let value: BigUint = self.0.into();
+        let modulus: BigUint = F::Params::MODULUS.into();
+        if value > (&modulus >> 1usize) {
+            (false, modulus - value)
+        } else {
+            (true, value)
+        }
+    }
+}
+
+/// Formats the canonical limbs of a prime field element.
+///
+/// Constructed via [`FieldFmt::limbs`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Limbs<F>(pub F);
+
+impl<F: PrimeField> fmt::Display for Limbs<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let repr = self.0.into_repr();
+        write!(f, "[")?;
+        for (i, limb) in repr.as_ref().iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{:#018x}", limb)?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// Implements a formatting trait by printing the integer value in the given radix.
+macro_rules! impl_radix_fmt {
+    ($trait:ident, $prefix:expr, $fmt:expr) => {
+        impl<F: PrimeField> fmt::$trait for FieldFmt<F> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let value: BigUint = self.0.into();
+                f.pad_integral(true, $prefix, &format!($fmt, value))
+            }
+        }
+
+        impl<F: PrimeField> fmt::$trait for Balanced<F> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let (non_negative, magnitude) = self.sign_and_magnitude();
+                f.pad_integral(non_negative, $prefix, &format!($fmt, magnitude))
+            }
+        }
+    };
+}
+
+impl_radix_fmt!(Display, "", "{}");
+impl_radix_fmt!(LowerHex, "0x", "{:x}");
+impl_radix_fmt!(UpperHex, "0x", "{:X}");
+impl_radix_fmt!(Binary, "0b", "{:b}");
@@ -0,0 +1,189 @@
+//! LaTeX formatting of field elements, points, polynomials and pairing equations.
+//!
+//! The integers of cryptographic fields have dozens of digits, which is too many for a
+//! write-up. [`LatexOptions`] prints them in [balanced](super::FieldFmt::balanced)
+//! representation by default (so that `-1` is `-1` and not `r - 1`), and truncates the
+//! middle digits of anything longer than [`max_digits`](LatexOptions::max_digits):
+//!
+//! ```rust
+//! use ark_algebra_intro::display::latex::LatexOptions;
+//! use ark_algebra_intro::poly::{DensePolynomial, UVPolynomial};
+//! use ark_bls12_381::{Fr, G1Affine};
+//! use ark_ec::AffineCurve;
+//! use ark_ff::Field;
+//!
+//! let latex = LatexOptions::default();
+//! assert_eq!(latex.field(&-Fr::from(5u64)), "-5");
+//! // 1/2 = (r + 1)/2, which is -(r - 1)/2 in balanced representation.
+//! assert_eq!(latex.field(&Fr::from(2u64).inverse().unwrap()), "-262179\\ldots{}592256");
+//!
+//! let g = G1Affine::prime_subgroup_generator();
+//! assert_eq!(
+//!     latex.point(&g),
+//!     "\\left(-316992\\ldots{}095280, 133950\\ldots{}441569\\right)",
+//! );
+//!
+//! // 3X^2 - X + 1
+//! let p = DensePolynomial::from_coefficients_vec(vec![
+//!     Fr::from(1u64),
+//!     -Fr::from(1u64),
+//!     Fr::from(3u64),
+//! ]);
+//! assert_eq!(latex.polynomial(&p), "3X^{2} - X + 1");
+//!
+//! // Or with every digit:
+//! let full = LatexOptions::full();
+//! assert_eq!(
+//!     full.field(&-Fr::from(5u64)),
+//!     "52435875175126190479447740508185965837690552500527637822603658699938581184508",
+//! );
+//! ```
+
+use super::FieldFmt;
+use crate::poly::DensePolynomial;
+use ark_ec::models::SWModelParameters;
+use ark_ec::short_weierstrass_jacobian::GroupAffine;
+use ark_ff::PrimeField;
+use ark_std::string::{String, ToString};
+use ark_std::vec::Vec;
+
+/// How to print values as LaTeX.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LatexOptions {
+    /// The largest number of digits to print in full; longer integers keep their first and
+    /// last `max_digits / 2` digits, around `\ldots{}`. `None` never truncates.
+    pub max_digits: Option<usize>,
+    /// Whether to print field elements in balanced representation, in `[-(p-1)/2, (p-1)/2]`,
+    /// instead of in `[0, p)`.
+    pub balanced: bool,
+    /// The name of the variable of polynomials.
+    pub variable: &'static str,
+}
+
+impl Default for LatexOptions {
+    /// Balanced representation, with integers truncated beyond 12 digits.
+    fn default() -> Self {
+        Self {
+            max_digits: Some(12),
+            balanced: true,
+            variable: "X",
+        }
+    }
+}
+
+impl LatexOptions {
+    /// Canonical representation, without truncation.
+    pub fn full() -> Self {
+        Self {
+            max_digits: None,
+            balanced: false,
+            ..Self::default()
+        }
+    }
+
+    /// Formats an integer given by its decimal digits (and an optional `-` sign),
+    /// truncating it if needed.
+    pub fn integer(&self, decimal: &str) -> String {
+        let (sign, digits) = match decimal.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", decimal),
+        };
+        match self.max_digits {
+            Some(max) if digits.len() > max => {
+                let keep = (max / 2).max(1);
+                format!(
+                    "{}{}\\ldots{{}}{}",
+                    sign,
+                    &digits[..keep],
+                    &digits[digits.len() - keep..]
+                )
+            }
+            _ => decimal.to_string(),
+        }
+    }
+
+    /// Formats a field element.
+    pub fn field<F: PrimeField>(&self, a: &F) -> String {
+        let decimal = if self.balanced {
+            FieldFmt(*a).balanced().to_string()
+        } else {
+            FieldFmt(*a).to_string()
+        };
+        self.integer(&decimal)
+    }
+
+    /// Formats a point as `\left(x, y\right)`, or `\mathcal{O}` for the point at infinity.
+    pub fn point<P: SWModelParameters>(&self, point: &GroupAffine<P>) -> String
+    where
+        P::BaseField: PrimeField,
+    {
+        if point.infinity {
+            "\\mathcal{O}".to_string()
+        } else {
+            format!(
+                "\\left({}, {}\\right)",
+                self.field(&point.x),
+                self.field(&point.y)
+            )
+        }
+    }
+
+    /// Formats a polynomial from its leading term down, skipping zero coefficients and
+    /// coefficients `±1` (except in the constant term).
+    pub fn polynomial<F: PrimeField>(&self, p: &DensePolynomial<F>) -> String {
+        let mut latex = String::new();
+        for (i, c) in p.coeffs.iter().enumerate().rev() {
+            if c.is_zero() {
+                continue;
+            }
+            let coeff = self.field(c);
+            let (negative, magnitude) = match coeff.strip_prefix('-') {
+                Some(magnitude) => (true, magnitude),
+                None => (false, coeff.as_str()),
+            };
+            if latex.is_empty() {
+                latex.push_str(if negative { "-" } else { "" });
+            } else {
+                latex.push_str(if negative { " - " } else { " + " });
+            }
+            let monomial = match i {
+                0 => String::new(),
+                1 => self.variable.to_string(),
+                _ => format!("{}^{{{}}}", self.variable, i),
+            };
+            if magnitude != "1" || i == 0 {
+                latex.push_str(magnitude);
+            }
+            latex.push_str(&monomial);
+        }
+        if latex.is_empty() {
+            latex.push('0');
+        }
+        latex
+    }
+
+    /// Formats the pairing equation `∏ e(P_i, Q_i) = ∏ e(R_j, S_j)` between named points.
+    ///
+    /// ```rust
+    /// use ark_algebra_intro::display::latex::LatexOptions;
+    ///
+    /// let latex = LatexOptions::default();
+    /// assert_eq!(
+    ///     latex.pairing_equation(&[("A", "B")], &[("\\alpha", "\\beta"), ("C", "\\delta")]),
+    ///     "e\\left(A, B\\right) = e\\left(\\alpha, \\beta\\right) \\cdot e\\left(C, \\delta\\right)",
+    /// );
+    /// ```
+    pub fn pairing_equation(&self, lhs: &[(&str, &str)], rhs: &[(&str, &str)]) -> String {
+        let side = |pairs: &[(&str, &str)]| {
+            if pairs.is_empty() {
+                return "1".to_string();
+            }
+            pairs
+                .iter()
+                .map(|(p, q)| format!("e\\left({}, {}\\right)", p, q))
+                .collect::<Vec<_>>()
+                .join(" \\cdot ")
+        };
+        format!("{} = {}", side(lhs), side(rhs))
+    }
+}
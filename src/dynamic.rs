@@ -0,0 +1,337 @@
+//! Prime fields whose modulus is chosen at runtime.
+//!
+//! The field types of `arkworks`, including those of
+//! [`define_prime_field!`](crate::define_prime_field), fix their modulus at compile
+//! time, so trying out a prime means writing and compiling code. [`DynPrimeField`]
+//! takes its modulus as a value instead, read from a file, a puzzle or a prompt, and
+//! [`DynField`] is an element of it:
+//!
+//! ```rust
+//! use ark_algebra_intro::dynamic::DynPrimeField;
+//! use ark_algebra_intro::Error;
+//! use num_bigint::BigUint;
+//!
+//! let f = DynPrimeField::new(BigUint::from(101u64)).unwrap();
+//! let (a, b) = (f.element(3u64), f.element(50u64));
+//! assert_eq!(&a * &b, f.element(150u64 - 101));
+//! assert_eq!(&a / &b * &b, a);
+//! assert_eq!(-&a, f.from_i64(-3));
+//! assert_eq!(a.pow(&BigUint::from(100u64)), f.one()); // Fermat's little theorem
+//! // 3 is not a square modulo 101, but 3^2 is.
+//! assert_eq!(a.sqrt(), Err(Error::NonResidue));
+//! assert!(a.square().sqrt().map_or(false, |r| r == a || r == -&a));
+//!
+//! // Composite moduli are rejected, since they don't give fields.
+//! assert!(DynPrimeField::new(BigUint::from(91u64)).is_err());
+//! ```
+//!
+//! Elements support `+`, `-`, `*`, `/` and negation, on values or references, and
+//! `==`; combining elements of different fields panics. In the
+//! [REPL](crate::repl), `fp(a, p)` is the element `a` of the field of order `p`.
+//!
+//! # Performance
+//!
+//! Every operation allocates a [`BigUint`] for its result and reduces it with a
+//! division, where a Montgomery field such as `Fr` works on a fixed number of limbs on
+//! the stack and never divides. In a release build, a multiplication in a 255-bit
+//! `DynField` takes about ten times as long as one in `Fr` (a couple of hundred
+//! nanoseconds), and so does an inversion. That is nothing for a calculation typed by
+//! hand, but adds up in a proof system or an MSM: once an experiment works, move it to a
+//! compile-time field.
+
+use crate::number_theory::{is_probable_prime, jacobi};
+use crate::Error;
+use ark_std::ops::{Add, Div, Mul, Neg, Sub};
+use ark_std::rand::Rng;
+use ark_std::rc::Rc;
+use ark_std::{fmt, One, Zero};
+use num_bigint::BigUint;
+
+/// A prime field, given by a modulus chosen at runtime.
+///
+/// Cloning a field, or an element, shares its modulus instead of copying it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DynPrimeField {
+    modulus: Rc<BigUint>,
+}
+
+impl DynPrimeField {
+    /// Returns the field of order `modulus`, or [`Error::NotPrime`] if `modulus` is not
+    /// a prime, as checked by [`is_probable_prime`].
+    pub fn new(modulus: BigUint) -> Result<Self, Error> {
+        if !is_probable_prime(&modulus) {
+            return Err(Error::NotPrime);
+        }
+        Ok(Self {
+            modulus: Rc::new(modulus),
+        })
+    }
+
+    /// Returns the modulus.
+    pub fn modulus(&self) -> &BigUint {
+        &self.modulus
+    }
+
+    /// Returns the element `value mod p`.
+    pub fn element(&self, value: impl Into<BigUint>) -> DynField {
+        DynField {
+            value: value.into() % &*self.modulus,
+            modulus: self.modulus.clone(),
+        }
+    }
+
+    /// Returns the element `value mod p`, for a possibly negative `value`.
+    pub fn from_i64(&self, value: i64) -> DynField {
+        let magnitude = self.element(value.unsigned_abs());
+        if value < 0 {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    /// Returns `0`.
+    pub fn zero(&self) -> DynField {
+        self.element(0u64)
+    }
+
+    /// Returns `1`.
+    pub fn one(&self) -> DynField {
+        self.element(1u64)
+    }
+
+    /// Returns a uniformly random element.
+    pub fn rand<R: Rng + ?Sized>(&self, rng: &mut R) -> DynField {
+        // 128 more bits than the modulus make the reduction indistinguishable from
+        // uniform.
+        let mut bytes = vec![0u8; (self.modulus.bits() as usize).div_ceil(8) + 16];
+        rng.fill_bytes(&mut bytes);
+        self.element(BigUint::from_bytes_le(&bytes))
+    }
+}
+
+/// An element of a [`DynPrimeField`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DynField {
+    /// The element, in `[0, p)`.
+    value: BigUint,
+    modulus: Rc<BigUint>,
+}
+
+impl DynField {
+    /// Returns the field of the element.
+    pub fn field(&self) -> DynPrimeField {
+        DynPrimeField {
+            modulus: self.modulus.clone(),
+        }
+    }
+
+    /// Returns the element as an integer in `[0, p)`.
+    pub fn value(&self) -> &BigUint {
+        &self.value
+    }
+
+    /// Returns the modulus of the field of the element.
+    pub fn modulus(&self) -> &BigUint {
+        &self.modulus
+    }
+
+    /// Returns whether the element is `0`.
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    /// Returns whether the element is `1`.
+    pub fn is_one(&self) -> bool {
+        self.value.is_one()
+    }
+
+    /// Returns `self + self`.
+    pub fn double(&self) -> Self {
+        self + self
+    }
+
+    /// Returns `self · self`.
+    pub fn square(&self) -> Self {
+        self * self
+    }
+
+    /// Returns `self^exponent`.
+    pub fn pow(&self, exponent: &BigUint) -> Self {
+        self.with_value(self.value.modpow(exponent, &self.modulus))
+    }
+
+    /// Returns `1 / self`, or [`Error::ZeroInverse`] if `self` is `0`.
+    ///
+    /// This is `self^(p - 2)`, by Fermat's little theorem.
+    pub fn inverse(&self) -> Result<Self, Error> {
+        if self.is_zero() {
+            return Err(Error::ZeroInverse);
+        }
+        Ok(self.pow(&(&*self.modulus - 2u64)))
+    }
+
+    /// Returns the Legendre symbol of `self`: `0` for `0`, `1` for nonzero squares,
+    /// and `-1` for the other elements.
+    pub fn legendre(&self) -> i8 {
+        if *self.modulus == BigUint::from(2u64) {
+            return if self.is_zero() { 0 } else { 1 };
+        }
+        jacobi(&self.value, &self.modulus)
+    }
+
+    /// Returns a square root of `self`, or [`Error::NonResidue`] if `self` is not a
+    /// square, by the Tonelli-Shanks algorithm.
+    pub fn sqrt(&self) -> Result<Self, Error> {
+        match self.legendre() {
+            0 => return Ok(self.clone()),
+            -1 => return Err(Error::NonResidue),
+            _ if *self.modulus == BigUint::from(2u64) => return Ok(self.clone()),
+            _ => {}
+        }
+        // p - 1 = 2^s · t, with t odd.
+        let p_minus_one = &*self.modulus - 1u64;
+        let s = p_minus_one.trailing_zeros().expect("p is at least 3");
+        let t = &p_minus_one >> s;
+        // A non-residue, to the power t, generates the 2-Sylow subgroup.
+        let mut z = self.field().element(2u64);
+        while z.legendre() != -1 {
+            z = &z + &self.field().one();
+        }
+        let mut c = z.pow(&t);
+        let mut x = self.pow(&((&t + 1u64) >> 1));
+        let mut b = self.pow(&t);
+        let mut m = s;
+        // Invariant: x^2 = self · b, and b has order dividing 2^(m - 1).
+        while !b.is_one() {
+            let mut i = 0;
+            let mut b2 = b.clone();
+            while !b2.is_one() {
+                b2 = b2.square();
+                i += 1;
+            }
+            for _ in 0..m - i - 1 {
+                c = c.square();
+            }
+            x = &x * &c;
+            c = c.square();
+            b = &b * &c;
+            m = i;
+        }
+        Ok(x)
+    }
+
+    /// Returns the element of the same field with the (reduced) `value`.
+    fn with_value(&self, value: BigUint) -> Self {
+        Self {
+            value,
+            modulus: self.modulus.clone(),
+        }
+    }
+
+    /// Panics unless `self` and `other` are elements of the same field.
+    fn assert_same_field(&self, other: &Self) {
+        assert!(
+            self.modulus == other.modulus,
+            "cannot combine elements of the fields of order {} and {}",
+            self.modulus,
+            other.modulus
+        );
+    }
+}
+
+impl fmt::Display for DynField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {})", self.value, self.modulus)
+    }
+}
+
+impl Add for &DynField {
+    type Output = DynField;
+
+    fn add(self, other: Self) -> DynField {
+        self.assert_same_field(other);
+        let sum = &self.value + &other.value;
+        if sum >= *self.modulus {
+            self.with_value(sum - &*self.modulus)
+        } else {
+            self.with_value(sum)
+        }
+    }
+}
+
+impl Sub for &DynField {
+    type Output = DynField;
+
+    fn sub(self, other: Self) -> DynField {
+        self + &-other
+    }
+}
+
+impl Mul for &DynField {
+    type Output = DynField;
+
+    fn mul(self, other: Self) -> DynField {
+        self.assert_same_field(other);
+        self.with_value(&self.value * &other.value % &*self.modulus)
+    }
+}
+
+impl Div for &DynField {
+    type Output = DynField;
+
+    /// # Panics
+    ///
+    /// Panics if `other` is `0`.
+    fn div(self, other: Self) -> DynField {
+        let inverse = other.inverse().expect("division by zero");
+        self.mul(&inverse)
+    }
+}
+
+impl Neg for &DynField {
+    type Output = DynField;
+
+    fn neg(self) -> DynField {
+        if self.is_zero() {
+            self.clone()
+        } else {
+            self.with_value(&*self.modulus - &self.value)
+        }
+    }
+}
+
+impl Neg for DynField {
+    type Output = DynField;
+
+    fn neg(self) -> DynField {
+        -&self
+    }
+}
+
+/// Implements a binary operator with an owned `DynField` on the left, by the one on
+/// references.
+macro_rules! owned_binary_op {
+    ($trait:ident, $method:ident) => {
+        impl $trait for DynField {
+            type Output = DynField;
+
+            fn $method(self, other: Self) -> DynField {
+                (&self).$method(&other)
+            }
+        }
+
+        impl<'a> $trait<&'a DynField> for DynField {
+            type Output = DynField;
+
+            fn $method(self, other: &'a DynField) -> DynField {
+                (&self).$method(other)
+            }
+        }
+    };
+}
+
+owned_binary_op!(Add, add);
+owned_binary_op!(Sub, sub);
+owned_binary_op!(Mul, mul);
+owned_binary_op!(Div, div);
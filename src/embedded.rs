@@ -0,0 +1,151 @@
+//! Embedded curves and cycles of curves.
+//!
+//! A SNARK over a pairing-friendly curve like BLS12-381 proves statements about
+//! arithmetic in its *scalar* field `Fr`. Proving statements about BLS12-381 points
+//! themselves is expensive, because their coordinates live in the (different) base field
+//! `Fq`, and every `Fq` operation has to be emulated with many `Fr` operations.
+//!
+//! An *embedded* curve is a curve whose base field is the scalar field of another
+//! ("outer") curve. Jubjub is embedded in BLS12-381: its coordinates are elements of
+//! BLS12-381's `Fr`, so a BLS12-381 SNARK can check Jubjub arithmetic (and hence, say,
+//! Jubjub signatures or Pedersen commitments) natively. In `arkworks`, the two fields are
+//! literally the same type:
+//!
+//! ```rust
+//! # #[cfg(feature = "ed-on-bls12-381")]
+//! # {
+//! use ark_algebra_intro::embedded::is_embedded;
+//! use ark_bls12_381::G1Affine;
+//! use ark_ed_on_bls12_381::EdwardsAffine;
+//!
+//! let x: ark_bls12_381::Fr = ark_ed_on_bls12_381::Fq::from(5u64);
+//! assert_eq!(x, ark_bls12_381::Fr::from(5u64));
+//! assert!(is_embedded::<EdwardsAffine, G1Affine>());
+//! // The converse fails: BLS12-381's base field is not Jubjub's scalar field.
+//! assert!(!is_embedded::<G1Affine, EdwardsAffine>());
+//! # }
+//! ```
+//!
+//! Recursive proof systems need more: a proof over the inner curve must be checked
+//! over the outer curve *and vice versa*. Two curves where each one's base field is the
+//! other's scalar field form a *cycle*, like Pallas and Vesta:
+//!
+//! ```rust
+//! # #[cfg(all(feature = "pallas", feature = "vesta"))]
+//! # {
+//! use ark_algebra_intro::embedded::forms_cycle;
+//!
+//! assert!(forms_cycle::<ark_pallas::Affine, ark_vesta::Affine>());
+//! # }
+//! ```
+//!
+//! No cycle is known in which both curves are pairing-friendly with reasonable
+//! parameters, which is why pairing-based recursion instead uses *chains* of curves
+//! (like BLS12-377 and BW6-761), and cycles like Pallas/Vesta are used with
+//! pairing-free proof systems.
+
+use crate::curves::AffineExt;
+use ark_ec::AffineCurve;
+use ark_ff::{Field, FpParameters, PrimeField, Zero};
+
+/// Returns whether the curve `Inner` is embedded in `Outer`, i.e., whether the
+/// coordinates of `Inner` are elements of the scalar field of `Outer`.
+///
+/// Only the field *sizes* are compared, so this also works for curves from crates
+/// that define the two fields as different types.
+pub fn is_embedded<Inner: AffineCurve, Outer: AffineCurve>() -> bool {
+    let characteristic = trim(<Inner::BaseField as Field>::characteristic());
+    let modulus = <Outer::ScalarField as PrimeField>::Params::MODULUS;
+    Inner::BaseField::extension_degree() == 1 && characteristic == trim(modulus.as_ref())
+}
+
+/// Returns whether `G1` and `G2` form a cycle of curves: each is embedded in the other.
+///
+/// ```rust
+/// use ark_algebra_intro::embedded::forms_cycle;
+/// use ark_bls12_381::{G1Affine, G2Affine};
+///
+/// assert!(!forms_cycle::<G1Affine, G2Affine>());
+/// ```
+pub fn forms_cycle<G1: AffineCurve, G2: AffineCurve>() -> bool {
+    is_embedded::<G1, G2>() && is_embedded::<G2, G1>()
+}
+
+/// Commits to a point of an embedded curve with a Pedersen commitment on the outer curve.
+///
+/// Because the coordinates `(x, y)` of `point` are scalars of `Outer`, they can be
+/// committed to as `x · bases[0] + y · bases[1] + blinding · bases[2]`. This is how a
+/// commitment computed on the inner curve (which is itself a point) is handed over to
+/// the outer curve, for instance to be opened inside a SNARK over `Outer`'s scalar field.
+///
+/// ```rust
+/// # #[cfg(feature = "ed-on-bls12-381")]
+/// # {
+/// use ark_algebra_intro::embedded::{commit_to_point, verify_point_commitment};
+/// use ark_bls12_381::{Fr, G1Projective};
+/// use ark_ec::ProjectiveCurve;
+/// use ark_ed_on_bls12_381::{EdwardsAffine, EdwardsProjective};
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let bases: Vec<_> = (0..3).map(|_| G1Projective::rand(&mut rng).into_affine()).collect();
+///
+/// // An inner commitment, e.g., the Jubjub point `m · G + r · H`...
+/// let inner = EdwardsProjective::rand(&mut rng).into_affine();
+/// // ...is committed to on BLS12-381...
+/// let blinding = Fr::rand(&mut rng);
+/// let outer = commit_to_point(&inner, &bases, blinding);
+/// // ...and the outer commitment opens only to that point.
+/// assert!(verify_point_commitment(&outer, &inner, &bases, blinding));
+/// let other = EdwardsProjective::rand(&mut rng).into_affine();
+/// assert!(!verify_point_commitment(&outer, &other, &bases, blinding));
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if `bases` does not contain exactly three points, or if `point` is the point at
+/// infinity of a short Weierstrass curve (which has no coordinates to commit to).
+pub fn commit_to_point<Inner, Outer>(
+    point: &Inner,
+    bases: &[Outer],
+    blinding: Outer::ScalarField,
+) -> Outer::Projective
+where
+    Inner: AffineExt<BaseField = Outer::ScalarField>,
+    Outer: AffineCurve,
+{
+    assert_eq!(
+        bases.len(),
+        3,
+        "a point commitment needs exactly three bases"
+    );
+    let (x, y) = point
+        .xy()
+        .expect("the point at infinity has no coordinates to commit to");
+    bases[0].mul(x.into_repr()) + bases[1].mul(y.into_repr()) + bases[2].mul(blinding.into_repr())
+}
+
+/// Checks that `commitment` is the [point commitment](commit_to_point) to `point` with
+/// the given `bases` and `blinding`.
+pub fn verify_point_commitment<Inner, Outer>(
+    commitment: &Outer::Projective,
+    point: &Inner,
+    bases: &[Outer],
+    blinding: Outer::ScalarField,
+) -> bool
+where
+    Inner: AffineExt<BaseField = Outer::ScalarField>,
+    Outer: AffineCurve,
+{
+    *commitment == commit_to_point(point, bases, blinding)
+}
+
+/// Strips the leading (most significant) zero limbs of a little-endian number.
+fn trim(limbs: &[u64]) -> &[u64] {
+    let length = limbs
+        .iter()
+        .rposition(|limb| !limb.is_zero())
+        .map_or(0, |i| i + 1);
+    &limbs[..length]
+}
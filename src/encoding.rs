@@ -0,0 +1,152 @@
+//! Canonical fixed-width byte encodings of prime field elements.
+//!
+//! Every function here works with encodings that are exactly as wide as the
+//! field modulus (e.g., 32 bytes for BLS12-381's `Fr`, and 48 bytes for its `Fq`),
+//! regardless of how small the encoded value is. This makes encodings safe
+//! to concatenate and compare.
+//!
+//! As with [integers](crate::bigint), the main pitfall is endianness: decoding
+//! a big-endian encoding as if it were little-endian silently produces the
+//! wrong field element. Always use matching pairs of functions:
+//!
+//! ```rust
+//! use ark_algebra_intro::encoding::{
+//!     from_be_bytes_mod_order, from_le_bytes_mod_order, to_bytes_be, to_bytes_le,
+//! };
+//! use ark_bls12_381::Fr;
+//! use ark_std::UniformRand;
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let a = Fr::rand(&mut rng);
+//!
+//! // Round trips with matching endianness recover the element...
+//! assert_eq!(from_be_bytes_mod_order::<Fr>(&to_bytes_be(&a)), a);
+//! assert_eq!(from_le_bytes_mod_order::<Fr>(&to_bytes_le(&a)), a);
+//!
+//! // ...but mismatched endianness does not.
+//! assert_ne!(from_le_bytes_mod_order::<Fr>(&to_bytes_be(&a)), a);
+//! ```
+
+use ark_ff::{BigInteger, FpParameters, PrimeField};
+use ark_std::string::String;
+use ark_std::vec::Vec;
+
+/// Returns the number of bytes in the canonical encoding of elements of `F`.
+///
+/// ```rust
+/// use ark_algebra_intro::encoding::encoded_size;
+/// use ark_algebra_intro::toy_fields::F7;
+/// use ark_bls12_381::{Fq, Fr};
+///
+/// assert_eq!(encoded_size::<Fr>(), 32);
+/// assert_eq!(encoded_size::<Fq>(), 48);
+/// assert_eq!(encoded_size::<F7>(), 1);
+/// ```
+pub fn encoded_size<F: PrimeField>() -> usize {
+    (F::Params::MODULUS_BITS as usize).div_ceil(8)
+}
+
+/// Returns the little-endian encoding of `a`, which is exactly
+/// [`encoded_size::<F>()`](encoded_size) bytes long.
+///
+/// This agrees with the (uncompressed) [`CanonicalSerialize`](ark_serialize::CanonicalSerialize)
+/// encoding of `a`:
+///
+/// ```rust
+/// use ark_algebra_intro::encoding::to_bytes_le;
+/// use ark_bls12_381::Fq;
+/// use ark_serialize::CanonicalSerialize;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let a = Fq::rand(&mut rng);
+/// let mut serialized = Vec::new();
+/// a.serialize(&mut serialized).unwrap();
+/// assert_eq!(to_bytes_le(&a), serialized);
+///
+/// // Small values are still encoded at full width:
+/// assert_eq!(to_bytes_le(&Fq::from(1u64)).len(), 48);
+/// ```
+pub fn to_bytes_le<F: PrimeField>(a: &F) -> Vec<u8> {
+    let mut bytes = a.into_repr().to_bytes_le();
+    bytes.truncate(encoded_size::<F>());
+    bytes
+}
+
+/// Returns the big-endian encoding of `a`, which is exactly
+/// [`encoded_size::<F>()`](encoded_size) bytes long.
+///
+/// ```rust
+/// use ark_algebra_intro::encoding::{to_bytes_be, to_bytes_le};
+/// use ark_bls12_381::Fr;
+///
+/// let a = Fr::from(0x0102u64);
+/// let be = to_bytes_be(&a);
+/// assert_eq!(be.len(), 32);
+/// assert_eq!(&be[30..], &[0x01, 0x02]);
+///
+/// // The big-endian encoding is the reversal of the little-endian one:
+/// let mut le = to_bytes_le(&a);
+/// le.reverse();
+/// assert_eq!(be, le);
+/// ```
+pub fn to_bytes_be<F: PrimeField>(a: &F) -> Vec<u8> {
+    let mut bytes = to_bytes_le(a);
+    bytes.reverse();
+    bytes
+}
+
+/// Interprets `bytes` as a big-endian integer and reduces it modulo the
+/// modulus of `F`.
+///
+/// The input may have any length. This is a thin wrapper around
+/// [`PrimeField::from_be_bytes_mod_order`].
+///
+/// ```rust
+/// use ark_algebra_intro::encoding::from_be_bytes_mod_order;
+/// use ark_algebra_intro::toy_fields::F7;
+///
+/// // 0x0102 = 258 = 36 · 7 + 6
+/// assert_eq!(from_be_bytes_mod_order::<F7>(&[0x01, 0x02]), F7::from(6u64));
+/// ```
+pub fn from_be_bytes_mod_order<F: PrimeField>(bytes: &[u8]) -> F {
+    F::from_be_bytes_mod_order(bytes)
+}
+
+/// Interprets `bytes` as a little-endian integer and reduces it modulo the
+/// modulus of `F`.
+///
+/// The input may have any length. This is a thin wrapper around
+/// [`PrimeField::from_le_bytes_mod_order`].
+///
+/// ```rust
+/// use ark_algebra_intro::encoding::from_le_bytes_mod_order;
+/// use ark_algebra_intro::toy_fields::F7;
+///
+/// // 0x0201 = 513 = 73 · 7 + 2
+/// assert_eq!(from_le_bytes_mod_order::<F7>(&[0x01, 0x02]), F7::from(2u64));
+/// ```
+pub fn from_le_bytes_mod_order<F: PrimeField>(bytes: &[u8]) -> F {
+    F::from_le_bytes_mod_order(bytes)
+}
+
+/// Returns the `0x`-prefixed, lowercase hexadecimal string of `bytes`.
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    let mut hex = String::from("0x");
+    for byte in bytes {
+        hex.push_str(&format!("{:02x}", byte));
+    }
+    hex
+}
+
+/// Parses a `0x`-prefixed hexadecimal string with an even number of digits.
+pub(crate) fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    let digits = hex.strip_prefix("0x")?;
+    if digits.len() % 2 != 0 || !digits.is_ascii() {
+        return None;
+    }
+    (0..digits.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&digits[i..i + 2], 16).ok())
+        .collect()
+}
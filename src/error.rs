@@ -0,0 +1,118 @@
+//! The errors returned by the helpers in this crate.
+//!
+//! Helpers that can fail on bad input return a [`Result`] with [`Error`], so that code
+//! built on them can propagate failures with `?` instead of calling `unwrap()` and
+//! panicking on the first malformed point someone sends it:
+//!
+//! ```rust
+//! use ark_algebra_intro::curves::{point_from_xy, validate_point};
+//! use ark_algebra_intro::fields::demo_inverse;
+//! use ark_algebra_intro::interop::zcash_format::decode_g1;
+//! use ark_algebra_intro::Error;
+//! use ark_bls12_381::{Fq, G1Affine};
+//! use ark_std::Zero;
+//!
+//! fn point(x: u64, y: u64) -> Result<G1Affine, Error> {
+//!     let p = point_from_xy(Fq::from(x), Fq::from(y))?;
+//!     validate_point(p)
+//! }
+//! assert_eq!(point(1, 1), Err(Error::NotOnCurve));
+//! // `(0, 2)` is on the curve, but has order 3.
+//! assert_eq!(point(0, 2), Err(Error::NotInSubgroup));
+//!
+//! // Errors from decoding other implementations' formats convert with `?` too.
+//! fn decode(bytes: &[u8]) -> Result<G1Affine, Error> {
+//!     Ok(decode_g1(bytes)?)
+//! }
+//! assert!(matches!(decode(&[0; 3]), Err(Error::Decode(_))));
+//!
+//! assert_eq!(demo_inverse(&Fq::zero()), Err(Error::ZeroInverse));
+//! ```
+//!
+//! Functions that can't fail in this sense still return an [`Option`]: lookups and
+//! accessors, where `None` just means that something is absent (like
+//! [`catalog::curve_info`](crate::catalog::curve_info) or the coordinates of the point
+//! at infinity), and methods that mirror an `arkworks` method returning an `Option`
+//! (like [`AffineExt::get_point_from_x`](crate::curves::AffineExt::get_point_from_x)).
+//!
+//! Functions whose names end in `_unchecked`, such as
+//! [`AffineExt::from_xy_unchecked`](crate::curves::AffineExt::from_xy_unchecked) and
+//! [`decode_g1_unchecked`](crate::interop::zcash_format::decode_g1_unchecked), skip
+//! some of these checks, for inputs that are already known to be valid.
+
+use crate::interop::DecodeError;
+use crate::protocols::sumcheck::Rejection;
+
+/// An error from one of the helpers in this crate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The coordinates do not satisfy the curve equation.
+    #[error("the coordinates do not satisfy the curve equation")]
+    NotOnCurve,
+    /// The point is on the curve, but not in the prime-order subgroup.
+    #[error("the point is not in the prime-order subgroup")]
+    NotInSubgroup,
+    /// The field element is not a square, so it has no square root.
+    #[error("the field element is not a square")]
+    NonResidue,
+    /// A modulus is not prime, so the integers modulo it are not a field.
+    #[error("the modulus is not prime")]
+    NotPrime,
+    /// The coefficients of a curve give a singular cubic, which is not an elliptic curve.
+    #[error("the curve is singular")]
+    SingularCurve,
+    /// Zero has no multiplicative inverse.
+    #[error("zero has no inverse")]
+    ZeroInverse,
+    /// An integer shares a factor with the modulus, so it has no inverse modulo it.
+    #[error("the integer is not invertible modulo the modulus")]
+    NotInvertible,
+    /// An integer is not smaller than the modulus of the field it should be converted to.
+    #[error("the integer is not smaller than the modulus")]
+    OutOfRange,
+    /// An element of an extension field does not lie in the subfield it should be
+    /// converted to.
+    #[error("the element does not lie in the subfield")]
+    NotInSubfield,
+    /// The field has no root of unity of the requested order.
+    #[error("the field has no root of unity of that order")]
+    NoRootOfUnity,
+    /// The curve has no point with the requested property, or none was found.
+    #[error("no such point was found on the curve")]
+    NoSuchPoint,
+    /// No discrete logarithm was found in the range that was searched.
+    #[error("no discrete logarithm was found in the searched range")]
+    DiscreteLogNotFound,
+    /// A proof does not verify.
+    #[error("the proof is invalid")]
+    InvalidProof,
+    /// A sumcheck verifier rejected the proof, for the given reason.
+    #[error("{0}")]
+    Sumcheck(Rejection),
+    /// A witness does not satisfy the statement it should prove.
+    #[error("the witness does not satisfy the statement")]
+    Unsatisfied,
+    /// A received word has too many erasures or corrupted values to be decoded.
+    #[error("the received word cannot be decoded")]
+    Undecodable,
+    /// The inputs of an attack lack the weakness it exploits, such as a reused nonce.
+    #[error("the inputs lack the weakness that the attack exploits")]
+    AttackFailed,
+    /// Bytes are not a valid encoding of a point.
+    #[error("{0}")]
+    Decode(DecodeError),
+}
+
+// `DecodeError` only implements `ark_std::error::Error`, which isn't the same trait as
+// `core::error::Error` without `std`, so this can't be thiserror's `#[from]`.
+impl From<DecodeError> for Error {
+    fn from(error: DecodeError) -> Self {
+        Error::Decode(error)
+    }
+}
+
+impl From<Rejection> for Error {
+    fn from(rejection: Rejection) -> Self {
+        Error::Sumcheck(rejection)
+    }
+}
@@ -0,0 +1,432 @@
+//! A workbook: exercises to implement yourself, with checks for your answers.
+//!
+//! Each exercise is a function whose body is `todo!()`. Replace the body with your own
+//! implementation, then run the checks:
+//!
+//! ```text
+//! cargo run --example exercises
+//! ```
+//!
+//! Each check is a small property test. It runs your function on inputs drawn from a
+//! [seeded](crate::rng::seeded) generator and on edge cases, and reports the first input
+//! on which the function misbehaves. Exercises that still panic with `todo!()` are
+//! reported as not attempted, so you can work through them in any order.
+//!
+//! With the `solutions` feature, the stubs are replaced by reference solutions, defined
+//! at the bottom of this file (`cargo run --example exercises --features solutions`).
+//! Try not to peek!
+//!
+//! ```rust
+//! use ark_algebra_intro::exercises::{run_all, Outcome};
+//!
+//! for (exercise, outcome) in run_all() {
+//!     println!("{}: {:?}", exercise.name, outcome);
+//!     # #[cfg(feature = "solutions")]
+//!     assert_eq!(outcome, Outcome::Passed);
+//! }
+//! ```
+//!
+//! The checks are also public, so you can run one directly on an implementation of your
+//! own without editing this file:
+//!
+//! ```rust
+//! use ark_algebra_intro::exercises::check_horner;
+//! use ark_bls12_381::Fr;
+//! use ark_ff::Zero;
+//!
+//! // Forgetting the leading coefficient is caught...
+//! let wrong = |coeffs: &[Fr], x: Fr| {
+//!     let n = coeffs.len().saturating_sub(1);
+//!     coeffs[..n].iter().rev().fold(Fr::zero(), |acc, c| acc * x + c)
+//! };
+//! assert!(check_horner(wrong).is_err());
+//!
+//! // ...while a correct implementation passes.
+//! let right = |coeffs: &[Fr], x: Fr| coeffs.iter().rev().fold(Fr::zero(), |acc, c| acc * x + c);
+//! assert_eq!(check_horner(right), Ok(()));
+//! ```
+
+#![cfg_attr(not(feature = "solutions"), allow(unused_variables))]
+
+use crate::number_theory;
+use crate::poly;
+use crate::protocols::bls::{self, PublicKey, Signature};
+use ark_bls12_381::{Fr, G1Projective};
+use ark_ec::ProjectiveCurve;
+use ark_ff::{Field, One, PrimeField, Zero};
+use ark_std::panic::{self, AssertUnwindSafe};
+use ark_std::sync::Arc;
+use ark_std::UniformRand;
+
+/// The number of random inputs each check tries.
+pub const TRIALS: usize = 16;
+
+/// Exercise 1: inverts `a` with Fermat's little theorem, returning `None` for zero.
+///
+/// Hint: for `a ≠ 0`, `a^(r - 1) = 1`, so `a^(r - 2)` is the inverse of `a`. Compute the
+/// exponent from [`FpParameters::MODULUS`](ark_ff::FpParameters::MODULUS), and use
+/// [`Field::pow`] (or, for extra credit, square-and-multiply by hand).
+#[cfg(not(feature = "solutions"))]
+pub fn inverse(a: Fr) -> Option<Fr> {
+    todo!("exercise 1: inverse")
+}
+
+/// Exercise 2: returns the Legendre symbol of `a`: `1` if `a` is a nonzero square, `-1`
+/// if it is not a square, and `0` if it is zero.
+///
+/// Hint: by Euler's criterion, `a^((r - 1) / 2)` is `1` for squares and `-1` for
+/// non-squares.
+#[cfg(not(feature = "solutions"))]
+pub fn legendre(a: Fr) -> i8 {
+    todo!("exercise 2: legendre")
+}
+
+/// Exercise 3: evaluates the polynomial with coefficients `coeffs` (from the constant
+/// term up) at `x`, with Horner's rule.
+///
+/// Hint: `c_0 + c_1 x + c_2 x² = c_0 + x (c_1 + x c_2)`, so start from the highest
+/// coefficient, and multiply-then-add your way down, with one multiplication per
+/// coefficient.
+#[cfg(not(feature = "solutions"))]
+pub fn horner(coeffs: &[Fr], x: Fr) -> Fr {
+    todo!("exercise 3: horner")
+}
+
+/// Exercise 4: computes `k · p` by double-and-add.
+///
+/// Hint: walk through the bits of `k.into_repr()` from the most significant down,
+/// doubling an accumulator at each bit and adding `p` when the bit is set. You may use
+/// `double` and `+`, but not `mul`.
+#[cfg(not(feature = "solutions"))]
+pub fn double_and_add(p: G1Projective, k: Fr) -> G1Projective {
+    todo!("exercise 4: double_and_add")
+}
+
+/// Exercise 5: returns the coefficients of the polynomial of degree less than
+/// `points.len()` that passes through `points`, whose `x`-coordinates are distinct.
+///
+/// Hint: `Σ y_i · L_i(X)`, where the Lagrange basis polynomial
+/// `L_i(X) = ∏_{j ≠ i} (X - x_j) / (x_i - x_j)` is `1` at `x_i` and `0` at every other
+/// `x_j`. An `O(n³)` solution is fine.
+#[cfg(not(feature = "solutions"))]
+pub fn interpolate(points: &[(Fr, Fr)]) -> Vec<Fr> {
+    todo!("exercise 5: interpolate")
+}
+
+/// Exercise 6: checks a BLS signature on `msg` under `pk`.
+///
+/// Hint: a signature is `sk · H(m)`, where `H` is [`bls::hash_message`] with the tag
+/// [`bls::BASIC_DST`], and a public key is `sk · g2`. Check that
+/// `e(σ, g2) = e(H(m), pk)`.
+#[cfg(not(feature = "solutions"))]
+pub fn bls_verify(pk: &PublicKey, msg: &[u8], signature: &Signature) -> bool {
+    todo!("exercise 6: bls_verify")
+}
+
+#[cfg(feature = "solutions")]
+pub use solutions::{bls_verify, double_and_add, horner, interpolate, inverse, legendre};
+
+/// The result of checking an exercise.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// The implementation passed every check.
+    Passed,
+    /// The implementation is wrong, for the reason given.
+    Failed(String),
+    /// The implementation still calls `todo!()`.
+    NotAttempted,
+}
+
+/// An exercise, and the check for the implementation in this module.
+#[derive(Clone, Copy, Debug)]
+pub struct Exercise {
+    /// The name of the function to implement.
+    pub name: &'static str,
+    check: fn() -> Result<(), String>,
+}
+
+/// Every exercise, in order.
+pub const EXERCISES: [Exercise; 6] = [
+    Exercise {
+        name: "inverse",
+        check: || check_inverse(inverse),
+    },
+    Exercise {
+        name: "legendre",
+        check: || check_legendre(legendre),
+    },
+    Exercise {
+        name: "horner",
+        check: || check_horner(horner),
+    },
+    Exercise {
+        name: "double_and_add",
+        check: || check_double_and_add(double_and_add),
+    },
+    Exercise {
+        name: "interpolate",
+        check: || check_interpolate(interpolate),
+    },
+    Exercise {
+        name: "bls_verify",
+        check: || check_bls_verify(bls_verify),
+    },
+];
+
+impl Exercise {
+    /// Checks the implementation of this exercise.
+    ///
+    /// A panic, other than the one from `todo!()`, counts as a failure. While the check
+    /// runs, the panic hook is replaced by one that stays quiet about `todo!()`, so this
+    /// shouldn't be called while other threads may panic.
+    pub fn run(&self) -> Outcome {
+        let previous = Arc::new(panic::take_hook());
+        let hook = previous.clone();
+        panic::set_hook(Box::new(move |info| {
+            if !info.to_string().contains("not yet implemented") {
+                hook(info)
+            }
+        }));
+        let result = panic::catch_unwind(AssertUnwindSafe(self.check));
+        let _ = panic::take_hook();
+        panic::set_hook(Box::new(move |info| previous(info)));
+
+        match result {
+            Ok(Ok(())) => Outcome::Passed,
+            Ok(Err(reason)) => Outcome::Failed(reason),
+            Err(payload) => {
+                let message = payload
+                    .downcast_ref::<String>()
+                    .cloned()
+                    .or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()))
+                    .unwrap_or_default();
+                if message.starts_with("not yet implemented") {
+                    Outcome::NotAttempted
+                } else {
+                    Outcome::Failed(format!("panicked: {}", message))
+                }
+            }
+        }
+    }
+}
+
+/// Checks every exercise, in order.
+pub fn run_all() -> Vec<(Exercise, Outcome)> {
+    EXERCISES.iter().map(|e| (*e, e.run())).collect()
+}
+
+/// Checks an implementation of [`inverse`].
+pub fn check_inverse(f: impl Fn(Fr) -> Option<Fr>) -> Result<(), String> {
+    if f(Fr::zero()).is_some() {
+        return Err("inverse(0) should be None".to_string());
+    }
+    let mut rng = crate::rng::seeded(1);
+    let inputs = [Fr::one(), -Fr::one()];
+    for a in inputs
+        .iter()
+        .copied()
+        .chain((0..TRIALS).map(|_| Fr::rand(&mut rng)))
+    {
+        match f(a) {
+            Some(b) if a * b == Fr::one() => {}
+            Some(b) => {
+                return Err(format!(
+                    "inverse({}) returned {}, but {} · {} ≠ 1",
+                    a, b, a, b
+                ))
+            }
+            None => return Err(format!("inverse({}) returned None", a)),
+        }
+    }
+    Ok(())
+}
+
+/// Checks an implementation of [`legendre`].
+pub fn check_legendre(f: impl Fn(Fr) -> i8) -> Result<(), String> {
+    if f(Fr::zero()) != 0 {
+        return Err("legendre(0) should be 0".to_string());
+    }
+    let mut rng = crate::rng::seeded(2);
+    for _ in 0..TRIALS {
+        let a = Fr::rand(&mut rng);
+        let expected = number_theory::legendre(&a);
+        if f(a) != expected {
+            return Err(format!(
+                "legendre({}) returned {}, not {}",
+                a,
+                f(a),
+                expected
+            ));
+        }
+        if f(a.square()) != 1 {
+            return Err(format!(
+                "legendre({}) should be 1, since it is a square",
+                a.square()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Checks an implementation of [`horner`].
+pub fn check_horner(f: impl Fn(&[Fr], Fr) -> Fr) -> Result<(), String> {
+    let mut rng = crate::rng::seeded(3);
+    if f(&[], Fr::rand(&mut rng)) != Fr::zero() {
+        return Err("the empty polynomial should evaluate to 0".to_string());
+    }
+    for len in 1..TRIALS {
+        let coeffs: Vec<Fr> = (0..len).map(|_| Fr::rand(&mut rng)).collect();
+        let x = Fr::rand(&mut rng);
+        if f(&coeffs, x) != poly::evaluate(&coeffs, x) {
+            return Err(format!(
+                "wrong value for a polynomial of degree {}",
+                len - 1
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Checks an implementation of [`double_and_add`].
+pub fn check_double_and_add(f: impl Fn(G1Projective, Fr) -> G1Projective) -> Result<(), String> {
+    let mut rng = crate::rng::seeded(4);
+    let p = G1Projective::rand(&mut rng);
+    let edge_cases = [Fr::zero(), Fr::one(), Fr::from(2u64), -Fr::one()];
+    for k in edge_cases
+        .iter()
+        .copied()
+        .chain((0..TRIALS).map(|_| Fr::rand(&mut rng)))
+    {
+        if f(p, k) != p.mul(k.into_repr()) {
+            return Err(format!("wrong result for k = {}", k));
+        }
+    }
+    if !f(G1Projective::zero(), Fr::rand(&mut rng)).is_zero() {
+        return Err("k · 0 should be 0".to_string());
+    }
+    Ok(())
+}
+
+/// Checks an implementation of [`interpolate`].
+pub fn check_interpolate(f: impl Fn(&[(Fr, Fr)]) -> Vec<Fr>) -> Result<(), String> {
+    let mut rng = crate::rng::seeded(5);
+    for n in 1..TRIALS / 2 {
+        let points: Vec<(Fr, Fr)> = (0..n)
+            .map(|_| (Fr::rand(&mut rng), Fr::rand(&mut rng)))
+            .collect();
+        let coeffs = f(&points);
+        if coeffs.len() > n {
+            return Err(format!(
+                "{} coefficients for {} points: the degree should be less than {}",
+                coeffs.len(),
+                n,
+                n
+            ));
+        }
+        if let Some((x, y)) = points
+            .iter()
+            .find(|(x, y)| poly::evaluate(&coeffs, *x) != *y)
+        {
+            return Err(format!(
+                "with {} points, the result misses ({}, {})",
+                n, x, y
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Checks an implementation of [`bls_verify`].
+pub fn check_bls_verify(f: impl Fn(&PublicKey, &[u8], &Signature) -> bool) -> Result<(), String> {
+    let mut rng = crate::rng::seeded(6);
+    let (sk, pk) = bls::keygen(&mut rng);
+    let (_, other_pk) = bls::keygen(&mut rng);
+    let msg = b"a message";
+    let signature = bls::sign(&sk, msg);
+    if !f(&pk, msg, &signature) {
+        return Err("a valid signature was rejected".to_string());
+    }
+    if f(&pk, b"another message", &signature) {
+        return Err("a signature on a different message was accepted".to_string());
+    }
+    if f(&other_pk, msg, &signature) {
+        return Err("a signature under a different key was accepted".to_string());
+    }
+    if f(&pk, msg, &Signature(bls::hash_message(msg, bls::BASIC_DST))) {
+        return Err("the hash of the message was accepted as a signature".to_string());
+    }
+    Ok(())
+}
+
+#[cfg(feature = "solutions")]
+mod solutions {
+    use super::*;
+    use ark_bls12_381::{Bls12_381, G2Affine};
+    use ark_ec::{AffineCurve, PairingEngine};
+    use ark_ff::{BigInteger, FpParameters};
+
+    pub fn inverse(a: Fr) -> Option<Fr> {
+        if a.is_zero() {
+            return None;
+        }
+        let mut exponent = <Fr as PrimeField>::Params::MODULUS;
+        exponent.sub_noborrow(&2u64.into());
+        Some(a.pow(exponent))
+    }
+
+    pub fn legendre(a: Fr) -> i8 {
+        let symbol = a.pow(<Fr as PrimeField>::Params::MODULUS_MINUS_ONE_DIV_TWO);
+        if symbol.is_zero() {
+            0
+        } else if symbol.is_one() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    pub fn horner(coeffs: &[Fr], x: Fr) -> Fr {
+        coeffs.iter().rev().fold(Fr::zero(), |acc, c| acc * x + c)
+    }
+
+    pub fn double_and_add(p: G1Projective, k: Fr) -> G1Projective {
+        let mut acc = G1Projective::zero();
+        for bit in k.into_repr().to_bits_be() {
+            acc.double_in_place();
+            if bit {
+                acc += p;
+            }
+        }
+        acc
+    }
+
+    pub fn interpolate(points: &[(Fr, Fr)]) -> Vec<Fr> {
+        let mut result = vec![Fr::zero(); points.len()];
+        for (i, (x_i, y_i)) in points.iter().enumerate() {
+            // Multiply out L_i(X), one factor (X - x_j) / (x_i - x_j) at a time.
+            let mut basis = vec![Fr::one()];
+            for (j, (x_j, _)) in points.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                let scale = (*x_i - x_j).inverse().expect("the nodes must be distinct");
+                let mut next = vec![Fr::zero(); basis.len() + 1];
+                for (k, c) in basis.iter().enumerate() {
+                    next[k + 1] += *c * scale;
+                    next[k] -= *c * x_j * scale;
+                }
+                basis = next;
+            }
+            for (r, b) in result.iter_mut().zip(&basis) {
+                *r += *y_i * b;
+            }
+        }
+        result
+    }
+
+    pub fn bls_verify(pk: &PublicKey, msg: &[u8], signature: &Signature) -> bool {
+        let h = bls::hash_message(msg, bls::BASIC_DST);
+        Bls12_381::pairing(signature.0, G2Affine::prime_subgroup_generator())
+            == Bls12_381::pairing(h, pk.0)
+    }
+}
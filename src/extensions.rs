@@ -0,0 +1,153 @@
+//! A walkthrough of how the BLS12-381 extension fields are built.
+//!
+//! Pairings on BLS12-381 output elements of `Fq12`, a field with
+//! `q^12` elements, where `q` is the modulus of the base field `Fq`.
+//! Rather than defining `Fq12` directly, `arkworks` builds it as a *tower* of
+//! smaller extensions, each of which is defined by adjoining a root of an
+//! irreducible polynomial to the previous field:
+//!
+//! * `Fq2 = Fq[u] / (u^2 + 1)`: elements are `c0 + c1·u` with `c0, c1` in `Fq`;
+//! * `Fq6 = Fq2[v] / (v^3 - (u + 1))`: elements are `c0 + c1·v + c2·v^2`
+//!   with `c0, c1, c2` in `Fq2`;
+//! * `Fq12 = Fq6[w] / (w^2 - v)`: elements are `c0 + c1·w` with `c0, c1` in `Fq6`.
+//!
+//! We can check each of these defining relations directly:
+//!
+//! ```rust
+//! use ark_bls12_381::{Fq, Fq12, Fq2, Fq6};
+//! use ark_ff::Field;
+//! use ark_std::{One, Zero};
+//!
+//! // `u` squares to -1...
+//! let u = Fq2::new(Fq::zero(), Fq::one());
+//! assert_eq!(u.square(), -Fq2::one());
+//!
+//! // ...`v` cubes to `u + 1`...
+//! let v = Fq6::new(Fq2::zero(), Fq2::one(), Fq2::zero());
+//! assert_eq!(v.square() * v, Fq6::new(u + Fq2::one(), Fq2::zero(), Fq2::zero()));
+//!
+//! // ...and `w` squares to `v`.
+//! let w = Fq12::new(Fq6::zero(), Fq6::one());
+//! assert_eq!(w.square(), Fq12::new(v, Fq6::zero()));
+//! ```
+//!
+//! The `02_extension_fields` example walks through this module:
+//! `cargo run --example 02_extension_fields`.
+
+use ark_bls12_381::{Fq, Fq12, Fq2, Fq6};
+use ark_ff::Field;
+use ark_std::vec::Vec;
+use ark_std::Zero;
+
+/// Embeds an element of the base field `Fq` into `Fq2` as `a + 0·u`.
+///
+/// ```rust
+/// use ark_algebra_intro::extensions::lift_to_fq2;
+/// use ark_bls12_381::Fq;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let a = Fq::rand(&mut rng);
+/// let b = Fq::rand(&mut rng);
+/// // Lifting respects the field operations:
+/// assert_eq!(lift_to_fq2(a) * lift_to_fq2(b), lift_to_fq2(a * b));
+/// assert_eq!(lift_to_fq2(a) + lift_to_fq2(b), lift_to_fq2(a + b));
+/// ```
+pub fn lift_to_fq2(a: Fq) -> Fq2 {
+    Fq2::new(a, Fq::zero())
+}
+
+/// Embeds an element of `Fq2` into `Fq6` as `a + 0·v + 0·v^2`.
+///
+/// ```rust
+/// use ark_algebra_intro::extensions::lift_to_fq6;
+/// use ark_bls12_381::Fq2;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let a = Fq2::rand(&mut rng);
+/// let b = Fq2::rand(&mut rng);
+/// assert_eq!(lift_to_fq6(a) * lift_to_fq6(b), lift_to_fq6(a * b));
+/// ```
+pub fn lift_to_fq6(a: Fq2) -> Fq6 {
+    Fq6::new(a, Fq2::zero(), Fq2::zero())
+}
+
+/// Embeds an element of `Fq6` into `Fq12` as `a + 0·w`.
+///
+/// ```rust
+/// use ark_algebra_intro::extensions::{lift_to_fq12, lift_to_fq2, lift_to_fq6};
+/// use ark_bls12_381::Fq;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// // Lifts compose, so we can move a base field element all the way up the tower:
+/// let a = Fq::rand(&mut rng);
+/// let b = Fq::rand(&mut rng);
+/// let lift = |x| lift_to_fq12(lift_to_fq6(lift_to_fq2(x)));
+/// assert_eq!(lift(a) * lift(b), lift(a * b));
+/// ```
+pub fn lift_to_fq12(a: Fq6) -> Fq12 {
+    Fq12::new(a, Fq6::zero())
+}
+
+/// Constructs the element `c0 + c1·u` of `Fq2`.
+///
+/// ```rust
+/// use ark_algebra_intro::extensions::fq2_from_coeffs;
+/// use ark_bls12_381::Fq;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let (a0, a1) = (Fq::rand(&mut rng), Fq::rand(&mut rng));
+/// let (b0, b1) = (Fq::rand(&mut rng), Fq::rand(&mut rng));
+///
+/// // Since u^2 = -1, multiplication works just like for complex numbers:
+/// let product = fq2_from_coeffs(a0, a1) * fq2_from_coeffs(b0, b1);
+/// assert_eq!(product, fq2_from_coeffs(a0 * b0 - a1 * b1, a0 * b1 + a1 * b0));
+/// ```
+pub fn fq2_from_coeffs(c0: Fq, c1: Fq) -> Fq2 {
+    Fq2::new(c0, c1)
+}
+
+/// Returns the orbit of `a` under the Frobenius automorphism, that is,
+/// `[a, a^p, a^(p^2), ..., a^(p^(k-1))]`, where `p` is the characteristic
+/// of `F` and `k` is its extension degree over the prime field.
+///
+/// These are the *conjugates* of `a`. Their product is always an element
+/// of the prime field (the *norm* of `a`), which is a useful sanity check.
+///
+/// ```rust
+/// use ark_algebra_intro::extensions::{frobenius_orbit, lift_to_fq2};
+/// use ark_bls12_381::{Fq, Fq2, Fq6};
+/// use ark_ff::Field;
+/// use ark_std::{One, UniformRand};
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// // Elements of `Fq6` have six conjugates:
+/// let a = Fq6::rand(&mut rng);
+/// let orbit = frobenius_orbit(a);
+/// assert_eq!(orbit.len(), 6);
+/// assert_eq!(orbit[1], a.pow(Fq6::characteristic()));
+///
+/// // For `Fq2`, the non-trivial conjugate of `c0 + c1·u` is `c0 - c1·u`,
+/// // and their product is the norm `c0^2 + c1^2`, which lies in `Fq`:
+/// let b = Fq2::rand(&mut rng);
+/// let orbit = frobenius_orbit(b);
+/// assert_eq!(orbit[1], Fq2::new(b.c0, -b.c1));
+/// assert_eq!(orbit[0] * orbit[1], lift_to_fq2(b.c0.square() + b.c1.square()));
+///
+/// // Elements of the base field are fixed by the Frobenius map:
+/// let c = lift_to_fq2(Fq::one());
+/// assert!(frobenius_orbit(c).iter().all(|x| *x == c));
+/// ```
+pub fn frobenius_orbit<F: Field>(a: F) -> Vec<F> {
+    let degree = F::extension_degree() as usize;
+    let mut orbit = Vec::with_capacity(degree);
+    let mut current = a;
+    for _ in 0..degree {
+        orbit.push(current);
+        current.frobenius_map(1);
+    }
+    orbit
+}
@@ -0,0 +1,155 @@
+//! Converting between the base field and the scalar field of BLS12-381.
+//!
+//! Every elliptic curve comes with *two* prime fields:
+//! the *base field* `Fq`, over which the coordinates of curve points are defined,
+//! and the *scalar field* `Fr`, whose elements we multiply curve points by.
+//! These are different fields with different moduli, and mixing them up is one
+//! of the most common sources of bugs in puzzle code.
+//!
+//! For BLS12-381, the scalar field modulus `r` (255 bits) is much smaller than
+//! the base field modulus `q` (381 bits). This means that every element of
+//! `Fr` can be losslessly converted into an element of `Fq`, but the converse
+//! is not true:
+//!
+//! ```rust
+//! use ark_bls12_381::{Fq, Fr};
+//! use ark_ff::{FpParameters, PrimeField};
+//! use num_bigint::BigUint;
+//!
+//! let q: BigUint = <Fq as PrimeField>::Params::MODULUS.into();
+//! let r: BigUint = <Fr as PrimeField>::Params::MODULUS.into();
+//! assert!(r < q);
+//! assert_eq!(<Fq as PrimeField>::size_in_bits(), 381);
+//! assert_eq!(<Fr as PrimeField>::size_in_bits(), 255);
+//! ```
+
+use crate::Error;
+use ark_bls12_381::{Fq, Fr};
+use ark_ff::{BigInteger, FpParameters, PrimeField};
+use ark_std::vec::Vec;
+use num_bigint::BigUint;
+
+/// Losslessly converts a scalar field element into a base field element
+/// with the same integer value.
+///
+/// ```rust
+/// use ark_algebra_intro::field_map::fr_to_fq;
+/// use ark_bls12_381::{Fq, Fr};
+/// use ark_std::UniformRand;
+/// use num_bigint::BigUint;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let a = Fr::rand(&mut rng);
+/// let a_int: BigUint = a.into();
+/// let b_int: BigUint = fr_to_fq(a).into();
+/// assert_eq!(a_int, b_int);
+///
+/// // Note that the conversion does *not* respect arithmetic, because
+/// // the two fields wrap around at different moduli:
+/// assert_ne!(fr_to_fq(-Fr::from(1u64)), -Fq::from(1u64));
+/// ```
+pub fn fr_to_fq(a: Fr) -> Fq {
+    // Since `r < q`, the integer value of `a` is already reduced modulo `q`.
+    Fq::from_le_bytes_mod_order(&a.into_repr().to_bytes_le())
+}
+
+/// Returns the little-endian byte encoding of `a` as an element of `Fq`,
+/// i.e., padded to the byte width of the base field.
+///
+/// This is the encoding you'd need, e.g., to feed a scalar into a routine
+/// that expects base field bytes.
+///
+/// ```rust
+/// use ark_algebra_intro::field_map::{fr_to_fq, fr_to_fq_bytes};
+/// use ark_bls12_381::{Fq, Fr};
+/// use ark_ff::{BigInteger, PrimeField};
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let a = Fr::rand(&mut rng);
+/// let bytes = fr_to_fq_bytes(&a);
+/// // The encoding is as wide as an `Fq` element, not an `Fr` element:
+/// assert_eq!(bytes.len(), 48);
+/// assert_eq!(a.into_repr().to_bytes_le().len(), 32);
+/// assert_eq!(Fq::from_le_bytes_mod_order(&bytes), fr_to_fq(a));
+/// ```
+pub fn fr_to_fq_bytes(a: &Fr) -> Vec<u8> {
+    fr_to_fq(*a).into_repr().to_bytes_le()
+}
+
+/// Checks whether the integer value of `a` is smaller than the scalar field
+/// modulus, i.e., whether `a` can be losslessly converted into an element of `Fr`.
+///
+/// ```rust
+/// use ark_algebra_intro::field_map::{fq_fits_in_fr, fr_to_fq};
+/// use ark_bls12_381::{Fq, Fr};
+/// use ark_ff::{FpParameters, PrimeField};
+/// use num_bigint::BigUint;
+///
+/// // Every scalar fits, of course...
+/// assert!(fq_fits_in_fr(&fr_to_fq(-Fr::from(1u64))));
+///
+/// // ...but there are base field elements that don't, such as `r` itself:
+/// let r: BigUint = <Fr as PrimeField>::Params::MODULUS.into();
+/// assert!(!fq_fits_in_fr(&Fq::from(r)));
+/// assert!(!fq_fits_in_fr(&-Fq::from(1u64)));
+/// ```
+pub fn fq_fits_in_fr(a: &Fq) -> bool {
+    let a: BigUint = (*a).into();
+    let r: BigUint = <Fr as PrimeField>::Params::MODULUS.into();
+    a < r
+}
+
+/// Converts a base field element into a scalar field element with the same
+/// integer value, returning [`Error::OutOfRange`] if this isn't possible.
+///
+/// ```rust
+/// use ark_algebra_intro::field_map::{fq_to_fr, fr_to_fq};
+/// use ark_algebra_intro::Error;
+/// use ark_bls12_381::{Fq, Fr};
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let a = Fr::rand(&mut rng);
+/// assert_eq!(fq_to_fr(&fr_to_fq(a)), Ok(a));
+///
+/// // -1 in `Fq` is `q - 1`, which is far larger than `r`:
+/// assert_eq!(fq_to_fr(&-Fq::from(1u64)), Err(Error::OutOfRange));
+/// ```
+pub fn fq_to_fr(a: &Fq) -> Result<Fr, Error> {
+    if fq_fits_in_fr(a) {
+        Ok(Fr::from_le_bytes_mod_order(&a.into_repr().to_bytes_le()))
+    } else {
+        Err(Error::OutOfRange)
+    }
+}
+
+/// Maps a base field element to a scalar field element by reducing its
+/// integer value modulo `r`.
+///
+/// Unlike [`fq_to_fr`], this never fails, but it is many-to-one: since `q` is
+/// roughly `2^126` times larger than `r`, many distinct base field elements
+/// map to the same scalar. It is also slightly *biased*, because `q` is not a
+/// multiple of `r`. This is fine for deriving challenges from a point's
+/// coordinates, but it should not be mistaken for a lossless conversion.
+///
+/// ```rust
+/// use ark_algebra_intro::field_map::{fr_to_fq, hash_fq_to_fr};
+/// use ark_bls12_381::{Fq, Fr};
+/// use ark_ff::{FpParameters, PrimeField};
+/// use ark_std::{UniformRand, Zero};
+/// use num_bigint::BigUint;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// // On inputs that fit, this agrees with the lossless conversion...
+/// let a = Fr::rand(&mut rng);
+/// assert_eq!(hash_fq_to_fr(&fr_to_fq(a)), a);
+///
+/// // ...but distinct inputs can collide: both `0` and `r` map to zero.
+/// let r: BigUint = <Fr as PrimeField>::Params::MODULUS.into();
+/// assert_eq!(hash_fq_to_fr(&Fq::from(r)), Fr::zero());
+/// assert_eq!(hash_fq_to_fr(&Fq::zero()), Fr::zero());
+/// ```
+pub fn hash_fq_to_fr(a: &Fq) -> Fr {
+    Fr::from_le_bytes_mod_order(&a.into_repr().to_bytes_le())
+}
@@ -0,0 +1,875 @@
+//! Runnable versions of the finite field examples from the crate documentation.
+//!
+//! Each function here packages up one of the snippets from the
+//! "Finite field arithmetic" section, so that you can call it on your own
+//! field elements instead of copy-pasting code around.
+//!
+//! The `01_prime_fields` example walks through this module:
+//! `cargo run --example 01_prime_fields`.
+
+use crate::Error;
+use ark_ff::{BigInteger, FftParameters, Field, FpParameters, PrimeField, SquareRootField};
+use ark_std::convert::TryFrom;
+use ark_std::rand::Rng;
+use ark_std::vec::Vec;
+use ark_std::{One, Zero};
+use num_bigint::BigUint;
+
+/// Samples a uniformly random square (i.e., a *quadratic residue*) in `F`
+/// via rejection sampling.
+///
+/// Roughly half of the elements of a finite field of odd characteristic are
+/// squares, so this loop terminates after two iterations on average.
+///
+/// ```rust
+/// use ark_algebra_intro::fields::random_square;
+/// use ark_bls12_381::Fq2 as F;
+/// use ark_ff::{Field, SquareRootField};
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let a: F = random_square(&mut rng);
+/// assert!(!a.legendre().is_qnr());
+///
+/// // Since `a` is a square, we can compute its square root:
+/// let b = a.sqrt().unwrap();
+/// assert_eq!(b.square(), a);
+/// ```
+pub fn random_square<F: SquareRootField, R: Rng + ?Sized>(rng: &mut R) -> F {
+    loop {
+        let a = F::rand(rng);
+        if !a.legendre().is_qnr() {
+            return a;
+        }
+    }
+}
+
+/// Computes the inverse of `a` via Fermat's little theorem, i.e., as
+/// `a^(p - 2)`, where `p` is the modulus of `F`.
+///
+/// This is what [`Field::inverse`] conceptually computes; in practice,
+/// `arkworks` uses a faster algorithm based on the binary extended Euclidean
+/// algorithm (see [`mod_inverse`](crate::number_theory::mod_inverse)). Unlike
+/// [`Field::inverse`], which returns `None` when `a` is zero, this returns
+/// [`Error::ZeroInverse`].
+///
+/// ```rust
+/// use ark_algebra_intro::fields::demo_inverse;
+/// use ark_algebra_intro::Error;
+/// use ark_bls12_381::Fq as F;
+/// use ark_ff::Field;
+/// use ark_std::{One, UniformRand, Zero};
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let a = F::rand(&mut rng);
+/// assert_eq!(demo_inverse(&a).ok(), a.inverse());
+/// assert_eq!(demo_inverse(&a)? * a, F::one());
+///
+/// // Zero has no inverse:
+/// assert_eq!(demo_inverse(&F::zero()), Err(Error::ZeroInverse));
+/// # Ok::<(), Error>(())
+/// ```
+pub fn demo_inverse<F: PrimeField>(a: &F) -> Result<F, Error> {
+    if a.is_zero() {
+        return Err(Error::ZeroInverse);
+    }
+    let mut exponent = F::Params::MODULUS;
+    exponent.sub_noborrow(&F::BigInt::from(2));
+    Ok(a.pow(exponent))
+}
+
+/// Applies the Frobenius automorphism `x -> x^p` to `a` `power` times, where
+/// `p` is the characteristic of `F`.
+///
+/// For prime fields the Frobenius map is the identity, but for extension
+/// fields like `Fq2` it is a non-trivial map that can be computed much
+/// more efficiently than the equivalent exponentiation.
+///
+/// ```rust
+/// use ark_algebra_intro::fields::demo_frobenius;
+/// use ark_bls12_381::{Fq, Fq2};
+/// use ark_ff::Field;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// // For a prime field, the Frobenius map does nothing...
+/// let a = Fq::rand(&mut rng);
+/// assert_eq!(demo_frobenius(&a, 1), a);
+///
+/// // ...but for an extension field, it agrees with raising to the `p`-th power.
+/// let b = Fq2::rand(&mut rng);
+/// assert_eq!(demo_frobenius(&b, 1), b.pow(Fq2::characteristic()));
+/// assert_ne!(demo_frobenius(&b, 1), b);
+/// // Since `Fq2` has degree 2, applying the map twice gets us back to where we started.
+/// assert_eq!(demo_frobenius(&b, 2), b);
+/// ```
+pub fn demo_frobenius<F: Field>(a: &F, power: usize) -> F {
+    let mut result = *a;
+    result.frobenius_map(power);
+    result
+}
+
+/// Inverts every element of `v` in place using Montgomery's trick,
+/// leaving zero elements untouched.
+///
+/// Computing an inverse is much more expensive than a multiplication.
+/// Montgomery's trick trades `n` inversions for a single inversion and
+/// roughly `3n` multiplications: we first compute the running products
+/// `v[0], v[0]·v[1], ..., v[0]·...·v[n-1]`, invert the final product, and
+/// then walk backwards, peeling off one element's inverse at a time.
+///
+/// `arkworks` provides an optimized (and parallelizable) version of this as
+/// [`ark_ff::batch_inversion`].
+///
+/// ```rust
+/// use ark_algebra_intro::fields::batch_inverse;
+/// use ark_bls12_381::Fr as F;
+/// use ark_ff::Field;
+/// use ark_std::{UniformRand, Zero};
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let mut v: Vec<F> = (0..10).map(|_| F::rand(&mut rng)).collect();
+/// v[3] = F::zero();
+///
+/// // The naive approach performs one inversion per element...
+/// let naive: Vec<F> = v.iter().map(|a| a.inverse().unwrap_or(F::zero())).collect();
+/// // ...while Montgomery's trick only needs a single inversion.
+/// batch_inverse(&mut v);
+/// assert_eq!(v, naive);
+/// ```
+pub fn batch_inverse<F: Field>(v: &mut [F]) {
+    // First pass: compute the running products of the non-zero elements.
+    let mut prod = Vec::with_capacity(v.len());
+    let mut acc = F::one();
+    for a in v.iter().filter(|a| !a.is_zero()) {
+        acc *= a;
+        prod.push(acc);
+    }
+
+    // Invert the product of all the non-zero elements.
+    // This is the only inversion we perform.
+    let mut acc_inv = match acc.inverse() {
+        Some(inv) => inv,
+        None => return,
+    };
+
+    // Second pass: walk backwards, using the running products to
+    // "peel off" the inverse of each element in turn.
+    let previous_products = prod.into_iter().rev().skip(1).chain(Some(F::one()));
+    for (a, p) in v
+        .iter_mut()
+        .rev()
+        .filter(|a| !a.is_zero())
+        .zip(previous_products)
+    {
+        // At this point, `acc_inv` is the inverse of the product of
+        // all elements up to and including `a`.
+        let new_acc_inv = acc_inv * *a;
+        *a = acc_inv * p;
+        acc_inv = new_acc_inv;
+    }
+}
+
+/// The state of the Tonelli–Shanks algorithm after one iteration of its main loop.
+///
+/// The variable names follow the [Wikipedia description](https://en.wikipedia.org/wiki/Tonelli%E2%80%93Shanks_algorithm)
+/// of the algorithm.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TonelliShanksStep<F> {
+    /// The least `i` such that `t^(2^i) = 1`.
+    pub i: u32,
+    /// The correction factor `b = c^(2^(m - i - 1))` applied in this iteration.
+    pub b: F,
+    /// The updated value of `c = b^2`, which is a primitive `2^i`-th root of unity.
+    pub c: F,
+    /// The updated value of `t`.
+    pub t: F,
+    /// The updated candidate square root `r`.
+    pub r: F,
+}
+
+/// A trace of an execution of [`sqrt_tonelli_shanks`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceSteps<F> {
+    /// The two-adicity `s` of `p - 1`, where `p - 1 = 2^s * q` for an odd `q`.
+    pub two_adicity: u32,
+    /// The quadratic non-residue `z` used to seed the algorithm.
+    pub non_residue: F,
+    /// The initial candidate square root `r = a^((q + 1)/2)`.
+    pub initial_root: F,
+    /// The state after each iteration of the main loop.
+    pub steps: Vec<TonelliShanksStep<F>>,
+}
+
+/// Computes a square root of `a` using the Tonelli–Shanks algorithm, returning
+/// both the root and a [trace](TraceSteps) of the algorithm's execution,
+/// or [`Error::NonResidue`] if `a` is not a square.
+///
+/// Write `p - 1 = 2^s * q` with `q` odd. The algorithm maintains a candidate
+/// root `r` and an "error" `t = r^2 / a`, which lies in the subgroup of order
+/// `2^s`. Each iteration shrinks the order of `t` by at least a factor of two,
+/// so the algorithm terminates after at most `s` iterations with `t = 1`.
+///
+/// This is a readable reference implementation; [`SquareRootField::sqrt`]
+/// implements the same algorithm far more efficiently.
+///
+/// ```rust
+/// use ark_algebra_intro::fields::sqrt_tonelli_shanks;
+/// use ark_algebra_intro::Error;
+/// use ark_bls12_381::{Fq, Fr};
+/// use ark_ff::{Field, SquareRootField};
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// // `r - 1` is divisible by 2^32, so the main loop can run many times for `Fr`:
+/// let a = Fr::rand(&mut rng).square();
+/// let (root, trace) = sqrt_tonelli_shanks(a)?;
+/// assert_eq!(root.square(), a);
+/// assert!(root == a.sqrt().unwrap() || root == -a.sqrt().unwrap());
+/// assert_eq!(trace.two_adicity, 32);
+/// assert!(trace.steps.len() <= 32);
+/// // Each step's `t` has strictly smaller order than the last:
+/// assert!(trace.steps.windows(2).all(|w| w[1].i < w[0].i));
+///
+/// // Since `q ≡ 3 (mod 4)`, for `Fq`, the initial candidate is already correct:
+/// let b = Fq::rand(&mut rng).square();
+/// let (root, trace) = sqrt_tonelli_shanks(b)?;
+/// assert_eq!(root.square(), b);
+/// assert_eq!(trace.two_adicity, 1);
+/// assert!(trace.steps.is_empty());
+///
+/// // Non-squares have no square root:
+/// assert_eq!(sqrt_tonelli_shanks(Fr::from(7u64)), Err(Error::NonResidue));
+/// # Ok::<(), Error>(())
+/// ```
+pub fn sqrt_tonelli_shanks<F: PrimeField>(a: F) -> Result<(F, TraceSteps<F>), Error> {
+    let s = F::Params::TWO_ADICITY;
+    // A multiplicative generator is never a square, so it makes a good choice of `z`.
+    let z = F::multiplicative_generator();
+    let mut trace = TraceSteps {
+        two_adicity: s,
+        non_residue: z,
+        initial_root: F::zero(),
+        steps: Vec::new(),
+    };
+    if a.is_zero() {
+        return Ok((F::zero(), trace));
+    }
+
+    // (q + 1) / 2
+    let mut q_plus_one_div_two = F::Params::T_MINUS_ONE_DIV_TWO;
+    q_plus_one_div_two.add_nocarry(&F::BigInt::from(1));
+
+    let mut m = s;
+    let mut c = z.pow(F::Params::T);
+    let mut t = a.pow(F::Params::T);
+    let mut r = a.pow(q_plus_one_div_two);
+    trace.initial_root = r;
+
+    while !t.is_one() {
+        // Find the least `i` with `t^(2^i) = 1`. If there is no such `i < m`,
+        // then `t` (and hence `a`) is not a square.
+        let mut i = 0;
+        let mut t_pow = t;
+        while !t_pow.is_one() {
+            t_pow.square_in_place();
+            i += 1;
+            if i == m {
+                return Err(Error::NonResidue);
+            }
+        }
+
+        let mut b = c;
+        for _ in 0..(m - i - 1) {
+            b.square_in_place();
+        }
+        m = i;
+        c = b.square();
+        t *= c;
+        r *= b;
+        trace.steps.push(TonelliShanksStep { i, b, c, t, r });
+    }
+    Ok((r, trace))
+}
+
+/// Returns the characteristic `p` of `F`, i.e., the modulus of its base prime field.
+///
+/// ```rust
+/// use ark_algebra_intro::fields::characteristic;
+/// use ark_bls12_381::{Fq, Fq12, Fq2};
+/// use ark_ff::{FpParameters, PrimeField};
+/// use num_bigint::BigUint;
+///
+/// let q: BigUint = <Fq as PrimeField>::Params::MODULUS.into();
+/// // Every field in the tower has the same characteristic:
+/// assert_eq!(characteristic::<Fq>(), q);
+/// assert_eq!(characteristic::<Fq2>(), q);
+/// assert_eq!(characteristic::<Fq12>(), q);
+/// ```
+pub fn characteristic<F: Field>() -> BigUint {
+    F::characteristic()
+        .iter()
+        .rev()
+        .fold(BigUint::zero(), |acc, limb| (acc << 64) + limb)
+}
+
+/// Returns the degree `k` of `F` over its base prime field.
+///
+/// ```rust
+/// use ark_algebra_intro::fields::extension_degree;
+/// use ark_bls12_381::{Fq, Fq12, Fq2, Fq6};
+///
+/// assert_eq!(extension_degree::<Fq>(), 1);
+/// assert_eq!(extension_degree::<Fq2>(), 2);
+/// assert_eq!(extension_degree::<Fq6>(), 6);
+/// assert_eq!(extension_degree::<Fq12>(), 12);
+/// ```
+pub fn extension_degree<F: Field>() -> u64 {
+    F::extension_degree()
+}
+
+/// Returns the number of elements of `F`, i.e., `p^k` where `p` is the
+/// [characteristic] and `k` the [extension degree](extension_degree) of `F`.
+///
+/// ```rust
+/// use ark_algebra_intro::fields::{characteristic, field_order};
+/// use ark_algebra_intro::toy_fields::F7;
+/// use ark_bls12_381::{Fq, Fq12, Fq2};
+/// use num_bigint::BigUint;
+///
+/// assert_eq!(field_order::<F7>(), BigUint::from(7u64));
+/// let q = characteristic::<Fq>();
+/// assert_eq!(field_order::<Fq>(), q);
+/// assert_eq!(field_order::<Fq2>(), q.pow(2));
+/// // `Fq12` has a whopping 4569 bits' worth of elements:
+/// assert_eq!(field_order::<Fq12>(), q.pow(12));
+/// assert_eq!(field_order::<Fq12>().bits(), 4569);
+/// ```
+pub fn field_order<F: Field>() -> BigUint {
+    characteristic::<F>().pow(extension_degree::<F>() as u32)
+}
+
+/// Returns the two-adicity of `F`, i.e., the largest `s` such that `2^s` divides `p - 1`.
+///
+/// This determines the largest power-of-two multiplicative subgroup of `F`,
+/// and hence the largest domain over which we can perform radix-2 FFTs.
+///
+/// ```rust
+/// use ark_algebra_intro::fields::two_adicity;
+/// use ark_bls12_381::{Fq, Fr};
+///
+/// // The BLS12-381 scalar field was chosen to be FFT-friendly...
+/// assert_eq!(two_adicity::<Fr>(), 32);
+/// // ...but the base field was not.
+/// assert_eq!(two_adicity::<Fq>(), 1);
+/// ```
+pub fn two_adicity<F: PrimeField>() -> u32 {
+    F::Params::TWO_ADICITY
+}
+
+/// Returns the size of the largest radix-2 FFT domain supported by `F`,
+/// i.e., `2^two_adicity`.
+///
+/// The result saturates at `u64::MAX` for fields with two-adicity 64 or more.
+///
+/// ```rust
+/// use ark_algebra_intro::fields::max_fft_size;
+/// use ark_bls12_381::Fr;
+///
+/// assert_eq!(max_fft_size::<Fr>(), 1 << 32);
+/// ```
+pub fn max_fft_size<F: PrimeField>() -> u64 {
+    1u64.checked_shl(two_adicity::<F>()).unwrap_or(u64::MAX)
+}
+
+/// Returns a primitive `2^log_size`-th root of unity in `F`, or
+/// [`Error::NoRootOfUnity`] if `log_size` exceeds the [two-adicity](two_adicity) of `F`.
+///
+/// ```rust
+/// use ark_algebra_intro::fields::primitive_root_of_unity;
+/// use ark_algebra_intro::Error;
+/// use ark_bls12_381::{Fq, Fr};
+/// use ark_ff::Field;
+/// use ark_std::One;
+///
+/// // BLS12-381's `Fr` supports domains of size 2^32, but no larger:
+/// let omega = primitive_root_of_unity::<Fr>(32).unwrap();
+/// assert_eq!(omega.pow([1u64 << 32]), Fr::one());
+/// assert_ne!(omega.pow([1u64 << 31]), Fr::one());
+/// assert_eq!(primitive_root_of_unity::<Fr>(33), Err(Error::NoRootOfUnity));
+///
+/// // The only non-trivial power-of-two root of unity in `Fq` is -1:
+/// assert_eq!(primitive_root_of_unity::<Fq>(1), Ok(-Fq::one()));
+/// assert_eq!(primitive_root_of_unity::<Fq>(2), Err(Error::NoRootOfUnity));
+/// ```
+pub fn primitive_root_of_unity<F: PrimeField>(log_size: u32) -> Result<F, Error> {
+    let s = two_adicity::<F>();
+    if log_size > s {
+        return Err(Error::NoRootOfUnity);
+    }
+    // `F::two_adic_root_of_unity()` has order `2^s`, so squaring it `s - log_size`
+    // times yields an element of order `2^log_size`.
+    let mut omega = F::two_adic_root_of_unity();
+    for _ in log_size..s {
+        omega.square_in_place();
+    }
+    Ok(omega)
+}
+
+/// Returns all `2^log_size`-th roots of unity in `F`, in the order
+/// `[1, ω, ω^2, ..., ω^(2^log_size - 1)]` for a primitive root `ω`, or
+/// [`Error::NoRootOfUnity`] if `log_size` exceeds the [two-adicity](two_adicity) of `F`.
+///
+/// These are exactly the points over which an FFT of size `2^log_size` evaluates polynomials.
+///
+/// ```rust
+/// use ark_algebra_intro::fields::roots_of_unity;
+/// use ark_bls12_381::Fr;
+/// use ark_ff::Field;
+/// use ark_std::{One, Zero};
+///
+/// let roots = roots_of_unity::<Fr>(3).unwrap();
+/// assert_eq!(roots.len(), 8);
+/// assert_eq!(roots[0], Fr::one());
+/// // Every root is a root of `X^8 - 1`...
+/// assert!(roots.iter().all(|x| x.pow([8]) == Fr::one()));
+/// // ...the roots come in pairs `x`, `-x`...
+/// assert_eq!(roots[4], -Fr::one());
+/// assert!((0..4).all(|i| roots[i + 4] == -roots[i]));
+/// // ...and they sum to zero.
+/// assert_eq!(roots.iter().sum::<Fr>(), Fr::zero());
+/// ```
+pub fn roots_of_unity<F: PrimeField>(log_size: u32) -> Result<Vec<F>, Error> {
+    let omega = primitive_root_of_unity::<F>(log_size)?;
+    let size = 1usize << log_size;
+    let mut roots = Vec::with_capacity(size);
+    let mut current = F::one();
+    for _ in 0..size {
+        roots.push(current);
+        current *= omega;
+    }
+    Ok(roots)
+}
+
+/// The different integer representations associated with a prime field element.
+///
+/// See [`montgomery_view`] for details.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MontgomeryView<F: PrimeField> {
+    /// The limbs that `arkworks` actually stores for the element `a`: `a·R mod p`.
+    pub montgomery_limbs: F::BigInt,
+    /// The Montgomery constant `R mod p`, where `R = 2^(64·n)` for an
+    /// `n`-limb field. This is also the Montgomery form of `1`.
+    pub r: F::BigInt,
+    /// The canonical integer representative of `a` in the range `[0, p - 1]`.
+    /// This is what [`PrimeField::into_repr`] returns.
+    pub canonical: F::BigInt,
+}
+
+/// Exposes the internal (Montgomery) and canonical representations of `a`.
+///
+/// To speed up multiplication, `arkworks` does not store a field element `a` as
+/// the integer `a`, but rather as `a·R mod p`, its *Montgomery form*.
+/// Multiplying two elements in Montgomery form and then dividing by `R` (which
+/// can be done very efficiently) yields the Montgomery form of their product.
+/// As a result, the limbs you see when `Debug`-printing a field element have
+/// nothing obvious to do with its value; to get the value, use [`PrimeField::into_repr`],
+/// which converts out of Montgomery form.
+///
+/// ```rust
+/// use ark_algebra_intro::fields::montgomery_view;
+/// use ark_bls12_381::Fq;
+/// use ark_ff::{FpParameters, PrimeField};
+/// use ark_std::{One, UniformRand};
+/// use num_bigint::BigUint;
+///
+/// // The internal limbs of the number `2` are not `[2, 0, 0, 0, 0, 0]`...
+/// let two = Fq::from(2u64);
+/// let view = montgomery_view(two);
+/// assert_eq!(view.montgomery_limbs, two.0);
+/// assert_ne!(view.montgomery_limbs.as_ref(), &[2, 0, 0, 0, 0, 0]);
+/// // ...but its canonical representation is.
+/// assert_eq!(view.canonical.as_ref(), &[2, 0, 0, 0, 0, 0]);
+/// assert_eq!(view.canonical, two.into_repr());
+///
+/// // In general, the Montgomery form of `a` is `a·R mod p`:
+/// let mut rng = ark_std::rand::thread_rng();
+/// let a = Fq::rand(&mut rng);
+/// let view = montgomery_view(a);
+/// let p: BigUint = <Fq as PrimeField>::Params::MODULUS.into();
+/// let (mont, r, canonical): (BigUint, BigUint, BigUint) =
+///     (view.montgomery_limbs.into(), view.r.into(), view.canonical.into());
+/// assert_eq!(mont, (canonical * r) % p);
+///
+/// // In particular, `R mod p` is how `1` is stored internally:
+/// assert_eq!(montgomery_view(Fq::one()).montgomery_limbs, view.r);
+/// ```
+pub fn montgomery_view<F: PrimeField>(a: F) -> MontgomeryView<F> {
+    let modulus: BigUint = F::Params::MODULUS.into();
+    let r = F::Params::R;
+    let canonical = a.into_repr();
+    let canonical_int: BigUint = canonical.into();
+    let r_int: BigUint = r.into();
+    let montgomery_limbs = F::BigInt::try_from((canonical_int * r_int) % modulus)
+        .ok()
+        .expect("a value reduced modulo p always fits in a field's BigInt");
+    MontgomeryView {
+        montgomery_limbs,
+        r,
+        canonical,
+    }
+}
+
+/// An error that occurs when parsing a field element from a string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The string contained no digits.
+    Empty,
+    /// The string contained a character that is not a digit in the expected radix.
+    InvalidDigit(char),
+    /// The parsed integer is not smaller than the field modulus.
+    OutOfRange,
+}
+
+impl ark_std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut ark_std::fmt::Formatter<'_>) -> ark_std::fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "cannot parse a field element from an empty string"),
+            ParseError::InvalidDigit(c) => write!(f, "invalid digit {:?} in field element", c),
+            ParseError::OutOfRange => write!(f, "integer is not smaller than the field modulus"),
+        }
+    }
+}
+
+impl ark_std::error::Error for ParseError {}
+
+/// Parses a field element from its decimal representation, optionally
+/// preceded by a `-` sign.
+///
+/// Returns [`ParseError::OutOfRange`] if the absolute value of the integer
+/// is not smaller than the field modulus; use [`parse_decimal_mod_order`] to
+/// reduce such inputs instead.
+///
+/// ```rust
+/// use ark_algebra_intro::fields::{parse_decimal, ParseError};
+/// use ark_bls12_381::Fr;
+///
+/// assert_eq!(parse_decimal::<Fr>("42"), Ok(Fr::from(42u64)));
+/// assert_eq!(parse_decimal::<Fr>("-1"), Ok(-Fr::from(1u64)));
+///
+/// // The modulus itself is out of range:
+/// let r = "52435875175126190479447740508185965837690552500527637822603658699938581184513";
+/// assert_eq!(parse_decimal::<Fr>(r), Err(ParseError::OutOfRange));
+///
+/// assert_eq!(parse_decimal::<Fr>(""), Err(ParseError::Empty));
+/// assert_eq!(parse_decimal::<Fr>("12a"), Err(ParseError::InvalidDigit('a')));
+/// ```
+pub fn parse_decimal<F: PrimeField>(s: &str) -> Result<F, ParseError> {
+    parse_radix(s, 10, false)
+}
+
+/// Parses a field element from its decimal representation, reducing
+/// integers that are too large modulo the field modulus.
+///
+/// ```rust
+/// use ark_algebra_intro::fields::parse_decimal_mod_order;
+/// use ark_bls12_381::Fr;
+/// use ark_std::{One, Zero};
+///
+/// let r = "52435875175126190479447740508185965837690552500527637822603658699938581184513";
+/// assert_eq!(parse_decimal_mod_order::<Fr>(r), Ok(Fr::zero()));
+/// let r_plus_one = "52435875175126190479447740508185965837690552500527637822603658699938581184514";
+/// assert_eq!(parse_decimal_mod_order::<Fr>(r_plus_one), Ok(Fr::one()));
+/// ```
+pub fn parse_decimal_mod_order<F: PrimeField>(s: &str) -> Result<F, ParseError> {
+    parse_radix(s, 10, true)
+}
+
+/// Parses a field element from its big-endian hexadecimal representation,
+/// optionally preceded by a `-` sign and/or a `0x` prefix.
+///
+/// Both upper- and lower-case digits are accepted. Returns
+/// [`ParseError::OutOfRange`] if the absolute value of the integer is not
+/// smaller than the field modulus; use [`parse_hex_mod_order`] to reduce
+/// such inputs instead.
+///
+/// ```rust
+/// use ark_algebra_intro::fields::{parse_hex, ParseError};
+/// use ark_bls12_381::Fr;
+///
+/// assert_eq!(parse_hex::<Fr>("0xff"), Ok(Fr::from(255u64)));
+/// assert_eq!(parse_hex::<Fr>("FF"), Ok(Fr::from(255u64)));
+/// assert_eq!(parse_hex::<Fr>("-0x1"), Ok(-Fr::from(1u64)));
+///
+/// let r = "0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001";
+/// assert_eq!(parse_hex::<Fr>(r), Err(ParseError::OutOfRange));
+/// assert_eq!(parse_hex::<Fr>("0x"), Err(ParseError::Empty));
+/// assert_eq!(parse_hex::<Fr>("0xfg"), Err(ParseError::InvalidDigit('g')));
+/// ```
+pub fn parse_hex<F: PrimeField>(s: &str) -> Result<F, ParseError> {
+    parse_radix(s, 16, false)
+}
+
+/// Parses a field element from its big-endian hexadecimal representation,
+/// reducing integers that are too large modulo the field modulus.
+///
+/// ```rust
+/// use ark_algebra_intro::fields::parse_hex_mod_order;
+/// use ark_bls12_381::Fr;
+/// use ark_std::Zero;
+///
+/// let r = "0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001";
+/// assert_eq!(parse_hex_mod_order::<Fr>(r), Ok(Fr::zero()));
+/// ```
+pub fn parse_hex_mod_order<F: PrimeField>(s: &str) -> Result<F, ParseError> {
+    parse_radix(s, 16, true)
+}
+
+fn parse_radix<F: PrimeField>(s: &str, radix: u32, reduce: bool) -> Result<F, ParseError> {
+    let (negate, digits) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let digits = match radix {
+        16 => digits
+            .strip_prefix("0x")
+            .or_else(|| digits.strip_prefix("0X"))
+            .unwrap_or(digits),
+        _ => digits,
+    };
+    if digits.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    if let Some(c) = digits.chars().find(|c| !c.is_digit(radix)) {
+        return Err(ParseError::InvalidDigit(c));
+    }
+    let value = BigUint::parse_bytes(digits.as_bytes(), radix).ok_or(ParseError::Empty)?;
+    let modulus: BigUint = F::Params::MODULUS.into();
+    if !reduce && value >= modulus {
+        return Err(ParseError::OutOfRange);
+    }
+    // `From<BigUint>` reduces modulo the field modulus.
+    let element = F::from(value);
+    Ok(if negate { -element } else { element })
+}
+
+/// Convenience methods for [`Field`] that accept plain integers instead of
+/// `BigInteger` limbs.
+///
+/// This trait is implemented for every type implementing [`Field`], so it
+/// suffices to bring it into scope (e.g., via the [prelude](crate::prelude)).
+///
+/// ```rust
+/// use ark_algebra_intro::fields::FieldExt;
+/// use ark_bls12_381::{Fq2, Fr};
+/// use ark_ff::Field;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let a = Fr::rand(&mut rng);
+/// // No more `a.pow([5u64])`:
+/// assert_eq!(a.pow_u64(5), a * a * a * a * a);
+/// assert_eq!(a.pow_u128(1 << 64), a.pow([0, 1]));
+///
+/// // Every square is, well, a square:
+/// let b = Fq2::rand(&mut rng);
+/// assert!(b.square().is_square());
+///
+/// // And we can compute cube roots:
+/// let c = b.pow_u64(3).nth_root(3).unwrap();
+/// assert_eq!(c.pow_u64(3), b.pow_u64(3));
+/// ```
+pub trait FieldExt: Field {
+    /// Raises `self` to the power `exp`.
+    fn pow_u64(&self, exp: u64) -> Self {
+        self.pow([exp])
+    }
+
+    /// Raises `self` to the power `exp`.
+    fn pow_u128(&self, exp: u128) -> Self {
+        self.pow([exp as u64, (exp >> 64) as u64])
+    }
+
+    /// Checks whether `self` is a square in `Self`.
+    ///
+    /// This computes the Legendre symbol via *Euler's criterion*:
+    /// a non-zero element `a` is a square if and only if `a^((|F| - 1)/2) = 1`.
+    /// Unlike [`SquareRootField::legendre`], this works for any field of odd
+    /// characteristic, including ones like `Fq6` and `Fq12` that don't
+    /// implement [`SquareRootField`].
+    ///
+    /// ```rust
+    /// use ark_algebra_intro::fields::FieldExt;
+    /// use ark_bls12_381::{Fq, Fq12};
+    /// use ark_ff::{Field, SquareRootField};
+    /// use ark_std::{UniformRand, Zero};
+    ///
+    /// let mut rng = ark_std::rand::thread_rng();
+    /// let a = Fq::rand(&mut rng);
+    /// assert_eq!(a.is_square(), a.legendre().is_qr());
+    ///
+    /// let b = Fq12::rand(&mut rng);
+    /// assert!(b.square().is_square());
+    /// assert!(Fq12::zero().is_square());
+    /// ```
+    fn is_square(&self) -> bool {
+        if self.is_zero() {
+            return true;
+        }
+        let exponent: BigUint = multiplicative_order::<Self>() >> 1usize;
+        self.pow(exponent.to_u64_digits()).is_one()
+    }
+
+    /// Computes an `n`-th root of `self`, i.e., an element `b` such that
+    /// `b^n = self`, or returns `None` if no such element exists.
+    ///
+    /// When `gcd(n, |F| - 1) = 1`, the map `x -> x^n` is a bijection and the
+    /// root is unique. Otherwise, this uses a generalization of the
+    /// Tonelli–Shanks algorithm for each prime factor `l` of `n`, which involves
+    /// a brute-force search over `l` elements, so it is only intended for small `n`.
+    /// This returns `None` for `n = 0`.
+    ///
+    /// ```rust
+    /// use ark_algebra_intro::fields::FieldExt;
+    /// use ark_bls12_381::Fr;
+    /// use ark_std::{One, UniformRand};
+    ///
+    /// let mut rng = ark_std::rand::thread_rng();
+    /// let a = Fr::rand(&mut rng);
+    ///
+    /// // 5 is coprime to r - 1, so every element has a unique fifth root:
+    /// assert_eq!(a.pow_u64(5).nth_root(5), Some(a));
+    /// assert_eq!(a.nth_root(5).unwrap().pow_u64(5), a);
+    ///
+    /// // 3 divides r - 1, so only a third of the non-zero elements are cubes,
+    /// // and each of those has three cube roots (we get one of them):
+    /// let cube = a.pow_u64(3);
+    /// assert_eq!(cube.nth_root(3).unwrap().pow_u64(3), cube);
+    /// // Composite degrees work too:
+    /// let b = a.pow_u64(12);
+    /// assert_eq!(b.nth_root(12).unwrap().pow_u64(12), b);
+    ///
+    /// // 7 is a generator of the multiplicative group of `Fr`,
+    /// // so it is not an `n`-th power for any `n` dividing r - 1:
+    /// let seven = Fr::from(7u64);
+    /// assert_eq!(seven.nth_root(2), None);
+    /// assert_eq!(seven.nth_root(3), None);
+    /// assert_eq!(Fr::one().nth_root(0), None);
+    /// ```
+    fn nth_root(&self, n: u64) -> Option<Self> {
+        if n == 0 {
+            return None;
+        }
+        if self.is_zero() {
+            return Some(Self::zero());
+        }
+        nth_root_with_factors(*self, &prime_factors(n))
+    }
+}
+
+impl<F: Field> FieldExt for F {}
+
+/// Returns an `n`-th root of `a`, where `n` is the product of `factors`.
+fn nth_root_with_factors<F: Field>(a: F, factors: &[u64]) -> Option<F> {
+    match factors.split_first() {
+        None => Some(a),
+        // Not every `l`-th root of `a` need be an `(n/l)`-th power, so we try all of them.
+        Some((&l, rest)) => prime_roots(a, l)
+            .into_iter()
+            .find_map(|b| nth_root_with_factors(b, rest)),
+    }
+}
+
+/// Returns all `l`-th roots of the non-zero element `a`, for a prime `l`.
+fn prime_roots<F: Field>(a: F, l: u64) -> Vec<F> {
+    let m = multiplicative_order::<F>();
+    let l_big = BigUint::from(l);
+    let pow = |x: F, e: &BigUint| x.pow((e % &m).to_u64_digits());
+
+    // If `l` does not divide `|F*| = m`, then `x -> x^l` is a bijection,
+    // and its inverse is `x -> x^(1/l mod m)`.
+    if !(&m % &l_big).is_zero() {
+        return vec![pow(a, &l_big.modinv(&m).unwrap())];
+    }
+    // Otherwise, `a` is an `l`-th power if and only if `a^(m/l) = 1`.
+    if !pow(a, &(&m / &l_big)).is_one() {
+        return Vec::new();
+    }
+
+    // Write `m = l^s * t`, where `t` is coprime to `l`.
+    let mut s = 0;
+    let mut t = m.clone();
+    while (&t % &l_big).is_zero() {
+        t /= &l_big;
+        s += 1;
+    }
+    let l_s = l_big.pow(s);
+
+    // `F*` is cyclic, so it splits into a subgroup of order `l^s` and one of order `t`.
+    // We decompose `a = a_sylow * a_rest` accordingly.
+    let (a_sylow, root_rest) = if t.is_one() {
+        (a, F::one())
+    } else {
+        let a_rest = pow(a, &(&l_s * l_s.modinv(&t).unwrap()));
+        let a_sylow = pow(a, &(&t * t.modinv(&l_s).unwrap()));
+        // In the subgroup of order `t`, taking `l`-th roots is again a bijection.
+        (a_sylow, pow(a_rest, &l_big.modinv(&t).unwrap()))
+    };
+
+    // Any `l`-th non-residue `c` yields a generator `g = c^t` of the subgroup of
+    // order `l^s`. Since half or more of all elements are non-residues, we can
+    // find one quickly by (deterministic) random sampling.
+    let mut rng = crate::rng::seeded(0);
+    let c = (0..)
+        .map(|_| F::rand(&mut rng))
+        .find(|c| !c.is_zero() && !pow(*c, &(&m / &l_big)).is_one())
+        .unwrap();
+    let g = pow(c, &t);
+    let g_inv = g.inverse().unwrap();
+    // `gamma` generates the subgroup of `l`-th roots of unity.
+    let gamma = pow(g, &l_big.pow(s - 1));
+
+    // Compute the discrete logarithm `e` of `a_sylow` with respect to `g`,
+    // one base-`l` digit at a time.
+    let mut e = BigUint::zero();
+    let mut l_k = BigUint::one();
+    for k in 0..s {
+        let h = pow(a_sylow * pow(g_inv, &e), &l_big.pow(s - 1 - k));
+        let digit = (0..l).find(|d| gamma.pow([*d]) == h).unwrap();
+        e += &l_k * digit;
+        l_k *= &l_big;
+    }
+    // Since `a` is an `l`-th power, `e` is divisible by `l`.
+    let root = pow(g, &(e / &l_big)) * root_rest;
+    debug_assert_eq!(pow(root, &l_big), a);
+
+    // The remaining roots differ from `root` by an `l`-th root of unity.
+    let mut roots = Vec::with_capacity(l as usize);
+    let mut current = root;
+    for _ in 0..l {
+        roots.push(current);
+        current *= gamma;
+    }
+    roots
+}
+
+/// Returns the prime factors of `n`, with multiplicity.
+pub(crate) fn prime_factors(mut n: u64) -> Vec<u64> {
+    let mut factors = Vec::new();
+    let mut p = 2;
+    while p <= n / p {
+        while n.is_multiple_of(p) {
+            factors.push(p);
+            n /= p;
+        }
+        p += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+/// Returns `|F| - 1`, the order of the multiplicative group of `F`.
+fn multiplicative_order<F: Field>() -> BigUint {
+    field_order::<F>() - 1u32
+}
@@ -0,0 +1,415 @@
+//! Hashing arbitrary messages to points on BLS12-381's G1.
+//!
+//! Many protocols (BLS signatures, VRFs, verifiable secret sharing, ...) need to turn a
+//! message into a curve point *whose discrete logarithm nobody knows*. The obvious
+//! approach, `hash(msg) · G` for a generator `G`, does not work: anyone who knows
+//! `hash(msg)` knows the discrete logarithm of the result! Instead, we need to map
+//! the hash output to a point directly.
+//!
+//! This module contains two ways to do so:
+//!
+//! * [`try_and_increment`] is the simplest approach: interpret the hash of the message
+//!   (and a counter) as an x-coordinate, and increment the counter until that
+//!   x-coordinate lies on the curve. It's easy to understand, but its running time
+//!   depends on the message, which can leak information in some applications.
+//! * [`hash_to_g1`] implements the `BLS12381G1_XMD:SHA-256_SSWU_RO_` suite from
+//!   [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html), which is what standards
+//!   such as the IETF BLS signature draft use. It is built from the
+//!   [simplified SWU map](map_to_curve), which maps *every* field element to a point
+//!   without any retries.
+//!
+//! ```rust
+//! use ark_algebra_intro::curves::is_in_correct_subgroup;
+//! use ark_algebra_intro::hash_to_curve::{hash_to_g1, try_and_increment};
+//!
+//! let dst = b"MY-APP-V01-CS01-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+//! let p = hash_to_g1(b"hello", dst);
+//! assert!(is_in_correct_subgroup(&p));
+//! // Hashing is deterministic...
+//! assert_eq!(p, hash_to_g1(b"hello", dst));
+//! // ...but different messages yield different points.
+//! assert_ne!(p, hash_to_g1(b"world", dst));
+//!
+//! let (q, _counter) = try_and_increment(b"hello");
+//! assert!(is_in_correct_subgroup(&q));
+//! ```
+//!
+//! `ark-ec` 0.3, which this crate is built on, does not ship a hash-to-curve
+//! implementation of its own; one was added in `ark-ec` 0.4 as `ark_ec::hashing`.
+//!
+//! The `07_hash_to_curve` example walks through this module:
+//! `cargo run --example 07_hash_to_curve`.
+
+use ark_bls12_381::{Fq, G1Affine};
+use ark_ec::AffineCurve;
+use ark_ff::{field_new, BigInteger, Field, One, PrimeField, SquareRootField, Zero};
+use ark_std::vec::Vec;
+use sha2::{Digest, Sha256};
+
+/// The effective cofactor `h_eff = 1 - x` that [`hash_to_g1`] multiplies by to
+/// clear the cofactor, as specified in RFC 9380, Section 8.8.1.
+///
+/// This is not the cofactor of G1 itself, but multiplying by it also maps every
+/// point on the curve into the prime-order subgroup, and is much cheaper.
+pub const H_EFF: u64 = 0xd201000000010001;
+
+/// Hashes `msg` to a point in G1 by the *try-and-increment* method, and returns the
+/// point together with the number of attempts that failed.
+///
+/// For `counter = 0, 1, 2, ...`, this computes `x = SHA-256(counter || msg)` (extended
+/// to 64 bytes and reduced modulo `q`), and stops at the first `x` for which
+/// `x^3 + 4` is a square, i.e., for which there is a point `(x, y)` on the curve. That
+/// happens for about half of all `x`s, so on average one attempt fails. Finally, it
+/// clears the cofactor to land in G1.
+///
+/// ```rust
+/// use ark_algebra_intro::hash_to_curve::try_and_increment;
+///
+/// let mut failures = 0;
+/// for i in 0u32..100 {
+///     let (_, counter) = try_and_increment(&i.to_le_bytes());
+///     failures += counter;
+/// }
+/// // Expect about one failure per message:
+/// assert!(failures > 50 && failures < 200);
+/// ```
+pub fn try_and_increment(msg: &[u8]) -> (G1Affine, u32) {
+    let mut counter = 0u32;
+    loop {
+        let mut bytes = Vec::with_capacity(64);
+        for block in 0u8..2 {
+            let mut hasher = Sha256::new();
+            hasher.update(counter.to_be_bytes());
+            hasher.update([block]);
+            hasher.update(msg);
+            bytes.extend_from_slice(&hasher.finalize());
+        }
+        let x = Fq::from_be_bytes_mod_order(&bytes);
+        if let Some(point) = G1Affine::get_point_from_x(x, false) {
+            return (point.mul_by_cofactor(), counter);
+        }
+        counter += 1;
+    }
+}
+
+/// Expands `msg` into `len` pseudorandom bytes with SHA-256, using the
+/// `expand_message_xmd` construction of RFC 9380, Section 5.3.1.
+///
+/// The domain separation tag `dst` ensures that different applications (or different
+/// uses within one application) get independent outputs even for the same message.
+///
+/// # Panics
+///
+/// Panics if `len` exceeds 255 · 32 bytes, or if `dst` is longer than 255 bytes.
+///
+/// ```rust
+/// use ark_algebra_intro::hash_to_curve::expand_message_xmd;
+///
+/// // Test vectors from RFC 9380, Appendix K.1.
+/// let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+/// let hex = |bytes: Vec<u8>| bytes.iter().map(|b| format!("{:02x}", b)).collect::<String>();
+/// assert_eq!(
+///     hex(expand_message_xmd(b"", dst, 0x20)),
+///     "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+/// );
+/// assert_eq!(
+///     hex(expand_message_xmd(b"abc", dst, 0x20)),
+///     "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+/// );
+/// ```
+pub fn expand_message_xmd(msg: &[u8], dst: &[u8], len: usize) -> Vec<u8> {
+    const B_IN_BYTES: usize = 32;
+    const R_IN_BYTES: usize = 64;
+    let ell = len.div_ceil(B_IN_BYTES);
+    assert!(ell <= 255, "cannot expand to {} bytes", len);
+    assert!(dst.len() <= 255, "domain separation tag is too long");
+    let dst_prime = [dst, &[dst.len() as u8]].concat();
+
+    let b_0 = Sha256::new()
+        .chain_update([0u8; R_IN_BYTES])
+        .chain_update(msg)
+        .chain_update((len as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(&dst_prime)
+        .finalize();
+    let mut b_i = Sha256::new()
+        .chain_update(b_0)
+        .chain_update([1u8])
+        .chain_update(&dst_prime)
+        .finalize();
+
+    let mut output = b_i.to_vec();
+    for i in 2..=ell {
+        let xored: Vec<u8> = b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect();
+        b_i = Sha256::new()
+            .chain_update(xored)
+            .chain_update([i as u8])
+            .chain_update(&dst_prime)
+            .finalize();
+        output.extend_from_slice(&b_i);
+    }
+    output.truncate(len);
+    output
+}
+
+/// Hashes `msg` to `count` elements of `Fq`, as specified in RFC 9380, Section 5.2.
+///
+/// Each element is obtained by reducing 64 bytes of [`expand_message_xmd`] output
+/// modulo `q`. Using 64 bytes, rather than the 48 bytes of an `Fq` element, makes the
+/// bias of the reduction negligible.
+///
+/// ```rust
+/// use ark_algebra_intro::hash_to_curve::hash_to_field;
+///
+/// let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+/// let u = hash_to_field(b"abc", dst, 2);
+/// assert_eq!(u.len(), 2);
+/// assert_ne!(u[0], u[1]);
+/// ```
+pub fn hash_to_field(msg: &[u8], dst: &[u8], count: usize) -> Vec<Fq> {
+    const L: usize = 64;
+    expand_message_xmd(msg, dst, count * L)
+        .chunks(L)
+        .map(Fq::from_be_bytes_mod_order)
+        .collect()
+}
+
+/// Maps a field element to a point on the BLS12-381 curve (*not* necessarily in G1),
+/// using the simplified Shallue–van de Woestijne–Ulas (SWU) map of RFC 9380,
+/// Section 6.6.2.
+///
+/// The simplified SWU map only works for curves `y^2 = x^3 + Ax + B` with `A ≠ 0`, which
+/// rules out BLS12-381 (where `A = 0`). So this maps `u` to a curve `E'` that *does*
+/// have `A ≠ 0`, and then moves the result to BLS12-381 with an *isogeny*, a
+/// rational map between curves that preserves the group law:
+///
+/// 1. For a fixed non-square `Z`, at least one of `x_1 = -B/A · (1 + 1/(Z^2 u^4 + Z u^2))`
+///    and `x_2 = Z u^2 x_1` is the x-coordinate of a point on `E'`: the right-hand side of
+///    the curve equation at `x_2` equals `Z^3 u^6` times that at `x_1`, and `Z^3 u^6` is a
+///    non-square.
+/// 2. The sign of `y` is chosen to match the *sign* (parity) of `u`.
+/// 3. The 11-isogeny from `E'` to BLS12-381 is applied.
+///
+/// ```rust
+/// use ark_algebra_intro::curves::{is_in_correct_subgroup, is_on_curve};
+/// use ark_algebra_intro::hash_to_curve::map_to_curve;
+/// use ark_bls12_381::Fq;
+/// use ark_ec::AffineCurve;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// for _ in 0..10 {
+///     // Every field element maps to a point on the curve...
+///     let p = map_to_curve(Fq::rand(&mut rng));
+///     assert!(is_on_curve(&p));
+///     // ...but not into G1, so the cofactor still needs to be cleared.
+///     assert!(!is_in_correct_subgroup(&p));
+///     assert!(is_in_correct_subgroup(&p.mul_by_cofactor()));
+/// }
+/// ```
+pub fn map_to_curve(u: Fq) -> G1Affine {
+    // Step 1: map `u` to `E'` with the simplified SWU map.
+    let z_u2 = ISO_Z * u.square();
+    let denominator = z_u2.square() + z_u2;
+    let x1 = if denominator.is_zero() {
+        // The exceptional case of RFC 9380: `x1 = B / (Z · A)`
+        ISO_B * (ISO_Z * ISO_A).inverse().unwrap()
+    } else {
+        -ISO_B * ISO_A.inverse().unwrap() * (Fq::one() + denominator.inverse().unwrap())
+    };
+    let (x, y) = match iso_curve_rhs(x1).sqrt() {
+        Some(y) => (x1, y),
+        None => {
+            let x2 = z_u2 * x1;
+            let y = iso_curve_rhs(x2)
+                .sqrt()
+                .expect("either g(x1) or g(x2) is a square");
+            (x2, y)
+        }
+    };
+
+    // Step 2: fix the sign of `y`.
+    let y = if sgn0(&u) == sgn0(&y) { y } else { -y };
+
+    // Step 3: apply the isogeny from `E'` to BLS12-381.
+    let x_den = evaluate(&X_DEN, x);
+    let y_den = evaluate(&Y_DEN, x);
+    if x_den.is_zero() || y_den.is_zero() {
+        // The isogeny maps exactly the points in its kernel to the point at infinity.
+        return G1Affine::zero();
+    }
+    let new_x = evaluate(&X_NUM, x) * x_den.inverse().unwrap();
+    let new_y = y * evaluate(&Y_NUM, x) * y_den.inverse().unwrap();
+    G1Affine::new(new_x, new_y, false)
+}
+
+/// Hashes `msg` to a point in G1, following the `BLS12381G1_XMD:SHA-256_SSWU_RO_`
+/// suite of RFC 9380.
+///
+/// This hashes `msg` to two field elements `u_0` and `u_1` with [`hash_to_field`],
+/// maps each of them to the curve with [`map_to_curve`], adds the results, and
+/// clears the cofactor by multiplying the sum by [`H_EFF`]. (Adding two mapped points
+/// is what makes the output indistinguishable from a random oracle; the output of a
+/// single [`map_to_curve`] is noticeably non-uniform.)
+///
+/// Applications should choose a unique domain separation tag `dst`; see
+/// RFC 9380, Section 3.1.
+///
+/// ```rust
+/// use ark_algebra_intro::hash_to_curve::hash_to_g1;
+/// use ark_algebra_intro::bigint::repr_to_hex;
+/// use ark_ff::PrimeField;
+///
+/// // Test vectors from RFC 9380, Appendix J.9.1.
+/// let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+/// let vectors: [(&[u8], &str, &str); 3] = [
+///     (
+///         b"",
+///         "0x052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1",
+///         "0x08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265",
+///     ),
+///     (
+///         b"abc",
+///         "0x03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903",
+///         "0x0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d",
+///     ),
+///     (
+///         b"abcdef0123456789",
+///         "0x11e0b079dea29a68f0383ee94fed1b940995272407e3bb916bbf268c263ddd57a6a27200a784cbc248e84f357ce82d98",
+///         "0x03a87ae2caf14e8ee52e51fa2ed8eefe80f02457004ba4d486d6aa1f517c0889501dc7413753f9599b099ebcbbd2d709",
+///     ),
+/// ];
+/// for (msg, x, y) in vectors.iter() {
+///     let p = hash_to_g1(msg, dst);
+///     assert_eq!(&repr_to_hex(&p.x.into_repr()), x);
+///     assert_eq!(&repr_to_hex(&p.y.into_repr()), y);
+/// }
+/// ```
+pub fn hash_to_g1(msg: &[u8], dst: &[u8]) -> G1Affine {
+    let u = hash_to_field(msg, dst, 2);
+    let q0 = map_to_curve(u[0]);
+    let q1 = map_to_curve(u[1]);
+    (q0 + q1).mul(H_EFF).into()
+}
+
+/// The "sign" of a field element, as defined in RFC 9380, Section 4.1: the parity
+/// of its canonical integer representative.
+fn sgn0(a: &Fq) -> bool {
+    a.into_repr().is_odd()
+}
+
+/// Evaluates the right-hand side `x^3 + A'x + B'` of the equation of `E'` at `x`.
+fn iso_curve_rhs(x: Fq) -> Fq {
+    (x.square() + ISO_A) * x + ISO_B
+}
+
+/// Evaluates the polynomial with the given coefficients (constant term first) at `x`,
+/// using Horner's rule.
+fn evaluate(coefficients: &[Fq], x: Fq) -> Fq {
+    coefficients
+        .iter()
+        .rev()
+        .fold(Fq::zero(), |acc, coefficient| acc * x + coefficient)
+}
+
+// The constants below are taken from RFC 9380, Sections 8.8.1 and E.2.
+
+/// The coefficient `A'` of the curve `E'` that is 11-isogenous to BLS12-381.
+const ISO_A: Fq = field_new!(Fq, "12190336318893619529228877361869031420615612348429846051986726275283378313155663745811710833465465981901188123677");
+
+/// The coefficient `B'` of the curve `E'` that is 11-isogenous to BLS12-381.
+const ISO_B: Fq = field_new!(Fq, "2906670324641927570491258158026293881577086121416628140204402091718288198173574630967936031029026176254968826637280");
+
+/// The non-square `Z` used by the simplified SWU map.
+const ISO_Z: Fq = field_new!(Fq, "11");
+
+/// Coefficients of the numerator of the x-coordinate map, constant term first.
+const X_NUM: [Fq; 12] = [
+    field_new!(Fq, "2712959285290305970661081772124144179193819192423276218370281158706191519995889425075952244140278856085036081760695"),
+    field_new!(Fq, "3564859427549639835253027846704205725951033235539816243131874237388832081954622352624080767121604606753339903542203"),
+    field_new!(Fq, "2051387046688339481714726479723076305756384619135044672831882917686431912682625619320120082313093891743187631791280"),
+    field_new!(Fq, "3612713941521031012780325893181011392520079402153354595775735142359240110423346445050803899623018402874731133626465"),
+    field_new!(Fq, "2247053637822768981792833880270996398470828564809439728372634811976089874056583714987807553397615562273407692740057"),
+    field_new!(Fq, "3415427104483187489859740871640064348492611444552862448295571438270821994900526625562705192993481400731539293415811"),
+    field_new!(Fq, "2067521456483432583860405634125513059912765526223015704616050604591207046392807563217109432457129564962571408764292"),
+    field_new!(Fq, "3650721292069012982822225637849018828271936405382082649291891245623305084633066170122780668657208923883092359301262"),
+    field_new!(Fq, "1239271775787030039269460763652455868148971086016832054354147730155061349388626624328773377658494412538595239256855"),
+    field_new!(Fq, "3479374185711034293956731583912244564891370843071137483962415222733470401948838363051960066766720884717833231600798"),
+    field_new!(Fq, "2492756312273161536685660027440158956721981129429869601638362407515627529461742974364729223659746272460004902959995"),
+    field_new!(Fq, "1058488477413994682556770863004536636444795456512795473806825292198091015005841418695586811009326456605062948114985"),
+];
+
+/// Coefficients of the denominator of the x-coordinate map, constant term first.
+const X_DEN: [Fq; 11] = [
+    field_new!(Fq, "1353092447850172218905095041059784486169131709710991428415161466575141675351394082965234118340787683181925558786844"),
+    field_new!(Fq, "2822220997908397120956501031591772354860004534930174057793539372552395729721474912921980407622851861692773516917759"),
+    field_new!(Fq, "1717937747208385987946072944131378949849282930538642983149296304709633281382731764122371874602115081850953846504985"),
+    field_new!(Fq, "501624051089734157816582944025690868317536915684467868346388760435016044027032505306995281054569109955275640941784"),
+    field_new!(Fq, "3025903087998593826923738290305187197829899948335370692927241015584233559365859980023579293766193297662657497834014"),
+    field_new!(Fq, "2224140216975189437834161136818943039444741035168992629437640302964164227138031844090123490881551522278632040105125"),
+    field_new!(Fq, "1146414465848284837484508420047674663876992808692209238763293935905506532411661921697047880549716175045414621825594"),
+    field_new!(Fq, "3179090966864399634396993677377903383656908036827452986467581478509513058347781039562481806409014718357094150199902"),
+    field_new!(Fq, "1549317016540628014674302140786462938410429359529923207442151939696344988707002602944342203885692366490121021806145"),
+    field_new!(Fq, "1442797143427491432630626390066422021593505165588630398337491100088557278058060064930663878153124164818522816175370"),
+    field_new!(Fq, "1"),
+];
+
+/// Coefficients of the numerator of the y-coordinate map, constant term first.
+const Y_NUM: [Fq; 16] = [
+    field_new!(Fq, "1393399195776646641963150658816615410692049723305861307490980409834842911816308830479576739332720113414154429643571"),
+    field_new!(Fq, "2968610969752762946134106091152102846225411740689724909058016729455736597929366401532929068084731548131227395540630"),
+    field_new!(Fq, "122933100683284845219599644396874530871261396084070222155796123161881094323788483360414289333111221370374027338230"),
+    field_new!(Fq, "303251954782077855462083823228569901064301365507057490567314302006681283228886645653148231378803311079384246777035"),
+    field_new!(Fq, "1353972356724735644398279028378555627591260676383150667237975415318226973994509601413730187583692624416197017403099"),
+    field_new!(Fq, "3443977503653895028417260979421240655844034880950251104724609885224259484262346958661845148165419691583810082940400"),
+    field_new!(Fq, "718493410301850496156792713845282235942975872282052335612908458061560958159410402177452633054233549648465863759602"),
+    field_new!(Fq, "1466864076415884313141727877156167508644960317046160398342634861648153052436926062434809922037623519108138661903145"),
+    field_new!(Fq, "1536886493137106337339531461344158973554574987550750910027365237255347020572858445054025958480906372033954157667719"),
+    field_new!(Fq, "2171468288973248519912068884667133903101171670397991979582205855298465414047741472281361964966463442016062407908400"),
+    field_new!(Fq, "3915937073730221072189646057898966011292434045388986394373682715266664498392389619761133407846638689998746172899634"),
+    field_new!(Fq, "3802409194827407598156407709510350851173404795262202653149767739163117554648574333789388883640862266596657730112910"),
+    field_new!(Fq, "1707589313757812493102695021134258021969283151093981498394095062397393499601961942449581422761005023512037430861560"),
+    field_new!(Fq, "349697005987545415860583335313370109325490073856352967581197273584891698473628451945217286148025358795756956811571"),
+    field_new!(Fq, "885704436476567581377743161796735879083481447641210566405057346859953524538988296201011389016649354976986251207243"),
+    field_new!(Fq, "3370924952219000111210625390420697640496067348723987858345031683392215988129398381698161406651860675722373763741188"),
+];
+
+/// Coefficients of the denominator of the y-coordinate map, constant term first.
+const Y_DEN: [Fq; 16] = [
+    field_new!(Fq, "3396434800020507717552209507749485772788165484415495716688989613875369612529138640646200921379825018840894888371137"),
+    field_new!(Fq, "3907278185868397906991868466757978732688957419873771881240086730384895060595583602347317992689443299391009456758845"),
+    field_new!(Fq, "854914566454823955479427412036002165304466268547334760894270240966182605542146252771872707010378658178126128834546"),
+    field_new!(Fq, "3496628876382137961119423566187258795236027183112131017519536056628828830323846696121917502443333849318934945158166"),
+    field_new!(Fq, "1828256966233331991927609917644344011503610008134915752990581590799656305331275863706710232159635159092657073225757"),
+    field_new!(Fq, "1362317127649143894542621413133849052553333099883364300946623208643344298804722863920546222860227051989127113848748"),
+    field_new!(Fq, "3443845896188810583748698342858554856823966611538932245284665132724280883115455093457486044009395063504744802318172"),
+    field_new!(Fq, "3484671274283470572728732863557945897902920439975203610275006103818288159899345245633896492713412187296754791689945"),
+    field_new!(Fq, "3755735109429418587065437067067640634211015783636675372165599470771975919172394156249639331555277748466603540045130"),
+    field_new!(Fq, "3459661102222301807083870307127272890283709299202626530836335779816726101522661683404130556379097384249447658110805"),
+    field_new!(Fq, "742483168411032072323733249644347333168432665415341249073150659015707795549260947228694495111018381111866512337576"),
+    field_new!(Fq, "1662231279858095762833829698537304807741442669992646287950513237989158777254081548205552083108208170765474149568658"),
+    field_new!(Fq, "1668238650112823419388205992952852912407572045257706138925379268508860023191233729074751042562151098884528280913356"),
+    field_new!(Fq, "369162719928976119195087327055926326601627748362769544198813069133429557026740823593067700396825489145575282378487"),
+    field_new!(Fq, "2164195715141237148945939585099633032390257748382945597506236650132835917087090097395995817229686247227784224263055"),
+    field_new!(Fq, "1"),
+];
+
+/// Derives a generator of any curve by hashing `label` and `index` (and a counter) until
+/// the result is a point, and clearing the cofactor.
+///
+/// Like [`try_and_increment`], this takes a variable amount of time, which doesn't matter
+/// for deriving public parameters.
+pub(crate) fn hash_to_group<G: AffineCurve>(label: &[u8], index: u64) -> G {
+    (0u64..)
+        .find_map(|counter| {
+            let hash = Sha256::new()
+                .chain_update(label)
+                .chain_update(index.to_be_bytes())
+                .chain_update(counter.to_be_bytes())
+                .finalize();
+            G::from_random_bytes(&hash)
+                .map(|p| p.mul_by_cofactor())
+                .filter(|p| !p.is_zero())
+        })
+        .unwrap()
+}
@@ -0,0 +1,11 @@
+//! Hash functions designed for use inside proof systems.
+//!
+//! Hashes like SHA-256 operate on bits, so proving a SHA-256 computation in a SNARK over
+//! a prime field takes tens of thousands of constraints. *Algebraic* hashes instead
+//! operate directly on field elements, using only field additions and multiplications,
+//! and are far cheaper to prove.
+
+pub mod pedersen;
+pub mod poseidon;
+
+pub use pedersen::pedersen_hash;
@@ -0,0 +1,88 @@
+//! The Pedersen hash.
+//!
+//! A [Pedersen commitment](crate::commitments::pedersen) without blinding is already a
+//! collision-resistant hash of a vector of scalars: given generators `G_1, ..., G_n`
+//! whose discrete logarithms relative to each other are unknown, finding two different
+//! vectors `m ≠ m'` with `Σ m_i · G_i = Σ m'_i · G_i` means finding a non-trivial
+//! relation `Σ (m_i - m'_i) · G_i = 0` between the generators, which is as hard as
+//! computing discrete logarithms.
+//!
+//! To hash bytes, this module splits the message into chunks of 31 bytes, each of which
+//! fits in a scalar of any of the curves in this crate, and adds the length of the
+//! message as an extra scalar, since otherwise `m` and `m || 0` would collide:
+//!
+//! ```text
+//! H(m) = |m| · G_0 + Σ chunk_i(m) · G_{i+1}.
+//! ```
+//!
+//! ```rust
+//! use ark_algebra_intro::hashes::pedersen::{pedersen_hash, PedersenParameters};
+//! use ark_bls12_381::G1Affine;
+//!
+//! let params = PedersenParameters::<G1Affine>::new(64, b"example");
+//! let digest = pedersen_hash(&params, b"hello");
+//! assert_eq!(digest, pedersen_hash(&params, b"hello"));
+//! assert_ne!(digest, pedersen_hash(&params, b"world"));
+//! // The length is part of the hash, so trailing zeros matter.
+//! assert_ne!(digest, pedersen_hash(&params, b"hello\0"));
+//! ```
+//!
+//! The hash is *not* a random oracle: it is linear, so
+//! `H(a) + H(b) - H(c)` is the hash of the chunk-wise sum `a + b - c` whenever that has
+//! no carries. It should only be used where collision resistance is all that is needed,
+//! such as in [Merkle trees](crate::merkle). Zcash's Sapling uses a variant over the
+//! Jubjub curve, with 3-bit windows rather than 31-byte chunks, which is cheaper to
+//! compute inside a circuit over BLS12-381's scalar field (Jubjub's base field).
+
+use crate::hash_to_curve::hash_to_group;
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_std::vec::Vec;
+
+/// The number of bytes of the message that go into each scalar.
+pub const CHUNK_SIZE: usize = 31;
+
+/// The parameters of a Pedersen hash: the generators `G_0, ..., G_n`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PedersenParameters<G: AffineCurve> {
+    /// The generator `G_0` for the length, followed by one generator per chunk.
+    pub generators: Vec<G>,
+}
+
+impl<G: AffineCurve> PedersenParameters<G> {
+    /// Derives parameters for messages of up to `max_len` bytes, by hashing `label` and
+    /// an index to the curve for each generator.
+    pub fn new(max_len: usize, label: &[u8]) -> Self {
+        let chunks = max_len.div_ceil(CHUNK_SIZE);
+        Self {
+            generators: (0..=chunks as u64)
+                .map(|i| hash_to_group(label, i))
+                .collect(),
+        }
+    }
+
+    /// Returns the maximum length of messages that can be hashed.
+    pub fn max_len(&self) -> usize {
+        (self.generators.len() - 1) * CHUNK_SIZE
+    }
+}
+
+/// Hashes `msg` to a point.
+///
+/// # Panics
+///
+/// Panics if `msg` is longer than the parameters support.
+pub fn pedersen_hash<G: AffineCurve>(params: &PedersenParameters<G>, msg: &[u8]) -> G {
+    assert!(
+        msg.len() <= params.max_len(),
+        "the parameters only support messages of up to {} bytes",
+        params.max_len()
+    );
+    let scalars: Vec<_> = ark_std::iter::once(G::ScalarField::from(msg.len() as u64))
+        .chain(
+            msg.chunks(CHUNK_SIZE)
+                .map(G::ScalarField::from_le_bytes_mod_order),
+        )
+        .collect();
+    crate::msm::arkworks(&params.generators[..scalars.len()], &scalars).into_affine()
+}
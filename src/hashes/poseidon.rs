@@ -0,0 +1,300 @@
+//! The Poseidon hash function and sponge.
+//!
+//! Poseidon is a sponge construction over a prime field `F`, built from a permutation of
+//! `F^t` (the *width* `t` is the *rate*, the number of elements absorbed or squeezed at a
+//! time, plus the *capacity*, which is never output and provides the security). Each
+//! round of the permutation
+//!
+//! 1. adds round constants to the state,
+//! 2. applies the S-box `x ↦ x^α` to the state, and
+//! 3. multiplies the state by an MDS matrix, which mixes all the elements together.
+//!
+//! The S-box is the only non-linear step, and by far the most expensive one in a circuit,
+//! so Poseidon applies it to the whole state only in the first and last `R_F / 2` *full*
+//! rounds, and to a single element in the `R_P` *partial* rounds in between.
+//!
+//! ```rust
+//! use ark_algebra_intro::hashes::poseidon::{hash, PoseidonParameters};
+//! use ark_bls12_381::Fr;
+//!
+//! let params = PoseidonParameters::<Fr>::recommended();
+//! let digest = hash(&params, &[Fr::from(1u64), Fr::from(2u64)]);
+//! assert_eq!(digest, hash(&params, &[Fr::from(1u64), Fr::from(2u64)]));
+//! assert_ne!(digest, hash(&params, &[Fr::from(2u64), Fr::from(1u64)]));
+//! // The input length is part of the hash, so trailing zeros matter.
+//! assert_ne!(digest, hash(&params, &[Fr::from(1u64), Fr::from(2u64), Fr::from(0u64)]));
+//! ```
+//!
+//! # Parameters
+//!
+//! The security of Poseidon depends on its parameters:
+//!
+//! * `α` must be the smallest integer such that `x ↦ x^α` is a permutation of `F`, i.e.,
+//!   with `gcd(α, p - 1) = 1`. For BLS12-381's and BN254's scalar fields, that is `α = 5`
+//!   (`α = 3` fails since `3` divides `p - 1`).
+//! * The numbers of rounds are chosen to resist statistical and algebraic attacks, with
+//!   a security margin; for `t = 3`, `α = 5` and 128-bit security over a 255-bit field,
+//!   the Poseidon paper recommends `R_F = 8` and `R_P = 57`.
+//! * The round constants should be "nothing-up-my-sleeve" numbers, and the MDS matrix
+//!   must avoid certain invariant subspaces.
+//!
+//! This module derives the round constants by hashing a description of the parameters
+//! with SHA-256, and uses a Cauchy matrix `M_{ij} = 1 / (x_i + y_j)` as the MDS matrix,
+//! but does not run the invariant-subspace checks. The reference implementation
+//! instead generates both with the Grain LFSR and checks the matrix, so the hashes
+//! computed here do *not* match other Poseidon implementations, and should not be used
+//! for anything but learning.
+
+use ark_ff::{PrimeField, Zero};
+use ark_std::vec::Vec;
+use sha2::{Digest, Sha256};
+
+/// The parameters of a Poseidon permutation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoseidonParameters<F: PrimeField> {
+    /// The width `t` of the state.
+    pub width: usize,
+    /// The total number `R_F` of full rounds, half of which come first.
+    pub full_rounds: usize,
+    /// The number `R_P` of partial rounds.
+    pub partial_rounds: usize,
+    /// The exponent `α` of the S-box.
+    pub alpha: u64,
+    /// The round constants, one vector of length `t` per round.
+    pub round_constants: Vec<Vec<F>>,
+    /// The `t × t` MDS matrix.
+    pub mds: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField> PoseidonParameters<F> {
+    /// Derives parameters with the given shape.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width < 2`, if `full_rounds` is odd, or if `x ↦ x^alpha` is not a
+    /// permutation of `F`.
+    pub fn new(width: usize, full_rounds: usize, partial_rounds: usize, alpha: u64) -> Self {
+        assert!(width >= 2, "the width must be at least two");
+        assert!(
+            full_rounds.is_multiple_of(2),
+            "the number of full rounds must be even"
+        );
+        assert!(alpha > 1, "the S-box must be non-linear");
+        assert!(
+            gcd(alpha, p_minus_one_mod::<F>(alpha)) == 1,
+            "x^{} is not a permutation of the field",
+            alpha
+        );
+
+        let description = format!(
+            "poseidon-t{}-rf{}-rp{}-alpha{}",
+            width, full_rounds, partial_rounds, alpha
+        );
+        let mut counter = 0u64;
+        let mut next_constant = || {
+            counter += 1;
+            let hash = Sha256::new()
+                .chain_update(description.as_bytes())
+                .chain_update(counter.to_be_bytes())
+                .finalize();
+            F::from_be_bytes_mod_order(&hash)
+        };
+        let round_constants = (0..full_rounds + partial_rounds)
+            .map(|_| (0..width).map(|_| next_constant()).collect())
+            .collect();
+
+        // A Cauchy matrix with x_i = i and y_j = width + j, so that all x_i + y_j are
+        // distinct and nonzero; every square submatrix of a Cauchy matrix is invertible.
+        let mds = (0..width)
+            .map(|i| {
+                (0..width)
+                    .map(|j| {
+                        F::from((i + width + j) as u64)
+                            .inverse()
+                            .expect("the field is larger than the matrix")
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            width,
+            full_rounds,
+            partial_rounds,
+            alpha,
+            round_constants,
+            mds,
+        }
+    }
+
+    /// Returns parameters with `t = 3` (rate 2, capacity 1), `α = 5`, `R_F = 8` and
+    /// `R_P = 57`, which are the recommended ones for 128-bit security over 255-bit
+    /// fields in which `x^5` is a permutation, such as BLS12-381's scalar field.
+    pub fn recommended() -> Self {
+        Self::new(3, 8, 57, 5)
+    }
+
+    /// Returns the rate, i.e., the number of elements absorbed per permutation.
+    pub fn rate(&self) -> usize {
+        self.width - 1
+    }
+}
+
+/// Applies the Poseidon permutation to `state`.
+///
+/// # Panics
+///
+/// Panics if `state` does not have the width of the parameters.
+pub fn permute<F: PrimeField>(params: &PoseidonParameters<F>, state: &mut [F]) {
+    assert_eq!(state.len(), params.width, "the state has the wrong width");
+    let half = params.full_rounds / 2;
+    for (round, constants) in params.round_constants.iter().enumerate() {
+        for (x, c) in state.iter_mut().zip(constants) {
+            *x += c;
+        }
+        let is_full = round < half || round >= half + params.partial_rounds;
+        if is_full {
+            state.iter_mut().for_each(|x| *x = x.pow([params.alpha]));
+        } else {
+            state[0] = state[0].pow([params.alpha]);
+        }
+        let mixed: Vec<F> = params
+            .mds
+            .iter()
+            .map(|row| row.iter().zip(state.iter()).map(|(m, x)| *m * x).sum())
+            .collect();
+        state.copy_from_slice(&mixed);
+    }
+}
+
+/// A Poseidon sponge, which absorbs and squeezes any number of field elements.
+///
+/// The first element of the state is the capacity; the others are the rate.
+///
+/// ```rust
+/// use ark_algebra_intro::hashes::poseidon::{PoseidonParameters, PoseidonSponge};
+/// use ark_bls12_381::Fr;
+///
+/// let params = PoseidonParameters::<Fr>::recommended();
+/// let mut sponge = PoseidonSponge::new(&params);
+/// sponge.absorb(&[Fr::from(1u64), Fr::from(2u64), Fr::from(3u64)]);
+/// let outputs = sponge.squeeze(4);
+/// assert_eq!(outputs.len(), 4);
+///
+/// // Absorbing in several calls is the same as absorbing all at once.
+/// let mut other = PoseidonSponge::new(&params);
+/// other.absorb(&[Fr::from(1u64)]);
+/// other.absorb(&[Fr::from(2u64), Fr::from(3u64)]);
+/// assert_eq!(other.squeeze(4), outputs);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PoseidonSponge<'a, F: PrimeField> {
+    params: &'a PoseidonParameters<F>,
+    state: Vec<F>,
+    /// The next rate position to absorb into or squeeze from.
+    position: usize,
+    squeezing: bool,
+}
+
+impl<'a, F: PrimeField> PoseidonSponge<'a, F> {
+    /// Creates a sponge with an all-zero state.
+    pub fn new(params: &'a PoseidonParameters<F>) -> Self {
+        Self::with_capacity(params, F::zero())
+    }
+
+    /// Creates a sponge whose capacity element is initialized to `domain`, which
+    /// separates different uses of the same parameters.
+    pub fn with_capacity(params: &'a PoseidonParameters<F>, domain: F) -> Self {
+        let mut state = vec![F::zero(); params.width];
+        state[0] = domain;
+        Self {
+            params,
+            state,
+            position: 0,
+            squeezing: false,
+        }
+    }
+
+    /// Absorbs `inputs`, adding them into the rate part of the state and permuting
+    /// whenever it is full.
+    pub fn absorb(&mut self, inputs: &[F]) {
+        if self.squeezing {
+            // Start absorbing afresh after squeezing.
+            self.squeezing = false;
+            self.position = 0;
+            permute(self.params, &mut self.state);
+        }
+        for input in inputs {
+            if self.position == self.params.rate() {
+                permute(self.params, &mut self.state);
+                self.position = 0;
+            }
+            self.state[1 + self.position] += input;
+            self.position += 1;
+        }
+    }
+
+    /// Squeezes `count` outputs out of the rate part of the state, permuting before each
+    /// block of outputs.
+    pub fn squeeze(&mut self, count: usize) -> Vec<F> {
+        let mut outputs = Vec::with_capacity(count);
+        for _ in 0..count {
+            if !self.squeezing || self.position == self.params.rate() {
+                permute(self.params, &mut self.state);
+                self.squeezing = true;
+                self.position = 0;
+            }
+            outputs.push(self.state[1 + self.position]);
+            self.position += 1;
+        }
+        outputs
+    }
+}
+
+/// Hashes a sequence of field elements to a single field element.
+///
+/// The capacity is initialized to the number of inputs, so that inputs of different
+/// lengths never collide because of padding.
+pub fn hash<F: PrimeField>(params: &PoseidonParameters<F>, inputs: &[F]) -> F {
+    let mut sponge = PoseidonSponge::with_capacity(params, F::from(inputs.len() as u64));
+    sponge.absorb(inputs);
+    sponge.squeeze(1)[0]
+}
+
+/// Hashes a byte string to a field element, by packing it into field elements and
+/// [hashing](hash) those.
+///
+/// Each field element holds as many whole bytes as fit below the modulus (31 bytes for
+/// a 255-bit field), so the packing is injective.
+///
+/// ```rust
+/// use ark_algebra_intro::hashes::poseidon::{hash_to_field, PoseidonParameters};
+/// use ark_bls12_381::Fr;
+///
+/// let params = PoseidonParameters::<Fr>::recommended();
+/// let long = [7u8; 100];
+/// assert_ne!(hash_to_field(&params, &long), hash_to_field(&params, &long[..99]));
+/// assert_ne!(hash_to_field(&params, b""), hash_to_field(&params, b"\0"));
+/// ```
+pub fn hash_to_field<F: PrimeField>(params: &PoseidonParameters<F>, bytes: &[u8]) -> F {
+    let chunk_size = (F::size_in_bits() - 1) / 8;
+    let mut elements = vec![F::from(bytes.len() as u64)];
+    elements.extend(bytes.chunks(chunk_size).map(F::from_le_bytes_mod_order));
+    hash(params, &elements)
+}
+
+/// Returns `(p - 1) mod m` for the characteristic `p` of `F`.
+fn p_minus_one_mod<F: PrimeField>(m: u64) -> u64 {
+    let p_mod_m = F::characteristic().iter().rev().fold(0u128, |r, limb| {
+        ((r << 64) | u128::from(*limb)) % u128::from(m)
+    });
+    ((p_mod_m as u64) + m - 1) % m
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b.is_zero() {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
@@ -0,0 +1,99 @@
+//! Converting to and from the encodings that other implementations use.
+//!
+//! The [canonical encodings](crate::serialize) of arkworks are specific to arkworks: they
+//! are little-endian, and store flags in the last byte. Data from elsewhere (Ethereum
+//! transactions, specifications, other libraries) uses other encodings, which the
+//! modules here convert.
+
+use crate::curves::{point_from_xy, AffineExt};
+use crate::encoding::{encoded_size, from_be_bytes_mod_order, to_bytes_be};
+use ark_ff::PrimeField;
+use ark_std::fmt;
+use ark_std::vec::Vec;
+
+pub mod eth;
+pub mod sage;
+pub mod zcash_format;
+
+/// The error returned when bytes are not a valid encoding of a point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The input does not have the length of an encoding.
+    InvalidLength {
+        /// The length of an encoding.
+        expected: usize,
+        /// The length of the input.
+        found: usize,
+    },
+    /// A coordinate is not smaller than the modulus, or padding or flag bits have the
+    /// wrong value.
+    NonCanonical,
+    /// The coordinates do not satisfy the curve equation.
+    NotOnCurve,
+    /// The point is on the curve, but not in the prime-order subgroup.
+    NotInSubgroup,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidLength { expected, found } => {
+                write!(f, "expected {} bytes, found {}", expected, found)
+            }
+            DecodeError::NonCanonical => write!(f, "the encoding is not canonical"),
+            DecodeError::NotOnCurve => write!(f, "the point is not on the curve"),
+            DecodeError::NotInSubgroup => write!(f, "the point is not in the prime-order subgroup"),
+        }
+    }
+}
+
+impl ark_std::error::Error for DecodeError {}
+
+/// Returns an error unless `bytes` has length `expected`.
+pub(crate) fn check_length(bytes: &[u8], expected: usize) -> Result<(), DecodeError> {
+    if bytes.len() == expected {
+        Ok(())
+    } else {
+        Err(DecodeError::InvalidLength {
+            expected,
+            found: bytes.len(),
+        })
+    }
+}
+
+/// Appends the big-endian encoding of `x`, left-padded with zeros to `width` bytes.
+pub(crate) fn push_field_be<F: PrimeField>(bytes: &mut Vec<u8>, x: &F, width: usize) {
+    let encoding = to_bytes_be(x);
+    bytes.extend(ark_std::iter::repeat_n(0, width - encoding.len()));
+    bytes.extend(encoding);
+}
+
+/// Decodes a left-padded big-endian field element, rejecting nonzero padding and
+/// integers that are not smaller than the modulus.
+pub(crate) fn field_from_be<F: PrimeField>(bytes: &[u8]) -> Result<F, DecodeError> {
+    let (padding, encoding) = bytes.split_at(bytes.len() - encoded_size::<F>());
+    let x = from_be_bytes_mod_order(encoding);
+    if padding.iter().any(|b| *b != 0) || to_bytes_be(&x) != encoding {
+        return Err(DecodeError::NonCanonical);
+    }
+    Ok(x)
+}
+
+/// Builds the point `(x, y)`, checking that it is on the curve and in the prime-order
+/// subgroup.
+pub(crate) fn checked_point<G: AffineExt>(
+    x: G::BaseField,
+    y: G::BaseField,
+) -> Result<G, DecodeError> {
+    let point = point_from_xy::<G>(x, y).map_err(|_| DecodeError::NotOnCurve)?;
+    subgroup_checked(point)
+}
+
+/// Returns `point`, which must be on the curve, if it is in the prime-order subgroup.
+pub(crate) fn subgroup_checked<G: AffineExt>(point: G) -> Result<G, DecodeError> {
+    if point.is_in_correct_subgroup_assuming_on_curve() {
+        Ok(point)
+    } else {
+        Err(DecodeError::NotInSubgroup)
+    }
+}
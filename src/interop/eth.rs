@@ -0,0 +1,252 @@
+//! The point encodings of Ethereum's precompiled contracts.
+//!
+//! Ethereum exposes elliptic curve operations to smart contracts as *precompiles*, which
+//! take and return points as fixed-width, big-endian, uncompressed byte strings:
+//!
+//! * [EIP-2537](https://eips.ethereum.org/EIPS/eip-2537) (BLS12-381) encodes a base field
+//!   element as 64 bytes (the 48 bytes of the integer, left-padded with 16 zero bytes), a
+//!   G1 point as `x ‖ y` (128 bytes), and a G2 point as `x.c0 ‖ x.c1 ‖ y.c0 ‖ y.c1`
+//!   (256 bytes), where `x = x.c0 + x.c1 · u`.
+//! * [EIP-196](https://eips.ethereum.org/EIPS/eip-196) and
+//!   [EIP-197](https://eips.ethereum.org/EIPS/eip-197) (BN254) use 32 bytes per base field
+//!   element, so 64 bytes for G1 and 128 bytes for G2, but put the *imaginary* part first:
+//!   `x.c1 ‖ x.c0 ‖ y.c1 ‖ y.c0`. These are in the `bn254` module, with the `bn254`
+//!   feature.
+//!
+//! In both, the point at infinity is encoded as all zeros, which is unambiguous since
+//! `(0, 0)` is not on any of these curves.
+//!
+//! ```rust
+//! use ark_algebra_intro::interop::eth::{decode_g1, encode_g1};
+//! use ark_bls12_381::G1Affine;
+//! use ark_ec::AffineCurve;
+//!
+//! let hex = |bytes: &[u8]| bytes.iter().map(|b| format!("{:02x}", b)).collect::<String>();
+//!
+//! // The generator of G1, as in the test vectors of EIP-2537.
+//! let g = G1Affine::prime_subgroup_generator();
+//! let encoding = encode_g1(&g);
+//! assert_eq!(
+//!     hex(&encoding),
+//!     "00000000000000000000000000000000\
+//!      17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb\
+//!      00000000000000000000000000000000\
+//!      08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1",
+//! );
+//! assert_eq!(decode_g1(&encoding), Ok(g));
+//! ```
+//!
+//! Decoding rejects inputs that the precompiles reject: wrong lengths, nonzero padding,
+//! coordinates that are not smaller than the modulus, and points that are not on the
+//! curve. It also rejects points outside the prime-order subgroup, which the EIP-2537
+//! addition precompiles accept but the multiplication and pairing precompiles don't.
+//!
+//! ```rust
+//! use ark_algebra_intro::interop::eth::{decode_g1, encode_g1};
+//! use ark_algebra_intro::interop::DecodeError;
+//! use ark_bls12_381::G1Affine;
+//! use ark_ec::AffineCurve;
+//! use ark_std::Zero;
+//!
+//! assert_eq!(decode_g1(&[0u8; 128]), Ok(G1Affine::zero()));
+//! assert_eq!(
+//!     decode_g1(&[0u8; 96]),
+//!     Err(DecodeError::InvalidLength { expected: 128, found: 96 }),
+//! );
+//!
+//! let mut encoding = encode_g1(&G1Affine::prime_subgroup_generator());
+//! encoding[0] = 1;
+//! assert_eq!(decode_g1(&encoding), Err(DecodeError::NonCanonical));
+//! encoding[0] = 0;
+//! encoding[127] ^= 1;
+//! assert_eq!(decode_g1(&encoding), Err(DecodeError::NotOnCurve));
+//! ```
+
+use super::{check_length, checked_point, field_from_be, push_field_be, DecodeError};
+use crate::curves::AffineExt;
+use ark_bls12_381::{Fq, Fq2, G1Affine, G2Affine};
+use ark_std::vec::Vec;
+use ark_std::Zero;
+
+/// The width of an encoded BLS12-381 base field element.
+const FQ_WIDTH: usize = 64;
+
+/// Returns the 128-byte EIP-2537 encoding of a G1 point.
+pub fn encode_g1(point: &G1Affine) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(2 * FQ_WIDTH);
+    match point.xy() {
+        Some((x, y)) => {
+            push_field_be(&mut bytes, &x, FQ_WIDTH);
+            push_field_be(&mut bytes, &y, FQ_WIDTH);
+        }
+        None => bytes.resize(2 * FQ_WIDTH, 0),
+    }
+    bytes
+}
+
+/// Decodes a 128-byte EIP-2537 encoding of a G1 point.
+pub fn decode_g1(bytes: &[u8]) -> Result<G1Affine, DecodeError> {
+    check_length(bytes, 2 * FQ_WIDTH)?;
+    if bytes.iter().all(|b| *b == 0) {
+        return Ok(G1Affine::zero());
+    }
+    let x: Fq = field_from_be(&bytes[..FQ_WIDTH])?;
+    let y: Fq = field_from_be(&bytes[FQ_WIDTH..])?;
+    checked_point(x, y)
+}
+
+/// Returns the 256-byte EIP-2537 encoding of a G2 point.
+///
+/// ```rust
+/// use ark_algebra_intro::interop::eth::{decode_g2, encode_g2};
+/// use ark_bls12_381::G2Affine;
+/// use ark_ec::AffineCurve;
+///
+/// let hex = |bytes: &[u8]| bytes.iter().map(|b| format!("{:02x}", b)).collect::<String>();
+///
+/// let h = G2Affine::prime_subgroup_generator();
+/// let encoding = encode_g2(&h);
+/// // The real part of x comes first.
+/// assert_eq!(
+///     hex(&encoding[16..64]),
+///     "024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8",
+/// );
+/// assert_eq!(
+///     hex(&encoding[80..128]),
+///     "13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e",
+/// );
+/// assert_eq!(decode_g2(&encoding), Ok(h));
+/// ```
+pub fn encode_g2(point: &G2Affine) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(4 * FQ_WIDTH);
+    match point.xy() {
+        Some((x, y)) => {
+            for c in [x.c0, x.c1, y.c0, y.c1].iter() {
+                push_field_be(&mut bytes, c, FQ_WIDTH);
+            }
+        }
+        None => bytes.resize(4 * FQ_WIDTH, 0),
+    }
+    bytes
+}
+
+/// Decodes a 256-byte EIP-2537 encoding of a G2 point.
+pub fn decode_g2(bytes: &[u8]) -> Result<G2Affine, DecodeError> {
+    check_length(bytes, 4 * FQ_WIDTH)?;
+    if bytes.iter().all(|b| *b == 0) {
+        return Ok(G2Affine::zero());
+    }
+    let c = |i: usize| field_from_be::<Fq>(&bytes[i * FQ_WIDTH..(i + 1) * FQ_WIDTH]);
+    let x = Fq2::new(c(0)?, c(1)?);
+    let y = Fq2::new(c(2)?, c(3)?);
+    checked_point(x, y)
+}
+
+/// The point encodings of the BN254 precompiles of EIP-196 and EIP-197.
+///
+/// ```rust
+/// use ark_algebra_intro::interop::eth::bn254::{decode_g1, encode_g1};
+/// use ark_bn254::G1Affine;
+/// use ark_ec::AffineCurve;
+///
+/// let hex = |bytes: &[u8]| bytes.iter().map(|b| format!("{:02x}", b)).collect::<String>();
+///
+/// // The generator is (1, 2).
+/// let g = G1Affine::prime_subgroup_generator();
+/// assert_eq!(hex(&encode_g1(&g)), format!("{:064x}{:064x}", 1, 2));
+///
+/// // The `ecAdd` precompile maps (g, g) to 2g.
+/// let double = encode_g1(&(g + g));
+/// assert_eq!(
+///     hex(&double),
+///     "030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3\
+///      15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4",
+/// );
+/// assert_eq!(decode_g1(&double), Ok(g + g));
+/// ```
+#[cfg(feature = "bn254")]
+pub mod bn254 {
+    use super::super::{check_length, checked_point, field_from_be, push_field_be, DecodeError};
+    use crate::curves::AffineExt;
+    use ark_bn254::{Fq, Fq2, G1Affine, G2Affine};
+    use ark_std::vec::Vec;
+    use ark_std::Zero;
+
+    /// The width of an encoded BN254 base field element.
+    const FQ_WIDTH: usize = 32;
+
+    /// Returns the 64-byte EIP-196 encoding of a G1 point.
+    pub fn encode_g1(point: &G1Affine) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(2 * FQ_WIDTH);
+        match point.xy() {
+            Some((x, y)) => {
+                push_field_be(&mut bytes, &x, FQ_WIDTH);
+                push_field_be(&mut bytes, &y, FQ_WIDTH);
+            }
+            None => bytes.resize(2 * FQ_WIDTH, 0),
+        }
+        bytes
+    }
+
+    /// Decodes a 64-byte EIP-196 encoding of a G1 point.
+    pub fn decode_g1(bytes: &[u8]) -> Result<G1Affine, DecodeError> {
+        check_length(bytes, 2 * FQ_WIDTH)?;
+        if bytes.iter().all(|b| *b == 0) {
+            return Ok(G1Affine::zero());
+        }
+        let x: Fq = field_from_be(&bytes[..FQ_WIDTH])?;
+        let y: Fq = field_from_be(&bytes[FQ_WIDTH..])?;
+        checked_point(x, y)
+    }
+
+    /// Returns the 128-byte EIP-197 encoding of a G2 point, with the imaginary parts first.
+    ///
+    /// ```rust
+    /// use ark_algebra_intro::fields::parse_decimal;
+    /// use ark_algebra_intro::interop::eth::bn254::{decode_g2, encode_g2};
+    /// use ark_bn254::{Fq, G2Affine};
+    /// use ark_ec::AffineCurve;
+    /// use ark_ff::PrimeField;
+    ///
+    /// // The generator of G2 used by EIP-197, as (imaginary, real) decimal pairs.
+    /// let x_im = "11559732032986387107991004021392285783925812861821192530917403151452391805634";
+    /// let x_re = "10857046999023057135944570762232829481370756359578518086990519993285655852781";
+    /// let y_im = "4082367875863433681332203403145435568316851327593401208105741076214120093531";
+    /// let y_re = "8495653923123431417604973247489272438418190587263600148770280649306958101930";
+    ///
+    /// let h = G2Affine::prime_subgroup_generator();
+    /// let encoding = encode_g2(&h);
+    /// let coordinate = |i: usize| Fq::from_be_bytes_mod_order(&encoding[32 * i..32 * (i + 1)]);
+    /// for (i, decimal) in [x_im, x_re, y_im, y_re].iter().enumerate() {
+    ///     assert_eq!(coordinate(i), parse_decimal::<Fq>(decimal).unwrap());
+    /// }
+    /// assert_eq!(decode_g2(&encoding), Ok(h));
+    /// ```
+    pub fn encode_g2(point: &G2Affine) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(4 * FQ_WIDTH);
+        match point.xy() {
+            Some((x, y)) => {
+                for c in [x.c1, x.c0, y.c1, y.c0].iter() {
+                    push_field_be(&mut bytes, c, FQ_WIDTH);
+                }
+            }
+            None => bytes.resize(4 * FQ_WIDTH, 0),
+        }
+        bytes
+    }
+
+    /// Decodes a 128-byte EIP-197 encoding of a G2 point.
+    ///
+    /// Unlike G1, whose cofactor is 1, G2 contains points outside the prime-order
+    /// subgroup, which are rejected.
+    pub fn decode_g2(bytes: &[u8]) -> Result<G2Affine, DecodeError> {
+        check_length(bytes, 4 * FQ_WIDTH)?;
+        if bytes.iter().all(|b| *b == 0) {
+            return Ok(G2Affine::zero());
+        }
+        let c = |i: usize| field_from_be::<Fq>(&bytes[i * FQ_WIDTH..(i + 1) * FQ_WIDTH]);
+        let x = Fq2::new(c(1)?, c(0)?);
+        let y = Fq2::new(c(3)?, c(2)?);
+        checked_point(x, y)
+    }
+}
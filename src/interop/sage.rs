@@ -0,0 +1,186 @@
+//! Exporting values to [SageMath](https://www.sagemath.org), and reading points back.
+//!
+//! Sage can compute with finite fields, elliptic curves and polynomials directly, which
+//! makes it a convenient second opinion for the computations in this crate. The functions
+//! here print Sage expressions that rebuild a value, to paste into a Sage session:
+//!
+//! ```rust
+//! use ark_algebra_intro::interop::sage::{field_to_sage, point_to_sage, polynomial_to_sage};
+//! use ark_algebra_intro::poly::{DensePolynomial, UVPolynomial};
+//! use ark_algebra_intro::toy_fields::F7;
+//! use ark_bls12_381::G1Affine;
+//! use ark_ec::AffineCurve;
+//!
+//! assert_eq!(field_to_sage(&F7::from(3u64)), "GF(7)(3)");
+//!
+//! let coeffs = vec![F7::from(1u64), F7::from(0u64), F7::from(5u64)];
+//! let p = DensePolynomial::from_coefficients_vec(coeffs);
+//! assert_eq!(polynomial_to_sage(&p), "PolynomialRing(GF(7), 'x')([1, 0, 5])");
+//!
+//! let g = point_to_sage(&G1Affine::prime_subgroup_generator());
+//! assert!(g.starts_with(
+//!     "EllipticCurve(GF(\
+//!      4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559787\
+//!      ), [0, 4])(\
+//!      3685416753713387016781088315183077757961620795782546409894578378688607592378376318836054947676345821548104185464507, "
+//! ));
+//! ```
+//!
+//! For instance, the following Sage session checks that the generator of BLS12-381's G1
+//! has order `r`:
+//!
+//! ```text
+//! sage: g = EllipticCurve(GF(4002...9787), [0, 4])(3685...4507, 1339...1569)
+//! sage: g.order() == 0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001
+//! True
+//! ```
+//!
+//! # Reading points printed by Sage
+//!
+//! Sage prints points in projective coordinates `(x : y : z)`, normalized so that `z = 1`,
+//! except for the point at infinity `(0 : 1 : 0)`. [`parse_point`] reads that format:
+//!
+//! ```rust
+//! use ark_algebra_intro::display::FieldFmt;
+//! use ark_algebra_intro::interop::sage::{parse_point, SageParseError};
+//! use ark_bls12_381::G1Affine;
+//! use ark_ec::AffineCurve;
+//! use ark_std::Zero;
+//!
+//! let g = G1Affine::prime_subgroup_generator();
+//! let printed = format!("({} : {} : 1)", FieldFmt(g.x), FieldFmt(g.y));
+//! assert_eq!(parse_point::<ark_bls12_381::g1::Parameters>(&printed), Ok(g));
+//! assert_eq!(parse_point::<ark_bls12_381::g1::Parameters>("(0 : 1 : 0)"), Ok(G1Affine::zero()));
+//! assert_eq!(
+//!     parse_point::<ark_bls12_381::g1::Parameters>("(1 : 1 : 1)"),
+//!     Err(SageParseError::NotOnCurve),
+//! );
+//! ```
+
+use crate::curves::point_from_xy;
+use crate::display::FieldFmt;
+use crate::fields::{parse_decimal, ParseError};
+use crate::poly::DensePolynomial;
+use ark_ec::models::SWModelParameters;
+use ark_ec::short_weierstrass_jacobian::GroupAffine;
+use ark_ff::{FpParameters, One, PrimeField, Zero};
+use ark_std::fmt;
+use ark_std::string::{String, ToString};
+use ark_std::vec::Vec;
+use num_bigint::BigUint;
+
+/// Returns the Sage expression `GF(p)` for the field `F`.
+pub fn field_of<F: PrimeField>() -> String {
+    let modulus: BigUint = F::Params::MODULUS.into();
+    format!("GF({})", modulus)
+}
+
+/// Returns the Sage expression `GF(p)(a)` for `a`.
+pub fn field_to_sage<F: PrimeField>(a: &F) -> String {
+    format!("{}({})", field_of::<F>(), FieldFmt(*a))
+}
+
+/// Returns the Sage expression `EllipticCurve(GF(p), [a, b])` for the curve
+/// `y² = x³ + a · x + b` with parameters `P`.
+pub fn curve_to_sage<P: SWModelParameters>() -> String
+where
+    P::BaseField: PrimeField,
+{
+    format!(
+        "EllipticCurve({}, [{}, {}])",
+        field_of::<P::BaseField>(),
+        FieldFmt(P::COEFF_A),
+        FieldFmt(P::COEFF_B)
+    )
+}
+
+/// Returns a Sage expression for `point`: `E(x, y)`, or `E(0)` for the point at infinity,
+/// where `E` is the [curve](curve_to_sage).
+pub fn point_to_sage<P: SWModelParameters>(point: &GroupAffine<P>) -> String
+where
+    P::BaseField: PrimeField,
+{
+    if point.infinity {
+        format!("{}(0)", curve_to_sage::<P>())
+    } else {
+        format!(
+            "{}({}, {})",
+            curve_to_sage::<P>(),
+            FieldFmt(point.x),
+            FieldFmt(point.y)
+        )
+    }
+}
+
+/// Returns the Sage expression `PolynomialRing(GF(p), 'x')([c_0, c_1, ...])` for `p`,
+/// with coefficients from the constant term up, like Sage's list constructor expects.
+pub fn polynomial_to_sage<F: PrimeField>(p: &DensePolynomial<F>) -> String {
+    let coeffs: Vec<_> = p.coeffs.iter().map(|c| FieldFmt(*c).to_string()).collect();
+    format!(
+        "PolynomialRing({}, 'x')([{}])",
+        field_of::<F>(),
+        coeffs.join(", ")
+    )
+}
+
+/// The error returned when parsing a point printed by Sage.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SageParseError {
+    /// The string is not of the form `(x : y : z)`, with `z` equal to `1` (or `0` for the
+    /// point at infinity).
+    Malformed,
+    /// A coordinate is not a valid field element.
+    InvalidCoordinate(ParseError),
+    /// The coordinates do not satisfy the curve equation.
+    NotOnCurve,
+}
+
+impl fmt::Display for SageParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SageParseError::Malformed => write!(f, "expected a point of the form (x : y : 1)"),
+            SageParseError::InvalidCoordinate(e) => write!(f, "invalid coordinate: {}", e),
+            SageParseError::NotOnCurve => write!(f, "the point is not on the curve"),
+        }
+    }
+}
+
+impl ark_std::error::Error for SageParseError {}
+
+/// Parses a point in Sage's default format, `(x : y : 1)` or `(0 : 1 : 0)`, with decimal
+/// coordinates.
+///
+/// This checks that the point is on the curve, but not that it is in the prime-order
+/// subgroup (which Sage doesn't know about).
+pub fn parse_point<P: SWModelParameters>(s: &str) -> Result<GroupAffine<P>, SageParseError>
+where
+    P::BaseField: PrimeField,
+{
+    let inner = s
+        .trim()
+        .strip_prefix('(')
+        .and_then(|s| s.strip_suffix(')'))
+        .ok_or(SageParseError::Malformed)?;
+    let coordinates: Vec<_> = inner.split(':').map(str::trim).collect();
+    if coordinates.len() != 3 {
+        return Err(SageParseError::Malformed);
+    }
+    let parse =
+        |s: &str| parse_decimal::<P::BaseField>(s).map_err(SageParseError::InvalidCoordinate);
+    let (x, y, z) = (
+        parse(coordinates[0])?,
+        parse(coordinates[1])?,
+        parse(coordinates[2])?,
+    );
+    if z.is_zero() {
+        return if x.is_zero() && y.is_one() {
+            Ok(GroupAffine::zero())
+        } else {
+            Err(SageParseError::Malformed)
+        };
+    }
+    if !z.is_one() {
+        return Err(SageParseError::Malformed);
+    }
+    point_from_xy(x, y).map_err(|_| SageParseError::NotOnCurve)
+}
@@ -0,0 +1,240 @@
+//! The compressed point encoding of ZCash, used by the IRTF BLS signature drafts.
+//!
+//! Most BLS12-381 implementations (and their test vectors) encode points the way ZCash
+//! introduced: a G1 point is the 48-byte big-endian `x`-coordinate, and a G2 point is the
+//! 96 bytes of `x.c1 ‖ x.c0` (imaginary part first). Since `q` has 381 bits, the top three
+//! bits of the first byte are free, and store flags:
+//!
+//! | Bit (of the first byte) | Meaning                                                  |
+//! |-------------------------|----------------------------------------------------------|
+//! | `0x80`                  | compression: always set in compressed encodings          |
+//! | `0x40`                  | infinity: the point at infinity, with all other bits 0   |
+//! | `0x20`                  | sort: `y` is the lexicographically largest of `±y`       |
+//!
+//! An element of `F_q` is "largest" if it is greater than its negation as an integer; an
+//! element of `F_{q^2}` compares its imaginary parts first, and its real parts if those
+//! are zero.
+//!
+//! ```rust
+//! use ark_algebra_intro::interop::zcash_format::{decode_g1, encode_g1};
+//! use ark_bls12_381::G1Affine;
+//! use ark_ec::AffineCurve;
+//! use ark_std::Zero;
+//!
+//! let hex = |bytes: &[u8]| bytes.iter().map(|b| format!("{:02x}", b)).collect::<String>();
+//!
+//! let g = G1Affine::prime_subgroup_generator();
+//! assert_eq!(
+//!     hex(&encode_g1(&g)),
+//!     "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
+//! );
+//! assert_eq!(decode_g1(&encode_g1(&g)), Ok(g));
+//! // The negation only differs in the sort flag.
+//! assert_eq!(encode_g1(&-g)[0], 0x97 | 0x20);
+//!
+//! let mut infinity = [0u8; 48];
+//! infinity[0] = 0xc0;
+//! assert_eq!(decode_g1(&infinity), Ok(G1Affine::zero()));
+//! ```
+//!
+//! Decoding checks that points are in the prime-order subgroup, like the BLS signature
+//! drafts require:
+//!
+//! ```rust
+//! use ark_algebra_intro::curves::point_from_xy;
+//! use ark_algebra_intro::interop::zcash_format::{decode_g1, decode_g1_unchecked, encode_g1};
+//! use ark_algebra_intro::interop::DecodeError;
+//! use ark_bls12_381::{Fq, G1Affine};
+//!
+//! // A point of order 3.
+//! let p: G1Affine = point_from_xy(Fq::from(0u64), Fq::from(2u64)).unwrap();
+//! assert_eq!(decode_g1(&encode_g1(&p)), Err(DecodeError::NotInSubgroup));
+//! // Unless the check is skipped explicitly.
+//! assert_eq!(decode_g1_unchecked(&encode_g1(&p)), Ok(p));
+//! ```
+//!
+//! # Comparison with arkworks
+//!
+//! The [compressed encoding](crate::serialize::to_bytes_compressed) of arkworks stores
+//! the same `x`-coordinate, but little-endian, with its own flags in the top bits of the
+//! *last* byte (and the coordinates of `x ∈ F_{q^2}` real part first). So the two
+//! encodings are byte reversals of each other, up to the flags:
+//!
+//! ```rust
+//! use ark_algebra_intro::interop::zcash_format::{encode_g1, encode_g2};
+//! use ark_algebra_intro::serialize::to_bytes_compressed;
+//! use ark_bls12_381::{G1Projective, G2Projective};
+//! use ark_ec::ProjectiveCurve;
+//! use ark_std::UniformRand;
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let clear_flags = |mut bytes: Vec<u8>, flag_byte: usize| {
+//!     bytes[flag_byte] &= 0x1f;
+//!     bytes
+//! };
+//!
+//! let p = G1Projective::rand(&mut rng).into_affine();
+//! let mut arkworks = clear_flags(to_bytes_compressed(&p), 47);
+//! arkworks.reverse();
+//! assert_eq!(clear_flags(encode_g1(&p), 0), arkworks);
+//!
+//! let q = G2Projective::rand(&mut rng).into_affine();
+//! let mut arkworks = clear_flags(to_bytes_compressed(&q), 95);
+//! arkworks.reverse();
+//! assert_eq!(clear_flags(encode_g2(&q), 0), arkworks);
+//! ```
+
+use super::{check_length, field_from_be, subgroup_checked, DecodeError};
+use crate::curves::AffineExt;
+use crate::encoding::to_bytes_be;
+use ark_bls12_381::{Fq, Fq2, G1Affine, G2Affine};
+use ark_ff::Zero;
+use ark_std::vec::Vec;
+
+/// The compression flag.
+const COMPRESSION: u8 = 0x80;
+
+/// The infinity flag.
+const INFINITY: u8 = 0x40;
+
+/// The sort flag.
+const SORT: u8 = 0x20;
+
+/// The width of an encoded base field element.
+const FQ_WIDTH: usize = 48;
+
+/// Returns the 48-byte compressed encoding of a G1 point.
+pub fn encode_g1(point: &G1Affine) -> Vec<u8> {
+    match point.xy() {
+        Some((x, y)) => with_flags(to_bytes_be(&x), is_largest(&y)),
+        None => infinity(FQ_WIDTH),
+    }
+}
+
+/// Decodes a 48-byte compressed encoding of a G1 point, checking that it is on the curve
+/// and in the prime-order subgroup.
+pub fn decode_g1(bytes: &[u8]) -> Result<G1Affine, DecodeError> {
+    subgroup_checked(decode_g1_unchecked(bytes)?)
+}
+
+/// Decodes a 48-byte compressed encoding of a G1 point, checking that it is on the curve
+/// but *not* that it is in the prime-order subgroup, e.g., to inspect invalid inputs.
+pub fn decode_g1_unchecked(bytes: &[u8]) -> Result<G1Affine, DecodeError> {
+    check_length(bytes, FQ_WIDTH)?;
+    let (sort, x) = match split_flags(bytes)? {
+        Some(split) => split,
+        None => return Ok(G1Affine::zero()),
+    };
+    let x: Fq = field_from_be(&x)?;
+    let point = G1Affine::get_point_from_x(x, true).ok_or(DecodeError::NotOnCurve)?;
+    Ok(if is_largest(&point.y) == sort {
+        point
+    } else {
+        -point
+    })
+}
+
+/// Returns the 96-byte compressed encoding of a G2 point.
+///
+/// ```rust
+/// use ark_algebra_intro::interop::zcash_format::{decode_g2, encode_g2};
+/// use ark_bls12_381::G2Affine;
+/// use ark_ec::AffineCurve;
+///
+/// let hex = |bytes: &[u8]| bytes.iter().map(|b| format!("{:02x}", b)).collect::<String>();
+///
+/// let h = G2Affine::prime_subgroup_generator();
+/// assert_eq!(
+///     hex(&encode_g2(&h)),
+///     "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e\
+///      024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8",
+/// );
+/// assert_eq!(decode_g2(&encode_g2(&h)), Ok(h));
+/// assert_eq!(decode_g2(&encode_g2(&-h)), Ok(-h));
+/// ```
+pub fn encode_g2(point: &G2Affine) -> Vec<u8> {
+    match point.xy() {
+        Some((x, y)) => {
+            let mut bytes = to_bytes_be(&x.c1);
+            bytes.extend(to_bytes_be(&x.c0));
+            with_flags(bytes, is_largest_fq2(&y))
+        }
+        None => infinity(2 * FQ_WIDTH),
+    }
+}
+
+/// Decodes a 96-byte compressed encoding of a G2 point, checking that it is on the curve
+/// and in the prime-order subgroup.
+pub fn decode_g2(bytes: &[u8]) -> Result<G2Affine, DecodeError> {
+    subgroup_checked(decode_g2_unchecked(bytes)?)
+}
+
+/// Decodes a 96-byte compressed encoding of a G2 point, checking that it is on the curve
+/// but *not* that it is in the prime-order subgroup.
+pub fn decode_g2_unchecked(bytes: &[u8]) -> Result<G2Affine, DecodeError> {
+    check_length(bytes, 2 * FQ_WIDTH)?;
+    let (sort, x) = match split_flags(bytes)? {
+        Some(split) => split,
+        None => return Ok(G2Affine::zero()),
+    };
+    let c1: Fq = field_from_be(&x[..FQ_WIDTH])?;
+    let c0: Fq = field_from_be(&x[FQ_WIDTH..])?;
+    let point =
+        G2Affine::get_point_from_x(Fq2::new(c0, c1), true).ok_or(DecodeError::NotOnCurve)?;
+    Ok(if is_largest_fq2(&point.y) == sort {
+        point
+    } else {
+        -point
+    })
+}
+
+/// Checks whether `y` is greater than `-y`, as integers in `[0, q)`.
+fn is_largest(y: &Fq) -> bool {
+    *y > -*y
+}
+
+/// Checks whether `y` is lexicographically greater than `-y`, comparing the imaginary
+/// parts first.
+fn is_largest_fq2(y: &Fq2) -> bool {
+    if y.c1.is_zero() {
+        is_largest(&y.c0)
+    } else {
+        is_largest(&y.c1)
+    }
+}
+
+/// Sets the compression flag, and the sort flag if `largest`, on a big-endian encoding.
+fn with_flags(mut bytes: Vec<u8>, largest: bool) -> Vec<u8> {
+    bytes[0] |= COMPRESSION;
+    if largest {
+        bytes[0] |= SORT;
+    }
+    bytes
+}
+
+/// Returns the encoding of the point at infinity.
+fn infinity(width: usize) -> Vec<u8> {
+    let mut bytes = vec![0; width];
+    bytes[0] = COMPRESSION | INFINITY;
+    bytes
+}
+
+/// Returns the sort flag and the encoding of `x` without flags, or `None` for the point at
+/// infinity.
+fn split_flags(bytes: &[u8]) -> Result<Option<(bool, Vec<u8>)>, DecodeError> {
+    let flags = bytes[0] & (COMPRESSION | INFINITY | SORT);
+    let mut x = bytes.to_vec();
+    x[0] &= !flags;
+    if flags & COMPRESSION == 0 {
+        return Err(DecodeError::NonCanonical);
+    }
+    if flags & INFINITY != 0 {
+        // The point at infinity has a single encoding.
+        return if flags & SORT == 0 && x.iter().all(|b| *b == 0) {
+            Ok(None)
+        } else {
+            Err(DecodeError::NonCanonical)
+        };
+    }
+    Ok(Some((flags & SORT != 0, x)))
+}
@@ -0,0 +1,279 @@
+//! Checking that a type obeys the laws of a group or a field.
+//!
+//! Everything in this crate relies on the arithmetic of its fields and groups obeying
+//! a handful of laws: associativity, commutativity, identities, inverses and, in
+//! fields, distributivity. [`check_field`] and [`check_group`] test these laws on
+//! random inputs (and on edge cases like `0`, `1` and `-1`), and return a
+//! [`Violation`] describing the first one that fails:
+//!
+//! ```rust
+//! use ark_algebra_intro::laws::{check_field, check_group};
+//! use ark_algebra_intro::rng::seeded;
+//! use ark_algebra_intro::toy_fields::F13;
+//! use ark_bls12_381::{Fq12, Fr, G1Projective};
+//!
+//! let mut rng = seeded(0);
+//! assert_eq!(check_field::<Fr, _>(100, &mut rng), Ok(()));
+//! assert_eq!(check_field::<Fq12, _>(10, &mut rng), Ok(()));
+//! assert_eq!(check_field::<F13, _>(100, &mut rng), Ok(()));
+//! assert_eq!(check_group::<G1Projective, _>(10, &mut rng), Ok(()));
+//! ```
+//!
+//! This is mostly useful for types of your own. [`check_group`] only needs the group
+//! operations, so it can check, say, an implementation of the integers modulo 12 under
+//! addition, and find its bug:
+//!
+//! ```rust
+//! use ark_algebra_intro::laws::{check_group, Law};
+//! use ark_algebra_intro::rng::seeded;
+//! use ark_std::rand::distributions::{Distribution, Standard};
+//! use ark_std::rand::Rng;
+//! use ark_std::{fmt, ops, Zero};
+//!
+//! #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+//! struct Clock(u8);
+//!
+//! impl ops::Add for Clock {
+//!     type Output = Clock;
+//!     fn add(self, other: Clock) -> Clock {
+//!         let sum = self.0 + other.0;
+//!         Clock(if sum > 12 { sum - 12 } else { sum }) // Should be `sum >= 12`.
+//!     }
+//! }
+//!
+//! impl ops::Neg for Clock {
+//!     type Output = Clock;
+//!     fn neg(self) -> Clock {
+//!         Clock((12 - self.0) % 12)
+//!     }
+//! }
+//!
+//! impl Zero for Clock {
+//!     fn zero() -> Clock {
+//!         Clock(0)
+//!     }
+//!     fn is_zero(&self) -> bool {
+//!         self.0 == 0
+//!     }
+//! }
+//!
+//! impl fmt::Display for Clock {
+//!     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+//!         write!(f, "{}", self.0)
+//!     }
+//! }
+//!
+//! impl Distribution<Clock> for Standard {
+//!     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Clock {
+//!         Clock(rng.gen_range(0..12))
+//!     }
+//! }
+//!
+//! let violation = check_group::<Clock, _>(100, &mut seeded(0)).unwrap_err();
+//! assert_eq!(violation.law, Law::AdditiveInverse);
+//! assert_eq!(
+//!     violation.to_string(),
+//!     "the law a + (-a) = 0 fails for
+//!   a = 9
+//! a + (-a) = 12
+//!        0 = 0"
+//! );
+//! ```
+//!
+//! With the `testing` feature, `testing::field_axioms` and `testing::group_axioms`
+//! check the same laws with `proptest`, which also shrinks the failing inputs.
+
+use ark_ff::Field;
+use ark_std::ops::{Add, Neg};
+use ark_std::rand::Rng;
+use ark_std::string::{String, ToString};
+use ark_std::vec::Vec;
+use ark_std::{fmt, UniformRand, Zero};
+
+/// A law of groups or fields.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Law {
+    /// `(a + b) + c = a + (b + c)`.
+    AdditiveAssociativity,
+    /// `a + b = b + a`.
+    AdditiveCommutativity,
+    /// `a + 0 = a`.
+    AdditiveIdentity,
+    /// `a + (-a) = 0`.
+    AdditiveInverse,
+    /// `(a · b) · c = a · (b · c)`.
+    MultiplicativeAssociativity,
+    /// `a · b = b · a`.
+    MultiplicativeCommutativity,
+    /// `a · 1 = a`.
+    MultiplicativeIdentity,
+    /// `a · a⁻¹ = 1` for `a ≠ 0`, and `0` has no inverse.
+    MultiplicativeInverse,
+    /// `a · (b + c) = a · b + a · c`.
+    Distributivity,
+}
+
+impl Law {
+    /// Returns the two sides of the equation that the law asserts.
+    pub fn sides(&self) -> (&'static str, &'static str) {
+        match self {
+            Law::AdditiveAssociativity => ("(a + b) + c", "a + (b + c)"),
+            Law::AdditiveCommutativity => ("a + b", "b + a"),
+            Law::AdditiveIdentity => ("a + 0", "a"),
+            Law::AdditiveInverse => ("a + (-a)", "0"),
+            Law::MultiplicativeAssociativity => ("(a · b) · c", "a · (b · c)"),
+            Law::MultiplicativeCommutativity => ("a · b", "b · a"),
+            Law::MultiplicativeIdentity => ("a · 1", "a"),
+            Law::MultiplicativeInverse => ("a · a⁻¹", "1"),
+            Law::Distributivity => ("a · (b + c)", "a · b + a · c"),
+        }
+    }
+}
+
+impl fmt::Display for Law {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (left, right) = self.sides();
+        write!(f, "{} = {}", left, right)
+    }
+}
+
+/// A law that failed, with the inputs it failed for and the two sides it computed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation {
+    /// The law.
+    pub law: Law,
+    /// The name and value of each input.
+    pub inputs: Vec<(&'static str, String)>,
+    /// The value of the left-hand side.
+    pub left: String,
+    /// The value of the right-hand side.
+    pub right: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "the law {} fails for", self.law)?;
+        for (name, value) in &self.inputs {
+            writeln!(f, "  {} = {}", name, value)?;
+        }
+        let (left, right) = self.law.sides();
+        let width = left.chars().count().max(right.chars().count());
+        writeln!(f, "{:>width$} = {}", left, self.left, width = width)?;
+        write!(f, "{:>width$} = {}", right, self.right, width = width)
+    }
+}
+
+impl ark_std::error::Error for Violation {}
+
+/// Returns a violation of `law` unless `left == right`.
+fn law<T: PartialEq + fmt::Display>(
+    law: Law,
+    inputs: &[(&'static str, &T)],
+    left: T,
+    right: T,
+) -> Result<(), Violation> {
+    if left == right {
+        return Ok(());
+    }
+    Err(Violation {
+        law,
+        inputs: inputs
+            .iter()
+            .map(|(name, value)| (*name, value.to_string()))
+            .collect(),
+        left: left.to_string(),
+        right: right.to_string(),
+    })
+}
+
+/// Samples an input: one of `edge_cases` an eighth of the time, and a uniformly random
+/// element otherwise.
+fn sample<T: Copy + UniformRand, R: Rng + ?Sized>(edge_cases: &[T], rng: &mut R) -> T {
+    if rng.gen_ratio(1, 8) {
+        edge_cases[rng.gen_range(0..edge_cases.len())]
+    } else {
+        T::rand(rng)
+    }
+}
+
+/// Checks the group laws on `iterations` random triples `(a, b, c)`, and returns the
+/// first violation.
+///
+/// `G` is any type with an addition, a negation and a zero, such as a curve group.
+pub fn check_group<G, R>(iterations: usize, rng: &mut R) -> Result<(), Violation>
+where
+    G: Copy + PartialEq + fmt::Display + Add<Output = G> + Neg<Output = G> + Zero + UniformRand,
+    R: Rng + ?Sized,
+{
+    let edge_cases = [G::zero()];
+    for _ in 0..iterations {
+        let (a, b, c) = (
+            sample(&edge_cases, rng),
+            sample(&edge_cases, rng),
+            sample(&edge_cases, rng),
+        );
+        check_group_laws(a, b, c)?;
+    }
+    Ok(())
+}
+
+/// Checks the laws of [`check_group`] on `(a, b, c)`.
+fn check_group_laws<G>(a: G, b: G, c: G) -> Result<(), Violation>
+where
+    G: Copy + PartialEq + fmt::Display + Add<Output = G> + Neg<Output = G> + Zero,
+{
+    let abc = [("a", &a), ("b", &b), ("c", &c)];
+    law(Law::AdditiveAssociativity, &abc, (a + b) + c, a + (b + c))?;
+    law(Law::AdditiveCommutativity, &abc[..2], a + b, b + a)?;
+    law(Law::AdditiveIdentity, &abc[..1], a + G::zero(), a)?;
+    law(Law::AdditiveInverse, &abc[..1], a + (-a), G::zero())
+}
+
+/// Checks the field laws on `iterations` random triples `(a, b, c)`, and returns the
+/// first violation.
+///
+/// These are the group laws of [`check_group`] for addition, the same laws for
+/// multiplication (with inverses for every element but `0`), and distributivity.
+pub fn check_field<F: Field, R: Rng + ?Sized>(
+    iterations: usize,
+    rng: &mut R,
+) -> Result<(), Violation> {
+    let edge_cases = [F::zero(), F::one(), -F::one()];
+    for _ in 0..iterations {
+        let (a, b, c) = (
+            sample(&edge_cases, rng),
+            sample(&edge_cases, rng),
+            sample(&edge_cases, rng),
+        );
+        check_group_laws(a, b, c)?;
+
+        let abc = [("a", &a), ("b", &b), ("c", &c)];
+        law(
+            Law::MultiplicativeAssociativity,
+            &abc,
+            (a * b) * c,
+            a * (b * c),
+        )?;
+        law(Law::MultiplicativeCommutativity, &abc[..2], a * b, b * a)?;
+        law(Law::MultiplicativeIdentity, &abc[..1], a * F::one(), a)?;
+        law(Law::Distributivity, &abc, a * (b + c), a * b + a * c)?;
+        // When `a` is zero, or nonzero without an inverse, the two sides are what `a⁻¹`
+        // is and what it should be.
+        let (left, right) = match (a.is_zero(), a.inverse()) {
+            (false, Some(inverse)) => {
+                law(Law::MultiplicativeInverse, &abc[..1], a * inverse, F::one())?;
+                continue;
+            }
+            (true, None) => continue,
+            (true, Some(inverse)) => (inverse.to_string(), "no inverse".to_string()),
+            (false, None) => ("no inverse".to_string(), "an inverse".to_string()),
+        };
+        return Err(Violation {
+            law: Law::MultiplicativeInverse,
+            inputs: vec![("a", a.to_string())],
+            left,
+            right,
+        });
+    }
+    Ok(())
+}
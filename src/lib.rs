@@ -1 +1,74 @@
-#![doc = include_str!("../README.md")]
\ No newline at end of file
+#![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+#[macro_use]
+extern crate alloc;
+
+pub mod attacks;
+pub mod backends;
+#[cfg(feature = "std")]
+pub mod bench;
+pub mod bigint;
+pub mod catalog;
+pub mod codes;
+pub mod commitments;
+pub mod compat;
+pub mod constants;
+pub mod convert;
+pub mod coords;
+#[cfg(feature = "std")]
+pub mod cost;
+pub mod curves;
+pub mod custom_fields;
+pub mod discrete_log;
+pub mod display;
+pub mod dynamic;
+pub mod embedded;
+pub mod encoding;
+pub mod error;
+#[cfg(feature = "std")]
+pub mod exercises;
+pub mod extensions;
+pub mod field_map;
+pub mod fields;
+pub mod hash_to_curve;
+pub mod hashes;
+pub mod interop;
+pub mod laws;
+pub mod merkle;
+pub mod models;
+pub mod msm;
+pub mod number_theory;
+pub mod pairings;
+pub mod poly;
+pub mod prelude;
+pub mod protocols;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "r1cs")]
+pub mod r1cs;
+pub mod repl;
+pub mod rng;
+pub mod sampling;
+#[cfg(feature = "std")]
+pub mod scaffold;
+#[cfg(feature = "serde")]
+pub mod serde_support;
+pub mod serialize;
+pub mod setup;
+pub mod snark;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod toy_curves;
+pub mod toy_fields;
+#[cfg(feature = "std")]
+pub mod trace;
+pub mod transcript;
+pub mod twists;
+#[cfg(feature = "vectors")]
+pub mod vectors;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use error::Error;
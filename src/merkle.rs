@@ -0,0 +1,243 @@
+//! Merkle trees and membership proofs.
+//!
+//! A Merkle tree commits to a list of `2^k` leaves with a single hash, the *root*: each
+//! leaf is hashed, and then adjacent pairs of hashes are compressed into one, level by
+//! level, until a single hash remains. To prove that a leaf is in the tree, it's enough
+//! to reveal the `k` *siblings* on the path from the leaf to the root; the verifier
+//! recomputes the path and compares the result with the root.
+//!
+//! The tree is generic over the hash, through the [`MerkleHasher`] trait. Inside a proof
+//! system, the hash should be an algebraic one such as [Poseidon](crate::hashes::poseidon)
+//! or [Pedersen](crate::hashes::pedersen), so that checking a path is cheap to prove.
+//!
+//! ```rust
+//! use ark_algebra_intro::hashes::poseidon::PoseidonParameters;
+//! use ark_algebra_intro::merkle::{verify, MerkleTree};
+//! use ark_bls12_381::Fr;
+//!
+//! let params = PoseidonParameters::<Fr>::recommended();
+//! let leaves: Vec<_> = (0..8u64).map(Fr::from).collect();
+//! let tree = MerkleTree::new(&params, &leaves);
+//!
+//! let proof = tree.prove(5);
+//! assert_eq!(proof.siblings.len(), 3);
+//! assert!(verify(&params, &tree.root(), &Fr::from(5u64), &proof));
+//!
+//! // The proof is for a specific leaf at a specific position...
+//! assert!(!verify(&params, &tree.root(), &Fr::from(6u64), &proof));
+//! let mut moved = proof.clone();
+//! moved.index = 4;
+//! assert!(!verify(&params, &tree.root(), &Fr::from(5u64), &moved));
+//! // ...in a specific tree.
+//! let other = MerkleTree::new(&params, &leaves[..4]);
+//! assert!(!verify(&params, &other.root(), &Fr::from(5u64), &proof));
+//! ```
+//!
+//! # Domain separation
+//!
+//! Leaves and internal nodes must be hashed differently. Otherwise, the two children of
+//! an internal node could be passed off as a leaf of a shorter tree, and the verifier
+//! would accept a "leaf" that was never inserted (the *second-preimage attack* fixed in
+//! RFC 6962). The hashers in this module hash leaves and nodes with different input
+//! lengths or prefixes.
+//!
+//! The Pedersen hasher works with the same tree, its nodes being x-coordinates of points:
+//!
+//! ```rust
+//! use ark_algebra_intro::hashes::pedersen::PedersenParameters;
+//! use ark_algebra_intro::merkle::{verify, MerkleTree};
+//! use ark_bls12_381::{Fq, G1Affine};
+//!
+//! let params = PedersenParameters::<G1Affine>::new(128, b"example");
+//! let leaves: Vec<_> = (0..4u64).map(Fq::from).collect();
+//! let tree = MerkleTree::new(&params, &leaves);
+//! for (i, leaf) in leaves.iter().enumerate() {
+//!     assert!(verify(&params, &tree.root(), leaf, &tree.prove(i)));
+//! }
+//! assert!(!verify(&params, &tree.root(), &Fq::from(4u64), &tree.prove(0)));
+//! ```
+
+use crate::curves::AffineExt;
+use crate::hashes::pedersen::{pedersen_hash, PedersenParameters};
+use crate::hashes::poseidon::{hash, PoseidonParameters};
+use ark_ff::{Field, PrimeField, Zero};
+use ark_std::vec::Vec;
+
+/// A hash function for the leaves and internal nodes of a Merkle tree over `F`.
+pub trait MerkleHasher<F> {
+    /// Hashes a leaf.
+    fn hash_leaf(&self, leaf: &F) -> F;
+
+    /// Compresses the hashes of two children into the hash of their parent.
+    fn compress(&self, left: &F, right: &F) -> F;
+}
+
+/// Poseidon hashes leaves as inputs of length one, and nodes as inputs of length two.
+impl<F: PrimeField> MerkleHasher<F> for PoseidonParameters<F> {
+    fn hash_leaf(&self, leaf: &F) -> F {
+        hash(self, &[*leaf])
+    }
+
+    fn compress(&self, left: &F, right: &F) -> F {
+        hash(self, &[*left, *right])
+    }
+}
+
+/// The Pedersen hash of the serialized inputs, prefixed with `0` for leaves and `1` for
+/// nodes, reduced to its x-coordinate.
+///
+/// Dropping the y-coordinate only identifies each point with its negation, so a
+/// collision still yields a relation between the generators.
+///
+/// # Panics
+///
+/// Panics if the parameters don't support messages of one byte plus two serialized
+/// field elements.
+impl<G: AffineExt> MerkleHasher<G::BaseField> for PedersenParameters<G> {
+    fn hash_leaf(&self, leaf: &G::BaseField) -> G::BaseField {
+        pedersen_to_field(self, 0, &[leaf])
+    }
+
+    fn compress(&self, left: &G::BaseField, right: &G::BaseField) -> G::BaseField {
+        pedersen_to_field(self, 1, &[left, right])
+    }
+}
+
+fn pedersen_to_field<G: AffineExt>(
+    params: &PedersenParameters<G>,
+    tag: u8,
+    inputs: &[&G::BaseField],
+) -> G::BaseField {
+    let mut msg = vec![tag];
+    for input in inputs {
+        msg.extend(crate::protocols::to_bytes(*input));
+    }
+    pedersen_hash(params, &msg)
+        .xy()
+        .map_or_else(G::BaseField::zero, |(x, _)| x)
+}
+
+/// A Merkle tree, storing every level from the leaf hashes up to the root.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleTree<F> {
+    /// The levels of the tree: `levels[0]` holds the hashes of the leaves, and the last
+    /// level holds only the root.
+    pub levels: Vec<Vec<F>>,
+}
+
+/// A proof that a leaf is in a Merkle tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleProof<F> {
+    /// The position of the leaf.
+    pub index: usize,
+    /// The siblings on the path from the leaf to the root, starting at the bottom.
+    pub siblings: Vec<F>,
+}
+
+impl<F: Field> MerkleTree<F> {
+    /// Builds the tree with the given leaves.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of leaves is not a power of two.
+    pub fn new<H: MerkleHasher<F>>(hasher: &H, leaves: &[F]) -> Self {
+        assert!(
+            leaves.len().is_power_of_two(),
+            "the number of leaves must be a power of two"
+        );
+        let mut levels = vec![leaves
+            .iter()
+            .map(|l| hasher.hash_leaf(l))
+            .collect::<Vec<_>>()];
+        while levels.last().unwrap().len() > 1 {
+            let parents = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| hasher.compress(&pair[0], &pair[1]))
+                .collect();
+            levels.push(parents);
+        }
+        Self { levels }
+    }
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> F {
+        self.levels.last().unwrap()[0]
+    }
+
+    /// Returns the number of leaves.
+    pub fn num_leaves(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Proves that the leaf at `index` is in the tree.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn prove(&self, index: usize) -> MerkleProof<F> {
+        assert!(
+            index < self.num_leaves(),
+            "there are only {} leaves",
+            self.num_leaves()
+        );
+        let siblings = self.levels[..self.levels.len() - 1]
+            .iter()
+            .enumerate()
+            .map(|(height, level)| level[(index >> height) ^ 1])
+            .collect();
+        MerkleProof { index, siblings }
+    }
+}
+
+/// Checks that `proof` proves that `leaf` is in the tree with the given `root`.
+///
+/// The bits of the index, from the least significant, say whether the node on the path
+/// is the left (`0`) or the right (`1`) child of its parent. The proof comes from the
+/// prover, so any number of siblings is handled, even more than `index` has bits:
+///
+/// ```rust
+/// use ark_algebra_intro::hashes::poseidon::PoseidonParameters;
+/// use ark_algebra_intro::merkle::{verify, MerkleHasher, MerkleProof};
+/// use ark_bls12_381::Fr;
+///
+/// let params = PoseidonParameters::<Fr>::recommended();
+/// let leaf = Fr::from(1u64);
+/// // The leftmost path of a tree of height 70.
+/// let mut proof = MerkleProof { index: 0, siblings: vec![Fr::from(2u64); 70] };
+/// let root = proof
+///     .siblings
+///     .iter()
+///     .fold(params.hash_leaf(&leaf), |node, sibling| params.compress(&node, sibling));
+/// assert!(verify(&params, &root, &leaf, &proof));
+/// proof.index = 1 << 63;
+/// assert!(!verify(&params, &root, &leaf, &proof));
+/// ```
+pub fn verify<F: Field, H: MerkleHasher<F>>(
+    hasher: &H,
+    root: &F,
+    leaf: &F,
+    proof: &MerkleProof<F>,
+) -> bool {
+    if proof
+        .index
+        .checked_shr(proof.siblings.len() as u32)
+        .unwrap_or(0)
+        != 0
+    {
+        return false;
+    }
+    let node = proof.siblings.iter().enumerate().fold(
+        hasher.hash_leaf(leaf),
+        |node, (height, sibling)| {
+            // Past the bits of `index`, the path only goes left.
+            if proof.index.checked_shr(height as u32).unwrap_or(0) & 1 == 0 {
+                hasher.compress(&node, sibling)
+            } else {
+                hasher.compress(sibling, &node)
+            }
+        },
+    );
+    node == *root
+}
@@ -0,0 +1,308 @@
+//! Converting between the short Weierstrass, Montgomery, and twisted Edwards models
+//! of an elliptic curve.
+//!
+//! The same elliptic curve can be written down by many different equations. The three
+//! that show up most in practice are
+//!
+//! * *short Weierstrass* form `y^2 = x^3 + a x + b`, which every curve has (over fields
+//!   of characteristic greater than 3);
+//! * *Montgomery* form `B v^2 = u^3 + A u^2 + u`, which allows fast x-only arithmetic;
+//! * *twisted Edwards* form `a x^2 + y^2 = 1 + d x^2 y^2`, whose addition law can be made
+//!   *complete* (free of special cases for doubling and the identity).
+//!
+//! Only curves with a point of order 4 (like Jubjub, whose cofactor is 8) have Edwards and
+//! Montgomery forms, and the three are related by *birational maps*: rational functions
+//! that are inverse to each other, and that respect the group law. In `arkworks`, a
+//! twisted Edwards curve's `TEModelParameters` come with the coefficients of the
+//! corresponding Montgomery curve, so this module can go from Edwards to Montgomery to
+//! Weierstrass form and back, for any twisted Edwards curve.
+//!
+//! With the `ed-on-bls12-381` feature, `jubjub::WeierstrassParameters` describes the
+//! Weierstrass form of Jubjub, which makes it possible to check that the maps commute
+//! with scalar multiplication:
+//!
+//! ```rust
+//! use ark_algebra_intro::models::{to_edwards, to_weierstrass};
+//! use ark_ec::{
+//!     models::{SWModelParameters, TEModelParameters},
+//!     short_weierstrass_jacobian, twisted_edwards_extended, AffineCurve, ProjectiveCurve,
+//! };
+//! use ark_std::UniformRand;
+//!
+//! fn check<P, Q>()
+//! where
+//!     P: TEModelParameters,
+//!     Q: SWModelParameters<BaseField = P::BaseField, ScalarField = P::ScalarField>,
+//! {
+//!     let mut rng = ark_std::rand::thread_rng();
+//!     let p = twisted_edwards_extended::GroupProjective::<P>::rand(&mut rng).into_affine();
+//!     let k = P::ScalarField::rand(&mut rng);
+//!
+//!     let w: short_weierstrass_jacobian::GroupAffine<Q> = to_weierstrass(&p);
+//!     assert!(w.is_on_curve());
+//!     assert_eq!(to_weierstrass::<P, Q>(&p.mul(k).into_affine()), w.mul(k).into_affine());
+//!     assert_eq!(to_edwards::<Q, P>(&w), Some(p));
+//! }
+//!
+//! #[cfg(feature = "ed-on-bls12-381")]
+//! check::<ark_ed_on_bls12_381::EdwardsParameters, ark_algebra_intro::models::jubjub::WeierstrassParameters>();
+//! ```
+
+use ark_ec::{
+    models::{MontgomeryModelParameters, SWModelParameters, TEModelParameters},
+    short_weierstrass_jacobian, twisted_edwards_extended,
+};
+use ark_ff::{Field, One, Zero};
+
+/// Returns the coefficients `(a, b)` of the short Weierstrass curve
+/// `y^2 = x^3 + a x + b` that is isomorphic to the twisted Edwards curve `P`.
+///
+/// Writing the Montgomery form of `P` as `B v^2 = u^3 + A u^2 + u`, substituting
+/// `u = B x - A / 3` and `v = B y` and dividing by `B^3` gives
+/// `a = (3 - A^2) / (3 B^2)` and `b = (2 A^3 - 9 A) / (27 B^3)`.
+///
+/// ```rust
+/// use ark_algebra_intro::models::weierstrass_coefficients;
+/// use ark_ec::models::{MontgomeryModelParameters, TEModelParameters};
+/// use ark_ff::Field;
+///
+/// fn check<P: TEModelParameters>() {
+///     // The Montgomery coefficients `arkworks` provides are derived from the Edwards ones,
+///     // as `A = 2 (a + d) / (a - d)` and `B = 4 / (a - d)`:
+///     let (a, d) = (P::COEFF_A, P::COEFF_D);
+///     let inverse = (a - d).inverse().unwrap();
+///     let montgomery_a = <P::MontgomeryModelParameters as MontgomeryModelParameters>::COEFF_A;
+///     let montgomery_b = <P::MontgomeryModelParameters as MontgomeryModelParameters>::COEFF_B;
+///     assert_eq!(montgomery_a, (a + d).double() * inverse);
+///     assert_eq!(montgomery_b, P::BaseField::from(4u64) * inverse);
+///     println!("{:?}", weierstrass_coefficients::<P>());
+/// }
+///
+/// #[cfg(feature = "ed-on-bls12-381")]
+/// check::<ark_ed_on_bls12_381::EdwardsParameters>();
+/// ```
+pub fn weierstrass_coefficients<P: TEModelParameters>() -> (P::BaseField, P::BaseField) {
+    let (a, b) = montgomery_coefficients::<P>();
+    let three = P::BaseField::from(3u64);
+    let b_squared = b.square();
+    let coeff_a = (three - a.square()) * (three * b_squared).inverse().unwrap();
+    let coeff_b = (a.square() * a.double() - a * P::BaseField::from(9u64))
+        * (P::BaseField::from(27u64) * b_squared * b)
+            .inverse()
+            .unwrap();
+    (coeff_a, coeff_b)
+}
+
+/// Maps a point on the twisted Edwards curve `P` to Montgomery coordinates `(u, v)`,
+/// with `u = (1 + y) / (1 - y)` and `v = u / x`.
+///
+/// Returns `None` for the Edwards identity `(0, 1)`, which corresponds to the point at
+/// infinity of the Montgomery curve. Its other point with `x = 0`, the point `(0, -1)`
+/// of order 2, maps to the Montgomery point `(0, 0)` of order 2.
+///
+/// ```rust
+/// use ark_algebra_intro::models::{from_montgomery, to_montgomery};
+/// use ark_ec::{
+///     models::{MontgomeryModelParameters, TEModelParameters},
+///     twisted_edwards_extended::{GroupAffine, GroupProjective},
+///     ProjectiveCurve,
+/// };
+/// use ark_std::{UniformRand, Zero};
+///
+/// fn check<P: TEModelParameters>() {
+///     let mut rng = ark_std::rand::thread_rng();
+///     let p = GroupProjective::<P>::rand(&mut rng).into_affine();
+///     let (u, v) = to_montgomery(&p).unwrap();
+///
+///     // (u, v) lies on the Montgomery curve B v^2 = u^3 + A u^2 + u...
+///     let a = <P::MontgomeryModelParameters as MontgomeryModelParameters>::COEFF_A;
+///     let b = <P::MontgomeryModelParameters as MontgomeryModelParameters>::COEFF_B;
+///     assert_eq!(b * v * v, u * u * u + a * u * u + u);
+///     // ...and maps back to p.
+///     assert_eq!(from_montgomery::<P>(u, v), Some(p));
+///
+///     assert_eq!(to_montgomery(&GroupAffine::<P>::zero()), None);
+/// }
+///
+/// #[cfg(feature = "ed-on-bls12-381")]
+/// check::<ark_ed_on_bls12_381::EdwardsParameters>();
+/// ```
+pub fn to_montgomery<P: TEModelParameters>(
+    point: &twisted_edwards_extended::GroupAffine<P>,
+) -> Option<(P::BaseField, P::BaseField)> {
+    let one = P::BaseField::one();
+    if point.x.is_zero() {
+        return if point.y == one {
+            None
+        } else {
+            Some((P::BaseField::zero(), P::BaseField::zero()))
+        };
+    }
+    let u = (one + point.y) * (one - point.y).inverse().unwrap();
+    let v = u * point.x.inverse().unwrap();
+    Some((u, v))
+}
+
+/// Maps the point `(u, v)` on the Montgomery form of `P` to the twisted Edwards curve,
+/// with `x = u / v` and `y = (u - 1) / (u + 1)`.
+///
+/// Returns `None` if `v = 0` or `u = -1` (except for the point `(0, 0)`, which maps to
+/// `(0, -1)`): such points correspond to points "at infinity" of the Edwards curve,
+/// which have no affine coordinates. Curves like Jubjub, whose Edwards addition law
+/// is complete, have no such points.
+pub fn from_montgomery<P: TEModelParameters>(
+    u: P::BaseField,
+    v: P::BaseField,
+) -> Option<twisted_edwards_extended::GroupAffine<P>> {
+    let one = P::BaseField::one();
+    if u.is_zero() && v.is_zero() {
+        return Some(twisted_edwards_extended::GroupAffine::new(u, -one));
+    }
+    let x = u * v.inverse()?;
+    let y = (u - one) * (u + one).inverse()?;
+    Some(twisted_edwards_extended::GroupAffine::new(x, y))
+}
+
+/// Maps a point on the twisted Edwards curve `P` to the short Weierstrass curve `Q`,
+/// going through the Montgomery form of `P`.
+///
+/// The Montgomery point `(u, v)` is mapped to `(u / B + A / (3 B), v / B)`, and the
+/// Edwards identity to the point at infinity.
+///
+/// # Panics
+///
+/// Panics if the coefficients of `Q` are not the [`weierstrass_coefficients`] of `P`.
+pub fn to_weierstrass<P, Q>(
+    point: &twisted_edwards_extended::GroupAffine<P>,
+) -> short_weierstrass_jacobian::GroupAffine<Q>
+where
+    P: TEModelParameters,
+    Q: SWModelParameters<BaseField = P::BaseField>,
+{
+    assert_weierstrass_form::<P, Q>();
+    match to_montgomery(point) {
+        None => short_weierstrass_jacobian::GroupAffine::zero(),
+        Some((u, v)) => {
+            let (a, b) = montgomery_coefficients::<P>();
+            let b_inv = b.inverse().unwrap();
+            let x = (u + a * P::BaseField::from(3u64).inverse().unwrap()) * b_inv;
+            short_weierstrass_jacobian::GroupAffine::new(x, v * b_inv, false)
+        }
+    }
+}
+
+/// Maps a point on the short Weierstrass curve `Q` to the twisted Edwards curve `P`,
+/// inverting [`to_weierstrass`].
+///
+/// Returns `None` if the point has no image on the Edwards curve; see
+/// [`from_montgomery`].
+///
+/// ```rust
+/// use ark_algebra_intro::models::{to_edwards, to_weierstrass};
+/// use ark_ec::{
+///     models::{SWModelParameters, TEModelParameters},
+///     short_weierstrass_jacobian, twisted_edwards_extended, AffineCurve,
+/// };
+/// use ark_std::Zero;
+///
+/// fn check<P, Q>()
+/// where
+///     P: TEModelParameters,
+///     Q: SWModelParameters<BaseField = P::BaseField, ScalarField = P::ScalarField>,
+/// {
+///     // The maps send generators to generators, and the identity to the identity.
+///     let g = short_weierstrass_jacobian::GroupAffine::<Q>::prime_subgroup_generator();
+///     let h = twisted_edwards_extended::GroupAffine::<P>::prime_subgroup_generator();
+///     assert_eq!(to_edwards(&g), Some(h));
+///     assert_eq!(to_weierstrass::<P, Q>(&h), g);
+///     let zero = short_weierstrass_jacobian::GroupAffine::<Q>::zero();
+///     assert_eq!(to_edwards(&zero), Some(twisted_edwards_extended::GroupAffine::<P>::zero()));
+/// }
+///
+/// #[cfg(feature = "ed-on-bls12-381")]
+/// check::<ark_ed_on_bls12_381::EdwardsParameters, ark_algebra_intro::models::jubjub::WeierstrassParameters>();
+/// ```
+///
+/// # Panics
+///
+/// Panics if the coefficients of `Q` are not the [`weierstrass_coefficients`] of `P`.
+pub fn to_edwards<Q, P>(
+    point: &short_weierstrass_jacobian::GroupAffine<Q>,
+) -> Option<twisted_edwards_extended::GroupAffine<P>>
+where
+    Q: SWModelParameters,
+    P: TEModelParameters<BaseField = Q::BaseField>,
+{
+    assert_weierstrass_form::<P, Q>();
+    if point.infinity {
+        return Some(twisted_edwards_extended::GroupAffine::zero());
+    }
+    let (a, b) = montgomery_coefficients::<P>();
+    let u = b * point.x - a * P::BaseField::from(3u64).inverse().unwrap();
+    let v = b * point.y;
+    from_montgomery(u, v)
+}
+
+/// Returns the coefficients `(A, B)` of the Montgomery form of `P`.
+fn montgomery_coefficients<P: TEModelParameters>() -> (P::BaseField, P::BaseField) {
+    (
+        <P::MontgomeryModelParameters as MontgomeryModelParameters>::COEFF_A,
+        <P::MontgomeryModelParameters as MontgomeryModelParameters>::COEFF_B,
+    )
+}
+
+fn assert_weierstrass_form<P, Q>()
+where
+    P: TEModelParameters,
+    Q: SWModelParameters<BaseField = P::BaseField>,
+{
+    assert_eq!(
+        (Q::COEFF_A, Q::COEFF_B),
+        weierstrass_coefficients::<P>(),
+        "the short Weierstrass curve does not match the twisted Edwards curve"
+    );
+}
+
+/// The short Weierstrass form of the Jubjub curve.
+#[cfg(feature = "ed-on-bls12-381")]
+pub mod jubjub {
+    use ark_ec::models::{ModelParameters, SWModelParameters};
+    use ark_ed_on_bls12_381::{Fq, Fr};
+    use ark_ff::field_new;
+
+    /// The parameters of the curve `y^2 = x^3 + a x + b` that is isomorphic to Jubjub,
+    /// as computed by [`weierstrass_coefficients`](super::weierstrass_coefficients).
+    ///
+    /// ```rust
+    /// use ark_algebra_intro::models::{jubjub::WeierstrassParameters, weierstrass_coefficients};
+    /// use ark_ec::models::SWModelParameters;
+    ///
+    /// assert_eq!(
+    ///     weierstrass_coefficients::<ark_ed_on_bls12_381::EdwardsParameters>(),
+    ///     (WeierstrassParameters::COEFF_A, WeierstrassParameters::COEFF_B),
+    /// );
+    /// ```
+    #[derive(Clone, Default, PartialEq, Eq)]
+    pub struct WeierstrassParameters;
+
+    impl ModelParameters for WeierstrassParameters {
+        type BaseField = Fq;
+        type ScalarField = Fr;
+    }
+
+    impl SWModelParameters for WeierstrassParameters {
+        #[rustfmt::skip]
+        const COEFF_A: Fq = field_new!(Fq, "52296097456646850916096512823759002727550416093741407922227928430486925478210");
+        #[rustfmt::skip]
+        const COEFF_B: Fq = field_new!(Fq, "48351165704696163914533707656614864561753505123260775585269522553028192119009");
+        const COFACTOR: &'static [u64] = &[8];
+        #[rustfmt::skip]
+        const COFACTOR_INV: Fr = field_new!(Fr, "819310549611346726241370945440405716213240158234039660170669895299022906775");
+        /// The image of the Jubjub generator.
+        #[rustfmt::skip]
+        const AFFINE_GENERATOR_COEFFS: (Fq, Fq) = (
+            field_new!(Fq, "33835869156188682335217394949746694649676633840125476177319971163079011318731"),
+            field_new!(Fq, "43777270878440091394432848052353307184915192688165709016756678962558652055320"),
+        );
+    }
+}
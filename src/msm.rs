@@ -0,0 +1,419 @@
+//! Multi-scalar multiplication (MSM).
+//!
+//! A *multi-scalar multiplication* computes `s_1 · G_1 + s_2 · G_2 + ... + s_n · G_n`
+//! for scalars `s_i` and curve points `G_i`. MSMs are everywhere in pairing-based
+//! proof systems: committing to a polynomial with KZG, or computing a Groth16
+//! proof, boils down to a few MSMs over millions of points. As a result, MSMs
+//! account for most of the running time of a typical prover.
+//!
+//! This module contains three implementations with identical results:
+//!
+//! * [`naive`] performs `n` independent scalar multiplications and adds up the
+//!   results;
+//! * [`pippenger`] implements the *bucket method* of Pippenger, which shares
+//!   work between the scalar multiplications, and is what real libraries use;
+//! * [`arkworks`] calls the optimized implementation in `ark-ec`.
+//!
+//! ```rust
+//! use ark_algebra_intro::msm;
+//! use ark_bls12_381::{Fr, G1Projective};
+//! use ark_ec::ProjectiveCurve;
+//! use ark_std::UniformRand;
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let bases: Vec<_> = (0..100).map(|_| G1Projective::rand(&mut rng).into_affine()).collect();
+//! let scalars: Vec<_> = (0..100).map(|_| Fr::rand(&mut rng)).collect();
+//!
+//! let expected = msm::naive(&bases, &scalars);
+//! assert_eq!(msm::pippenger(&bases, &scalars), expected);
+//! assert_eq!(msm::arkworks(&bases, &scalars), expected);
+//! ```
+//!
+//! To compare their running times, run `cargo bench --bench msm`.
+//!
+//! When the *same* base is multiplied by many different scalars (e.g., the
+//! generator of a group, when computing public keys or a KZG setup), we can do
+//! even better by precomputing multiples of the base once; see [`FixedBaseTable`].
+//!
+//! The `04_scalar_multiplication` example walks through this module:
+//! `cargo run --release --example 04_scalar_multiplication`.
+//!
+//! # Parallelism
+//!
+//! With the `parallel` feature, `pippenger_parallel` (and, in this crate, the FFT
+//! `poly::fft_parallel`) runs on `rayon`'s thread pool, and so do
+//! `arkworks`' own MSMs and FFTs, including [`arkworks`]. Compare the serial and parallel
+//! versions with `cargo bench --bench parallel --features parallel`.
+//!
+//! Parallelism pays off when each task has plenty of work and the tasks are independent.
+//! An MSM of size `n` has about `n · b / window` additions, split into `b / window`
+//! independent bucket computations, so MSMs of a few thousand terms and up scale well
+//! to as many cores as there are digit positions. Below a few hundred terms, or on a
+//! single core, the overhead of scheduling tasks and of `rayon`'s atomics outweighs the
+//! gains, and the parallel version is slightly *slower* than the serial one. The same goes
+//! for an FFT, whose layers must run one after the other: it only scales once a layer
+//! has enough butterflies (tens of thousands) for every thread, and its memory
+//! traffic eventually becomes the bottleneck rather than arithmetic.
+//!
+//! In a prover, it's often better to run independent MSMs and FFTs side by side than to
+//! parallelize each one internally.
+
+use ark_ec::msm::VariableBaseMSM;
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{BigInteger, FpParameters, PrimeField};
+use ark_std::vec::Vec;
+use ark_std::Zero;
+
+/// Computes `Σ scalars[i] · bases[i]` with one scalar multiplication per term.
+///
+/// For 255-bit scalars, this costs about 255 doublings and 127 additions per term.
+///
+/// # Panics
+///
+/// Panics if `bases` and `scalars` have different lengths.
+///
+/// ```rust
+/// use ark_algebra_intro::msm::naive;
+/// use ark_bls12_381::{Fr, G1Affine};
+/// use ark_ec::AffineCurve;
+///
+/// let g = G1Affine::prime_subgroup_generator();
+/// // 2 · g + 3 · g = 5 · g
+/// let result = naive(&[g, g], &[Fr::from(2u64), Fr::from(3u64)]);
+/// assert_eq!(result, g.mul(Fr::from(5u64)));
+/// ```
+pub fn naive<G: AffineCurve>(bases: &[G], scalars: &[G::ScalarField]) -> G::Projective {
+    assert_eq!(
+        bases.len(),
+        scalars.len(),
+        "mismatched number of bases and scalars"
+    );
+    bases
+        .iter()
+        .zip(scalars)
+        .map(|(base, scalar)| base.mul(scalar.into_repr()))
+        .sum()
+}
+
+/// Computes `Σ scalars[i] · bases[i]` with Pippenger's bucket method, using a window
+/// size chosen based on the number of terms.
+///
+/// See [`pippenger_with_window`] for how the algorithm works.
+///
+/// # Panics
+///
+/// Panics if `bases` and `scalars` have different lengths.
+///
+/// ```rust
+/// use ark_algebra_intro::msm::{naive, pippenger};
+/// use ark_bls12_381::{Fr, G2Projective};
+/// use ark_ec::ProjectiveCurve;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let bases: Vec<_> = (0..10).map(|_| G2Projective::rand(&mut rng).into_affine()).collect();
+/// let scalars: Vec<_> = (0..10).map(|_| Fr::rand(&mut rng)).collect();
+/// assert_eq!(pippenger(&bases, &scalars), naive(&bases, &scalars));
+/// ```
+pub fn pippenger<G: AffineCurve>(bases: &[G], scalars: &[G::ScalarField]) -> G::Projective {
+    pippenger_with_window(bases, scalars, default_window_size(bases.len()))
+}
+
+/// Computes `Σ scalars[i] · bases[i]` with Pippenger's bucket method, processing
+/// `window` bits of every scalar at a time.
+///
+/// The algorithm splits each `b`-bit scalar into `b / window` digits of `window` bits,
+/// so that `s_i = Σ_j d_ij · 2^(window · j)`. Then, for each digit position `j`:
+///
+/// 1. it sorts the bases into `2^window - 1` *buckets* by the value of their `j`-th digit,
+///    and adds up the bases in each bucket (one addition per term);
+/// 2. it computes `Σ_k k · bucket_k` with a running sum, which takes only
+///    `2 · (2^window - 1)` additions, no matter how many terms there are.
+///
+/// Finally, it combines the sums for each digit position with `b` doublings in
+/// total. In all, that's about `(b / window) · (n + 2^(window + 1))` additions,
+/// compared to `b · n / 2` for the [naive] method; the best window size
+/// grows logarithmically with `n`.
+///
+/// # Panics
+///
+/// Panics if `bases` and `scalars` have different lengths, or if `window` is not
+/// between 1 and 20.
+///
+/// ```rust
+/// use ark_algebra_intro::msm::{naive, pippenger_with_window};
+/// use ark_bls12_381::{Fr, G1Projective};
+/// use ark_ec::ProjectiveCurve;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let bases: Vec<_> = (0..20).map(|_| G1Projective::rand(&mut rng).into_affine()).collect();
+/// let scalars: Vec<_> = (0..20).map(|_| Fr::rand(&mut rng)).collect();
+/// let expected = naive(&bases, &scalars);
+/// for window in 1..=8 {
+///     assert_eq!(pippenger_with_window(&bases, &scalars, window), expected);
+/// }
+/// ```
+pub fn pippenger_with_window<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[G::ScalarField],
+    window: usize,
+) -> G::Projective {
+    assert_eq!(
+        bases.len(),
+        scalars.len(),
+        "mismatched number of bases and scalars"
+    );
+    assert!(
+        (1..=20).contains(&window),
+        "window size must be between 1 and 20, not {}",
+        window
+    );
+    let num_bits = <G::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
+    let scalar_bits: Vec<Vec<bool>> = scalars.iter().map(|s| s.into_repr().to_bits_le()).collect();
+    let window_sums = (0..num_bits)
+        .step_by(window)
+        .map(|start| window_sum(bases, &scalar_bits, start, window))
+        .collect();
+    combine_window_sums::<G>(window_sums, window)
+}
+
+/// Computes `Σ scalars[i] · bases[i]` like [`pippenger`], but with the digit positions
+/// handled in parallel, on [`rayon`]'s thread pool.
+///
+/// Each digit position is an independent bucket computation, so this is the easiest
+/// part of Pippenger's method to parallelize, and it's also what `ark-ec` does with its
+/// `parallel` feature. There are only `b / window` digit positions (20 for 255-bit
+/// scalars and `2^16` terms), so this stops scaling beyond that many cores.
+///
+/// Only available with the `parallel` feature.
+///
+/// # Panics
+///
+/// Panics if `bases` and `scalars` have different lengths.
+///
+/// ```rust
+/// use ark_algebra_intro::msm::{pippenger, pippenger_parallel};
+/// use ark_bls12_381::{Fr, G1Projective};
+/// use ark_ec::ProjectiveCurve;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let bases: Vec<_> = (0..100).map(|_| G1Projective::rand(&mut rng).into_affine()).collect();
+/// let scalars: Vec<_> = (0..100).map(|_| Fr::rand(&mut rng)).collect();
+/// assert_eq!(pippenger_parallel(&bases, &scalars), pippenger(&bases, &scalars));
+/// ```
+#[cfg(feature = "parallel")]
+pub fn pippenger_parallel<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[G::ScalarField],
+) -> G::Projective {
+    use rayon::prelude::*;
+
+    assert_eq!(
+        bases.len(),
+        scalars.len(),
+        "mismatched number of bases and scalars"
+    );
+    let window = default_window_size(bases.len());
+    let num_bits = <G::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
+    let scalar_bits: Vec<Vec<bool>> = scalars
+        .par_iter()
+        .map(|s| s.into_repr().to_bits_le())
+        .collect();
+    let starts: Vec<usize> = (0..num_bits).step_by(window).collect();
+    let window_sums = starts
+        .into_par_iter()
+        .map(|start| window_sum(bases, &scalar_bits, start, window))
+        .collect();
+    combine_window_sums::<G>(window_sums, window)
+}
+
+/// Computes `Σ_i d_i · bases[i]`, where `d_i` is the `window`-bit digit of the `i`-th
+/// scalar starting at bit `start`, with the bucket method.
+fn window_sum<G: AffineCurve>(
+    bases: &[G],
+    scalar_bits: &[Vec<bool>],
+    start: usize,
+    window: usize,
+) -> G::Projective {
+    // Step 1: bucket_k holds the sum of all bases whose digit is k.
+    // (We don't need a bucket for k = 0.)
+    let mut buckets = vec![G::Projective::zero(); (1 << window) - 1];
+    for (base, bits) in bases.iter().zip(scalar_bits) {
+        let k: usize = (0..window)
+            .filter(|i| bits.get(start + i) == Some(&true))
+            .map(|i| 1 << i)
+            .sum();
+        if k != 0 {
+            buckets[k - 1].add_assign_mixed(base);
+        }
+    }
+
+    // Step 2: Σ_k k · bucket_k = bucket_max + (bucket_max + bucket_max-1) + ...
+    let mut running_sum = G::Projective::zero();
+    let mut window_sum = G::Projective::zero();
+    for bucket in buckets.into_iter().rev() {
+        running_sum += bucket;
+        window_sum += running_sum;
+    }
+    window_sum
+}
+
+/// Step 3: combines the window sums, from the most significant one down.
+fn combine_window_sums<G: AffineCurve>(
+    window_sums: Vec<G::Projective>,
+    window: usize,
+) -> G::Projective {
+    let mut result = G::Projective::zero();
+    for window_sum in window_sums.into_iter().rev() {
+        for _ in 0..window {
+            result.double_in_place();
+        }
+        result += window_sum;
+    }
+    result
+}
+
+/// Computes `Σ scalars[i] · bases[i]` with the optimized MSM implementation in
+/// `ark-ec`, [`VariableBaseMSM`].
+///
+/// This is a thin wrapper that accepts scalars as field elements, like the other
+/// functions in this module, rather than as [`BigInteger`]s.
+///
+/// # Panics
+///
+/// Panics if `bases` and `scalars` have different lengths. (`VariableBaseMSM` itself
+/// silently ignores any extra bases or scalars.)
+///
+/// ```rust
+/// use ark_algebra_intro::msm::{arkworks, naive};
+/// use ark_bls12_381::{Fr, G1Projective};
+/// use ark_ec::ProjectiveCurve;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let bases: Vec<_> = (0..50).map(|_| G1Projective::rand(&mut rng).into_affine()).collect();
+/// let scalars: Vec<_> = (0..50).map(|_| Fr::rand(&mut rng)).collect();
+/// assert_eq!(arkworks(&bases, &scalars), naive(&bases, &scalars));
+/// ```
+pub fn arkworks<G: AffineCurve>(bases: &[G], scalars: &[G::ScalarField]) -> G::Projective {
+    assert_eq!(
+        bases.len(),
+        scalars.len(),
+        "mismatched number of bases and scalars"
+    );
+    let scalars: Vec<_> = scalars.iter().map(|s| s.into_repr()).collect();
+    VariableBaseMSM::multi_scalar_mul(bases, &scalars)
+}
+
+/// A table of precomputed multiples of a fixed base, for fast repeated scalar
+/// multiplication by that base.
+///
+/// For a window size `w`, the table stores `k · 2^(w · j) · base` for every `w`-bit
+/// digit value `k` and every digit position `j`. Multiplying `base` by a scalar
+/// `s = Σ_j d_j · 2^(w · j)` then takes one table lookup and one addition per digit,
+/// and *no doublings at all*: for a 255-bit scalar and `w = 8`, that's 32 additions
+/// instead of about 255 doublings and 127 additions.
+///
+/// The price is memory and precomputation: the table holds `2^w · ⌈b / w⌉` points
+/// for `b`-bit scalars, which is why this only pays off when the same base is
+/// used many times.
+///
+/// ```rust
+/// use ark_algebra_intro::msm::FixedBaseTable;
+/// use ark_bls12_381::{Fr, G1Projective};
+/// use ark_ec::ProjectiveCurve;
+/// use ark_ff::PrimeField;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let g = G1Projective::prime_subgroup_generator();
+/// let table = FixedBaseTable::new(g, 4);
+/// assert_eq!(table.window(), 4);
+/// assert_eq!(table.num_points(), 16 * 64); // 2^4 multiples for each of 255/4 ≈ 64 digits
+///
+/// for _ in 0..10 {
+///     let s = Fr::rand(&mut rng);
+///     assert_eq!(table.mul(&s), g.mul(s.into_repr()));
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedBaseTable<G: ProjectiveCurve> {
+    window: usize,
+    /// `tables[j][k]` is `k · 2^(window · j) · base`.
+    tables: Vec<Vec<G::Affine>>,
+}
+
+impl<G: ProjectiveCurve> FixedBaseTable<G> {
+    /// Precomputes the multiples of `base` needed for scalar multiplication with
+    /// digits of `window` bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is not between 1 and 20.
+    pub fn new(base: G, window: usize) -> Self {
+        assert!(
+            (1..=20).contains(&window),
+            "window size must be between 1 and 20, not {}",
+            window
+        );
+        let num_bits = <G::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
+        let num_digits = num_bits.div_ceil(window);
+
+        let mut tables = Vec::with_capacity(num_digits);
+        // `shifted_base` is `2^(window · j) · base`.
+        let mut shifted_base = base;
+        for _ in 0..num_digits {
+            let mut multiples = Vec::with_capacity(1 << window);
+            let mut multiple = G::zero();
+            for _ in 0..(1 << window) {
+                multiples.push(multiple);
+                multiple += shifted_base;
+            }
+            // Normalizing all multiples at once takes a single field inversion.
+            tables.push(G::batch_normalization_into_affine(&multiples));
+            for _ in 0..window {
+                shifted_base.double_in_place();
+            }
+        }
+        FixedBaseTable { window, tables }
+    }
+
+    /// Returns the window size of this table.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Returns the number of precomputed points stored in this table.
+    pub fn num_points(&self) -> usize {
+        self.tables.iter().map(Vec::len).sum()
+    }
+
+    /// Multiplies the base of this table by `scalar`, using one mixed addition
+    /// per digit of `scalar`.
+    pub fn mul(&self, scalar: &G::ScalarField) -> G {
+        let bits = scalar.into_repr().to_bits_le();
+        let mut result = G::zero();
+        for (j, table) in self.tables.iter().enumerate() {
+            let digit: usize = (0..self.window)
+                .filter(|i| bits.get(j * self.window + i) == Some(&true))
+                .map(|i| 1 << i)
+                .sum();
+            result.add_assign_mixed(&table[digit]);
+        }
+        result
+    }
+}
+
+/// Picks a window size of roughly `ln(n) + 2`, which is what `ark-ec` uses, up to the
+/// limit of 20 of [`pippenger_with_window`].
+pub(crate) fn default_window_size(n: usize) -> usize {
+    if n < 32 {
+        3
+    } else {
+        // log2(n) · ln(2) ≈ ln(n)
+        let log2 = (usize::BITS - n.leading_zeros()) as usize;
+        (log2 * 69 / 100 + 2).min(20)
+    }
+}
@@ -0,0 +1,406 @@
+//! Elementary number theory over arbitrary-precision integers.
+//!
+//! The field types in `arkworks` fix their modulus at compile time. The
+//! functions in this module instead work with arbitrary [`BigUint`]s, which
+//! makes them handy for exploring the arithmetic behind those fields
+//! (or for working modulo integers that aren't prime at all).
+
+use crate::Error;
+use ark_ff::{FpParameters, LegendreSymbol, PrimeField, SquareRootField};
+use ark_std::ops::{Div, Mul, Neg, Sub};
+use ark_std::vec::Vec;
+use ark_std::{One, Zero};
+use num_bigint::{BigInt, BigUint};
+
+/// Computes the Jacobi symbol `(a / n)`, which is one of `-1`, `0`, or `1`.
+///
+/// When `n` is prime, this is the Legendre symbol: it is `0` if `n` divides `a`,
+/// `1` if `a` is a non-zero square modulo `n`, and `-1` otherwise. For composite
+/// `n = p_1 · ... · p_k`, it is the product of the Legendre symbols `(a / p_i)`.
+/// Note that in the composite case, `(a / n) = 1` does *not* imply that `a` is
+/// a square modulo `n`.
+///
+/// The computation uses quadratic reciprocity, and so never needs to factor `n`.
+///
+/// # Panics
+///
+/// Panics if `n` is even.
+///
+/// ```rust
+/// use ark_algebra_intro::number_theory::jacobi;
+/// use num_bigint::BigUint;
+///
+/// let n = |x: u64| BigUint::from(x);
+/// // The squares modulo 7 are 1, 2, and 4:
+/// assert_eq!(jacobi(&n(2), &n(7)), 1);
+/// assert_eq!(jacobi(&n(3), &n(7)), -1);
+/// assert_eq!(jacobi(&n(14), &n(7)), 0);
+///
+/// // 2 is not a square modulo 15 = 3 · 5, even though (2 / 15) = (2 / 3)(2 / 5) = 1:
+/// assert_eq!(jacobi(&n(2), &n(15)), 1);
+/// assert!((0..15u64).all(|x| (x * x) % 15 != 2));
+/// ```
+pub fn jacobi(a: &BigUint, n: &BigUint) -> i8 {
+    assert!(n.bit(0), "the Jacobi symbol is only defined for odd moduli");
+    let mut a = a % n;
+    let mut n = n.clone();
+    let mut result = 1;
+    while !a.is_zero() {
+        // Pull out factors of two using the second supplementary law:
+        // (2 / n) = -1 if and only if n ≡ 3, 5 (mod 8).
+        while !a.bit(0) {
+            a >>= 1;
+            let n_mod_8 = n.iter_u32_digits().next().unwrap_or(0) % 8;
+            if n_mod_8 == 3 || n_mod_8 == 5 {
+                result = -result;
+            }
+        }
+        // By quadratic reciprocity, (a / n) = -(n / a) if and only if a ≡ n ≡ 3 (mod 4).
+        ark_std::mem::swap(&mut a, &mut n);
+        if a.bit(1) && n.bit(1) {
+            result = -result;
+        }
+        a %= &n;
+    }
+    if n.is_one() {
+        result
+    } else {
+        0
+    }
+}
+
+/// Computes the Legendre symbol of `a` via [`jacobi`], cross-checking the
+/// result against [`SquareRootField::legendre`].
+///
+/// # Panics
+///
+/// Panics if the two computations disagree, which would indicate a bug.
+///
+/// ```rust
+/// use ark_algebra_intro::number_theory::legendre;
+/// use ark_bls12_381::{Fq, Fr};
+/// use ark_ff::Field;
+/// use ark_std::{UniformRand, Zero};
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let a = Fq::rand(&mut rng);
+/// assert_eq!(legendre(&a.square()), 1);
+/// assert_eq!(legendre(&Fr::zero()), 0);
+/// // -1 is not a square modulo the BLS12-381 base field modulus, because q ≡ 3 (mod 4).
+/// assert_eq!(legendre(&-Fq::from(1u64)), -1);
+/// ```
+pub fn legendre<F: PrimeField + SquareRootField>(a: &F) -> i8 {
+    let modulus: BigUint = F::Params::MODULUS.into();
+    let symbol = jacobi(&(*a).into(), &modulus);
+    let expected = match a.legendre() {
+        LegendreSymbol::Zero => 0,
+        LegendreSymbol::QuadraticResidue => 1,
+        LegendreSymbol::QuadraticNonResidue => -1,
+    };
+    assert_eq!(
+        symbol, expected,
+        "Jacobi symbol disagrees with `Field::legendre`"
+    );
+    symbol
+}
+
+/// Returns whether `n` is prime, by the Miller-Rabin test with the first thirteen
+/// primes as bases.
+///
+/// The answer is always right for `n < 3.3 · 10^24`. Above that, a composite `n` can
+/// pass the test, but only one constructed to do so: the chance that a composite
+/// number picked without that aim passes is negligible.
+///
+/// ```rust
+/// use ark_algebra_intro::number_theory::is_probable_prime;
+/// use ark_bls12_381::Fq;
+/// use ark_ff::{FpParameters, PrimeField};
+/// use num_bigint::BigUint;
+///
+/// let n = |x: u64| BigUint::from(x);
+/// assert!(is_probable_prime(&n(101)));
+/// assert!(!is_probable_prime(&n(1)));
+/// // 561 = 3 · 11 · 17 is a Carmichael number, which fools the Fermat test.
+/// assert!(!is_probable_prime(&n(561)));
+/// // 399165290221 · 798330580441 passes the test for every base up to 37, but not 41.
+/// let psi_12 = n(399_165_290_221) * n(798_330_580_441);
+/// assert_eq!(psi_12.to_string(), "318665857834031151167461");
+/// assert!(!is_probable_prime(&psi_12));
+///
+/// let q: BigUint = <Fq as PrimeField>::Params::MODULUS.into();
+/// assert!(is_probable_prime(&q));
+/// assert!(!is_probable_prime(&(q * 3u64)));
+/// ```
+pub fn is_probable_prime(n: &BigUint) -> bool {
+    const BASES: [u64; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+    if let Some(&p) = BASES.iter().find(|&&p| (n % p).is_zero()) {
+        return *n == BigUint::from(p);
+    }
+    if *n < BigUint::from(2u64) {
+        return false;
+    }
+    // n - 1 = 2^s · d, with d odd.
+    let n_minus_one = n - 1u64;
+    let s = n_minus_one
+        .trailing_zeros()
+        .expect("n - 1 is nonzero, since n is not 2");
+    let d = &n_minus_one >> s;
+    BASES.iter().all(|&base| {
+        let mut x = BigUint::from(base).modpow(&d, n);
+        if x.is_one() || x == n_minus_one {
+            return true;
+        }
+        for _ in 1..s {
+            x = &x * &x % n;
+            if x == n_minus_one {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// The result of the extended Euclidean algorithm on `a` and `b`, computed by [`xgcd`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Xgcd<T> {
+    /// The greatest common divisor `g` of `a` and `b`.
+    pub gcd: T,
+    /// The Bézout coefficient `x`, with `a · x + b · y = g`.
+    pub x: T,
+    /// The Bézout coefficient `y`, with `a · x + b · y = g`.
+    pub y: T,
+}
+
+/// One division step of the extended Euclidean algorithm, as recorded by
+/// [`xgcd_with_trace`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XgcdStep<T> {
+    /// The quotient `q` of the division of the previous two remainders.
+    pub quotient: T,
+    /// The new remainder `r`.
+    pub r: T,
+    /// The coefficient `s` of `a` in `r = a · s + b · t`.
+    pub s: T,
+    /// The coefficient `t` of `b` in `r = a · s + b · t`.
+    pub t: T,
+}
+
+/// Computes the greatest common divisor `g` of the nonnegative integers `a` and `b`,
+/// along with `x` and `y` such that `a · x + b · y = g`.
+///
+/// This works for any signed integer type, such as `i128` or [`BigInt`]. One of the
+/// coefficients is usually negative, so unsigned types like [`BigUint`] are rejected:
+/// convert them to [`BigInt`] first, as [`mod_inverse`] does. See [`xgcd_with_trace`]
+/// for how it works.
+///
+/// ```rust
+/// use ark_algebra_intro::number_theory::{xgcd, Xgcd};
+/// use num_bigint::BigInt;
+///
+/// assert_eq!(xgcd(240i128, 46), Xgcd { gcd: 2, x: -9, y: 47 });
+/// let n = |x: i64| BigInt::from(x);
+/// assert_eq!(xgcd(n(240), n(46)), Xgcd { gcd: n(2), x: n(-9), y: n(47) });
+///
+/// let (a, b) = (BigInt::from(u128::MAX), BigInt::from(u64::MAX) * 7u64);
+/// let Xgcd { gcd, x, y } = xgcd(a.clone(), b.clone());
+/// assert_eq!(gcd, BigInt::from(u64::MAX));
+/// assert_eq!(a * x + b * y, gcd);
+/// ```
+///
+/// ```compile_fail
+/// use ark_algebra_intro::number_theory::xgcd;
+/// use num_bigint::BigUint;
+///
+/// // The coefficients -9 and 47 don't fit in a `BigUint`.
+/// xgcd(BigUint::from(240u64), BigUint::from(46u64));
+/// ```
+pub fn xgcd<T>(a: T, b: T) -> Xgcd<T>
+where
+    T: Clone + Zero + One + Neg<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    xgcd_with_trace(a, b).0
+}
+
+/// Computes the same result as [`xgcd`], along with each division step of the
+/// algorithm.
+///
+/// The Euclidean algorithm replaces `(a, b)` by `(b, a mod b)` until the remainder is
+/// `0`, which leaves the greatest common divisor, since `a` and `b` have the same
+/// common divisors as `b` and `a mod b`. The extended algorithm also writes each
+/// remainder as `r_i = a · s_i + b · t_i`: starting from `a = a · 1 + b · 0` and
+/// `b = a · 0 + b · 1`, a division `r_{i+1} = r_{i-1} - q · r_i` gives
+/// `s_{i+1} = s_{i-1} - q · s_i`, and likewise for `t`. The step with remainder `0`
+/// is recorded too: its coefficients are `b / g` and `-a / g`, up to sign.
+///
+/// ```rust
+/// use ark_algebra_intro::number_theory::{xgcd_with_trace, Xgcd};
+///
+/// let (a, b) = (240i128, 46);
+/// let (result, steps) = xgcd_with_trace(a, b);
+/// assert_eq!(result, Xgcd { gcd: 2, x: -9, y: 47 });
+/// // 240 = 5 · 46 + 10, 46 = 4 · 10 + 6, 10 = 1 · 6 + 4, 6 = 1 · 4 + 2, 4 = 2 · 2.
+/// let quotients: Vec<_> = steps.iter().map(|step| step.quotient).collect();
+/// assert_eq!(quotients, [5, 4, 1, 1, 2]);
+/// let remainders: Vec<_> = steps.iter().map(|step| step.r).collect();
+/// assert_eq!(remainders, [10, 6, 4, 2, 0]);
+/// assert!(steps.iter().all(|step| a * step.s + b * step.t == step.r));
+/// ```
+pub fn xgcd_with_trace<T>(a: T, b: T) -> (Xgcd<T>, Vec<XgcdStep<T>>)
+where
+    T: Clone + Zero + One + Neg<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    let (mut r0, mut r1) = (a, b);
+    let (mut s0, mut s1) = (T::one(), T::zero());
+    let (mut t0, mut t1) = (T::zero(), T::one());
+    let mut steps = Vec::new();
+    while !r1.is_zero() {
+        let quotient = r0.clone() / r1.clone();
+        let r2 = r0 - quotient.clone() * r1.clone();
+        let s2 = s0 - quotient.clone() * s1.clone();
+        let t2 = t0 - quotient.clone() * t1.clone();
+        steps.push(XgcdStep {
+            quotient,
+            r: r2.clone(),
+            s: s2.clone(),
+            t: t2.clone(),
+        });
+        (r0, r1) = (r1, r2);
+        (s0, s1) = (s1, s2);
+        (t0, t1) = (t1, t2);
+    }
+    let result = Xgcd {
+        gcd: r0,
+        x: s0,
+        y: t0,
+    };
+    (result, steps)
+}
+
+/// Returns the inverse of `a` modulo `m`, or [`Error::NotInvertible`] if `a` and `m`
+/// are not coprime or `m` is zero.
+///
+/// If `gcd(a, m) = 1`, [`xgcd`] gives `a · x + m · y = 1`, so `a · x ≡ 1 (mod m)`;
+/// otherwise every multiple of `a` is a multiple of the gcd, and none is `1` modulo
+/// `m`. For a prime `m`, this is what [`Field::inverse`](ark_ff::Field::inverse)
+/// conceptually computes: `arkworks` uses a binary variant of the same algorithm,
+/// which only shifts and subtracts.
+///
+/// ```rust
+/// use ark_algebra_intro::number_theory::mod_inverse;
+/// use ark_algebra_intro::Error;
+/// use ark_bls12_381::Fr;
+/// use ark_ff::{Field, FpParameters, PrimeField};
+/// use ark_std::UniformRand;
+/// use num_bigint::BigUint;
+///
+/// let n = |x: u64| BigUint::from(x);
+/// assert_eq!(mod_inverse(&n(3), &n(7)), Ok(n(5)));
+/// // 6 and 9 share the factor 3.
+/// assert_eq!(mod_inverse(&n(6), &n(9)), Err(Error::NotInvertible));
+///
+/// let p: BigUint = <Fr as PrimeField>::Params::MODULUS.into();
+/// let a = Fr::rand(&mut ark_std::rand::thread_rng());
+/// let inverse = mod_inverse(&a.into_repr().into(), &p).unwrap();
+/// assert_eq!(Fr::from(inverse), a.inverse().unwrap());
+/// ```
+pub fn mod_inverse(a: &BigUint, m: &BigUint) -> Result<BigUint, Error> {
+    if m.is_zero() {
+        return Err(Error::NotInvertible);
+    }
+    let m = BigInt::from(m.clone());
+    let Xgcd { gcd, x, .. } = xgcd(BigInt::from(a % m.magnitude()), m.clone());
+    if !gcd.is_one() {
+        return Err(Error::NotInvertible);
+    }
+    // x can be negative, and is larger than -m.
+    Ok(((x + &m) % &m).to_biguint().expect("x + m is positive"))
+}
+
+/// Returns the `x` modulo the product of the moduli that is congruent to each residue
+/// modulo its modulus, for pairwise coprime moduli, by the Chinese remainder theorem.
+///
+/// The congruences are added one at a time: `x ≡ r (mod m)` and `x ≡ r' (mod m')`
+/// give `x = r + m · ((r' - r) / m mod m')` modulo `m · m'`.
+///
+/// # Panics
+///
+/// Panics if a modulus is zero, or two moduli are not coprime.
+///
+/// ```rust
+/// use ark_algebra_intro::number_theory::crt;
+/// use num_bigint::BigUint;
+///
+/// let n = |x: u64| BigUint::from(x);
+/// // The classic puzzle from the Sunzi Suanjing: x ≡ 2 (mod 3), x ≡ 3 (mod 5), and
+/// // x ≡ 2 (mod 7).
+/// assert_eq!(crt(&[(n(2), n(3)), (n(3), n(5)), (n(2), n(7))]), n(23));
+///
+/// // The moduli can be as large as needed: the product of these is about 2^128.
+/// let (p, q) = (n(18_446_744_073_709_551_557), n(18_446_744_073_709_551_533));
+/// let x = n(u64::MAX) * n(u64::MAX / 2);
+/// assert_eq!(crt(&[(&x % &p, p), (&x % &q, q)]), x);
+/// ```
+pub fn crt(residues: &[(BigUint, BigUint)]) -> BigUint {
+    let (mut x, mut m) = (BigUint::zero(), BigUint::one());
+    for (r, modulus) in residues {
+        assert!(!modulus.is_zero(), "the moduli must be nonzero");
+        let m_inverse = mod_inverse(&m, modulus).expect("the moduli are pairwise coprime");
+        let t = (r % modulus + modulus - &x % modulus) * m_inverse % modulus;
+        x += &m * t;
+        m *= modulus;
+    }
+    x
+}
+
+/// Returns the same `x` as [`crt`], by Garner's algorithm.
+///
+/// Garner's algorithm writes `x` in the mixed radix of the moduli `m₁, ..., m_k`, as
+/// `x = v₁ + v₂ · m₁ + v₃ · m₁ · m₂ + ...` with each digit `v_i < m_i`. Each digit is
+/// computed modulo its own `m_i`, using the inverses of the `m_j` modulo `m_i`, which
+/// only depend on the moduli: when the same moduli are used for many residues, as in
+/// residue number systems, they can be computed once. Only the final sum involves
+/// numbers as large as the product of the moduli.
+///
+/// # Panics
+///
+/// Panics if a modulus is zero, or two moduli are not coprime.
+///
+/// ```rust
+/// use ark_algebra_intro::number_theory::{crt, garner};
+/// use ark_bls12_381::{Fq, Fr};
+/// use ark_ff::{FpParameters, PrimeField};
+/// use num_bigint::BigUint;
+///
+/// let n = |x: u64| BigUint::from(x);
+/// assert_eq!(garner(&[(n(2), n(3)), (n(3), n(5)), (n(2), n(7))]), n(23));
+///
+/// // An integer below r · q is determined by its images in the scalar field Fr and the
+/// // base field Fq of BLS12-381.
+/// let r: BigUint = <Fr as PrimeField>::Params::MODULUS.into();
+/// let q: BigUint = <Fq as PrimeField>::Params::MODULUS.into();
+/// let x = &r * &q - n(1_000_000);
+/// let residues = [
+///     (Fr::from(x.clone()).into_repr().into(), r),
+///     (Fq::from(x.clone()).into_repr().into(), q),
+/// ];
+/// assert_eq!(garner(&residues), x);
+/// assert_eq!(crt(&residues), x);
+/// ```
+pub fn garner(residues: &[(BigUint, BigUint)]) -> BigUint {
+    let mut digits: Vec<BigUint> = Vec::with_capacity(residues.len());
+    for (i, (r, modulus)) in residues.iter().enumerate() {
+        assert!(!modulus.is_zero(), "the moduli must be nonzero");
+        // v_i = (((r - v₁) / m₁ - v₂) / m₂ - ...) mod m_i.
+        let mut v = r % modulus;
+        for (v_j, (_, m_j)) in digits.iter().zip(&residues[..i]) {
+            let m_j_inverse = mod_inverse(m_j, modulus).expect("the moduli are pairwise coprime");
+            v = (v + modulus - v_j % modulus) * m_j_inverse % modulus;
+        }
+        digits.push(v);
+    }
+    let mut x = BigUint::zero();
+    for (v, (_, modulus)) in digits.iter().zip(residues).rev() {
+        x = x * modulus + v;
+    }
+    x
+}
@@ -0,0 +1,599 @@
+//! The algebraic identities that pairing-based protocols rely on.
+//!
+//! A pairing `e: G1 × G2 → Gt` is *bilinear*: `e(a · P, b · Q) = e(P, Q)^(a · b)` for all
+//! scalars `a` and `b`. Verifier equations in pairing-based protocols are almost always
+//! checks that two products of pairings are equal; this module provides helpers for
+//! writing such checks.
+//!
+//! For example, a BLS signature on a message `m` under the public key `pk = sk · g2` is
+//! `σ = sk · H(m)`, where `H` hashes into G1. Bilinearity gives
+//! `e(σ, g2) = e(H(m), g2)^sk = e(H(m), pk)`, which anyone can check:
+//!
+//! ```rust
+//! use ark_algebra_intro::{hash_to_curve::hash_to_g1, pairings::pairing_ratio_check};
+//! use ark_bls12_381::{Bls12_381, Fr, G2Affine};
+//! use ark_ec::{AffineCurve, ProjectiveCurve};
+//! use ark_std::UniformRand;
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let dst = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+//! let g2 = G2Affine::prime_subgroup_generator();
+//! let sk = Fr::rand(&mut rng);
+//! let pk = g2.mul(sk).into_affine();
+//!
+//! let h = hash_to_g1(b"hello", dst);
+//! let signature = h.mul(sk).into_affine();
+//! assert!(pairing_ratio_check::<Bls12_381>((signature, g2), (h, pk)));
+//!
+//! // A signature on a different message doesn't verify.
+//! let other = hash_to_g1(b"goodbye", dst);
+//! assert!(!pairing_ratio_check::<Bls12_381>((signature, g2), (other, pk)));
+//! ```
+//!
+//! The `05_pairings` example walks through this module:
+//! `cargo run --release --example 05_pairings`.
+
+use crate::constants::bls12_381::{X, X_IS_NEGATIVE};
+use crate::extensions::{lift_to_fq12, lift_to_fq2, lift_to_fq6};
+use crate::twists::untwist;
+use crate::Error;
+use ark_bls12_381::{Fq, Fq12, Fr, G1Affine, G2Affine};
+use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::{BitIteratorBE, Field, FpParameters, One, PrimeField, UniformRand};
+use ark_std::rand::Rng;
+use ark_std::vec::Vec;
+use ark_std::{fmt, ops};
+use num_bigint::BigUint;
+
+/// Checks that the pairing of `E` is bilinear and non-degenerate on random inputs.
+///
+/// Specifically, this samples random points `P, P'` in G1, `Q, Q'` in G2, and scalars
+/// `a, b`, and checks that
+///
+/// * `e(P + P', Q) = e(P, Q) · e(P', Q)` and `e(P, Q + Q') = e(P, Q) · e(P, Q')`;
+/// * `e(a · P, b · Q) = e(P, Q)^(a · b)`;
+/// * `e(g1, g2) ≠ 1` for the generators `g1` and `g2`.
+///
+/// Note that the group operation of Gt is written multiplicatively, since it is a
+/// subgroup of the multiplicative group of `E::Fqk`.
+///
+/// ```rust
+/// use ark_algebra_intro::pairings::check_bilinearity;
+/// use ark_bls12_381::Bls12_381;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// assert!(check_bilinearity::<Bls12_381, _>(&mut rng));
+/// #[cfg(feature = "bn254")]
+/// assert!(check_bilinearity::<ark_bn254::Bn254, _>(&mut rng));
+/// ```
+pub fn check_bilinearity<E: PairingEngine, R: Rng + ?Sized>(rng: &mut R) -> bool {
+    let p = E::G1Projective::rand(rng);
+    let p_prime = E::G1Projective::rand(rng);
+    let q = E::G2Projective::rand(rng);
+    let q_prime = E::G2Projective::rand(rng);
+    let a = E::Fr::rand(rng);
+    let b = E::Fr::rand(rng);
+
+    let e_pq = E::pairing(p, q);
+    let additive_in_g1 = E::pairing(p + p_prime, q) == e_pq * E::pairing(p_prime, q);
+    let additive_in_g2 = E::pairing(p, q + q_prime) == e_pq * E::pairing(p, q_prime);
+    let scalars =
+        E::pairing(p.mul(a.into_repr()), q.mul(b.into_repr())) == e_pq.pow((a * b).into_repr());
+    let generators = E::pairing(
+        E::G1Affine::prime_subgroup_generator(),
+        E::G2Affine::prime_subgroup_generator(),
+    );
+    additive_in_g1 && additive_in_g2 && scalars && !generators.is_one()
+}
+
+/// Computes the product of pairings `e(P_1, Q_1) · ... · e(P_n, Q_n)`.
+///
+/// This is faster than computing each pairing separately, because `arkworks` runs
+/// all of the Miller loops together and performs a single final exponentiation.
+/// The empty product is `1`.
+///
+/// ```rust
+/// use ark_algebra_intro::pairings::pairing_product;
+/// use ark_bls12_381::{Bls12_381, G1Projective, G2Projective};
+/// use ark_ec::{PairingEngine, ProjectiveCurve};
+/// use ark_ff::One;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let pairs: Vec<_> = (0..3)
+///     .map(|_| {
+///         let p = G1Projective::rand(&mut rng).into_affine();
+///         let q = G2Projective::rand(&mut rng).into_affine();
+///         (p, q)
+///     })
+///     .collect();
+///
+/// let expected: <Bls12_381 as PairingEngine>::Fqk =
+///     pairs.iter().map(|(p, q)| Bls12_381::pairing(*p, *q)).product();
+/// assert_eq!(pairing_product::<Bls12_381>(&pairs), expected);
+/// assert!(pairing_product::<Bls12_381>(&[]).is_one());
+/// ```
+pub fn pairing_product<E: PairingEngine>(pairs: &[(E::G1Affine, E::G2Affine)]) -> E::Fqk {
+    let prepared: Vec<_> = pairs
+        .iter()
+        .map(|(p, q)| (E::G1Prepared::from(*p), E::G2Prepared::from(*q)))
+        .collect();
+    E::product_of_pairings(&prepared)
+}
+
+/// Computes `e(a[0], b[0]) · ... · e(a[n-1], b[n-1])` with `n` Miller loops, but only
+/// one final exponentiation.
+///
+/// A pairing is computed in two steps: the *Miller loop*, which produces some element
+/// `f` of `E::Fqk`, and the *final exponentiation*, which raises `f` to the power
+/// `(q^k - 1) / r` to land in Gt. Because exponentiation is multiplicative,
+/// `f_1^e · f_2^e = (f_1 · f_2)^e`, so the final exponentiations can be merged into one.
+/// The Miller loops share their squarings, too: `arkworks`' `miller_loop` runs
+/// all of them together, accumulating every line evaluation in a single `Fqk` element.
+///
+/// For BLS12-381, the final exponentiation is the more expensive of the two steps, so
+/// a product of eight pairings costs less than three separate pairings; run
+/// `cargo bench --bench pairings` to see the difference on your machine. This is exactly what SNARK
+/// verifiers do: a Groth16 proof, for example, is checked with a product of four
+/// pairings.
+///
+/// ```rust
+/// use ark_algebra_intro::pairings::multi_pairing;
+/// use ark_bls12_381::{Bls12_381, G1Projective, G2Projective};
+/// use ark_ec::{PairingEngine, ProjectiveCurve};
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let a: Vec<_> = (0..4).map(|_| G1Projective::rand(&mut rng).into_affine()).collect();
+/// let b: Vec<_> = (0..4).map(|_| G2Projective::rand(&mut rng).into_affine()).collect();
+///
+/// let separately: <Bls12_381 as PairingEngine>::Fqk =
+///     a.iter().zip(&b).map(|(p, q)| Bls12_381::pairing(*p, *q)).product();
+/// assert_eq!(multi_pairing::<Bls12_381>(&a, &b), separately);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `a` and `b` have different lengths.
+pub fn multi_pairing<E: PairingEngine>(a: &[E::G1Affine], b: &[E::G2Affine]) -> E::Fqk {
+    assert_eq!(
+        a.len(),
+        b.len(),
+        "the number of G1 and G2 points must be equal"
+    );
+    let prepared: Vec<_> = a
+        .iter()
+        .zip(b)
+        .map(|(p, q)| (E::G1Prepared::from(*p), E::G2Prepared::from(*q)))
+        .collect();
+    let f = E::miller_loop(&prepared);
+    // `final_exponentiation` only fails on zero, which the Miller loop never outputs.
+    E::final_exponentiation(&f).unwrap()
+}
+
+/// Checks the equation `e(P_1, Q_1) = e(P_2, Q_2)`, given `left = (P_1, Q_1)` and
+/// `right = (P_2, Q_2)`.
+///
+/// Instead of computing both pairings, this checks the equivalent
+/// `e(P_1, Q_1) · e(-P_2, Q_2) = 1` with a single [`pairing_product`], since negating
+/// a point is free and the inverse of `e(P_2, Q_2)` is `e(-P_2, Q_2)`.
+///
+/// ```rust
+/// use ark_algebra_intro::pairings::pairing_ratio_check;
+/// use ark_bls12_381::{Bls12_381, Fr, G1Affine, G2Affine};
+/// use ark_ec::{AffineCurve, ProjectiveCurve};
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let (g1, g2) = (G1Affine::prime_subgroup_generator(), G2Affine::prime_subgroup_generator());
+/// let a = Fr::rand(&mut rng);
+///
+/// // Scalars can be moved from one side of the pairing to the other.
+/// let a_g1 = g1.mul(a).into_affine();
+/// let a_g2 = g2.mul(a).into_affine();
+/// assert!(pairing_ratio_check::<Bls12_381>((a_g1, g2), (g1, a_g2)));
+/// assert!(!pairing_ratio_check::<Bls12_381>((a_g1, g2), (g1, g2)));
+/// ```
+pub fn pairing_ratio_check<E: PairingEngine>(
+    left: (E::G1Affine, E::G2Affine),
+    right: (E::G1Affine, E::G2Affine),
+) -> bool {
+    pairing_product::<E>(&[left, (-right.0, right.1)]).is_one()
+}
+
+/// One step of [`miller_loop_naive`], with the value of the line function used in it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MillerStep {
+    /// `T` was doubled; holds the tangent line at `T`, evaluated at `P`.
+    Double(Fq12),
+    /// `Q` was added to `T`; holds the line through `T` and `Q`, evaluated at `P`.
+    Add(Fq12),
+}
+
+/// Computes the Miller loop of the BLS12-381 pairing directly from line functions,
+/// returning its output together with the line evaluations of each step.
+///
+/// The Miller loop computes a function `f` with zeros and poles at prescribed multiples of
+/// `Q`, evaluated at `P`. It does so with double-and-add over the bits of the curve
+/// parameter `x`: starting from `f = 1` and `T = Q`, each bit
+///
+/// * squares `f` and multiplies it by `l_{T,T}(P)`, where `l_{T,T}` is the tangent line
+///   at `T`, and doubles `T`;
+/// * if the bit is set, also multiplies `f` by `l_{T,Q}(P)`, where `l_{T,Q}` is the line
+///   through `T` and `Q`, and adds `Q` to `T`.
+///
+/// To evaluate the lines, `Q` is first [untwisted](crate::twists::untwist) to a point on
+/// the original curve over `Fq12`, so that both points live on the same curve. The
+/// textbook algorithm would also divide by the vertical lines through `2T` and `T + Q`,
+/// but their values at `P` lie in the subfield `Fq6`, and every non-zero element of
+/// `Fq6` is mapped to 1 by the final exponentiation (which contains the factor
+/// `q^6 - 1`). Finally, since `x` is negative, `f` is inverted, which (again up to the
+/// final exponentiation) is the same as conjugating it.
+///
+/// `arkworks` computes the same loop much more cleverly, with projective coordinates,
+/// sparse multiplications, and lines evaluated on the twist, so its output differs from
+/// this one by a factor that the final exponentiation removes:
+///
+/// ```rust
+/// use ark_algebra_intro::pairings::{miller_loop_naive, MillerStep};
+/// use ark_bls12_381::{Bls12_381, G1Projective, G2Projective};
+/// use ark_ec::{PairingEngine, ProjectiveCurve};
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let p = G1Projective::rand(&mut rng).into_affine();
+/// let q = G2Projective::rand(&mut rng).into_affine();
+///
+/// let (f, trace) = miller_loop_naive(&p, &q);
+/// let f_arkworks = Bls12_381::miller_loop(&[(p.into(), q.into())]);
+/// assert_ne!(f, f_arkworks);
+/// assert_eq!(
+///     Bls12_381::final_exponentiation(&f),
+///     Bls12_381::final_exponentiation(&f_arkworks),
+/// );
+///
+/// // |x| = 0xd201000000010000 has 64 bits, 6 of which are set.
+/// let additions = trace.iter().filter(|step| matches!(step, MillerStep::Add(_))).count();
+/// assert_eq!((trace.len() - additions, additions), (63, 5));
+/// ```
+pub fn miller_loop_naive(p: &G1Affine, q: &G2Affine) -> (Fq12, Vec<MillerStep>) {
+    let mut trace = Vec::new();
+    let f = miller_loop_in(p, q, |is_add, value: Fq12| {
+        trace.push(if is_add {
+            MillerStep::Add(value)
+        } else {
+            MillerStep::Double(value)
+        })
+    });
+    (f, trace)
+}
+
+/// The arithmetic of `Fq12` that [`miller_loop_naive`] uses, so that the same loop can
+/// also run on [`Traced<Fq12>`](crate::trace::Traced) to count its operations.
+///
+/// The methods are named differently from those of [`Field`], which would otherwise be
+/// shadowed by these wherever the trait is in scope.
+pub(crate) trait LoopField:
+    Copy + ops::Add<Output = Self> + ops::Sub<Output = Self> + ops::Mul<Output = Self>
+{
+    fn from_fq12(a: Fq12) -> Self;
+    fn squared(self) -> Self;
+    fn doubled(self) -> Self;
+    fn inverted(self) -> Option<Self>;
+    fn conjugated(self) -> Self;
+}
+
+impl LoopField for Fq12 {
+    fn from_fq12(a: Fq12) -> Self {
+        a
+    }
+
+    fn squared(self) -> Self {
+        Field::square(&self)
+    }
+
+    fn doubled(self) -> Self {
+        Field::double(&self)
+    }
+
+    fn inverted(self) -> Option<Self> {
+        Field::inverse(&self)
+    }
+
+    fn conjugated(mut self) -> Self {
+        Fq12::conjugate(&mut self);
+        self
+    }
+}
+
+/// Runs the loop of [`miller_loop_naive`] in `F`, calling `on_step` with whether each
+/// step is an addition and with its line evaluation.
+pub(crate) fn miller_loop_in<F: LoopField>(
+    p: &G1Affine,
+    q: &G2Affine,
+    mut on_step: impl FnMut(bool, F),
+) -> F {
+    let one = F::from_fq12(Fq12::one());
+    if p.infinity || q.infinity {
+        return one;
+    }
+    let lift = |a| F::from_fq12(lift_to_fq12(lift_to_fq6(lift_to_fq2(a))));
+    let (px, py) = (lift(p.x), lift(p.y));
+    let (qx, qy) = untwist(q);
+    let (qx, qy) = (F::from_fq12(qx), F::from_fq12(qy));
+
+    // The line through (x1, y1) with the given slope, evaluated at P.
+    let line = |slope: F, x1: F, y1: F| (py - y1) - slope * (px - x1);
+
+    let mut f = one;
+    let (mut tx, mut ty) = (qx, qy);
+    let bits = BitIteratorBE::without_leading_zeros([X]);
+    // The leading bit is accounted for by starting with T = Q.
+    for bit in bits.skip(1) {
+        // Tangent at T: slope 3 x_T^2 / (2 y_T), since the curve has a = 0.
+        let tx2 = tx.squared();
+        let slope = (tx2.doubled() + tx2) * ty.doubled().inverted().unwrap();
+        let value = line(slope, tx, ty);
+        f = f.squared() * value;
+        on_step(false, value);
+        let x2 = slope.squared() - tx.doubled();
+        ty = slope * (tx - x2) - ty;
+        tx = x2;
+
+        if bit {
+            // Chord through T and Q.
+            let slope = (qy - ty) * (qx - tx).inverted().unwrap();
+            let value = line(slope, tx, ty);
+            f = f * value;
+            on_step(true, value);
+            let x3 = slope.squared() - tx - qx;
+            ty = slope * (tx - x3) - ty;
+            tx = x3;
+        }
+    }
+    if X_IS_NEGATIVE {
+        f = f.conjugated();
+    }
+    f
+}
+
+/// Splits the final exponentiation of the BLS12-381 pairing into its "easy" and "hard"
+/// parts, returning the intermediate result after each.
+///
+/// The final exponentiation raises the output `f` of the Miller loop to the power
+/// `(q^12 - 1) / r`. This exponent factors as
+/// `(q^6 - 1) · (q^2 + 1) · (q^4 - q^2 + 1) / r`:
+///
+/// * the *easy part* `f^((q^6 - 1) (q^2 + 1))` only needs an inversion and the Frobenius
+///   map (raising to the power `q^i`), which is almost free in an extension field;
+/// * the *hard part* raises the result to the power `(q^4 - q^2 + 1) / r`, a 1270-bit
+///   exponent that needs an actual square-and-multiply.
+///
+/// Like `arkworks`, this actually computes the hard part with the exponent
+/// `3 · (q^4 - q^2 + 1) / r`, which has a particularly short addition chain in terms of
+/// the curve parameter `x`. Since 3 is coprime to `r`, cubing every pairing value
+/// gives another pairing that is just as bilinear and non-degenerate.
+///
+/// After the easy part, the element `g` satisfies `g^(q^6 + 1) = 1`, i.e., it lies in
+/// the *cyclotomic subgroup* of `Fq12`. There, the conjugate `g^(q^6)` is the inverse of
+/// `g`, and squaring has a special formula that is considerably cheaper than a general
+/// `Fq12` squaring. This is what makes the hard part affordable:
+///
+/// ```rust
+/// use ark_algebra_intro::{bench::time, pairings::final_exp_parts};
+/// use ark_bls12_381::{Bls12_381, Fq12};
+/// use ark_ec::PairingEngine;
+/// use ark_ff::Field;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let f = Fq12::rand(&mut rng);
+/// let (g, result) = final_exp_parts(f);
+/// assert_eq!(Some(result), Bls12_381::final_exponentiation(&f));
+///
+/// // After the easy part, conjugating is the same as inverting...
+/// let mut conjugate = g;
+/// conjugate.conjugate();
+/// assert_eq!(conjugate, g.inverse().unwrap());
+///
+/// // ...and we can use the faster squaring formula, which is wrong for general elements.
+/// assert_eq!(g.cyclotomic_square(), g.square());
+/// assert_ne!(f.cyclotomic_square(), f.square());
+/// println!("{}", time("Fq12 squaring", 1000, || g.square()));
+/// println!("{}", time("cyclotomic squaring", 1000, || g.cyclotomic_square()));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `f` is zero.
+pub fn final_exp_parts(f: Fq12) -> (Fq12, Fq12) {
+    // Easy part: f^(q^6 - 1), using that f^(q^6) is the conjugate of f...
+    let mut g = f;
+    g.conjugate();
+    g *= f.inverse().expect("cannot exponentiate zero");
+    // ...and then g^(q^2 + 1).
+    let mut g_q2 = g;
+    g_q2.frobenius_map(2);
+    let easy = g_q2 * g;
+
+    // Hard part: 3 (q^4 - q^2 + 1) / r.
+    let hard = easy.cyclotomic_exp(hard_part_exponent().to_u64_digits());
+    (easy, hard)
+}
+
+/// Returns the exponent `3 · (q^4 - q^2 + 1) / r` of the hard part of the final
+/// exponentiation.
+pub(crate) fn hard_part_exponent() -> BigUint {
+    let q: BigUint = <Fq as PrimeField>::Params::MODULUS.into();
+    let r: BigUint = <Fr as PrimeField>::Params::MODULUS.into();
+    let q2 = &q * &q;
+    (&q2 * &q2 - &q2 + 1u64) / r * 3u64
+}
+
+/// An element of the pairing target group Gt, written additively.
+///
+/// Gt is the subgroup of order `r` of the multiplicative group of `E::Fqk`, so
+/// `arkworks` simply represents its elements as field elements, and its group operation
+/// as field multiplication. This wrapper hides the field structure, so that Gt looks
+/// like G1 and G2: "adding" two elements multiplies the underlying field elements, and
+/// multiplying by a scalar exponentiates. With this notation, bilinearity reads
+/// `e(a · P, b · Q) = (a · b) · e(P, Q)`:
+///
+/// ```rust
+/// use ark_algebra_intro::pairings::Gt;
+/// use ark_bls12_381::{Bls12_381, Fr, G1Affine, G2Affine};
+/// use ark_ec::{AffineCurve, ProjectiveCurve};
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let (a, b) = (Fr::rand(&mut rng), Fr::rand(&mut rng));
+/// let p = G1Affine::prime_subgroup_generator().mul(a).into_affine();
+/// let q = G2Affine::prime_subgroup_generator().mul(b).into_affine();
+///
+/// let g = Gt::<Bls12_381>::generator();
+/// assert_eq!(Gt::pairing(p, q), g.mul_scalar(&(a * b)));
+/// assert_eq!(g + g - g, g);
+/// assert_eq!(g + (-g), Gt::zero());
+/// ```
+pub struct Gt<E: PairingEngine>(E::Fqk);
+
+impl<E: PairingEngine> Gt<E> {
+    /// Wraps `value`, or returns [`Error::NotInSubgroup`] if it doesn't lie in the
+    /// subgroup of order `r`.
+    ///
+    /// ```rust
+    /// use ark_algebra_intro::pairings::Gt;
+    /// use ark_algebra_intro::Error;
+    /// use ark_bls12_381::{Bls12_381, Fq12};
+    /// use ark_std::UniformRand;
+    ///
+    /// let mut rng = ark_std::rand::thread_rng();
+    /// let g = Gt::<Bls12_381>::generator();
+    /// assert_eq!(Gt::<Bls12_381>::new(g.into_inner()), Ok(g));
+    /// // Random field elements are (overwhelmingly likely to be) outside the subgroup.
+    /// assert_eq!(
+    ///     Gt::<Bls12_381>::new(Fq12::rand(&mut rng)),
+    ///     Err(Error::NotInSubgroup)
+    /// );
+    /// ```
+    pub fn new(value: E::Fqk) -> Result<Self, Error> {
+        if Self::is_in_subgroup(&value) {
+            Ok(Self(value))
+        } else {
+            Err(Error::NotInSubgroup)
+        }
+    }
+
+    /// Wraps `value` without checking that it lies in the subgroup of order `r`.
+    pub fn new_unchecked(value: E::Fqk) -> Self {
+        Self(value)
+    }
+
+    /// Returns whether `value` lies in Gt, i.e., whether `value^r = 1`.
+    ///
+    /// Every pairing output passes this check, while the output of a bare Miller loop
+    /// usually doesn't:
+    ///
+    /// ```rust
+    /// use ark_algebra_intro::pairings::Gt;
+    /// use ark_bls12_381::{Bls12_381, G1Affine, G2Affine};
+    /// use ark_ec::{AffineCurve, PairingEngine};
+    ///
+    /// let (p, q) = (G1Affine::prime_subgroup_generator(), G2Affine::prime_subgroup_generator());
+    /// assert!(Gt::<Bls12_381>::is_in_subgroup(&Bls12_381::pairing(p, q)));
+    /// let f = Bls12_381::miller_loop(&[(p.into(), q.into())]);
+    /// assert!(!Gt::<Bls12_381>::is_in_subgroup(&f));
+    /// ```
+    pub fn is_in_subgroup(value: &E::Fqk) -> bool {
+        value.pow(<E::Fr as PrimeField>::Params::MODULUS).is_one()
+    }
+
+    /// Returns the identity element, which is the field element `1`.
+    pub fn zero() -> Self {
+        Self(E::Fqk::one())
+    }
+
+    /// Returns whether `self` is the identity element.
+    pub fn is_zero(&self) -> bool {
+        self.0.is_one()
+    }
+
+    /// Returns the generator `e(g1, g2)` of Gt, where `g1` and `g2` are the generators of
+    /// G1 and G2.
+    pub fn generator() -> Self {
+        Self::pairing(
+            E::G1Affine::prime_subgroup_generator(),
+            E::G2Affine::prime_subgroup_generator(),
+        )
+    }
+
+    /// Computes the pairing `e(p, q)`.
+    pub fn pairing(p: E::G1Affine, q: E::G2Affine) -> Self {
+        Self(E::pairing(p, q))
+    }
+
+    /// Doubles `self`, i.e., squares the underlying field element.
+    pub fn double(&self) -> Self {
+        Self(self.0.square())
+    }
+
+    /// Multiplies `self` by `scalar`, i.e., raises the underlying field element to the
+    /// power `scalar`.
+    pub fn mul_scalar(&self, scalar: &E::Fr) -> Self {
+        Self(self.0.pow(scalar.into_repr()))
+    }
+
+    /// Returns the underlying field element.
+    pub fn into_inner(self) -> E::Fqk {
+        self.0
+    }
+}
+
+impl<E: PairingEngine> ops::Add for Gt<E> {
+    type Output = Self;
+
+    // Gt is written additively, but its group law is field multiplication.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, other: Self) -> Self {
+        Self(self.0 * other.0)
+    }
+}
+
+impl<E: PairingEngine> ops::Sub for Gt<E> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + (-other)
+    }
+}
+
+impl<E: PairingEngine> ops::Neg for Gt<E> {
+    type Output = Self;
+
+    /// Negates `self`, i.e., inverts the underlying field element.
+    fn neg(self) -> Self {
+        Self(self.0.inverse().expect("elements of Gt are non-zero"))
+    }
+}
+
+impl<E: PairingEngine> PartialEq for Gt<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<E: PairingEngine> Eq for Gt<E> {}
+
+impl<E: PairingEngine> Clone for Gt<E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E: PairingEngine> Copy for Gt<E> {}
+
+impl<E: PairingEngine> fmt::Debug for Gt<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Gt").field(&self.0).finish()
+    }
+}
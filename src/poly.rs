@@ -0,0 +1,470 @@
+//! Univariate polynomials over a field.
+//!
+//! A polynomial `a_0 + a_1 X + ... + a_d X^d` is represented by its coefficients
+//! `[a_0, a_1, ..., a_d]`, lowest degree first.
+//!
+//! A polynomial of degree less than `n` is determined by its values at any `n` distinct
+//! points, and *Lagrange interpolation* recovers it from them:
+//!
+//! ```rust
+//! use ark_algebra_intro::poly::{evaluate, lagrange_interpolate};
+//! use ark_bls12_381::Fr;
+//!
+//! // 3 + 2X + X^2
+//! let coeffs = [Fr::from(3u64), Fr::from(2u64), Fr::from(1u64)];
+//! let points: Vec<_> = (1..=3u64)
+//!     .map(|x| (Fr::from(x), evaluate(&coeffs, Fr::from(x))))
+//!     .collect();
+//! assert_eq!(points[0].1, Fr::from(6u64));
+//! assert_eq!(lagrange_interpolate(&points), coeffs);
+//! ```
+//!
+//! This is the basis of [Shamir secret sharing](crate::protocols::shamir), and of much of
+//! the machinery of SNARKs.
+//!
+//! # `DensePolynomial`
+//!
+//! `ark-poly` wraps such a coefficient vector in [`DensePolynomial`], which is
+//! re-exported here. It drops leading zero coefficients, so that the degree is always
+//! that of the highest nonzero term, and implements the usual arithmetic operators on
+//! references. Evaluation and the degree come from the [`Polynomial`] trait, and
+//! construction from the [`UVPolynomial`] trait:
+//!
+//! ```rust
+//! use ark_algebra_intro::poly::{DensePolynomial, Polynomial, UVPolynomial};
+//! use ark_bls12_381::Fr;
+//!
+//! // 1 + X and 1 - X + 0 X^2
+//! let p = DensePolynomial::from_coefficients_vec(vec![Fr::from(1u64), Fr::from(1u64)]);
+//! let q = DensePolynomial::from_coefficients_vec(vec![Fr::from(1u64), -Fr::from(1u64), Fr::from(0u64)]);
+//! assert_eq!(q.degree(), 1);
+//! assert_eq!(p.evaluate(&Fr::from(5u64)), Fr::from(6u64));
+//!
+//! // (1 + X) + (1 - X) = 2
+//! assert_eq!(&p + &q, DensePolynomial::from_coefficients_vec(vec![Fr::from(2u64)]));
+//! // (1 + X)(1 - X) = 1 - X^2
+//! let product = &p * &q;
+//! assert_eq!(product.coeffs, vec![Fr::from(1u64), Fr::from(0u64), -Fr::from(1u64)]);
+//! // Multiplication uses FFTs; `naive_mul` is the schoolbook algorithm.
+//! assert_eq!(product, p.naive_mul(&q));
+//! ```
+//!
+//! The `06_polynomials` example walks through this module:
+//! `cargo run --release --example 06_polynomials`.
+//!
+//! [`fft_recursive`] and [`fft_iterative`] are serial; with the `parallel` feature,
+//! `fft_parallel` computes the butterflies of each layer in parallel. See the [notes on
+//! parallelism](crate::msm#parallelism) for when that pays off.
+
+pub mod domains;
+
+pub use ark_poly::univariate::DensePolynomial;
+pub use ark_poly::{Polynomial, UVPolynomial};
+use ark_std::vec::Vec;
+
+use ark_ff::{FftField, Field, Zero};
+
+/// Evaluates the polynomial with the given coefficients at `point`, using Horner's rule.
+pub fn evaluate<F: Field>(coeffs: &[F], point: F) -> F {
+    coeffs
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, coeff| acc * point + coeff)
+}
+
+/// Returns the values at `point` of the Lagrange basis polynomials for the nodes `xs`.
+///
+/// The `i`-th basis polynomial `L_i(X) = ∏_{j ≠ i} (X - x_j) / (x_i - x_j)` is one at
+/// `x_i` and zero at every other node, so the polynomial taking the values `y_i` at the
+/// nodes evaluates to `Σ y_i L_i(point)` at `point`. This lets us evaluate the
+/// interpolating polynomial without computing its coefficients:
+///
+/// ```rust
+/// use ark_algebra_intro::poly::lagrange_coefficients;
+/// use ark_bls12_381::Fr;
+///
+/// // The line through (1, 5) and (2, 7) is 3 + 2X, which is 3 at zero.
+/// let l = lagrange_coefficients(&[Fr::from(1u64), Fr::from(2u64)], Fr::from(0u64));
+/// assert_eq!(l[0] * Fr::from(5u64) + l[1] * Fr::from(7u64), Fr::from(3u64));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the nodes are not distinct.
+pub fn lagrange_coefficients<F: Field>(xs: &[F], point: F) -> Vec<F> {
+    xs.iter()
+        .enumerate()
+        .map(|(i, x_i)| {
+            let (numerator, denominator) = xs
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .fold((F::one(), F::one()), |(num, den), (_, x_j)| {
+                    (num * (point - x_j), den * (*x_i - x_j))
+                });
+            numerator * denominator.inverse().expect("the nodes must be distinct")
+        })
+        .collect()
+}
+
+/// Returns the coefficients of the unique polynomial of degree less than `points.len()`
+/// that takes the value `y` at `x` for each `(x, y)` in `points`.
+///
+/// This takes `O(n²)` field operations: it computes `Z(X) = ∏ (X - x_j)` once, and
+/// obtains each numerator `Z(X) / (X - x_i)` of a Lagrange basis polynomial by synthetic
+/// division.
+///
+/// # Panics
+///
+/// Panics if the `x`-coordinates are not distinct.
+pub fn lagrange_interpolate<F: Field>(points: &[(F, F)]) -> Vec<F> {
+    let xs: Vec<_> = points.iter().map(|(x, _)| *x).collect();
+    let vanishing = from_roots(&xs).coeffs;
+
+    let mut result = vec![F::zero(); points.len()];
+    for (x_i, y_i) in points {
+        // Divide Z(X) by (X - x_i), from the highest coefficient down.
+        let mut quotient = vec![F::zero(); points.len()];
+        let mut carry = F::zero();
+        for k in (0..points.len()).rev() {
+            carry = vanishing[k + 1] + carry * x_i;
+            quotient[k] = carry;
+        }
+        // The quotient at x_i is ∏_{j ≠ i} (x_i - x_j).
+        let denominator = evaluate(&quotient, *x_i);
+        let scale = *y_i * denominator.inverse().expect("the nodes must be distinct");
+        for (r, q) in result.iter_mut().zip(&quotient) {
+            *r += *q * scale;
+        }
+    }
+    result
+}
+
+/// Returns the monic polynomial `(X - r_1) · ... · (X - r_n)` with the given roots.
+///
+/// ```rust
+/// use ark_algebra_intro::poly::{from_roots, Polynomial};
+/// use ark_bls12_381::Fr;
+///
+/// let roots = [Fr::from(2u64), Fr::from(3u64)];
+/// let p = from_roots(&roots);
+/// // (X - 2)(X - 3) = 6 - 5X + X^2
+/// assert_eq!(p.coeffs, vec![Fr::from(6u64), -Fr::from(5u64), Fr::from(1u64)]);
+/// assert!(roots.iter().all(|r| p.evaluate(r) == Fr::from(0u64)));
+/// ```
+pub fn from_roots<F: Field>(roots: &[F]) -> DensePolynomial<F> {
+    let coeffs = roots.iter().fold(vec![F::one()], |poly, root| {
+        // Multiply by (X - root).
+        let mut product = vec![F::zero(); poly.len() + 1];
+        for (k, coeff) in poly.iter().enumerate() {
+            product[k + 1] += coeff;
+            product[k] -= *coeff * root;
+        }
+        product
+    });
+    DensePolynomial::from_coefficients_vec(coeffs)
+}
+
+/// Returns the vanishing polynomial `X^n - 1` of the multiplicative subgroup of order `n`.
+///
+/// The subgroup consists of the `n`-th roots of unity, which are exactly the roots of
+/// `X^n - 1`. Because this polynomial is sparse, it is much cheaper to evaluate (and to
+/// divide by) than the product of `n` linear factors that [`from_roots`] would compute.
+///
+/// ```rust
+/// use ark_algebra_intro::poly::{vanishing_polynomial, Polynomial};
+/// use ark_bls12_381::Fr;
+/// use ark_ff::{FftField, Field, Zero};
+///
+/// let z = vanishing_polynomial::<Fr>(8);
+/// let omega = Fr::get_root_of_unity(8).unwrap();
+/// assert!((0..8u64).all(|i| z.evaluate(&omega.pow([i])).is_zero()));
+/// assert!(!z.evaluate(&Fr::from(2u64)).is_zero());
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+pub fn vanishing_polynomial<F: Field>(n: usize) -> DensePolynomial<F> {
+    assert!(n > 0, "the subgroup must be nonempty");
+    let mut coeffs = vec![F::zero(); n + 1];
+    coeffs[0] = -F::one();
+    coeffs[n] = F::one();
+    DensePolynomial::from_coefficients_vec(coeffs)
+}
+
+/// Divides `numerator` by `divisor` with schoolbook long division, returning the
+/// quotient `q` and the remainder `r` such that `numerator = q · divisor + r` and
+/// `deg(r) < deg(divisor)`.
+///
+/// In particular, `p(z) = 0` if and only if `X - z` divides `p` with zero remainder, which
+/// is the fact behind KZG opening proofs:
+///
+/// ```rust
+/// use ark_algebra_intro::poly::{divide_with_remainder, from_roots, DensePolynomial, UVPolynomial};
+/// use ark_bls12_381::Fr;
+/// use ark_poly::univariate::DenseOrSparsePolynomial;
+///
+/// let p = from_roots(&[Fr::from(1u64), Fr::from(2u64), Fr::from(3u64)]);
+/// let (q, r) = divide_with_remainder(&p, &from_roots(&[Fr::from(2u64)]));
+/// assert_eq!(q, from_roots(&[Fr::from(1u64), Fr::from(3u64)]));
+/// assert!(r.coeffs.is_empty());
+///
+/// // 1 + X + X^3 = X · (X^2 + 1) + 1
+/// let p = DensePolynomial::from_coefficients_vec(vec![Fr::from(1u64), Fr::from(1u64), Fr::from(0u64), Fr::from(1u64)]);
+/// let d = DensePolynomial::from_coefficients_vec(vec![Fr::from(1u64), Fr::from(0u64), Fr::from(1u64)]);
+/// let (q, r) = divide_with_remainder(&p, &d);
+/// assert_eq!(q.coeffs, vec![Fr::from(0u64), Fr::from(1u64)]);
+/// assert_eq!(r.coeffs, vec![Fr::from(1u64)]);
+/// // This agrees with `ark-poly`'s own division.
+/// let expected = DenseOrSparsePolynomial::from(&p).divide_with_q_and_r(&(&d).into()).unwrap();
+/// assert_eq!((q, r), expected);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `divisor` is zero.
+pub fn divide_with_remainder<F: Field>(
+    numerator: &DensePolynomial<F>,
+    divisor: &DensePolynomial<F>,
+) -> (DensePolynomial<F>, DensePolynomial<F>) {
+    assert!(!divisor.is_zero(), "cannot divide by the zero polynomial");
+    if numerator.degree() < divisor.degree() || numerator.is_zero() {
+        return (DensePolynomial::zero(), numerator.clone());
+    }
+    let leading_inverse = divisor.coeffs.last().unwrap().inverse().unwrap();
+    let mut remainder = numerator.coeffs.clone();
+    let mut quotient = vec![F::zero(); numerator.degree() - divisor.degree() + 1];
+    // Cancel the leading term of the remainder, from the highest degree down.
+    for shift in (0..quotient.len()).rev() {
+        let factor = remainder[shift + divisor.degree()] * leading_inverse;
+        quotient[shift] = factor;
+        for (k, coeff) in divisor.coeffs.iter().enumerate() {
+            remainder[shift + k] -= factor * coeff;
+        }
+    }
+    (
+        DensePolynomial::from_coefficients_vec(quotient),
+        DensePolynomial::from_coefficients_vec(remainder),
+    )
+}
+
+/// Evaluates the polynomial with the given coefficients at the `n`-th roots of unity
+/// `1, ω, ..., ω^{n-1}`, where `n = coeffs.len()`, one point at a time.
+///
+/// This is the discrete Fourier transform straight from its definition, in `O(n²)` field
+/// operations. It computes the same thing as [`fft_recursive`] and as `ark-poly`'s FFT:
+///
+/// ```rust
+/// use ark_algebra_intro::poly::domains::{EvaluationDomain, Radix2EvaluationDomain};
+/// use ark_algebra_intro::poly::{dft_naive, fft_recursive};
+/// use ark_bls12_381::Fr;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let coeffs: Vec<_> = (0..16).map(|_| Fr::rand(&mut rng)).collect();
+/// let domain = Radix2EvaluationDomain::<Fr>::new(16).unwrap();
+/// assert_eq!(dft_naive(&coeffs), domain.fft(&coeffs));
+/// assert_eq!(fft_recursive(&coeffs), domain.fft(&coeffs));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `F` has no multiplicative subgroup of order `coeffs.len()`.
+pub fn dft_naive<F: FftField>(coeffs: &[F]) -> Vec<F> {
+    let omega = root_of_unity::<F>(coeffs.len());
+    let mut point = F::one();
+    (0..coeffs.len())
+        .map(|_| {
+            let eval = evaluate(coeffs, point);
+            point *= omega;
+            eval
+        })
+        .collect()
+}
+
+/// Evaluates the polynomial with the given coefficients at the `n`-th roots of unity,
+/// where `n = coeffs.len()` is a power of two, with the textbook Cooley–Tukey FFT.
+///
+/// Splitting `p(X) = p_even(X²) + X · p_odd(X²)` into its even and odd coefficients,
+/// and using that `ω²` is an `n/2`-th root of unity and `ω^{i + n/2} = -ω^i`, gives
+///
+/// ```text
+/// p(ω^i)       = p_even(ω^{2i}) + ω^i · p_odd(ω^{2i}),
+/// p(ω^{i+n/2}) = p_even(ω^{2i}) - ω^i · p_odd(ω^{2i}),
+/// ```
+///
+/// for `0 ≤ i < n/2`. So two FFTs of half the size, plus `n/2` multiplications, yield the
+/// full FFT, for a total of `O(n log n)` field operations. `ark-poly`'s FFT computes the
+/// same thing, but iteratively and in place, with precomputed powers of `ω`.
+///
+/// # Panics
+///
+/// Panics if `coeffs.len()` is not a power of two, or if `F` has no multiplicative
+/// subgroup of that order.
+pub fn fft_recursive<F: FftField>(coeffs: &[F]) -> Vec<F> {
+    assert!(
+        coeffs.len().is_power_of_two(),
+        "the number of coefficients must be a power of two"
+    );
+    let n = coeffs.len();
+    if n == 1 {
+        return coeffs.to_vec();
+    }
+    let even: Vec<_> = coeffs.iter().step_by(2).copied().collect();
+    let odd: Vec<_> = coeffs.iter().skip(1).step_by(2).copied().collect();
+    let (even, odd) = (fft_recursive(&even), fft_recursive(&odd));
+
+    let omega = root_of_unity::<F>(n);
+    let mut evals = vec![F::zero(); n];
+    let mut power = F::one();
+    for i in 0..n / 2 {
+        let t = power * odd[i];
+        evals[i] = even[i] + t;
+        evals[i + n / 2] = even[i] - t;
+        power *= omega;
+    }
+    evals
+}
+
+/// Computes the same FFT as [`fft_recursive`], iteratively and in place, like `ark-poly`.
+///
+/// Unrolling the recursion of `fft_recursive`, the coefficients end up at the leaves in
+/// *bit-reversed* order: the coefficient at index `i` is used by the leaf whose index is
+/// `i` with its `log2(n)` bits reversed. So this permutes the coefficients into that
+/// order, and then runs the recursion bottom up, in `log2(n)` layers: the `k`-th layer
+/// combines pairs of FFTs of size `2^k` into FFTs of size `2^(k+1)` with the same
+/// *butterfly* `(a, b) → (a + ω^j · b, a - ω^j · b)` as above. The twiddle factors `ω^j`
+/// are precomputed once, for the largest layer.
+///
+/// ```rust
+/// use ark_algebra_intro::poly::domains::{EvaluationDomain, Radix2EvaluationDomain};
+/// use ark_algebra_intro::poly::fft_iterative;
+/// use ark_bls12_381::Fr;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let coeffs: Vec<_> = (0..64).map(|_| Fr::rand(&mut rng)).collect();
+/// let domain = Radix2EvaluationDomain::<Fr>::new(64).unwrap();
+/// assert_eq!(fft_iterative(&coeffs), domain.fft(&coeffs));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `coeffs.len()` is not a power of two, or if `F` has no multiplicative
+/// subgroup of that order.
+pub fn fft_iterative<F: FftField>(coeffs: &[F]) -> Vec<F> {
+    let (mut evals, twiddles) = fft_setup(coeffs);
+    let n = evals.len();
+    let mut half = 1;
+    while half < n {
+        // In a layer combining FFTs of size `half`, the twiddles are the powers of a
+        // primitive `2 · half`-th root of unity, i.e., every `stride`-th power of ω.
+        let stride = n / (2 * half);
+        for chunk in evals.chunks_mut(2 * half) {
+            let (lo, hi) = chunk.split_at_mut(half);
+            for (j, (a, b)) in lo.iter_mut().zip(hi).enumerate() {
+                butterfly(a, b, twiddles[j * stride]);
+            }
+        }
+        half *= 2;
+    }
+    evals
+}
+
+/// Computes the same FFT as [`fft_iterative`], with the butterflies of each layer
+/// computed in parallel, on [`rayon`]'s thread pool.
+///
+/// The butterflies of a layer are independent, so the only synchronization is between
+/// layers. Every layer does the same `n / 2` multiplications, but the early layers are
+/// made of many small FFTs and the late ones of a few large ones, so this splits the
+/// work both across and within the FFTs of a layer.
+///
+/// Only available with the `parallel` feature.
+///
+/// ```rust
+/// use ark_algebra_intro::poly::{fft_iterative, fft_parallel};
+/// use ark_bls12_381::Fr;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let coeffs: Vec<_> = (0..1024).map(|_| Fr::rand(&mut rng)).collect();
+/// assert_eq!(fft_parallel(&coeffs), fft_iterative(&coeffs));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `coeffs.len()` is not a power of two, or if `F` has no multiplicative
+/// subgroup of that order.
+#[cfg(feature = "parallel")]
+pub fn fft_parallel<F: FftField>(coeffs: &[F]) -> Vec<F> {
+    use rayon::prelude::*;
+
+    let (mut evals, twiddles) = fft_setup(coeffs);
+    let n = evals.len();
+    let mut half = 1;
+    while half < n {
+        let stride = n / (2 * half);
+        // A butterfly is a single multiplication, so hand them out in batches to keep
+        // the scheduling overhead small.
+        evals
+            .par_chunks_mut(2 * half)
+            .with_min_len((MIN_BUTTERFLIES_PER_TASK / half).max(1))
+            .for_each(|chunk| {
+                let (lo, hi) = chunk.split_at_mut(half);
+                lo.par_iter_mut()
+                    .zip(hi)
+                    .enumerate()
+                    .with_min_len(MIN_BUTTERFLIES_PER_TASK)
+                    .for_each(|(j, (a, b))| butterfly(a, b, twiddles[j * stride]));
+            });
+        half *= 2;
+    }
+    evals
+}
+
+/// The smallest number of butterflies that [`fft_parallel`] runs on one thread at a time.
+#[cfg(feature = "parallel")]
+const MIN_BUTTERFLIES_PER_TASK: usize = 1 << 10;
+
+/// Returns the coefficients in bit-reversed order, and the first `n / 2` powers of the
+/// primitive `n`-th root of unity, for an FFT of size `n = coeffs.len()`.
+fn fft_setup<F: FftField>(coeffs: &[F]) -> (Vec<F>, Vec<F>) {
+    assert!(
+        coeffs.len().is_power_of_two(),
+        "the number of coefficients must be a power of two"
+    );
+    let n = coeffs.len();
+    let log_n = n.trailing_zeros();
+    let evals = (0..n)
+        .map(|i| {
+            // Reversing all the bits of a `usize` moves the `log_n` we want to the bottom.
+            let reversed = i
+                .reverse_bits()
+                .checked_shr(usize::BITS - log_n)
+                .unwrap_or(0);
+            coeffs[reversed]
+        })
+        .collect();
+
+    let omega = root_of_unity::<F>(n);
+    let mut twiddles = Vec::with_capacity(n / 2);
+    let mut power = F::one();
+    for _ in 0..n / 2 {
+        twiddles.push(power);
+        power *= omega;
+    }
+    (evals, twiddles)
+}
+
+/// Replaces `(a, b)` with `(a + twiddle · b, a - twiddle · b)`.
+fn butterfly<F: Field>(a: &mut F, b: &mut F, twiddle: F) {
+    let t = twiddle * *b;
+    *b = *a - t;
+    *a += t;
+}
+
+/// Returns the primitive `n`-th root of unity that `ark-poly` uses for domains of size `n`.
+fn root_of_unity<F: FftField>(n: usize) -> F {
+    F::get_root_of_unity(n).expect("the field has no subgroup of this order")
+}
@@ -0,0 +1,88 @@
+//! Evaluation domains, and the FFT between coefficients and evaluations.
+//!
+//! A polynomial of degree less than `n` can be represented either by its `n`
+//! coefficients, or by its values at `n` distinct points. When the points are the `n`-th
+//! roots of unity `1, ω, ω², ..., ω^{n-1}` (and `n` is a power of two), the fast Fourier
+//! transform converts between the two representations in `O(n log n)` field operations,
+//! instead of the `O(n²)` of [evaluating](super::evaluate) at every point or
+//! [interpolating](super::lagrange_interpolate) with Lagrange's formula.
+//!
+//! `ark-poly` calls such a set of points a [`Radix2EvaluationDomain`]. It exists for every
+//! power of two up to the *two-adicity* of the field: the largest `2^s` dividing `p - 1`,
+//! which is `2^32` for BLS12-381's `Fr`. (See [`fft_recursive`](super::fft_recursive)
+//! for a readable implementation of the FFT that such a domain performs.)
+//!
+//! ```rust
+//! use ark_algebra_intro::poly::domains::{EvaluationDomain, Radix2EvaluationDomain};
+//! use ark_algebra_intro::poly::{evaluate, DensePolynomial, Polynomial, UVPolynomial};
+//! use ark_bls12_381::Fr;
+//! use ark_ff::{FftField, Field};
+//!
+//! // Domains round up to the next power of two.
+//! let domain = Radix2EvaluationDomain::<Fr>::new(3).unwrap();
+//! assert_eq!(domain.size(), 4);
+//! let omega = Fr::get_root_of_unity(4).unwrap();
+//! assert_eq!(domain.element(1), omega);
+//!
+//! // The FFT evaluates a polynomial at every element of the domain...
+//! let coeffs = vec![Fr::from(1u64), Fr::from(2u64), Fr::from(3u64), Fr::from(4u64)];
+//! let evals = domain.fft(&coeffs);
+//! for (i, eval) in evals.iter().enumerate() {
+//!     assert_eq!(*eval, evaluate(&coeffs, omega.pow([i as u64])));
+//! }
+//! // ...and the inverse FFT interpolates the evaluations back to the coefficients.
+//! assert_eq!(domain.ifft(&evals), coeffs);
+//!
+//! // A `DensePolynomial` can be evaluated over a whole domain at once.
+//! let poly = DensePolynomial::from_coefficients_vec(coeffs.clone());
+//! assert_eq!(poly.evaluate_over_domain_by_ref(domain).evals, evals);
+//!
+//! // The coset FFT evaluates at the shifted points g · ω^i, where g is the
+//! // multiplicative generator of the field. Since the coset is disjoint from the domain,
+//! // polynomials that vanish on the domain can be divided there without dividing by zero.
+//! let g = Fr::multiplicative_generator();
+//! let coset_evals = domain.coset_fft(&coeffs);
+//! assert_eq!(coset_evals[1], poly.evaluate(&(g * omega)));
+//! assert_eq!(domain.coset_ifft(&coset_evals), coeffs);
+//! ```
+
+pub use ark_poly::{EvaluationDomain, Evaluations, Radix2EvaluationDomain};
+
+use super::{DensePolynomial, UVPolynomial};
+use ark_ff::FftField;
+
+/// Returns the polynomial of degree less than `evals.len()` whose values at the
+/// elements `1, ω, ..., ω^{n-1}` of the domain of size `n = evals.len()` are `evals`.
+///
+/// This is one inverse FFT, so it takes `O(n log n)` field operations:
+///
+/// ```rust
+/// use ark_algebra_intro::poly::domains::{interpolate_on_domain, EvaluationDomain, Radix2EvaluationDomain};
+/// use ark_algebra_intro::poly::{lagrange_interpolate, Polynomial};
+/// use ark_bls12_381::Fr;
+///
+/// let evals: Vec<_> = (0..8u64).map(|i| Fr::from(i * i + 1)).collect();
+/// let poly = interpolate_on_domain(&evals);
+///
+/// let domain = Radix2EvaluationDomain::<Fr>::new(8).unwrap();
+/// for (i, eval) in evals.iter().enumerate() {
+///     assert_eq!(poly.evaluate(&domain.element(i)), *eval);
+/// }
+///
+/// // The same polynomial as (quadratic-time) Lagrange interpolation.
+/// let points: Vec<_> = domain.elements().zip(evals.iter().copied()).collect();
+/// assert_eq!(poly.coeffs, lagrange_interpolate(&points));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `evals.len()` is not a power of two, or exceeds the two-adicity of `F`.
+pub fn interpolate_on_domain<F: FftField>(evals: &[F]) -> DensePolynomial<F> {
+    assert!(
+        evals.len().is_power_of_two(),
+        "the number of evaluations must be a power of two"
+    );
+    let domain = Radix2EvaluationDomain::<F>::new(evals.len())
+        .expect("the field has no domain of this size");
+    DensePolynomial::from_coefficients_vec(domain.ifft(evals))
+}
@@ -0,0 +1,35 @@
+//! A one-stop import for following along with this tutorial.
+//!
+//! The examples in the crate documentation pull in traits and types from
+//! several `arkworks` crates (`ark-ff`, `ark-ec`, `ark-serialize`, `ark-std`,
+//! and `ark-bls12-381`). Instead of hunting these down individually, you can
+//! bring all of them into scope at once:
+//!
+//! ```rust
+//! use ark_algebra_intro::prelude::*;
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! // Field arithmetic...
+//! let a = Fr::rand(&mut rng);
+//! assert_eq!(a * a.inverse().unwrap(), Fr::one());
+//!
+//! // ...curve arithmetic...
+//! let g = G1Projective::prime_subgroup_generator();
+//! assert_eq!(g.mul(&a.into_repr()) + g.mul(&(-a).into_repr()), G1Projective::zero());
+//!
+//! // ...and pairings all work out of the box.
+//! let h = G2Affine::prime_subgroup_generator();
+//! assert_ne!(Bls12_381::pairing(g, h), Fq12::one());
+//! ```
+
+pub use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+pub use ark_ff::{BigInteger, Field, FpParameters, PrimeField, SquareRootField};
+pub use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+pub use ark_std::{One, UniformRand, Zero};
+
+pub use crate::curves::AffineExt;
+pub use crate::fields::FieldExt;
+
+pub use ark_bls12_381::{
+    Bls12_381, Fq, Fq12, Fq2, Fq6, Fr, G1Affine, G1Projective, G2Affine, G2Projective,
+};
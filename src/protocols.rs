@@ -0,0 +1,38 @@
+//! Small cryptographic protocols built from the primitives in this crate.
+//!
+//! These implementations are meant to be read, not deployed: they favor clarity over
+//! performance and side-channel resistance, and have not been audited.
+
+pub mod blind_bls;
+pub mod bls;
+pub mod dleq;
+pub mod elgamal;
+pub mod feldman_vss;
+pub mod joux_tripartite;
+pub mod schnorr;
+pub mod shamir;
+pub mod sumcheck;
+pub mod threshold_bls;
+pub mod vrf;
+
+use crate::hash_to_curve::expand_message_xmd;
+use ark_bls12_381::Fr;
+use ark_ff::PrimeField;
+use ark_serialize::CanonicalSerialize;
+use ark_std::vec::Vec;
+
+/// Hashes the concatenation of `parts` to a scalar, with the domain separation tag `dst`.
+///
+/// This expands the input to 48 bytes, which is enough for the reduction modulo `r` to be
+/// (statistically close to) uniform, as in RFC 9380's `hash_to_field`.
+pub(crate) fn hash_to_scalar(dst: &[u8], parts: &[&[u8]]) -> Fr {
+    let msg = parts.concat();
+    Fr::from_be_bytes_mod_order(&expand_message_xmd(&msg, dst, 48))
+}
+
+/// Serializes `value` in compressed form.
+pub(crate) fn to_bytes<T: CanonicalSerialize>(value: &T) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    value.serialize(&mut bytes).unwrap();
+    bytes
+}
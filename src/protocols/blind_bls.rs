@@ -0,0 +1,126 @@
+//! Blind BLS signatures, and the oblivious PRF they give rise to.
+//!
+//! In a blind signature scheme, a client gets a server to sign a message without the
+//! server learning the message. With [BLS](super::bls), this falls out of the fact that
+//! scalar multiplication commutes:
+//!
+//! 1. the client hashes its message to `H = H(m)`, picks a random *blinding factor* `r`,
+//!    and sends the server `M' = r · H`, which is a uniformly random point;
+//! 2. the server returns `σ' = sk · M'`;
+//! 3. the client *unblinds* `σ = r⁻¹ · σ' = sk · H`, which is an ordinary BLS
+//!    signature on `m`.
+//!
+//! ```rust
+//! use ark_algebra_intro::protocols::blind_bls::{blind, sign_blinded, unblind, verify_blinded};
+//! use ark_algebra_intro::protocols::bls::{keygen, sign, verify};
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let (sk, pk) = keygen(&mut rng);
+//!
+//! let (factor, blinded) = blind(b"anonymous token", &mut rng);
+//! let blind_signature = sign_blinded(&sk, &blinded);
+//! assert!(verify_blinded(&pk, &blinded, &blind_signature));
+//! let signature = unblind(&factor, &blind_signature);
+//!
+//! assert!(verify(&pk, b"anonymous token", &signature));
+//! // It's the same signature the server would have computed on the message itself...
+//! assert_eq!(signature, sign(&sk, b"anonymous token"));
+//! // ...but the server never saw it.
+//! assert_ne!(blind_signature.0, signature.0);
+//! ```
+//!
+//! Since BLS signatures are unique, hashing the message together with its signature gives
+//! a pseudorandom function of the message keyed by `sk`, which the client can evaluate
+//! *obliviously*, i.e., without the server learning its input or output; see
+//! [`prf_output`].
+
+use super::bls::{hash_message, PublicKey, SecretKey, Signature, BASIC_DST};
+use super::to_bytes;
+use crate::pairings::pairing_ratio_check;
+use ark_bls12_381::{Bls12_381, Fr, G1Affine, G2Affine};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{Field, UniformRand, Zero};
+use ark_std::rand::Rng;
+use sha2::{Digest, Sha256};
+
+/// The prefix for hashing PRF outputs.
+const PRF_PREFIX: &[u8] = b"ARK-ALGEBRA-INTRO-BLIND-BLS-PRF";
+
+/// The client's secret blinding factor `r`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlindingFactor(pub Fr);
+
+/// A blinded message `r · H(m)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlindedMessage(pub G1Affine);
+
+/// A signature `sk · r · H(m)` on a blinded message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlindSignature(pub G1Affine);
+
+/// Blinds `msg` with a fresh random blinding factor.
+pub fn blind<R: Rng + ?Sized>(msg: &[u8], rng: &mut R) -> (BlindingFactor, BlindedMessage) {
+    let r = loop {
+        let r = Fr::rand(rng);
+        if !r.is_zero() {
+            break r;
+        }
+    };
+    let blinded = hash_message(msg, BASIC_DST).mul(r).into_affine();
+    (BlindingFactor(r), BlindedMessage(blinded))
+}
+
+/// Signs a blinded message, as the server.
+pub fn sign_blinded(sk: &SecretKey, blinded: &BlindedMessage) -> BlindSignature {
+    BlindSignature(blinded.0.mul(sk.0).into_affine())
+}
+
+/// Checks that the server signed the blinded message with the key of `pk`, by checking
+/// `e(σ', g2) = e(M', pk)`.
+///
+/// A client should do this before using the unblinded signature, so that a server
+/// can't tag clients by signing with a different key for each of them.
+pub fn verify_blinded(
+    pk: &PublicKey,
+    blinded: &BlindedMessage,
+    signature: &BlindSignature,
+) -> bool {
+    let g2 = G2Affine::prime_subgroup_generator();
+    !pk.0.is_zero() && pairing_ratio_check::<Bls12_381>((signature.0, g2), (blinded.0, pk.0))
+}
+
+/// Removes the blinding factor from a blind signature, yielding a signature on the
+/// original message.
+pub fn unblind(factor: &BlindingFactor, signature: &BlindSignature) -> Signature {
+    let inverse = factor.0.inverse().expect("blinding factors are nonzero");
+    Signature(signature.0.mul(inverse).into_affine())
+}
+
+/// Computes the PRF output `SHA-256(prefix || len(m) || m || σ)` for a message and its signature.
+///
+/// ```rust
+/// use ark_algebra_intro::protocols::blind_bls::{blind, prf_output, sign_blinded, unblind};
+/// use ark_algebra_intro::protocols::bls::{keygen, sign};
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let (sk, _) = keygen(&mut rng);
+///
+/// // Two blinded evaluations of the same input look unrelated to the server...
+/// let (f1, b1) = blind(b"password", &mut rng);
+/// let (f2, b2) = blind(b"password", &mut rng);
+/// assert_ne!(b1, b2);
+/// // ...but give the client the same output.
+/// let out1 = prf_output(b"password", &unblind(&f1, &sign_blinded(&sk, &b1)));
+/// let out2 = prf_output(b"password", &unblind(&f2, &sign_blinded(&sk, &b2)));
+/// assert_eq!(out1, out2);
+/// assert_eq!(out1, prf_output(b"password", &sign(&sk, b"password")));
+/// ```
+pub fn prf_output(msg: &[u8], signature: &Signature) -> [u8; 32] {
+    Sha256::new()
+        .chain_update(PRF_PREFIX)
+        .chain_update((msg.len() as u64).to_be_bytes())
+        .chain_update(msg)
+        .chain_update(to_bytes(&signature.0))
+        .finalize()
+        .into()
+}
@@ -0,0 +1,294 @@
+//! BLS signatures over BLS12-381.
+//!
+//! A BLS secret key is a scalar `sk`, and the public key is `pk = sk · g2`. To sign a
+//! message `m`, [hash it to G1](crate::hash_to_curve::hash_to_g1) and multiply the
+//! result by `sk`: `σ = sk · H(m)`. Verification checks `e(σ, g2) = e(H(m), pk)`,
+//! which holds because both sides equal `e(H(m), g2)^sk`. (This is the "minimal
+//! signature size" variant, with 48-byte signatures in G1 and 96-byte public keys in G2.)
+//!
+//! ```rust
+//! use ark_algebra_intro::protocols::bls::{keygen, sign, verify};
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let (sk, pk) = keygen(&mut rng);
+//! let signature = sign(&sk, b"hello");
+//! assert!(verify(&pk, b"hello", &signature));
+//! assert!(!verify(&pk, b"goodbye", &signature));
+//! ```
+//!
+//! # Aggregation
+//!
+//! Because signing is linear, signatures can be *aggregated* by adding them up: the sum
+//! of `n` signatures is a single G1 point that convinces a verifier of all `n` at once,
+//! with `n + 1` pairings instead of `2n` (see [`aggregate_verify`]).
+//!
+//! # Rogue-key attacks
+//!
+//! When all signers sign the *same* message, it is tempting to verify an aggregate
+//! signature against the sum of the public keys, with a single two-pairing check.
+//! This is insecure if the keys are chosen adversarially: after seeing Alice's key
+//! `pk_A`, Mallory can publish the *rogue key* `pk_M = x · g2 - pk_A` for an `x` she
+//! knows. The sum `pk_A + pk_M = x · g2` is a key she controls, so she can produce an
+//! "aggregate signature" `x · H(m)` that Alice never agreed to:
+//!
+//! ```rust
+//! use ark_algebra_intro::protocols::bls::{
+//!     aggregate_public_keys, hash_message, keygen, verify, PublicKey, Signature, BASIC_DST,
+//! };
+//! use ark_bls12_381::{Fr, G2Affine};
+//! use ark_ec::{AffineCurve, ProjectiveCurve};
+//! use ark_std::UniformRand;
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let (_, alice) = keygen(&mut rng);
+//!
+//! let x = Fr::rand(&mut rng);
+//! let x_g2 = G2Affine::prime_subgroup_generator().mul(x);
+//! let mallory = PublicKey((x_g2 - alice.0.into_projective()).into_affine());
+//!
+//! let msg = b"Alice owes Mallory $100";
+//! let forgery = Signature(hash_message(msg, BASIC_DST).mul(x).into_affine());
+//! let aggregate_key = aggregate_public_keys(&[alice, mallory]);
+//! assert!(verify(&aggregate_key, msg, &forgery));
+//! ```
+//!
+//! There are two standard defenses. The *basic* scheme, implemented at the top level
+//! of this module, requires aggregated messages to be distinct, so the sum of public
+//! keys never appears. The *proof-of-possession* scheme in [`pop`] instead requires every
+//! signer to prove knowledge of its secret key before its public key is accepted, which
+//! Mallory can't do for `pk_M`.
+//!
+//! The `11_bls_signatures` example walks through this module:
+//! `cargo run --release --example 11_bls_signatures`.
+
+use crate::hash_to_curve::hash_to_g1;
+use crate::pairings::{multi_pairing, pairing_ratio_check};
+use ark_bls12_381::{Bls12_381, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{One, UniformRand, Zero};
+use ark_std::rand::Rng;
+
+/// The domain separation tag of the basic scheme of the IETF BLS signature draft.
+pub const BASIC_DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+
+/// A secret signing key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SecretKey(pub Fr);
+
+/// A public key `sk · g2`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PublicKey(pub G2Affine);
+
+/// A signature, or an aggregate of several signatures.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature(pub G1Affine);
+
+/// Samples a random secret key, and returns it with the corresponding public key.
+pub fn keygen<R: Rng + ?Sized>(rng: &mut R) -> (SecretKey, PublicKey) {
+    let sk = SecretKey(Fr::rand(rng));
+    (sk, public_key(&sk))
+}
+
+/// Computes the public key `sk · g2` of a secret key.
+pub fn public_key(sk: &SecretKey) -> PublicKey {
+    PublicKey(G2Affine::prime_subgroup_generator().mul(sk.0).into_affine())
+}
+
+/// Hashes `msg` to G1 with the domain separation tag `dst`.
+pub fn hash_message(msg: &[u8], dst: &[u8]) -> G1Affine {
+    hash_to_g1(msg, dst)
+}
+
+/// Signs `msg` with the basic scheme.
+pub fn sign(sk: &SecretKey, msg: &[u8]) -> Signature {
+    sign_with_dst(sk, msg, BASIC_DST)
+}
+
+/// Checks that `signature` is a signature on `msg` under `pk`, with the basic scheme.
+///
+/// The signature is assumed to be a valid point of G1, which is checked when it is
+/// deserialized.
+pub fn verify(pk: &PublicKey, msg: &[u8], signature: &Signature) -> bool {
+    verify_with_dst(pk, msg, signature, BASIC_DST)
+}
+
+/// Aggregates several signatures into one, by adding them.
+///
+/// ```rust
+/// use ark_algebra_intro::protocols::bls::{aggregate_signatures, aggregate_verify, keygen, sign};
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let (sk1, pk1) = keygen(&mut rng);
+/// let (sk2, pk2) = keygen(&mut rng);
+/// let aggregate = aggregate_signatures(&[sign(&sk1, b"first"), sign(&sk2, b"second")]);
+///
+/// let messages: [&[u8]; 2] = [b"first", b"second"];
+/// assert!(aggregate_verify(&[pk1, pk2], &messages, &aggregate));
+/// assert!(!aggregate_verify(&[pk2, pk1], &messages, &aggregate));
+///
+/// // The basic scheme rejects repeated messages, which would open the door to
+/// // rogue-key attacks.
+/// let repeated = aggregate_signatures(&[sign(&sk1, b"first"), sign(&sk2, b"first")]);
+/// let messages: [&[u8]; 2] = [b"first", b"first"];
+/// assert!(!aggregate_verify(&[pk1, pk2], &messages, &repeated));
+/// ```
+pub fn aggregate_signatures(signatures: &[Signature]) -> Signature {
+    let sum: G1Projective = signatures.iter().map(|s| s.0.into_projective()).sum();
+    Signature(sum.into_affine())
+}
+
+/// Aggregates several public keys into one, by adding them.
+///
+/// On its own, an aggregate public key is vulnerable to rogue-key attacks (see the
+/// [module documentation](self)); use it only with keys that come with a
+/// [proof of possession](pop::prove_possession).
+pub fn aggregate_public_keys(keys: &[PublicKey]) -> PublicKey {
+    let sum: G2Projective = keys.iter().map(|k| k.0.into_projective()).sum();
+    PublicKey(sum.into_affine())
+}
+
+/// Checks that `signature` is an [aggregate](aggregate_signatures) of signatures on
+/// `messages[i]` under `keys[i]`, with the basic scheme.
+///
+/// This checks that `e(σ, g2) = e(H(m_1), pk_1) · ... · e(H(m_n), pk_n)`, as a single
+/// product of `n + 1` pairings. Following the basic scheme, it rejects any aggregate in
+/// which a message appears more than once.
+///
+/// Like [`verify`], it rejects the zero public key: the pair `(0, m)` would contribute
+/// `e(H(m), 0) = 1` to the product, so the aggregate would "sign" `m` without anyone
+/// holding a key. The keys and the signature are assumed to be points of the prime-order
+/// subgroups of G2 and G1, which is checked when they are deserialized but not when
+/// [`PublicKey`] or [`Signature`] is built from its field.
+///
+/// ```rust
+/// use ark_algebra_intro::protocols::bls::{aggregate_verify, keygen, sign, PublicKey};
+/// use ark_bls12_381::G2Affine;
+/// use ark_ff::Zero;
+///
+/// let (sk, pk) = keygen(&mut ark_std::rand::thread_rng());
+/// let messages: [&[u8]; 2] = [b"first", b"second"];
+/// let zero = PublicKey(G2Affine::zero());
+/// assert!(!aggregate_verify(&[pk, zero], &messages, &sign(&sk, b"first")));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `keys` and `messages` have different lengths.
+pub fn aggregate_verify(keys: &[PublicKey], messages: &[&[u8]], signature: &Signature) -> bool {
+    assert_eq!(
+        keys.len(),
+        messages.len(),
+        "the number of public keys and messages must be equal"
+    );
+    let mut sorted = messages.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+    if sorted.len() != messages.len() || messages.is_empty() {
+        return false;
+    }
+    if keys.iter().any(|key| key.0.is_zero()) {
+        return false;
+    }
+    let mut g1 = vec![-signature.0];
+    let mut g2 = vec![G2Affine::prime_subgroup_generator()];
+    for (key, msg) in keys.iter().zip(messages) {
+        g1.push(hash_message(msg, BASIC_DST));
+        g2.push(key.0);
+    }
+    multi_pairing::<Bls12_381>(&g1, &g2).is_one()
+}
+
+fn sign_with_dst(sk: &SecretKey, msg: &[u8], dst: &[u8]) -> Signature {
+    Signature(hash_message(msg, dst).mul(sk.0).into_affine())
+}
+
+fn verify_with_dst(pk: &PublicKey, msg: &[u8], signature: &Signature, dst: &[u8]) -> bool {
+    // A zero public key would accept the zero signature on every message.
+    if pk.0.is_zero() {
+        return false;
+    }
+    let g2 = G2Affine::prime_subgroup_generator();
+    pairing_ratio_check::<Bls12_381>((signature.0, g2), (hash_message(msg, dst), pk.0))
+}
+
+/// The proof-of-possession scheme of the IETF BLS signature draft.
+///
+/// Every public key must be accompanied by a proof of possession: a signature on the
+/// public key itself, under a separate domain separation tag. Once all keys have been
+/// checked with [`verify_possession`](pop::verify_possession), signatures on the *same* message can be verified
+/// against the [sum of the public keys](aggregate_public_keys), with just two
+/// pairings no matter how many signers there are:
+///
+/// ```rust
+/// use ark_algebra_intro::protocols::bls::{aggregate_signatures, keygen, pop};
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let signers: Vec<_> = (0..4).map(|_| keygen(&mut rng)).collect();
+/// for (sk, pk) in &signers {
+///     assert!(pop::verify_possession(pk, &pop::prove_possession(sk)));
+/// }
+///
+/// let keys: Vec<_> = signers.iter().map(|(_, pk)| *pk).collect();
+/// let signatures: Vec<_> = signers.iter().map(|(sk, _)| pop::sign(sk, b"block 42")).collect();
+/// let aggregate = aggregate_signatures(&signatures);
+/// assert!(pop::fast_aggregate_verify(&keys, b"block 42", &aggregate));
+/// ```
+///
+/// A rogue key can't come with a valid proof, since producing one requires its secret key:
+///
+/// ```rust
+/// use ark_algebra_intro::protocols::bls::{keygen, pop, PublicKey, SecretKey};
+/// use ark_bls12_381::{Fr, G2Affine};
+/// use ark_ec::{AffineCurve, ProjectiveCurve};
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let (_, alice) = keygen(&mut rng);
+/// let x = Fr::rand(&mut rng);
+/// let x_g2 = G2Affine::prime_subgroup_generator().mul(x);
+/// let rogue = PublicKey((x_g2 - alice.0.into_projective()).into_affine());
+///
+/// // The best Mallory can do is a proof for the key she actually knows.
+/// let proof = pop::prove_possession(&SecretKey(x));
+/// assert!(!pop::verify_possession(&rogue, &proof));
+/// ```
+pub mod pop {
+    use super::{
+        aggregate_public_keys, public_key, sign_with_dst, verify_with_dst, PublicKey, SecretKey,
+        Signature,
+    };
+    use crate::protocols::to_bytes;
+
+    /// The domain separation tag for signatures in the proof-of-possession scheme.
+    pub const POP_SIGNATURE_DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_";
+
+    /// The domain separation tag for proofs of possession.
+    pub const POP_PROOF_DST: &[u8] = b"BLS_POP_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_";
+
+    /// Signs `msg` with the proof-of-possession scheme.
+    pub fn sign(sk: &SecretKey, msg: &[u8]) -> Signature {
+        sign_with_dst(sk, msg, POP_SIGNATURE_DST)
+    }
+
+    /// Checks that `signature` is a signature on `msg` under `pk`, with the
+    /// proof-of-possession scheme.
+    pub fn verify(pk: &PublicKey, msg: &[u8], signature: &Signature) -> bool {
+        verify_with_dst(pk, msg, signature, POP_SIGNATURE_DST)
+    }
+
+    /// Proves knowledge of `sk`, by signing the (compressed) public key.
+    pub fn prove_possession(sk: &SecretKey) -> Signature {
+        sign_with_dst(sk, &to_bytes(&public_key(sk).0), POP_PROOF_DST)
+    }
+
+    /// Checks a proof of possession for `pk`.
+    pub fn verify_possession(pk: &PublicKey, proof: &Signature) -> bool {
+        verify_with_dst(pk, &to_bytes(&pk.0), proof, POP_PROOF_DST)
+    }
+
+    /// Checks that `signature` is an aggregate of signatures on `msg` under all of `keys`,
+    /// each of which must already have passed [`verify_possession`].
+    pub fn fast_aggregate_verify(keys: &[PublicKey], msg: &[u8], signature: &Signature) -> bool {
+        !keys.is_empty() && verify(&aggregate_public_keys(keys), msg, signature)
+    }
+}
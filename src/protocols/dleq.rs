@@ -0,0 +1,188 @@
+//! Chaum–Pedersen proofs of discrete-logarithm equality.
+//!
+//! Given points `A = x · G` and `B = x · H`, a DLEQ proof convinces a verifier that
+//! `log_G(A) = log_H(B)`, without revealing `x`. It is Schnorr's protocol run for both
+//! bases at once, with the same nonce and challenge:
+//!
+//! 1. the prover picks a random `k`, and sends `U = k · G` and `V = k · H`;
+//! 2. the verifier replies with a random challenge `c`;
+//! 3. the prover sends `s = k + c · x`, and the verifier checks `s · G = U + c · A` and
+//!    `s · H = V + c · B`.
+//!
+//! If the two logarithms differed, the two checks could only both pass for a single
+//! challenge, which a prover has a negligible chance of guessing. As usual, the
+//! Fiat–Shamir transform makes the proof non-interactive.
+//!
+//! ```rust
+//! use ark_algebra_intro::protocols::dleq::{prove, verify};
+//! use ark_bls12_381::{Fr, G1Affine, G1Projective};
+//! use ark_ec::{AffineCurve, ProjectiveCurve};
+//! use ark_std::UniformRand;
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let g = G1Affine::prime_subgroup_generator();
+//! let h = G1Projective::rand(&mut rng).into_affine();
+//! let x = Fr::rand(&mut rng);
+//! let (a, b) = (g.mul(x).into_affine(), h.mul(x).into_affine());
+//!
+//! let proof = prove(&g, &h, &x, &mut rng);
+//! assert!(verify(&g, &a, &h, &b, &proof));
+//!
+//! // The proof fails if the logarithms differ.
+//! let other = h.mul(x + Fr::from(1u64)).into_affine();
+//! assert!(!verify(&g, &a, &h, &other, &proof));
+//! ```
+//!
+//! Compact proofs like this one, which send the challenge instead of the commitments
+//! `U` and `V`, are used by the [VRF](super::vrf).
+
+use crate::transcript::Transcript;
+use ark_bls12_381::{Fr, G1Affine};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::UniformRand;
+use ark_std::rand::Rng;
+use ark_std::vec::Vec;
+
+/// The transcript label for proofs.
+const PROOF_LABEL: &[u8] = b"ark-algebra-intro/dleq/proof";
+
+/// The transcript label for the coefficients of batched proofs.
+const BATCH_LABEL: &[u8] = b"ark-algebra-intro/dleq/batch";
+
+/// A proof that `log_G(A) = log_H(B)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Proof {
+    /// The Fiat–Shamir challenge `c`.
+    pub challenge: Fr,
+    /// The response `s = k + c · x`.
+    pub response: Fr,
+}
+
+/// Proves that `x · g` and `x · h` have the same discrete logarithm `x` with respect to
+/// `g` and `h`.
+pub fn prove<R: Rng + ?Sized>(g: &G1Affine, h: &G1Affine, x: &Fr, rng: &mut R) -> Proof {
+    prove_with_nonce(g, h, x, Fr::rand(rng))
+}
+
+/// Like [`prove`], but with a given nonce `k`.
+///
+/// The nonce must be secret and never be reused, or `x` can be recovered as for
+/// [Schnorr signatures](super::schnorr::recover_key_from_nonce_reuse).
+pub fn prove_with_nonce(g: &G1Affine, h: &G1Affine, x: &Fr, nonce: Fr) -> Proof {
+    let a = g.mul(*x).into_affine();
+    let b = h.mul(*x).into_affine();
+    let u = g.mul(nonce).into_affine();
+    let v = h.mul(nonce).into_affine();
+    let challenge = challenge(g, &a, h, &b, &u, &v);
+    Proof {
+        challenge,
+        response: nonce + challenge * x,
+    }
+}
+
+/// Checks a proof that `log_G(A) = log_H(B)`.
+///
+/// The verifier recomputes the commitments as `U = s · G - c · A` and
+/// `V = s · H - c · B`, and checks that they hash to the challenge `c`.
+pub fn verify(g: &G1Affine, a: &G1Affine, h: &G1Affine, b: &G1Affine, proof: &Proof) -> bool {
+    let u = (g.mul(proof.response) - a.mul(proof.challenge)).into_affine();
+    let v = (h.mul(proof.response) - b.mul(proof.challenge)).into_affine();
+    challenge(g, a, h, b, &u, &v) == proof.challenge
+}
+
+/// Proves that `log_G(A) = log_{H_i}(B_i)` for all pairs `(H_i, B_i)` in `pairs`, where
+/// `A = x · g`, with a single proof.
+///
+/// Both parties derive coefficients `d_i` by hashing all the points, and combine the
+/// pairs into `H = Σ d_i · H_i` and `B = Σ d_i · B_i`. If some `B_i` had a different
+/// logarithm, `log_H(B)` would differ from `x` except with negligible probability, so a
+/// single DLEQ proof for `(G, A)` and `(H, B)` covers all the pairs. This is how, for
+/// instance, an oblivious PRF server proves that it evaluated a whole batch of inputs
+/// with the same key.
+///
+/// ```rust
+/// use ark_algebra_intro::protocols::dleq::{prove_batch, verify_batch};
+/// use ark_bls12_381::{Fr, G1Affine, G1Projective};
+/// use ark_ec::{AffineCurve, ProjectiveCurve};
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let g = G1Affine::prime_subgroup_generator();
+/// let x = Fr::rand(&mut rng);
+/// let a = g.mul(x).into_affine();
+/// let mut pairs: Vec<_> = (0..4)
+///     .map(|_| {
+///         let h = G1Projective::rand(&mut rng).into_affine();
+///         (h, h.mul(x).into_affine())
+///     })
+///     .collect();
+///
+/// let proof = prove_batch(&g, &pairs, &x, &mut rng);
+/// assert!(verify_batch(&g, &a, &pairs, &proof));
+///
+/// pairs[2].1 = G1Projective::rand(&mut rng).into_affine();
+/// assert!(!verify_batch(&g, &a, &pairs, &proof));
+/// ```
+pub fn prove_batch<R: Rng + ?Sized>(
+    g: &G1Affine,
+    pairs: &[(G1Affine, G1Affine)],
+    x: &Fr,
+    rng: &mut R,
+) -> Proof {
+    let a = g.mul(*x).into_affine();
+    let (h, _) = combine(g, &a, pairs);
+    prove(g, &h, x, rng)
+}
+
+/// Checks a proof produced by [`prove_batch`].
+pub fn verify_batch(
+    g: &G1Affine,
+    a: &G1Affine,
+    pairs: &[(G1Affine, G1Affine)],
+    proof: &Proof,
+) -> bool {
+    let (h, b) = combine(g, a, pairs);
+    verify(g, a, &h, &b, proof)
+}
+
+/// Combines the pairs of a batched proof into a single pair `(Σ d_i · H_i, Σ d_i · B_i)`.
+fn combine(g: &G1Affine, a: &G1Affine, pairs: &[(G1Affine, G1Affine)]) -> (G1Affine, G1Affine) {
+    let mut transcript = Transcript::new(BATCH_LABEL);
+    transcript.append_point(b"g", g);
+    transcript.append_point(b"a", a);
+    for (h, b) in pairs {
+        transcript.append_point(b"h", h);
+        transcript.append_point(b"b", b);
+    }
+    let coefficients: Vec<_> = pairs
+        .iter()
+        .map(|_| transcript.challenge_scalar(b"coefficient"))
+        .collect();
+    let (hs, bs): (Vec<_>, Vec<_>) = pairs.iter().copied().unzip();
+    (
+        crate::msm::arkworks(&hs, &coefficients).into_affine(),
+        crate::msm::arkworks(&bs, &coefficients).into_affine(),
+    )
+}
+
+fn challenge(
+    g: &G1Affine,
+    a: &G1Affine,
+    h: &G1Affine,
+    b: &G1Affine,
+    u: &G1Affine,
+    v: &G1Affine,
+) -> Fr {
+    let mut transcript = Transcript::new(PROOF_LABEL);
+    for (label, point) in [
+        (b"g", g),
+        (b"a", a),
+        (b"h", h),
+        (b"b", b),
+        (b"u", u),
+        (b"v", v),
+    ] {
+        transcript.append_point(label, point);
+    }
+    transcript.challenge_scalar(b"challenge")
+}
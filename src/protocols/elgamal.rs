@@ -0,0 +1,141 @@
+//! Exponential ElGamal encryption over G1.
+//!
+//! ElGamal encrypts a *point* `M` under the public key `X = x · G` as the pair
+//! `(r · G, M + r · X)` for a fresh random `r`. Whoever knows `x` can compute
+//! `r · X = x · (r · G)` and subtract it to recover `M`.
+//!
+//! To encrypt a *number* `m`, *exponential* ElGamal encrypts the point `m · G`. This makes
+//! the scheme additively homomorphic, since adding two ciphertexts component-wise yields
+//! an encryption of the sum of the plaintexts. The price is that decryption only recovers
+//! `m · G`, and finding `m` from it is a discrete logarithm problem. That is only feasible
+//! when `m` is known to be small, for instance with [`baby_step_giant_step`].
+//!
+//! ```rust
+//! use ark_algebra_intro::protocols::elgamal::{add, decrypt, encrypt, keygen};
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let (sk, pk) = keygen(&mut rng);
+//! let votes: Vec<_> = [1, 0, 1, 1, 0].iter().map(|&v| encrypt(&pk, v, &mut rng)).collect();
+//!
+//! // Anyone can tally the encrypted votes...
+//! let tally = votes[1..].iter().fold(votes[0], |sum, vote| add(&sum, vote));
+//! // ...but only the holder of the secret key can decrypt the result.
+//! assert_eq!(decrypt(&sk, &tally, 5), Ok(3));
+//! ```
+
+use crate::discrete_log::bsgs;
+use crate::Error;
+use ark_bls12_381::{Fr, G1Affine, G1Projective};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{UniformRand, Zero};
+use ark_std::rand::Rng;
+
+/// A secret decryption key `x`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SecretKey(pub Fr);
+
+/// A public encryption key `x · G`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PublicKey(pub G1Affine);
+
+/// An ElGamal ciphertext `(r · G, M + r · X)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ciphertext {
+    /// The ephemeral key `r · G`.
+    pub ephemeral: G1Affine,
+    /// The masked plaintext `M + r · X`.
+    pub masked: G1Affine,
+}
+
+/// Samples a random secret key, and returns it with the corresponding public key.
+pub fn keygen<R: Rng + ?Sized>(rng: &mut R) -> (SecretKey, PublicKey) {
+    let sk = Fr::rand(rng);
+    let pk = G1Affine::prime_subgroup_generator().mul(sk).into_affine();
+    (SecretKey(sk), PublicKey(pk))
+}
+
+/// Encrypts the number `msg`, i.e., the point `msg · G`.
+pub fn encrypt<R: Rng + ?Sized>(pk: &PublicKey, msg: u64, rng: &mut R) -> Ciphertext {
+    encrypt_point(pk, &encode(msg), Fr::rand(rng))
+}
+
+/// Encrypts the point `msg` with the randomness `r`.
+pub fn encrypt_point(pk: &PublicKey, msg: &G1Affine, r: Fr) -> Ciphertext {
+    let g = G1Affine::prime_subgroup_generator();
+    Ciphertext {
+        ephemeral: g.mul(r).into_affine(),
+        masked: (pk.0.mul(r) + msg.into_projective()).into_affine(),
+    }
+}
+
+/// Decrypts `ciphertext` to the point it encrypts.
+pub fn decrypt_point(sk: &SecretKey, ciphertext: &Ciphertext) -> G1Affine {
+    let mask = ciphertext.ephemeral.mul(sk.0);
+    (ciphertext.masked.into_projective() - mask).into_affine()
+}
+
+/// Decrypts `ciphertext` to the number it encrypts, or returns
+/// [`Error::DiscreteLogNotFound`] if that number is larger than `max`.
+///
+/// This takes time proportional to the square root of `max`.
+pub fn decrypt(sk: &SecretKey, ciphertext: &Ciphertext, max: u64) -> Result<u64, Error> {
+    baby_step_giant_step(&decrypt_point(sk, ciphertext), max)
+}
+
+/// Adds two ciphertexts, yielding an encryption of the sum of their plaintexts.
+pub fn add(first: &Ciphertext, second: &Ciphertext) -> Ciphertext {
+    Ciphertext {
+        ephemeral: first.ephemeral + second.ephemeral,
+        masked: first.masked + second.masked,
+    }
+}
+
+/// Adds a fresh encryption of zero with randomness `r` to `ciphertext`.
+///
+/// The result encrypts the same plaintext, but cannot be linked to the original
+/// ciphertext by anyone who doesn't know the secret key.
+///
+/// ```rust
+/// use ark_algebra_intro::protocols::elgamal::{decrypt, encrypt, keygen, rerandomize};
+/// use ark_bls12_381::Fr;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let (sk, pk) = keygen(&mut rng);
+/// let ciphertext = encrypt(&pk, 42, &mut rng);
+/// let fresh = rerandomize(&pk, &ciphertext, Fr::rand(&mut rng));
+/// assert_ne!(fresh, ciphertext);
+/// assert_eq!(decrypt(&sk, &fresh, 100), Ok(42));
+/// ```
+pub fn rerandomize(pk: &PublicKey, ciphertext: &Ciphertext, r: Fr) -> Ciphertext {
+    add(ciphertext, &encrypt_point(pk, &G1Affine::zero(), r))
+}
+
+/// Finds `m ≤ max` such that `target = m · G`, or returns [`Error::DiscreteLogNotFound`]
+/// if there is none.
+///
+/// This is [`bsgs`] with the base `G`, which takes `O(√max)` time and memory, instead
+/// of the `O(max)` time of trying every `m`.
+///
+/// ```rust
+/// use ark_algebra_intro::protocols::elgamal::baby_step_giant_step;
+/// use ark_algebra_intro::Error;
+/// use ark_bls12_381::G1Affine;
+/// use ark_ec::{AffineCurve, ProjectiveCurve};
+///
+/// let target = G1Affine::prime_subgroup_generator().mul(123_456u64).into_affine();
+/// assert_eq!(baby_step_giant_step(&target, 1_000_000), Ok(123_456));
+/// assert_eq!(baby_step_giant_step(&target, 100_000), Err(Error::DiscreteLogNotFound));
+/// ```
+pub fn baby_step_giant_step(target: &G1Affine, max: u64) -> Result<u64, Error> {
+    bsgs(
+        &G1Projective::prime_subgroup_generator(),
+        &target.into_projective(),
+        max,
+    )
+}
+
+/// Encodes `msg` as the point `msg · G`.
+fn encode(msg: u64) -> G1Affine {
+    G1Affine::prime_subgroup_generator().mul(msg).into_affine()
+}
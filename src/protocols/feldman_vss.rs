@@ -0,0 +1,178 @@
+//! Feldman's verifiable secret sharing, and distributed key generation built from it.
+//!
+//! In [Shamir secret sharing](super::shamir), the parties have to trust the dealer: a
+//! malicious dealer can hand out shares that don't lie on a common polynomial, so that
+//! different sets of parties reconstruct different secrets. In Feldman's scheme, the
+//! dealer additionally publishes commitments `C_k = a_k · G` to the coefficients of the
+//! polynomial `f(X) = a_0 + a_1 X + ... + a_{t-1} X^{t-1}`. Since
+//!
+//! ```text
+//! f(i) · G = Σ_k (i^k · a_k) · G = Σ_k i^k · C_k,
+//! ```
+//!
+//! each party can check its share against the commitments without learning anything
+//! about the other shares.
+//!
+//! ```rust
+//! use ark_algebra_intro::protocols::feldman_vss::{deal, verify_share};
+//! use ark_bls12_381::Fr;
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let (mut shares, commitments) = deal(Fr::from(42u64), 3, 5, &mut rng);
+//! assert!(shares.iter().all(|share| verify_share(&commitments, share)));
+//!
+//! // A malicious dealer who tampers with a share is caught by its recipient.
+//! shares[1].value += Fr::from(1u64);
+//! assert!(!verify_share(&commitments, &shares[1]));
+//! ```
+//!
+//! The commitments are [Pedersen commitments](crate::commitments::pedersen) without
+//! blinding factors, so they are binding but not hiding: `C_0 = s · G` reveals the
+//! "public key" of the secret `s`. This is exactly what is wanted for threshold
+//! cryptography, where `s` is a secret key.
+//!
+//! # Distributed key generation
+//!
+//! To generate a shared key with *no* trusted dealer, every party deals a random secret
+//! of its own. Each party then adds up the (verified) shares it received, and everyone
+//! adds up the commitments. The result is a Feldman sharing of the sum of all the
+//! secrets, which nobody knows unless all the dealers collude.
+//!
+//! ```rust
+//! use ark_algebra_intro::protocols::feldman_vss::{
+//!     combine_commitments, combine_shares, deal, verify_share,
+//! };
+//! use ark_algebra_intro::protocols::shamir::reconstruct;
+//! use ark_bls12_381::{Fr, G1Affine};
+//! use ark_ec::{AffineCurve, ProjectiveCurve};
+//! use ark_std::UniformRand;
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let secrets: Vec<_> = (0..3).map(|_| Fr::rand(&mut rng)).collect();
+//! let dealings: Vec<_> = secrets.iter().map(|&s| deal(s, 2, 3, &mut rng)).collect();
+//!
+//! // Party `i` combines the `i`-th share of every dealing...
+//! let shares: Vec<_> = (0..3)
+//!     .map(|i| combine_shares(&dealings.iter().map(|(shares, _)| shares[i]).collect::<Vec<_>>()))
+//!     .collect();
+//! // ...and everyone combines the commitments.
+//! let commitments: Vec<_> = dealings.into_iter().map(|(_, c)| c).collect();
+//! let commitments = combine_commitments(&commitments);
+//!
+//! assert!(shares.iter().all(|share| verify_share(&commitments, share)));
+//! let key: Fr = secrets.iter().sum();
+//! assert_eq!(reconstruct(&shares[1..]), key);
+//! let g = G1Affine::prime_subgroup_generator();
+//! assert_eq!(commitments.public_key(), g.mul(key).into_affine());
+//! ```
+
+use super::shamir::{self, Share};
+use ark_bls12_381::{Fr, G1Affine, G1Projective};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{One, Zero};
+use ark_std::rand::Rng;
+use ark_std::vec::Vec;
+
+/// Commitments `a_k · G` to the coefficients of a sharing polynomial, lowest degree first.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Commitments(pub Vec<G1Affine>);
+
+impl Commitments {
+    /// Returns the number of shares needed to reconstruct the secret.
+    pub fn threshold(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the commitment `s · G` to the secret `s`.
+    pub fn public_key(&self) -> G1Affine {
+        self.0[0]
+    }
+
+    /// Returns the commitment `f(index) · G` to the share of the party with the given
+    /// index.
+    pub fn share_commitment(&self, index: u64) -> G1Projective {
+        let index = Fr::from(index);
+        let mut power = Fr::one();
+        let powers: Vec<_> = self
+            .0
+            .iter()
+            .map(|_| {
+                let current = power;
+                power *= index;
+                current
+            })
+            .collect();
+        crate::msm::arkworks(&self.0, &powers)
+    }
+}
+
+/// Splits `secret` into `n` shares, any `threshold` of which recover it, and commits to
+/// the sharing polynomial.
+///
+/// # Panics
+///
+/// Panics unless `1 ≤ threshold ≤ n`.
+pub fn deal<R: Rng + ?Sized>(
+    secret: Fr,
+    threshold: usize,
+    n: usize,
+    rng: &mut R,
+) -> (Vec<Share>, Commitments) {
+    let coeffs = shamir::random_polynomial(secret, threshold, n, rng);
+    let g = G1Affine::prime_subgroup_generator();
+    let commitments = coeffs.iter().map(|a| g.mul(*a)).collect::<Vec<_>>();
+    (
+        shamir::split_with_coefficients(&coeffs, n),
+        Commitments(G1Projective::batch_normalization_into_affine(&commitments)),
+    )
+}
+
+/// Checks that `share` is consistent with the dealer's `commitments`.
+pub fn verify_share(commitments: &Commitments, share: &Share) -> bool {
+    let g = G1Affine::prime_subgroup_generator();
+    g.mul(share.value) == commitments.share_commitment(share.index)
+}
+
+/// Adds up shares of the same party from several dealings.
+///
+/// # Panics
+///
+/// Panics if `shares` is empty, or if the shares have different indices.
+pub fn combine_shares(shares: &[Share]) -> Share {
+    let index = shares
+        .first()
+        .expect("there must be at least one share")
+        .index;
+    assert!(
+        shares.iter().all(|share| share.index == index),
+        "the shares must belong to the same party"
+    );
+    Share {
+        index,
+        value: shares.iter().map(|share| share.value).sum(),
+    }
+}
+
+/// Adds up the commitments of several dealings.
+///
+/// # Panics
+///
+/// Panics if `commitments` is empty, or if the dealings have different thresholds.
+pub fn combine_commitments(commitments: &[Commitments]) -> Commitments {
+    let threshold = commitments
+        .first()
+        .expect("there must be at least one dealing")
+        .threshold();
+    assert!(
+        commitments.iter().all(|c| c.threshold() == threshold),
+        "the dealings must have the same threshold"
+    );
+    let sums: Vec<_> = (0..threshold)
+        .map(|k| {
+            commitments.iter().fold(G1Projective::zero(), |sum, c| {
+                sum + c.0[k].into_projective()
+            })
+        })
+        .collect();
+    Commitments(G1Projective::batch_normalization_into_affine(&sums))
+}
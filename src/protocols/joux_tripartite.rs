@@ -0,0 +1,92 @@
+//! Joux's one-round tripartite Diffie–Hellman key agreement.
+//!
+//! With ordinary Diffie–Hellman, two parties publish `a · G` and `b · G` and both
+//! compute `(a · b) · G`. Extending this to three parties normally takes two rounds,
+//! because nobody can combine `b · G` and `c · G` into anything involving `b · c`.
+//! A pairing does exactly that: `e(b · g1, c · g2) = e(g1, g2)^(b · c)`, so after a single
+//! round of broadcasts, each party can raise this to its own secret and obtain the shared
+//! key `e(g1, g2)^(a · b · c)`.
+//!
+//! (Because BLS12-381's pairing takes inputs from two different groups, every party
+//! publishes its share in both G1 and G2.)
+//!
+//! ```rust
+//! use ark_algebra_intro::protocols::joux_tripartite::{keygen, shared_key};
+//! use ark_bls12_381::Bls12_381;
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//!
+//! // Alice, Bob, and Carol each pick a secret and broadcast the public share.
+//! let (alice_secret, alice_share) = keygen::<Bls12_381, _>(&mut rng);
+//! let (bob_secret, bob_share) = keygen::<Bls12_381, _>(&mut rng);
+//! let (carol_secret, carol_share) = keygen::<Bls12_381, _>(&mut rng);
+//!
+//! // Each party sees only the other two shares, and its own secret.
+//! let alice_key = shared_key(&alice_secret, &bob_share, &carol_share);
+//! let bob_key = shared_key(&bob_secret, &carol_share, &alice_share);
+//! let carol_key = shared_key(&carol_secret, &alice_share, &bob_share);
+//! assert_eq!(alice_key, bob_key);
+//! assert_eq!(bob_key, carol_key);
+//!
+//! // An eavesdropper who sees all three shares but no secret can't compute the key:
+//! // pairing two shares gives e(g1, g2)^(a · b), and getting from there to the key is
+//! // the (bilinear) Diffie–Hellman problem.
+//! let eavesdropped = ark_algebra_intro::pairings::Gt::<Bls12_381>::pairing(
+//!     alice_share.g1,
+//!     bob_share.g2,
+//! );
+//! assert_ne!(eavesdropped, alice_key);
+//! ```
+
+use crate::pairings::Gt;
+use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::UniformRand;
+use ark_std::rand::Rng;
+
+/// The value a party broadcasts: its secret `a` times the generators of G1 and G2.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PublicShare<E: PairingEngine> {
+    /// `a · g1`.
+    pub g1: E::G1Affine,
+    /// `a · g2`.
+    pub g2: E::G2Affine,
+}
+
+/// Samples a secret scalar `a`, and returns it together with the corresponding
+/// [`PublicShare`].
+pub fn keygen<E: PairingEngine, R: Rng + ?Sized>(rng: &mut R) -> (E::Fr, PublicShare<E>) {
+    let secret = E::Fr::rand(rng);
+    let share = PublicShare {
+        g1: E::G1Affine::prime_subgroup_generator()
+            .mul(secret)
+            .into_affine(),
+        g2: E::G2Affine::prime_subgroup_generator()
+            .mul(secret)
+            .into_affine(),
+    };
+    (secret, share)
+}
+
+/// Computes the shared key `e(g1, g2)^(a · b · c)` as `e(b · g1, c · g2)^a`, from a party's
+/// own secret `a` and the shares of the other two parties.
+///
+/// The order of the two shares doesn't matter, since `e(b · g1, c · g2)` and
+/// `e(c · g1, b · g2)` are both equal to `e(g1, g2)^(b · c)`.
+///
+/// ```rust
+/// use ark_algebra_intro::protocols::joux_tripartite::{keygen, shared_key};
+/// use ark_bls12_381::Bls12_381;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let (a, _) = keygen::<Bls12_381, _>(&mut rng);
+/// let (_, b) = keygen::<Bls12_381, _>(&mut rng);
+/// let (_, c) = keygen::<Bls12_381, _>(&mut rng);
+/// assert_eq!(shared_key(&a, &b, &c), shared_key(&a, &c, &b));
+/// ```
+pub fn shared_key<E: PairingEngine>(
+    secret: &E::Fr,
+    first: &PublicShare<E>,
+    second: &PublicShare<E>,
+) -> Gt<E> {
+    Gt::pairing(first.g1, second.g2).mul_scalar(secret)
+}
@@ -0,0 +1,198 @@
+//! Schnorr signatures and proofs of knowledge of a discrete logarithm over G1.
+//!
+//! The Schnorr identification protocol lets a prover convince a verifier that it knows
+//! the discrete logarithm `x` of a public point `X = x · G`:
+//!
+//! 1. the prover picks a random *nonce* `k` and sends the *commitment* `R = k · G`;
+//! 2. the verifier replies with a random *challenge* `e`;
+//! 3. the prover sends the *response* `s = k + e · x`, and the verifier checks that
+//!    `s · G = R + e · X`.
+//!
+//! The Fiat–Shamir transform makes this non-interactive by computing the challenge as
+//! a hash of everything the verifier has seen so far. Hashing in a message as well
+//! turns the proof into a signature on that message.
+//!
+//! ```rust
+//! use ark_algebra_intro::protocols::schnorr::{keygen, sign, verify};
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let (sk, pk) = keygen(&mut rng);
+//! let signature = sign(&sk, b"hello", &mut rng);
+//! assert!(verify(&pk, b"hello", &signature));
+//! assert!(!verify(&pk, b"goodbye", &signature));
+//! ```
+//!
+//! # Nonce reuse
+//!
+//! The nonce must be fresh and secret for every signature. Two signatures with the same
+//! nonce on different messages have responses `s_1 = k + e_1 · x` and `s_2 = k + e_2 · x`,
+//! so anyone can compute the secret key as `x = (s_1 - s_2) / (e_1 - e_2)`; see
+//! [`recover_key_from_nonce_reuse`].
+
+use crate::transcript::Transcript;
+use crate::Error;
+use ark_bls12_381::{Fr, G1Affine};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{Field, UniformRand};
+use ark_std::rand::Rng;
+
+/// The transcript label for signatures.
+const SIGNATURE_LABEL: &[u8] = b"ark-algebra-intro/schnorr/signature";
+
+/// The transcript label for proofs of knowledge.
+const PROOF_LABEL: &[u8] = b"ark-algebra-intro/schnorr/proof";
+
+/// A secret signing key `x`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SecretKey(pub Fr);
+
+/// A public key `x · G`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PublicKey(pub G1Affine);
+
+/// A Schnorr signature, or a non-interactive proof of knowledge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature {
+    /// The commitment `R = k · G`.
+    pub commitment: G1Affine,
+    /// The response `s = k + e · x`.
+    pub response: Fr,
+}
+
+/// Samples a random secret key, and returns it with the corresponding public key.
+pub fn keygen<R: Rng + ?Sized>(rng: &mut R) -> (SecretKey, PublicKey) {
+    let sk = Fr::rand(rng);
+    let pk = G1Affine::prime_subgroup_generator().mul(sk).into_affine();
+    (SecretKey(sk), PublicKey(pk))
+}
+
+/// Signs `msg` with a fresh random nonce.
+pub fn sign<R: Rng + ?Sized>(sk: &SecretKey, msg: &[u8], rng: &mut R) -> Signature {
+    sign_with_nonce(sk, msg, Fr::rand(rng))
+}
+
+/// Signs `msg` with the given nonce.
+///
+/// This is exposed to demonstrate what goes wrong when nonces are reused; real code
+/// should always call [`sign`], or derive the nonce deterministically from the secret
+/// key and the message as in RFC 6979.
+pub fn sign_with_nonce(sk: &SecretKey, msg: &[u8], nonce: Fr) -> Signature {
+    let g = G1Affine::prime_subgroup_generator();
+    let pk = g.mul(sk.0).into_affine();
+    let commitment = g.mul(nonce).into_affine();
+    let e = signature_challenge(&commitment, &PublicKey(pk), msg);
+    Signature {
+        commitment,
+        response: nonce + e * sk.0,
+    }
+}
+
+/// Checks that `signature` is a signature on `msg` under `pk`.
+pub fn verify(pk: &PublicKey, msg: &[u8], signature: &Signature) -> bool {
+    let e = signature_challenge(&signature.commitment, pk, msg);
+    let g = G1Affine::prime_subgroup_generator();
+    check(&g, &pk.0, e, signature)
+}
+
+/// Recovers the secret key from two signatures on different messages that were
+/// produced with the same nonce.
+///
+/// Returns [`Error::AttackFailed`] if the signatures don't share a nonce (i.e., have
+/// different commitments), or if they have the same challenge.
+///
+/// ```rust
+/// use ark_algebra_intro::protocols::schnorr::{
+///     keygen, recover_key_from_nonce_reuse, sign_with_nonce, verify,
+/// };
+/// use ark_bls12_381::Fr;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let (sk, pk) = keygen(&mut rng);
+/// let nonce = Fr::rand(&mut rng);
+/// let first = sign_with_nonce(&sk, b"first message", nonce);
+/// let second = sign_with_nonce(&sk, b"second message", nonce);
+///
+/// // Both signatures are perfectly valid...
+/// assert!(verify(&pk, b"first message", &first));
+/// assert!(verify(&pk, b"second message", &second));
+/// // ...but together, they reveal the secret key.
+/// let recovered =
+///     recover_key_from_nonce_reuse(&pk, (b"first message", &first), (b"second message", &second));
+/// assert_eq!(recovered, Ok(sk));
+/// ```
+pub fn recover_key_from_nonce_reuse(
+    pk: &PublicKey,
+    first: (&[u8], &Signature),
+    second: (&[u8], &Signature),
+) -> Result<SecretKey, Error> {
+    let (msg1, sig1) = first;
+    let (msg2, sig2) = second;
+    if sig1.commitment != sig2.commitment {
+        return Err(Error::AttackFailed);
+    }
+    let e1 = signature_challenge(&sig1.commitment, pk, msg1);
+    let e2 = signature_challenge(&sig2.commitment, pk, msg2);
+    let x = (sig1.response - sig2.response) * (e1 - e2).inverse().ok_or(Error::AttackFailed)?;
+    Ok(SecretKey(x))
+}
+
+/// Proves knowledge of the discrete logarithm `witness` of
+/// `statement = witness · base`, with the Fiat–Shamir transform of Schnorr's protocol.
+///
+/// ```rust
+/// use ark_algebra_intro::protocols::schnorr::{prove_knowledge, verify_knowledge};
+/// use ark_bls12_381::{Fr, G1Projective};
+/// use ark_ec::{AffineCurve, ProjectiveCurve};
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let base = G1Projective::rand(&mut rng).into_affine();
+/// let x = Fr::rand(&mut rng);
+/// let statement = base.mul(x).into_affine();
+///
+/// let proof = prove_knowledge(&base, &x, &mut rng);
+/// assert!(verify_knowledge(&base, &statement, &proof));
+///
+/// // A proof for one statement says nothing about another.
+/// let other = G1Projective::rand(&mut rng).into_affine();
+/// assert!(!verify_knowledge(&base, &other, &proof));
+/// ```
+pub fn prove_knowledge<R: Rng + ?Sized>(base: &G1Affine, witness: &Fr, rng: &mut R) -> Signature {
+    let statement = base.mul(*witness).into_affine();
+    let nonce = Fr::rand(rng);
+    let commitment = base.mul(nonce).into_affine();
+    let e = proof_challenge(base, &statement, &commitment);
+    Signature {
+        commitment,
+        response: nonce + e * witness,
+    }
+}
+
+/// Checks a proof produced by [`prove_knowledge`] that the prover knows the discrete
+/// logarithm of `statement` with respect to `base`.
+pub fn verify_knowledge(base: &G1Affine, statement: &G1Affine, proof: &Signature) -> bool {
+    let e = proof_challenge(base, statement, &proof.commitment);
+    check(base, statement, e, proof)
+}
+
+/// Checks the verification equation `s · G = R + e · X`.
+fn check(base: &G1Affine, statement: &G1Affine, e: Fr, proof: &Signature) -> bool {
+    base.mul(proof.response) == proof.commitment.into_projective() + statement.mul(e)
+}
+
+fn signature_challenge(commitment: &G1Affine, pk: &PublicKey, msg: &[u8]) -> Fr {
+    let mut transcript = Transcript::new(SIGNATURE_LABEL);
+    transcript.append_point(b"public key", &pk.0);
+    transcript.append_bytes(b"message", msg);
+    transcript.append_point(b"commitment", commitment);
+    transcript.challenge_scalar(b"challenge")
+}
+
+fn proof_challenge(base: &G1Affine, statement: &G1Affine, commitment: &G1Affine) -> Fr {
+    let mut transcript = Transcript::new(PROOF_LABEL);
+    transcript.append_point(b"base", base);
+    transcript.append_point(b"statement", statement);
+    transcript.append_point(b"commitment", commitment);
+    transcript.challenge_scalar(b"challenge")
+}
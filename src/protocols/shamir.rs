@@ -0,0 +1,97 @@
+//! Shamir secret sharing over BLS12-381's scalar field.
+//!
+//! To split a secret `s` into `n` shares such that any `t` of them suffice to recover it,
+//! the dealer picks a random polynomial `f` of degree `t - 1` with `f(0) = s`, and gives
+//! the `i`-th party the share `f(i)`.
+//!
+//! * Any `t` shares determine `f`, and hence `s = f(0)`, by
+//!   [Lagrange interpolation](crate::poly::lagrange_interpolate).
+//! * Any `t - 1` shares reveal nothing about `s`: for every candidate secret, there is
+//!   exactly one polynomial of degree `t - 1` through those shares and that secret.
+//!
+//! ```rust
+//! use ark_algebra_intro::protocols::shamir::{reconstruct, split};
+//! use ark_bls12_381::Fr;
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let secret = Fr::from(42u64);
+//! let shares = split(secret, 3, 5, &mut rng);
+//!
+//! // Any three shares recover the secret...
+//! assert_eq!(reconstruct(&shares[..3]), secret);
+//! assert_eq!(reconstruct(&[shares[4], shares[0], shares[2]]), secret);
+//! // ...but two shares don't.
+//! assert_ne!(reconstruct(&shares[..2]), secret);
+//! ```
+//!
+//! The `12_secret_sharing` example walks through this module:
+//! `cargo run --example 12_secret_sharing`.
+
+use crate::poly::{evaluate, lagrange_coefficients};
+use ark_bls12_381::Fr;
+use ark_ff::{UniformRand, Zero};
+use ark_std::rand::Rng;
+use ark_std::vec::Vec;
+
+/// The share `f(index)` of the party with the given (nonzero) index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Share {
+    /// The point at which the polynomial was evaluated.
+    pub index: u64,
+    /// The value of the polynomial at `index`.
+    pub value: Fr,
+}
+
+/// Splits `secret` into `n` shares with indices `1, ..., n`, any `threshold` of which
+/// recover it.
+///
+/// # Panics
+///
+/// Panics unless `1 ≤ threshold ≤ n`.
+pub fn split<R: Rng + ?Sized>(secret: Fr, threshold: usize, n: usize, rng: &mut R) -> Vec<Share> {
+    split_with_coefficients(&random_polynomial(secret, threshold, n, rng), n)
+}
+
+/// Evaluates the polynomial with coefficients `coeffs` at `1, ..., n`.
+pub(crate) fn split_with_coefficients(coeffs: &[Fr], n: usize) -> Vec<Share> {
+    (1..=n as u64)
+        .map(|index| Share {
+            index,
+            value: evaluate(coeffs, Fr::from(index)),
+        })
+        .collect()
+}
+
+/// Samples the coefficients of a random polynomial of degree `threshold - 1` with
+/// constant term `secret`.
+pub(crate) fn random_polynomial<R: Rng + ?Sized>(
+    secret: Fr,
+    threshold: usize,
+    n: usize,
+    rng: &mut R,
+) -> Vec<Fr> {
+    assert!(
+        1 <= threshold && threshold <= n,
+        "the threshold must be between 1 and the number of shares"
+    );
+    let mut coeffs = vec![secret];
+    coeffs.extend((1..threshold).map(|_| Fr::rand(rng)));
+    coeffs
+}
+
+/// Recovers the secret from `shares`, by interpolating the polynomial through them and
+/// evaluating it at zero.
+///
+/// With fewer shares than the threshold, this silently returns an unrelated value.
+///
+/// # Panics
+///
+/// Panics if two shares have the same index.
+pub fn reconstruct(shares: &[Share]) -> Fr {
+    let indices: Vec<_> = shares.iter().map(|share| Fr::from(share.index)).collect();
+    lagrange_coefficients(&indices, Fr::zero())
+        .into_iter()
+        .zip(shares)
+        .map(|(l, share)| l * share.value)
+        .sum()
+}
@@ -0,0 +1,360 @@
+//! The sumcheck protocol for multilinear polynomials.
+//!
+//! Given an `n`-variate polynomial `g`, the sumcheck protocol lets a prover convince a
+//! verifier that
+//!
+//! ```text
+//! H = Σ_{x ∈ {0, 1}^n} g(x),
+//! ```
+//!
+//! while the verifier only does `O(n)` work, plus a *single* evaluation of `g` at a
+//! random point. (Computing `H` directly takes `2^n` evaluations.) It is a building block
+//! of many proof systems, including GKR, Spartan and HyperPlonk.
+//!
+//! This module handles *multilinear* polynomials, which have degree at most one in each
+//! variable. Every function `f: {0, 1}^n → F` has a unique multilinear extension: the
+//! multilinear polynomial that agrees with `f` on the Boolean hypercube. `ark-poly`
+//! represents it by its `2^n` values on the hypercube as a [`DenseMultilinearExtension`],
+//! where the bits of the index `i` (least significant first) give the point.
+//!
+//! ```rust
+//! use ark_algebra_intro::protocols::sumcheck::{DenseMultilinearExtension, MultilinearExtension};
+//! use ark_bls12_381::Fr;
+//!
+//! // g(0, 0) = 1, g(1, 0) = 2, g(0, 1) = 3, g(1, 1) = 4
+//! let values: Vec<_> = (1..=4u64).map(Fr::from).collect();
+//! let g = DenseMultilinearExtension::from_evaluations_vec(2, values);
+//! assert_eq!(g.evaluate(&[Fr::from(1u64), Fr::from(0u64)]), Some(Fr::from(2u64)));
+//! // Off the hypercube, g(x_1, x_2) = 1 + x_1 + 2 x_2.
+//! assert_eq!(g.evaluate(&[Fr::from(5u64), Fr::from(7u64)]), Some(Fr::from(20u64)));
+//! ```
+//!
+//! # The protocol
+//!
+//! In round `i`, the prover sends the univariate polynomial
+//!
+//! ```text
+//! g_i(X) = Σ_{x_{i+1}, ..., x_n ∈ {0, 1}} g(r_1, ..., r_{i-1}, X, x_{i+1}, ..., x_n),
+//! ```
+//!
+//! which has degree one, so it is described by [its values at zero and one](RoundMessage).
+//! The verifier checks that `g_i(0) + g_i(1)` equals the claim from the previous round
+//! (initially `H`), and replies with a random challenge `r_i`; the new claim is `g_i(r_i)`.
+//! After `n` rounds, the verifier checks the last claim against `g(r_1, ..., r_n)`.
+//!
+//! A prover who lies about `H` must lie about some `g_i`, and two distinct lines agree on
+//! at most one point, so the lie survives the random challenge `r_i` with probability at
+//! most `1 / |F|`. With [`Prover`] and [`Verifier`], the two sides can be run step by
+//! step; [`run_interactive`] runs them against each other:
+//!
+//! ```rust
+//! use ark_algebra_intro::protocols::sumcheck::{run_interactive, DenseMultilinearExtension, MultilinearExtension};
+//! use ark_bls12_381::Fr;
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let g = DenseMultilinearExtension::<Fr>::rand(4, &mut rng);
+//! let (transcript, result) = run_interactive(&g, &mut rng);
+//! assert!(result.is_ok());
+//! assert_eq!(transcript.claimed_sum, g.iter().sum());
+//! assert_eq!(transcript.messages.len(), 4);
+//! ```
+//!
+//! # Fiat–Shamir
+//!
+//! Since the verifier's messages are just random challenges, the Fiat–Shamir transform
+//! makes the protocol non-interactive: [`prove`] derives each challenge from a
+//! [transcript](crate::transcript) of the statement and all previous messages, and
+//! [`verify`] recomputes them.
+
+pub use ark_poly::{DenseMultilinearExtension, MultilinearExtension};
+use ark_std::vec::Vec;
+
+use crate::transcript::Transcript as FiatShamir;
+use crate::Error;
+use ark_ff::{Field, PrimeField};
+use ark_std::fmt;
+use ark_std::rand::Rng;
+
+/// The Fiat–Shamir transcript label.
+const PROOF_LABEL: &[u8] = b"ark-algebra-intro/sumcheck";
+
+/// A prover's message: the degree-one polynomial `g_i`, by its values at zero and one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RoundMessage<F: Field> {
+    /// `g_i(0)`.
+    pub at_zero: F,
+    /// `g_i(1)`.
+    pub at_one: F,
+}
+
+impl<F: Field> RoundMessage<F> {
+    /// Evaluates `g_i` at `point`.
+    pub fn evaluate(&self, point: F) -> F {
+        self.at_zero + (self.at_one - self.at_zero) * point
+    }
+}
+
+/// The reason a verifier rejected a sumcheck proof, as reported by [`Error::Sumcheck`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rejection {
+    /// In the given round, `g_i(0) + g_i(1)` did not match the previous claim.
+    InconsistentRound(usize),
+    /// The number of rounds did not match the number of variables.
+    WrongNumberOfRounds,
+    /// The final claim did not match the evaluation of the polynomial.
+    WrongFinalEvaluation,
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rejection::InconsistentRound(round) => {
+                write!(f, "the message of round {} is inconsistent", round)
+            }
+            Rejection::WrongNumberOfRounds => write!(f, "the proof has the wrong number of rounds"),
+            Rejection::WrongFinalEvaluation => {
+                write!(f, "the final claim does not match the polynomial")
+            }
+        }
+    }
+}
+
+impl ark_std::error::Error for Rejection {}
+
+/// The honest sumcheck prover.
+///
+/// The prover keeps the values of `g(r_1, ..., r_{i-1}, x_i, ..., x_n)` on the remaining
+/// hypercube, which halves in size every round.
+#[derive(Clone, Debug)]
+pub struct Prover<F: Field> {
+    table: Vec<F>,
+}
+
+impl<F: Field> Prover<F> {
+    /// Creates a prover for the polynomial `g`.
+    pub fn new<P: MultilinearExtension<F>>(g: &P) -> Self {
+        Self {
+            table: g.to_evaluations(),
+        }
+    }
+
+    /// Returns the sum `H` of `g` over the hypercube.
+    pub fn claimed_sum(&self) -> F {
+        self.table.iter().sum()
+    }
+
+    /// Returns the message `g_i` for the current round.
+    ///
+    /// # Panics
+    ///
+    /// Panics if all variables have already been fixed.
+    pub fn message(&self) -> RoundMessage<F> {
+        assert!(self.table.len() > 1, "the protocol is over");
+        // The first remaining variable is the least significant bit of the index.
+        let at_zero = self.table.iter().step_by(2).sum();
+        let at_one = self.table.iter().skip(1).step_by(2).sum();
+        RoundMessage { at_zero, at_one }
+    }
+
+    /// Fixes the current variable to the verifier's challenge.
+    pub fn receive_challenge(&mut self, challenge: F) {
+        self.table = self
+            .table
+            .chunks(2)
+            .map(|pair| pair[0] + (pair[1] - pair[0]) * challenge)
+            .collect();
+    }
+}
+
+/// The sumcheck verifier.
+#[derive(Clone, Debug)]
+pub struct Verifier<F: Field> {
+    num_vars: usize,
+    claim: F,
+    challenges: Vec<F>,
+}
+
+impl<F: Field> Verifier<F> {
+    /// Creates a verifier for the claim that a `num_vars`-variate polynomial sums to
+    /// `claimed_sum`.
+    pub fn new(num_vars: usize, claimed_sum: F) -> Self {
+        Self {
+            num_vars,
+            claim: claimed_sum,
+            challenges: Vec::new(),
+        }
+    }
+
+    /// Checks the prover's message for the current round, and returns a random challenge.
+    pub fn receive_message<R: Rng + ?Sized>(
+        &mut self,
+        message: &RoundMessage<F>,
+        rng: &mut R,
+    ) -> Result<F, Error> {
+        let challenge = F::rand(rng);
+        self.receive_message_with_challenge(message, challenge)?;
+        Ok(challenge)
+    }
+
+    /// Like [`Verifier::receive_message`], but with a given challenge.
+    pub fn receive_message_with_challenge(
+        &mut self,
+        message: &RoundMessage<F>,
+        challenge: F,
+    ) -> Result<(), Error> {
+        if self.challenges.len() == self.num_vars {
+            return Err(Rejection::WrongNumberOfRounds.into());
+        }
+        if message.at_zero + message.at_one != self.claim {
+            return Err(Rejection::InconsistentRound(self.challenges.len()).into());
+        }
+        self.claim = message.evaluate(challenge);
+        self.challenges.push(challenge);
+        Ok(())
+    }
+
+    /// Returns the challenges sent so far.
+    pub fn challenges(&self) -> &[F] {
+        &self.challenges
+    }
+
+    /// Checks the final claim with a single evaluation of `g`.
+    pub fn finish<P: MultilinearExtension<F>>(&self, g: &P) -> Result<(), Error> {
+        if self.challenges.len() != self.num_vars || g.num_vars() != self.num_vars {
+            return Err(Rejection::WrongNumberOfRounds.into());
+        }
+        if g.evaluate(&self.challenges) != Some(self.claim) {
+            return Err(Rejection::WrongFinalEvaluation.into());
+        }
+        Ok(())
+    }
+}
+
+/// Everything exchanged in a run of the interactive protocol.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transcript<F: Field> {
+    /// The claimed sum `H`.
+    pub claimed_sum: F,
+    /// The prover's messages `g_1, ..., g_n`.
+    pub messages: Vec<RoundMessage<F>>,
+    /// The verifier's challenges `r_1, ..., r_n`.
+    pub challenges: Vec<F>,
+}
+
+/// Runs the honest prover against the verifier for the polynomial `g`, and returns the
+/// transcript together with the verifier's verdict.
+pub fn run_interactive<F, P, R>(g: &P, rng: &mut R) -> (Transcript<F>, Result<(), Error>)
+where
+    F: Field,
+    P: MultilinearExtension<F>,
+    R: Rng + ?Sized,
+{
+    let mut prover = Prover::new(g);
+    let claimed_sum = prover.claimed_sum();
+    let mut verifier = Verifier::new(g.num_vars(), claimed_sum);
+    let mut messages = Vec::new();
+    let mut result = Ok(());
+    for _ in 0..g.num_vars() {
+        let message = prover.message();
+        messages.push(message);
+        match verifier.receive_message(&message, rng) {
+            Ok(challenge) => prover.receive_challenge(challenge),
+            Err(rejection) => {
+                result = Err(rejection);
+                break;
+            }
+        }
+    }
+    let result = result.and_then(|_| verifier.finish(g));
+    let transcript = Transcript {
+        claimed_sum,
+        messages,
+        challenges: verifier.challenges().to_vec(),
+    };
+    (transcript, result)
+}
+
+/// A non-interactive sumcheck proof.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proof<F: Field> {
+    /// The claimed sum `H`.
+    pub claimed_sum: F,
+    /// The prover's messages `g_1, ..., g_n`.
+    pub messages: Vec<RoundMessage<F>>,
+}
+
+/// Proves that `g` sums to [`Proof::claimed_sum`] over the hypercube, with Fiat–Shamir
+/// challenges.
+///
+/// ```rust
+/// use ark_algebra_intro::protocols::sumcheck::{
+///     prove, verify, DenseMultilinearExtension, MultilinearExtension, Rejection,
+/// };
+/// use ark_algebra_intro::Error;
+/// use ark_bls12_381::Fr;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let g = DenseMultilinearExtension::<Fr>::rand(5, &mut rng);
+/// let proof = prove(&g);
+/// assert_eq!(verify(&g, &proof), Ok(()));
+///
+/// // Claiming a wrong sum is caught in the first round...
+/// let mut cheat = proof.clone();
+/// cheat.claimed_sum += Fr::from(1u64);
+/// assert_eq!(verify(&g, &cheat), Err(Error::Sumcheck(Rejection::InconsistentRound(0))));
+/// // ...and patching the first message to match only moves the problem to the next one,
+/// // since the challenges now differ from those the other messages were computed for.
+/// cheat.messages[0].at_zero += Fr::from(1u64);
+/// assert_eq!(verify(&g, &cheat), Err(Error::Sumcheck(Rejection::InconsistentRound(1))));
+/// ```
+pub fn prove<F: PrimeField, P: MultilinearExtension<F>>(g: &P) -> Proof<F> {
+    let mut prover = Prover::new(g);
+    let claimed_sum = prover.claimed_sum();
+    let mut transcript = start_transcript(g, &claimed_sum);
+    let mut messages = Vec::new();
+    for _ in 0..g.num_vars() {
+        let message = prover.message();
+        prover.receive_challenge(absorb(&mut transcript, &message));
+        messages.push(message);
+    }
+    Proof {
+        claimed_sum,
+        messages,
+    }
+}
+
+/// Checks a proof produced by [`prove`].
+pub fn verify<F: PrimeField, P: MultilinearExtension<F>>(
+    g: &P,
+    proof: &Proof<F>,
+) -> Result<(), Error> {
+    let mut verifier = Verifier::new(g.num_vars(), proof.claimed_sum);
+    let mut transcript = start_transcript(g, &proof.claimed_sum);
+    for message in &proof.messages {
+        let challenge = absorb(&mut transcript, message);
+        verifier.receive_message_with_challenge(message, challenge)?;
+    }
+    verifier.finish(g)
+}
+
+/// Starts the transcript with the statement: the polynomial and its claimed sum.
+///
+/// Here the verifier knows `g` in full, so it absorbs all of its values; a real protocol
+/// would absorb a commitment to `g` instead. Leaving `g` out altogether would be the
+/// weak Fiat–Shamir transform (see [`crate::transcript`]).
+fn start_transcript<F: PrimeField, P: MultilinearExtension<F>>(
+    g: &P,
+    claimed_sum: &F,
+) -> FiatShamir {
+    let mut transcript = FiatShamir::new(PROOF_LABEL);
+    transcript.append_serializable(b"polynomial", &g.to_evaluations());
+    transcript.append_field(b"claimed sum", claimed_sum);
+    transcript
+}
+
+/// Absorbs `message` into the transcript, and squeezes the next challenge.
+fn absorb<F: PrimeField>(transcript: &mut FiatShamir, message: &RoundMessage<F>) -> F {
+    transcript.append_field(b"g_i(0)", &message.at_zero);
+    transcript.append_field(b"g_i(1)", &message.at_one);
+    transcript.challenge_scalar(b"challenge")
+}
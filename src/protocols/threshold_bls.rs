@@ -0,0 +1,139 @@
+//! Threshold BLS signatures.
+//!
+//! A `t`-of-`n` threshold signature scheme lets any `t` of `n` parties sign on behalf of
+//! the group, while any `t - 1` of them cannot. With [BLS signatures](super::bls), this
+//! is remarkably simple:
+//!
+//! 1. the group secret key `sk` is split into [Shamir shares](super::shamir)
+//!    `sk_i = f(i)`, with `f(0) = sk`;
+//! 2. party `i` signs a message with its share as usual, giving the *partial signature*
+//!    `σ_i = sk_i · H(m)`, which can be checked against its *verification key*
+//!    `sk_i · g2`;
+//! 3. since Lagrange interpolation is linear, any `t` partial signatures can be combined
+//!    "in the exponent": `Σ λ_i · σ_i = (Σ λ_i · sk_i) · H(m) = sk · H(m)`, where the
+//!    `λ_i` are the [Lagrange coefficients](crate::poly::lagrange_coefficients) at zero.
+//!
+//! The combined signature is an ordinary BLS signature under the group public key, and
+//! does not reveal which parties produced it.
+//!
+//! ```rust
+//! use ark_algebra_intro::protocols::bls::verify;
+//! use ark_algebra_intro::protocols::threshold_bls::{combine, deal, partial_sign};
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let (public_key, _, shares) = deal(3, 5, &mut rng);
+//!
+//! let msg = b"transfer 10 coins";
+//! let partials: Vec<_> = [0, 2, 4].iter().map(|&i| partial_sign(&shares[i], msg)).collect();
+//! let signature = combine(&partials);
+//! assert!(verify(&public_key, msg, &signature));
+//!
+//! // Two partial signatures are not enough.
+//! assert!(!verify(&public_key, msg, &combine(&partials[..2])));
+//! ```
+//!
+//! Here, [`deal`] plays the role of a trusted dealer; the dealer can be removed with
+//! [distributed key generation](super::feldman_vss).
+
+use super::bls::{self, PublicKey, SecretKey, Signature};
+use super::shamir;
+use crate::poly::lagrange_coefficients;
+use ark_bls12_381::Fr;
+use ark_ec::ProjectiveCurve;
+use ark_ff::Zero;
+use ark_std::rand::Rng;
+use ark_std::vec::Vec;
+
+/// A party's share `sk_i` of the group secret key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeyShare {
+    /// The (nonzero) index `i` of the party.
+    pub index: u64,
+    /// The share `sk_i`.
+    pub secret: SecretKey,
+}
+
+/// A signature `sk_i · H(m)` by a single party.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PartialSignature {
+    /// The index of the signing party.
+    pub index: u64,
+    /// The signature under the party's key share.
+    pub signature: Signature,
+}
+
+/// Samples a group key for a `threshold`-of-`n` scheme, and returns the group public key,
+/// the verification keys of the parties, and their key shares.
+///
+/// # Panics
+///
+/// Panics unless `1 ≤ threshold ≤ n`.
+pub fn deal<R: Rng + ?Sized>(
+    threshold: usize,
+    n: usize,
+    rng: &mut R,
+) -> (PublicKey, Vec<PublicKey>, Vec<KeyShare>) {
+    let (sk, pk) = bls::keygen(rng);
+    let shares: Vec<_> = shamir::split(sk.0, threshold, n, rng)
+        .into_iter()
+        .map(|share| KeyShare {
+            index: share.index,
+            secret: SecretKey(share.value),
+        })
+        .collect();
+    let verification_keys = shares.iter().map(|s| bls::public_key(&s.secret)).collect();
+    (pk, verification_keys, shares)
+}
+
+/// Signs `msg` with a key share.
+pub fn partial_sign(share: &KeyShare, msg: &[u8]) -> PartialSignature {
+    PartialSignature {
+        index: share.index,
+        signature: bls::sign(&share.secret, msg),
+    }
+}
+
+/// Checks a partial signature on `msg` against the verification key of its signer.
+///
+/// Checking partial signatures before combining them lets a combiner identify
+/// misbehaving parties, instead of just ending up with an invalid signature:
+///
+/// ```rust
+/// use ark_algebra_intro::protocols::bls::Signature;
+/// use ark_algebra_intro::protocols::threshold_bls::{deal, partial_sign, verify_partial};
+/// use ark_bls12_381::G1Affine;
+/// use ark_ec::AffineCurve;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let (_, verification_keys, shares) = deal(2, 3, &mut rng);
+///
+/// let honest = partial_sign(&shares[0], b"msg");
+/// assert!(verify_partial(&verification_keys[0], b"msg", &honest));
+///
+/// let mut bogus = partial_sign(&shares[1], b"msg");
+/// bogus.signature = Signature(G1Affine::prime_subgroup_generator());
+/// assert!(!verify_partial(&verification_keys[1], b"msg", &bogus));
+/// ```
+pub fn verify_partial(
+    verification_key: &PublicKey,
+    msg: &[u8],
+    partial: &PartialSignature,
+) -> bool {
+    bls::verify(verification_key, msg, &partial.signature)
+}
+
+/// Combines partial signatures from distinct parties into a signature under the group
+/// public key.
+///
+/// The result is only valid if there are at least `threshold` partial signatures, and
+/// they are all valid.
+///
+/// # Panics
+///
+/// Panics if two partial signatures have the same index.
+pub fn combine(partials: &[PartialSignature]) -> Signature {
+    let indices: Vec<_> = partials.iter().map(|p| Fr::from(p.index)).collect();
+    let bases: Vec<_> = partials.iter().map(|p| p.signature.0).collect();
+    let coefficients = lagrange_coefficients(&indices, Fr::zero());
+    Signature(crate::msm::arkworks(&bases, &coefficients).into_affine())
+}
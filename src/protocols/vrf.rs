@@ -0,0 +1,127 @@
+//! A verifiable random function in the style of ECVRF (RFC 9381), over BLS12-381's G1.
+//!
+//! A VRF is a keyed hash function whose outputs come with proofs: the holder of the
+//! secret key `x` can compute the output `β` on an input `α`, together with a proof `π`
+//! that convinces anyone holding the public key `Y = x · G` that `β` is *the* output on
+//! `α`. Without the secret key, the output is unpredictable. VRFs are used, for instance,
+//! to elect block proposers privately in proof-of-stake blockchains.
+//!
+//! The construction:
+//!
+//! 1. [hash](crate::hash_to_curve::hash_to_g1) the input to a point `H = H(α)`;
+//! 2. compute `Γ = x · H`, and prove that `log_G(Y) = log_H(Γ)`, i.e., that `Γ` was
+//!    computed with the secret key belonging to `Y`, with a
+//!    [Chaum–Pedersen proof](super::dleq);
+//! 3. hash `Γ` to get the output `β`.
+//!
+//! ```rust
+//! use ark_algebra_intro::protocols::vrf::{keygen, proof_to_output, prove, verify};
+//! use ark_algebra_intro::Error;
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let (sk, pk) = keygen(&mut rng);
+//! let proof = prove(&sk, b"round 17");
+//! let output = proof_to_output(&proof);
+//! assert_eq!(verify(&pk, b"round 17", &proof), Ok(output));
+//! assert_eq!(verify(&pk, b"round 18", &proof), Err(Error::InvalidProof));
+//! ```
+//!
+//! Unlike RFC 9381, which specifies suites over P-256 and Edwards25519, this uses
+//! BLS12-381 and this crate's own domain separation tags, so its outputs are *not*
+//! interoperable with any standardized VRF.
+
+use super::dleq;
+use super::{hash_to_scalar, to_bytes};
+use crate::hash_to_curve::hash_to_g1;
+use crate::Error;
+use ark_bls12_381::{Fr, G1Affine};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::UniformRand;
+use ark_std::rand::Rng;
+use sha2::{Digest, Sha256};
+
+/// The domain separation tag for hashing inputs to the curve.
+const INPUT_DST: &[u8] = b"ARK-ALGEBRA-INTRO-VRF-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+
+/// The domain separation tag for deriving nonces.
+const NONCE_DST: &[u8] = b"ARK-ALGEBRA-INTRO-VRF-NONCE";
+
+/// The prefix for hashing `Γ` to the output.
+const OUTPUT_PREFIX: &[u8] = b"ARK-ALGEBRA-INTRO-VRF-OUTPUT";
+
+/// A secret VRF key `x`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SecretKey(pub Fr);
+
+/// A public VRF key `x · G`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PublicKey(pub G1Affine);
+
+/// A VRF proof.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Proof {
+    /// The point `Γ = x · H(α)`, from which the output is derived.
+    pub gamma: G1Affine,
+    /// The proof that `log_G(Y) = log_H(Γ)`.
+    pub dleq: dleq::Proof,
+}
+
+/// Samples a random secret key, and returns it with the corresponding public key.
+pub fn keygen<R: Rng + ?Sized>(rng: &mut R) -> (SecretKey, PublicKey) {
+    let sk = SecretKey(Fr::rand(rng));
+    (sk, public_key(&sk))
+}
+
+/// Computes the public key `x · G` of a secret key.
+pub fn public_key(sk: &SecretKey) -> PublicKey {
+    PublicKey(G1Affine::prime_subgroup_generator().mul(sk.0).into_affine())
+}
+
+/// Computes the proof for the input `alpha`.
+///
+/// Proving is deterministic: the nonce `k` is derived by hashing the secret key and
+/// `H(α)`, as in RFC 9381, so that no randomness can be reused across inputs (compare
+/// [`schnorr::recover_key_from_nonce_reuse`](super::schnorr::recover_key_from_nonce_reuse)).
+pub fn prove(sk: &SecretKey, alpha: &[u8]) -> Proof {
+    let g = G1Affine::prime_subgroup_generator();
+    let h = hash_to_g1(alpha, INPUT_DST);
+    let nonce = hash_to_scalar(NONCE_DST, &[&to_bytes(&sk.0), &to_bytes(&h)]);
+    Proof {
+        gamma: h.mul(sk.0).into_affine(),
+        dleq: dleq::prove_with_nonce(&g, &h, &sk.0, nonce),
+    }
+}
+
+/// Checks `proof` for the input `alpha`, and returns the corresponding output if it is
+/// valid, or [`Error::InvalidProof`] if it is not.
+pub fn verify(pk: &PublicKey, alpha: &[u8], proof: &Proof) -> Result<[u8; 32], Error> {
+    let g = G1Affine::prime_subgroup_generator();
+    let h = hash_to_g1(alpha, INPUT_DST);
+    if dleq::verify(&g, &pk.0, &h, &proof.gamma, &proof.dleq) {
+        Ok(proof_to_output(proof))
+    } else {
+        Err(Error::InvalidProof)
+    }
+}
+
+/// Computes the output `β = SHA-256(prefix || Γ)` from a proof, without checking it.
+///
+/// The output only depends on the secret key and the input, so it can serve as a test
+/// vector:
+///
+/// ```rust
+/// use ark_algebra_intro::protocols::vrf::{proof_to_output, prove, SecretKey};
+/// use ark_bls12_381::Fr;
+///
+/// let sk = SecretKey(Fr::from(42u64));
+/// let proof = prove(&sk, b"sample");
+/// let output: String = proof_to_output(&proof).iter().map(|b| format!("{:02x}", b)).collect();
+/// assert_eq!(output, "af54556e41392f26aec83c466fc0016966902b48551d74a834da7b55c4c016f9");
+/// ```
+pub fn proof_to_output(proof: &Proof) -> [u8; 32] {
+    Sha256::new()
+        .chain_update(OUTPUT_PREFIX)
+        .chain_update(to_bytes(&proof.gamma))
+        .finalize()
+        .into()
+}
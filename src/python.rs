@@ -0,0 +1,304 @@
+//! Python bindings, for demos in notebooks.
+//!
+//! With the `python` feature, this module defines a Python extension module
+//! `algebra_intro` (with [PyO3](https://pyo3.rs)) that wraps BLS12-381's scalar field,
+//! groups and pairing, and [KZG commitments](crate::commitments::kzg). Python's operators
+//! map to the arkworks ones, so notebook code reads like the math:
+//!
+//! ```python
+//! from algebra_intro import Fr, G1, G2, KzgSrs, pairing
+//!
+//! a = Fr(3)
+//! assert a * a.inverse() == Fr(1)
+//! assert Fr(-1) == Fr(Fr.modulus() - 1)
+//!
+//! g, h = G1.generator(), G2.generator()
+//! assert pairing(a * g, h) == pairing(g, a * h) == pairing(g, h) ** Fr(3)
+//!
+//! srs = KzgSrs.setup(4, seed=0)
+//! p = [Fr(1), Fr(2), Fr(3)]           # 1 + 2X + 3X^2
+//! c = srs.commit(p)
+//! value, proof = srs.open(p, Fr(2))
+//! assert value == Fr(17)
+//! assert srs.verify(c, Fr(2), value, proof)
+//! ```
+//!
+//! Points convert to and from bytes in the [ZCash format](crate::interop::zcash_format).
+//!
+//! An extension module has to be a `cdylib` linked against Python in a specific way, so
+//! this crate only provides [`register`]; `examples/python` in the repository is the crate
+//! to build with [maturin](https://www.maturin.rs).
+
+use crate::commitments::kzg;
+use crate::display::FieldFmt;
+use crate::interop::zcash_format;
+use crate::poly::{DensePolynomial, UVPolynomial};
+use ark_bls12_381::{Bls12_381, Fq12, Fr, G1Affine, G2Affine};
+use ark_ec::{AffineCurve, PairingEngine};
+use ark_ff::{Field, FpParameters, One, PrimeField, Zero};
+use num_bigint::BigUint;
+use pyo3::exceptions::{PyValueError, PyZeroDivisionError};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+/// An element of BLS12-381's scalar field.
+#[pyclass(name = "Fr", module = "algebra_intro", frozen, eq, hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PyFr(pub Fr);
+
+#[pymethods]
+impl PyFr {
+    /// Builds an element from a Python `int` (reduced modulo `r`) or a decimal string.
+    #[new]
+    fn new(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let decimal = value.str()?.to_string();
+        let modulus: BigUint = <Fr as PrimeField>::Params::MODULUS.into();
+        let (negative, digits) = match decimal.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, decimal.as_str()),
+        };
+        let magnitude = BigUint::parse_bytes(digits.as_bytes(), 10)
+            .ok_or_else(|| PyValueError::new_err(format!("not an integer: {}", decimal)))?;
+        let element = Fr::from(magnitude % modulus);
+        Ok(PyFr(if negative { -element } else { element }))
+    }
+
+    /// Returns the modulus `r`.
+    #[staticmethod]
+    fn modulus() -> BigUint {
+        <Fr as PrimeField>::Params::MODULUS.into()
+    }
+
+    /// Returns the inverse, or raises `ZeroDivisionError` for zero.
+    fn inverse(&self) -> PyResult<Self> {
+        self.0
+            .inverse()
+            .map(PyFr)
+            .ok_or_else(|| PyZeroDivisionError::new_err("zero has no inverse"))
+    }
+
+    fn __add__(&self, other: &Self) -> Self {
+        PyFr(self.0 + other.0)
+    }
+
+    fn __sub__(&self, other: &Self) -> Self {
+        PyFr(self.0 - other.0)
+    }
+
+    fn __mul__(&self, other: &Self) -> Self {
+        PyFr(self.0 * other.0)
+    }
+
+    fn __truediv__(&self, other: &Self) -> PyResult<Self> {
+        Ok(PyFr(self.0 * other.inverse()?.0))
+    }
+
+    fn __neg__(&self) -> Self {
+        PyFr(-self.0)
+    }
+
+    fn __pow__(&self, exponent: u64, _modulo: Option<u64>) -> Self {
+        PyFr(self.0.pow([exponent]))
+    }
+
+    /// Returns the canonical integer in `[0, r)`.
+    fn __int__(&self) -> BigUint {
+        self.0.into()
+    }
+
+    /// Shows the balanced representative, e.g., `Fr(-1)` for `r - 1`.
+    fn __repr__(&self) -> String {
+        format!("Fr({})", FieldFmt(self.0).balanced())
+    }
+}
+
+/// Defines a Python class for a group, with the ZCash encoding.
+macro_rules! py_group {
+    ($name:ident, $py_name:literal, $affine:ty, $encode:path, $decode:path) => {
+        #[doc = concat!("A point of ", $py_name, ".")]
+        #[pyclass(name = $py_name, module = "algebra_intro", frozen, eq)]
+        #[derive(Clone, PartialEq, Eq)]
+        pub struct $name(pub $affine);
+
+        #[pymethods]
+        impl $name {
+            /// Returns the generator.
+            #[staticmethod]
+            fn generator() -> Self {
+                $name(<$affine>::prime_subgroup_generator())
+            }
+
+            /// Returns the point at infinity.
+            #[staticmethod]
+            fn identity() -> Self {
+                $name(<$affine>::zero())
+            }
+
+            /// Decodes the ZCash encoding of a point, checking that it is in the
+            /// prime-order subgroup.
+            #[staticmethod]
+            fn from_bytes(bytes: &[u8]) -> PyResult<Self> {
+                $decode(bytes)
+                    .map($name)
+                    .map_err(|e| PyValueError::new_err(e.to_string()))
+            }
+
+            /// Returns the ZCash encoding of the point.
+            fn to_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+                PyBytes::new(py, &$encode(&self.0))
+            }
+
+            fn __add__(&self, other: &Self) -> Self {
+                $name(self.0 + other.0)
+            }
+
+            fn __sub__(&self, other: &Self) -> Self {
+                $name(self.0 + (-other.0))
+            }
+
+            fn __neg__(&self) -> Self {
+                $name(-self.0)
+            }
+
+            fn __rmul__(&self, scalar: &PyFr) -> Self {
+                $name(self.0.mul(scalar.0).into())
+            }
+
+            fn __mul__(&self, scalar: &PyFr) -> Self {
+                self.__rmul__(scalar)
+            }
+
+            fn __repr__(&self) -> String {
+                let hex: String = $encode(&self.0)
+                    .iter()
+                    .map(|b| format!("{:02x}", b))
+                    .collect();
+                format!("{}(0x{})", $py_name, hex)
+            }
+        }
+    };
+}
+
+py_group!(
+    PyG1,
+    "G1",
+    G1Affine,
+    zcash_format::encode_g1,
+    zcash_format::decode_g1
+);
+py_group!(
+    PyG2,
+    "G2",
+    G2Affine,
+    zcash_format::encode_g2,
+    zcash_format::decode_g2
+);
+
+/// An element of the target group of the pairing, in `F_{q^12}`.
+#[pyclass(name = "Gt", module = "algebra_intro", frozen, eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct PyGt(pub Fq12);
+
+#[pymethods]
+impl PyGt {
+    /// Returns the identity, `1`.
+    #[staticmethod]
+    fn identity() -> Self {
+        PyGt(Fq12::one())
+    }
+
+    fn __mul__(&self, other: &Self) -> Self {
+        PyGt(self.0 * other.0)
+    }
+
+    fn __pow__(&self, exponent: &PyFr, _modulo: Option<u64>) -> Self {
+        PyGt(self.0.pow(exponent.0.into_repr()))
+    }
+
+    fn __repr__(&self) -> String {
+        "Gt(...)".to_string()
+    }
+}
+
+/// Computes the pairing `e(p, q)`.
+#[pyfunction]
+fn pairing(p: &PyG1, q: &PyG2) -> PyGt {
+    PyGt(Bls12_381::pairing(p.0, q.0))
+}
+
+/// A structured reference string for KZG commitments.
+#[pyclass(name = "KzgSrs", module = "algebra_intro", frozen)]
+pub struct PyKzgSrs(pub kzg::Srs<Bls12_381>);
+
+/// Converts a list of coefficients, from the constant term up, to a polynomial.
+fn polynomial(coeffs: Vec<PyFr>) -> DensePolynomial<Fr> {
+    DensePolynomial::from_coefficients_vec(coeffs.into_iter().map(|c| c.0).collect())
+}
+
+/// Raises `ValueError` unless `coeffs` fits in the SRS.
+fn check_degree(srs: &kzg::Srs<Bls12_381>, coeffs: &[PyFr]) -> PyResult<()> {
+    if coeffs.len() > srs.max_degree() + 1 {
+        return Err(PyValueError::new_err(format!(
+            "the SRS only supports polynomials of degree at most {}",
+            srs.max_degree()
+        )));
+    }
+    Ok(())
+}
+
+#[pymethods]
+impl PyKzgSrs {
+    /// Runs a setup for polynomials of degree at most `max_degree`, with a secret sampled
+    /// from a [seeded](crate::rng::seeded) generator if `seed` is given (which is insecure,
+    /// but reproducible), and from the system's randomness otherwise.
+    #[staticmethod]
+    #[pyo3(signature = (max_degree, seed = None))]
+    fn setup(max_degree: usize, seed: Option<u64>) -> Self {
+        let srs = match seed {
+            Some(seed) => kzg::Srs::setup(max_degree, &mut crate::rng::seeded(seed)),
+            None => kzg::Srs::setup(max_degree, &mut ark_std::rand::thread_rng()),
+        };
+        PyKzgSrs(srs)
+    }
+
+    /// Returns the maximum degree.
+    fn max_degree(&self) -> usize {
+        self.0.max_degree()
+    }
+
+    /// Commits to the polynomial with coefficients `coeffs`, from the constant term up.
+    fn commit(&self, coeffs: Vec<PyFr>) -> PyResult<PyG1> {
+        check_degree(&self.0, &coeffs)?;
+        Ok(PyG1(kzg::commit(&self.0, &polynomial(coeffs)).0))
+    }
+
+    /// Returns the value of the polynomial at `point`, and a proof of it.
+    fn open(&self, coeffs: Vec<PyFr>, point: &PyFr) -> PyResult<(PyFr, PyG1)> {
+        check_degree(&self.0, &coeffs)?;
+        let (value, proof) = kzg::open(&self.0, &polynomial(coeffs), point.0);
+        Ok((PyFr(value), PyG1(proof.0)))
+    }
+
+    /// Checks a proof that the committed polynomial takes the value `value` at `point`.
+    fn verify(&self, commitment: &PyG1, point: &PyFr, value: &PyFr, proof: &PyG1) -> bool {
+        kzg::verify(
+            &self.0,
+            &kzg::Commitment(commitment.0),
+            point.0,
+            value.0,
+            &kzg::Proof(proof.0),
+        )
+    }
+}
+
+/// Adds the classes and functions of the `algebra_intro` module to `m`, from the
+/// `#[pymodule]` of an extension crate.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyFr>()?;
+    m.add_class::<PyG1>()?;
+    m.add_class::<PyG2>()?;
+    m.add_class::<PyGt>()?;
+    m.add_class::<PyKzgSrs>()?;
+    m.add_function(wrap_pyfunction!(pairing, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,345 @@
+//! Rank-one constraint systems (R1CS), the language SNARKs such as Groth16 prove
+//! statements in.
+//!
+//! An R1CS over a field `F` is a list of constraints on a vector of variables
+//! `z = (1, public inputs..., witness...)`. Each constraint has the form
+//!
+//! ```text
+//! <a_i, z> · <b_i, z> = <c_i, z>,
+//! ```
+//!
+//! i.e., the product of two linear combinations of the variables equals a third. The
+//! rows `a_i`, `b_i` and `c_i` form three matrices `A`, `B` and `C`, and `z` *satisfies*
+//! the system if `Az ∘ Bz = Cz`, where `∘` is the entry-wise product.
+//!
+//! # Flattening a computation
+//!
+//! To prove knowledge of an `x` with `x³ + x + 5 = 35` (the running example of Vitalik
+//! Buterin's "Quadratic Arithmetic Programs: from Zero to Hero"), we break the
+//! computation into steps with at most one multiplication each:
+//!
+//! ```text
+//! sym_1 = x · x
+//! y     = sym_1 · x
+//! out   = (y + x + 5) · 1
+//! ```
+//!
+//! With the variables `z = (1, out, x, sym_1, y)`, where `out = 35` is public, each step is
+//! one row of the matrices; [`cubic_r1cs`] writes them out by hand. The solution `x = 3`
+//! gives the satisfying assignment `z = (1, 35, 3, 9, 27)`:
+//!
+//! ```rust
+//! use ark_algebra_intro::r1cs::{cubic_assignment, cubic_r1cs};
+//! use ark_bls12_381::Fr;
+//!
+//! let r1cs = cubic_r1cs::<Fr>();
+//! let z = cubic_assignment(Fr::from(3u64));
+//! assert_eq!(z[1], Fr::from(35u64));
+//! assert!(r1cs.is_satisfied(&z));
+//!
+//! // Any other assignment violates some constraint. With `x = 4`, the intermediate
+//! // values are consistent, but `out` is 73 rather than 35.
+//! let mut z = cubic_assignment(Fr::from(4u64));
+//! z[1] = Fr::from(35u64);
+//! assert_eq!(r1cs.first_unsatisfied(&z), Some(2));
+//! ```
+//!
+//! # Using `ark-relations`
+//!
+//! Writing matrices by hand does not scale: `arkworks` circuits instead implement
+//! [`ConstraintSynthesizer`], creating variables and enforcing constraints on a
+//! [`ConstraintSystemRef`], which records the matrices (and, when proving, the
+//! assignment). [`CubicCircuit`] is the circuit above, and [`R1CSDebugger`] shows what it
+//! compiles to:
+//!
+//! ```rust
+//! use ark_algebra_intro::r1cs::{cubic_r1cs, CubicCircuit, R1CSDebugger};
+//! use ark_bls12_381::Fr;
+//!
+//! let circuit = CubicCircuit { x: Some(Fr::from(3u64)), out: Some(Fr::from(35u64)) };
+//! let debugger = R1CSDebugger::new(circuit)
+//!     .unwrap()
+//!     .with_names(&["1", "out", "x", "sym_1", "y"]);
+//! assert!(debugger.is_satisfied());
+//! assert_eq!(debugger.matrices(), cubic_r1cs());
+//! assert_eq!(
+//!     debugger.constraints(),
+//!     ["(x) · (x) = (sym_1)", "(sym_1) · (x) = (y)", "(5 + x + y) · (1) = (out)"],
+//! );
+//! debugger.print_constraints();
+//! debugger.print_matrices();
+//!
+//! let wrong = CubicCircuit { x: Some(Fr::from(4u64)), out: Some(Fr::from(35u64)) };
+//! assert!(!R1CSDebugger::new(wrong).unwrap().is_satisfied());
+//! ```
+//!
+//! This module is only available with the `r1cs` feature.
+
+use crate::display::FieldFmt;
+use ark_ff::{Field, PrimeField};
+use ark_relations::lc;
+pub use ark_relations::r1cs::{
+    ConstraintMatrices, ConstraintSynthesizer, ConstraintSystem, ConstraintSystemRef,
+    SynthesisError, Variable,
+};
+
+/// An R1CS with dense matrices, over the variables `z = (1, public inputs..., witness...)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct R1CS<F> {
+    /// The number of public variables, including the constant `1`.
+    pub num_instance_variables: usize,
+    /// The left matrix `A`, one row per constraint.
+    pub a: Vec<Vec<F>>,
+    /// The right matrix `B`.
+    pub b: Vec<Vec<F>>,
+    /// The output matrix `C`.
+    pub c: Vec<Vec<F>>,
+}
+
+impl<F: Field> R1CS<F> {
+    /// Converts the sparse matrices produced by `ark-relations` into dense ones.
+    pub fn from_matrices(matrices: &ConstraintMatrices<F>) -> Self {
+        let width = matrices.num_instance_variables + matrices.num_witness_variables;
+        let densify = |sparse: &[Vec<(F, usize)>]| {
+            sparse
+                .iter()
+                .map(|row| {
+                    let mut dense = vec![F::zero(); width];
+                    for (coeff, column) in row {
+                        dense[*column] += coeff;
+                    }
+                    dense
+                })
+                .collect()
+        };
+        Self {
+            num_instance_variables: matrices.num_instance_variables,
+            a: densify(&matrices.a),
+            b: densify(&matrices.b),
+            c: densify(&matrices.c),
+        }
+    }
+
+    /// Returns the number of constraints.
+    pub fn num_constraints(&self) -> usize {
+        self.a.len()
+    }
+
+    /// Returns the number of variables, including the constant `1`.
+    pub fn num_variables(&self) -> usize {
+        self.a.first().map_or(self.num_instance_variables, Vec::len)
+    }
+
+    /// Returns the index of the first constraint that `z` violates, or `None` if `z`
+    /// satisfies every constraint.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `z` has the wrong length.
+    pub fn first_unsatisfied(&self, z: &[F]) -> Option<usize> {
+        assert_eq!(z.len(), self.num_variables(), "wrong number of variables");
+        (0..self.num_constraints()).find(|&i| {
+            inner_product(&self.a[i], z) * inner_product(&self.b[i], z)
+                != inner_product(&self.c[i], z)
+        })
+    }
+
+    /// Checks whether `z` satisfies every constraint, and starts with `1`.
+    pub fn is_satisfied(&self, z: &[F]) -> bool {
+        z.first() == Some(&F::one()) && self.first_unsatisfied(z).is_none()
+    }
+}
+
+fn inner_product<F: Field>(row: &[F], z: &[F]) -> F {
+    row.iter().zip(z).map(|(a, b)| *a * b).sum()
+}
+
+/// The hand-built R1CS for `x³ + x + 5 = out`, over `z = (1, out, x, sym_1, y)`.
+pub fn cubic_r1cs<F: Field>() -> R1CS<F> {
+    let row = |entries: [u64; 5]| entries.iter().map(|e| F::from(*e)).collect::<Vec<_>>();
+    R1CS {
+        num_instance_variables: 2,
+        a: vec![
+            row([0, 0, 1, 0, 0]),
+            row([0, 0, 0, 1, 0]),
+            row([5, 0, 1, 0, 1]),
+        ],
+        b: vec![
+            row([0, 0, 1, 0, 0]),
+            row([0, 0, 1, 0, 0]),
+            row([1, 0, 0, 0, 0]),
+        ],
+        c: vec![
+            row([0, 0, 0, 1, 0]),
+            row([0, 0, 0, 0, 1]),
+            row([0, 1, 0, 0, 0]),
+        ],
+    }
+}
+
+/// Returns the assignment `z = (1, out, x, sym_1, y)` that computes `out = x³ + x + 5`.
+pub fn cubic_assignment<F: Field>(x: F) -> Vec<F> {
+    let sym_1 = x * x;
+    let y = sym_1 * x;
+    vec![F::one(), y + x + F::from(5u64), x, sym_1, y]
+}
+
+/// The circuit for `x³ + x + 5 = out`, with `out` public and `x` secret.
+///
+/// Both values are `None` when generating parameters, since the constraints don't depend
+/// on them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CubicCircuit<F> {
+    /// The secret solution.
+    pub x: Option<F>,
+    /// The public output.
+    pub out: Option<F>,
+}
+
+impl<F: Field> ConstraintSynthesizer<F> for CubicCircuit<F> {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let out = cs.new_input_variable(|| self.out.ok_or(SynthesisError::AssignmentMissing))?;
+        let x_value = self.x.ok_or(SynthesisError::AssignmentMissing);
+        let x = cs.new_witness_variable(|| x_value)?;
+        let sym_1_value = x_value.map(|x| x * x);
+        let sym_1 = cs.new_witness_variable(|| sym_1_value)?;
+        let y = cs.new_witness_variable(|| Ok(sym_1_value? * x_value?))?;
+
+        cs.enforce_constraint(lc!() + x, lc!() + x, lc!() + sym_1)?;
+        cs.enforce_constraint(lc!() + sym_1, lc!() + x, lc!() + y)?;
+        cs.enforce_constraint(
+            lc!() + y + x + (F::from(5u64), Variable::One),
+            lc!() + Variable::One,
+            lc!() + out,
+        )
+    }
+}
+
+/// Synthesizes a circuit, and prints or checks the resulting constraint system.
+#[derive(Debug)]
+pub struct R1CSDebugger<F: Field> {
+    cs: ConstraintSystemRef<F>,
+    names: Vec<String>,
+}
+
+impl<F: PrimeField> R1CSDebugger<F> {
+    /// Generates the constraints of `circuit`, along with its assignment if it has one.
+    pub fn new<C: ConstraintSynthesizer<F>>(circuit: C) -> Result<Self, SynthesisError> {
+        let cs = ConstraintSystem::new_ref();
+        circuit.generate_constraints(cs.clone())?;
+        cs.finalize();
+        let num_instance_variables = cs.num_instance_variables();
+        let names = (0..num_instance_variables)
+            .map(|i| {
+                if i == 0 {
+                    "1".to_string()
+                } else {
+                    format!("in_{}", i)
+                }
+            })
+            .chain((0..cs.num_witness_variables()).map(|i| format!("w_{}", i)))
+            .collect();
+        Ok(Self { cs, names })
+    }
+
+    /// Names the variables, in the order of `z`, for [`R1CSDebugger::constraints`].
+    ///
+    /// By default, they are named `1`, `in_1`, ..., `w_0`, ...
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of names doesn't match the number of variables.
+    pub fn with_names(mut self, names: &[&str]) -> Self {
+        assert_eq!(names.len(), self.names.len(), "wrong number of names");
+        self.names = names.iter().map(|n| n.to_string()).collect();
+        self
+    }
+
+    /// Returns the dense matrices of the constraint system.
+    pub fn matrices(&self) -> R1CS<F> {
+        R1CS::from_matrices(&self.sparse_matrices())
+    }
+
+    /// Returns the full assignment `z`, if the circuit was given one.
+    pub fn assignment(&self) -> Option<Vec<F>> {
+        let cs = self.cs.borrow()?;
+        let z: Vec<_> = cs
+            .instance_assignment
+            .iter()
+            .chain(&cs.witness_assignment)
+            .copied()
+            .collect();
+        Some(z).filter(|z| z.len() == self.names.len())
+    }
+
+    /// Checks whether the assignment satisfies every constraint.
+    ///
+    /// Returns `false` if the circuit was not given an assignment.
+    pub fn is_satisfied(&self) -> bool {
+        self.assignment()
+            .is_some_and(|z| self.matrices().is_satisfied(&z))
+    }
+
+    /// Formats each constraint as `(a) · (b) = (c)`, where `a`, `b` and `c` are linear
+    /// combinations of the named variables.
+    pub fn constraints(&self) -> Vec<String> {
+        let matrices = self.sparse_matrices();
+        (0..matrices.num_constraints)
+            .map(|i| {
+                format!(
+                    "({}) · ({}) = ({})",
+                    self.format_lc(&matrices.a[i]),
+                    self.format_lc(&matrices.b[i]),
+                    self.format_lc(&matrices.c[i])
+                )
+            })
+            .collect()
+    }
+
+    /// Prints [`R1CSDebugger::constraints`], one per line.
+    pub fn print_constraints(&self) {
+        for (i, constraint) in self.constraints().iter().enumerate() {
+            println!("{}: {}", i, constraint);
+        }
+    }
+
+    /// Prints the dense matrices `A`, `B` and `C`, with a column per variable.
+    pub fn print_matrices(&self) {
+        let r1cs = self.matrices();
+        println!("z = ({})", self.names.join(", "));
+        for (name, matrix) in [("A", &r1cs.a), ("B", &r1cs.b), ("C", &r1cs.c)].iter() {
+            println!("{}:", name);
+            for row in matrix.iter() {
+                let entries: Vec<_> = row
+                    .iter()
+                    .map(|e| FieldFmt(*e).balanced().to_string())
+                    .collect();
+                println!("  [{}]", entries.join(", "));
+            }
+        }
+    }
+
+    fn sparse_matrices(&self) -> ConstraintMatrices<F> {
+        self.cs
+            .to_matrices()
+            .expect("the constraint system records its matrices")
+    }
+
+    fn format_lc(&self, lc: &[(F, usize)]) -> String {
+        if lc.is_empty() {
+            return "0".to_string();
+        }
+        let terms: Vec<_> = lc
+            .iter()
+            .map(|(coeff, column)| {
+                let coeff = FieldFmt(*coeff).balanced();
+                match (coeff.to_string().as_str(), *column) {
+                    (_, 0) => coeff.to_string(),
+                    ("1", _) => self.names[*column].clone(),
+                    (c, _) => format!("{}·{}", c, self.names[*column]),
+                }
+            })
+            .collect();
+        terms.join(" + ")
+    }
+}
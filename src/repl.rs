@@ -0,0 +1,806 @@
+//! A calculator for BLS12-381, behind the `algebra-repl` binary.
+//!
+//! Writing a new Rust file for every experiment is slow; `cargo run --bin algebra-repl`
+//! instead evaluates expressions line by line. The language has
+//!
+//! * integer literals (decimal, or hexadecimal with `0x`), which are scalars in `Fr`;
+//! * the generators `g1` and `g2`, and `_` for the previous result;
+//! * `+`, `-`, `*`, `/` and `^` (with a scalar exponent, negative exponents inverting
+//!   first), `==`, and parentheses;
+//! * the functions `pairing(p, q)`, `inv(a)`, `fq(a)` (an element of the base field, from
+//!   a literal or a scalar), `fp(a, p)` (the element `a` of the field of prime order `p`,
+//!   a [`DynField`]), and `g1(0x...)` and `g2(0x...)` (points from their [ZCash
+//!   encoding](crate::interop::zcash_format));
+//! * assignments `name = expression`;
+//! * the commands `:dec` and `:hex`, to print field elements in decimal (the default) or
+//!   hexadecimal, `:vars`, and `:help`.
+//!
+//! Field elements print in [balanced](crate::display::FieldFmt::balanced) representation,
+//! and points as their affine coordinates, or as their ZCash encoding in hexadecimal mode.
+//! Scalars combine with elements of `fp` fields as their balanced representatives, so
+//! that `fp(3, 7) - 1` is `fp(2, 7)`.
+//!
+//! ```rust
+//! use ark_algebra_intro::repl::Repl;
+//!
+//! let mut repl = Repl::new();
+//! assert_eq!(repl.handle("1 / 2 * 2 - 3").unwrap(), "-2");
+//! assert_eq!(repl.handle("a = 6").unwrap(), "6");
+//! assert_eq!(repl.handle("pairing(a * g1, g2) == pairing(g1, g2)^6").unwrap(), "true");
+//! assert_eq!(repl.handle("a^-1 * a").unwrap(), "1");
+//!
+//! assert_eq!(repl.handle("x = fp(3, 101)").unwrap(), "3");
+//! assert_eq!(repl.handle("x^100").unwrap(), "1");
+//! assert_eq!(repl.handle("1 / x").unwrap(), "34");
+//! assert_eq!(repl.handle("x - 5").unwrap(), "-2");
+//! assert_eq!(
+//!     repl.handle("fp(1, 91)").unwrap_err().to_string(),
+//!     "the modulus 91 is not prime",
+//! );
+//!
+//! repl.handle(":hex").unwrap();
+//! assert_eq!(repl.handle("255").unwrap(), "0xff");
+//! assert!(repl.handle("g1").unwrap().starts_with("0x97f1d3a7"));
+//!
+//! assert_eq!(
+//!     repl.handle("g1 + 1").unwrap_err().to_string(),
+//!     "cannot add a G1 point and a scalar",
+//! );
+//! ```
+//!
+//! Expressions can nest up to [`MAX_DEPTH`] levels deep:
+//!
+//! ```rust
+//! use ark_algebra_intro::repl::{Repl, ReplError, MAX_DEPTH};
+//!
+//! let mut repl = Repl::new();
+//! let nested = |depth| format!("{}1{}", "(".repeat(depth), ")".repeat(depth));
+//! assert_eq!(repl.handle(&nested(MAX_DEPTH - 1)).unwrap(), "1");
+//! assert_eq!(repl.handle(&nested(200_000)), Err(ReplError::TooDeep));
+//! assert_eq!(repl.handle(&"-".repeat(200_000)), Err(ReplError::TooDeep));
+//! ```
+
+use crate::display::FieldFmt;
+use crate::dynamic::{DynField, DynPrimeField};
+use crate::encoding::{from_hex, to_hex};
+use crate::fields::{parse_decimal_mod_order, parse_hex_mod_order};
+use crate::interop::{zcash_format, DecodeError};
+use ark_bls12_381::{Bls12_381, Fq, Fq12, Fq2, Fr, G1Affine, G2Affine};
+use ark_ec::{AffineCurve, PairingEngine};
+use ark_ff::{Field, FpParameters, One, PrimeField};
+use ark_serialize::CanonicalSerialize;
+use ark_std::collections::BTreeMap;
+use ark_std::fmt;
+use ark_std::string::{String, ToString};
+use ark_std::vec::Vec;
+use num_bigint::BigUint;
+
+/// The deepest nesting of parentheses, unary minuses and exponents that an expression
+/// can have.
+///
+/// Each level takes tens of kilobytes of stack in a debug build, so this stays well
+/// within the 8 MiB of the main thread, while no expression typed by hand comes close.
+pub const MAX_DEPTH: usize = 64;
+
+/// The help text of the `:help` command.
+pub const HELP: &str = "\
+values:     integers (scalars in Fr, e.g. 42 or 0x2a), g1, g2, _ (the previous result)
+operators:  + - * / ^ == ( )
+functions:  pairing(p, q), inv(a), fq(a), fp(a, p), g1(0x...), g2(0x...)
+variables:  name = expression
+commands:   :dec, :hex, :vars, :help, :quit";
+
+/// A value of the calculator.
+// A session only holds a handful of values, so `Gt` is not worth boxing.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    /// A scalar.
+    Fr(Fr),
+    /// An element of the base field.
+    Fq(Fq),
+    /// A point of G1.
+    G1(G1Affine),
+    /// A point of G2.
+    G2(G2Affine),
+    /// An element of the target group of the pairing.
+    Gt(Fq12),
+    /// An element of a prime field chosen at runtime, from `fp(a, p)`.
+    Fp(DynField),
+    /// The result of a comparison.
+    Bool(bool),
+}
+
+impl Value {
+    /// Returns a description of the type of the value, for error messages.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::Fr(_) => "a scalar",
+            Value::Fq(_) => "an Fq element",
+            Value::G1(_) => "a G1 point",
+            Value::G2(_) => "a G2 point",
+            Value::Gt(_) => "a Gt element",
+            Value::Fp(_) => "an Fp element",
+            Value::Bool(_) => "a boolean",
+        }
+    }
+}
+
+/// The error returned when a line cannot be evaluated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReplError {
+    /// The line is not a valid expression.
+    Syntax(String),
+    /// A variable is used before it is assigned.
+    UnknownVariable(String),
+    /// A function does not exist, or has the wrong number of arguments.
+    UnknownFunction(String),
+    /// An operation is applied to values of the wrong types.
+    Type(String),
+    /// An element is divided by zero.
+    DivisionByZero,
+    /// A point literal is not a valid encoding.
+    InvalidPoint(DecodeError),
+    /// The modulus of `fp(a, p)` is not prime.
+    NotPrime(String),
+    /// A command does not exist.
+    UnknownCommand(String),
+    /// The expression nests parentheses, unary minuses or exponents more than
+    /// [`MAX_DEPTH`] levels deep.
+    TooDeep,
+}
+
+impl fmt::Display for ReplError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplError::Syntax(e) => write!(f, "syntax error: {}", e),
+            ReplError::UnknownVariable(name) => write!(f, "unknown variable `{}`", name),
+            ReplError::UnknownFunction(name) => write!(f, "unknown function `{}`", name),
+            ReplError::Type(e) => write!(f, "{}", e),
+            ReplError::DivisionByZero => write!(f, "division by zero"),
+            ReplError::InvalidPoint(e) => write!(f, "invalid point: {}", e),
+            ReplError::NotPrime(p) => write!(f, "the modulus {} is not prime", p),
+            ReplError::UnknownCommand(c) => write!(f, "unknown command `{}`, try :help", c),
+            ReplError::TooDeep => write!(f, "the expression is nested too deeply"),
+        }
+    }
+}
+
+impl ark_std::error::Error for ReplError {}
+
+/// How the calculator prints field elements and points.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Radix {
+    /// Decimal integers, and points as affine coordinates.
+    Decimal,
+    /// Hexadecimal integers, and points as their ZCash encoding.
+    Hex,
+}
+
+/// The state of a calculator session: its variables and output format.
+#[derive(Clone, Debug)]
+pub struct Repl {
+    /// The variables, including `g1`, `g2` and `_`.
+    pub variables: BTreeMap<String, Value>,
+    /// How to print results.
+    pub radix: Radix,
+}
+
+impl Default for Repl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Repl {
+    /// Starts a session with the generators `g1` and `g2` defined, printing in decimal.
+    pub fn new() -> Self {
+        let mut variables = BTreeMap::new();
+        variables.insert(
+            "g1".to_string(),
+            Value::G1(G1Affine::prime_subgroup_generator()),
+        );
+        variables.insert(
+            "g2".to_string(),
+            Value::G2(G2Affine::prime_subgroup_generator()),
+        );
+        Self {
+            variables,
+            radix: Radix::Decimal,
+        }
+    }
+
+    /// Runs a line (a command, an assignment or an expression), and returns the output to
+    /// print.
+    pub fn handle(&mut self, line: &str) -> Result<String, ReplError> {
+        let line = line.trim();
+        match line {
+            "" => Ok(String::new()),
+            ":help" => Ok(HELP.to_string()),
+            ":dec" => {
+                self.radix = Radix::Decimal;
+                Ok("printing in decimal".to_string())
+            }
+            ":hex" => {
+                self.radix = Radix::Hex;
+                Ok("printing in hexadecimal".to_string())
+            }
+            ":vars" => Ok(self
+                .variables
+                .iter()
+                .map(|(name, value)| format!("{} = {}", name, self.format(value)))
+                .collect::<Vec<_>>()
+                .join("\n")),
+            command if command.starts_with(':') => {
+                Err(ReplError::UnknownCommand(command.to_string()))
+            }
+            _ => {
+                let value = self.eval(line)?;
+                Ok(self.format(&value))
+            }
+        }
+    }
+
+    /// Evaluates an assignment or an expression, and stores the result in `_` (and in the
+    /// assigned variable).
+    pub fn eval(&mut self, line: &str) -> Result<Value, ReplError> {
+        let tokens = tokenize(line)?;
+        let (name, expression) = match tokens.as_slice() {
+            [Token::Ident(name), Token::Op('='), rest @ ..] => (Some(name.clone()), rest),
+            _ => (None, tokens.as_slice()),
+        };
+        let mut parser = Parser {
+            tokens: expression,
+            position: 0,
+            variables: &self.variables,
+            depth: 0,
+        };
+        let value = parser.expression()?;
+        if let Some(token) = parser.peek() {
+            return Err(ReplError::Syntax(format!("unexpected {}", token)));
+        }
+        if let Some(name) = name {
+            self.variables.insert(name, value.clone());
+        }
+        self.variables.insert("_".to_string(), value.clone());
+        Ok(value)
+    }
+
+    /// Formats a value in the current radix.
+    pub fn format(&self, value: &Value) -> String {
+        match (value, self.radix) {
+            (Value::Fr(a), Radix::Decimal) => FieldFmt(*a).balanced().to_string(),
+            (Value::Fr(a), Radix::Hex) => format!("{:#x}", FieldFmt(*a).balanced()),
+            (Value::Fq(a), Radix::Decimal) => FieldFmt(*a).balanced().to_string(),
+            (Value::Fq(a), Radix::Hex) => format!("{:#x}", FieldFmt(*a).balanced()),
+            (Value::G1(p), Radix::Decimal) if p.infinity => "infinity".to_string(),
+            (Value::G1(p), Radix::Decimal) => format!("({}, {})", FieldFmt(p.x), FieldFmt(p.y)),
+            (Value::G1(p), Radix::Hex) => to_hex(&zcash_format::encode_g1(p)),
+            (Value::G2(p), Radix::Decimal) if p.infinity => "infinity".to_string(),
+            (Value::G2(p), Radix::Decimal) => format!("({}, {})", fq2(&p.x), fq2(&p.y)),
+            (Value::G2(p), Radix::Hex) => to_hex(&zcash_format::encode_g2(p)),
+            (Value::Gt(a), _) => {
+                // The 12 coefficients are too long to read; a prefix of the encoding is
+                // enough to tell elements apart, and `==` compares them exactly.
+                let mut bytes = Vec::new();
+                a.serialize(&mut bytes)
+                    .expect("serializing to a vector succeeds");
+                format!("Gt({}...)", to_hex(&bytes[..8]))
+            }
+            (Value::Fp(a), Radix::Decimal) => {
+                let (negative, magnitude) = balanced(a);
+                format!("{}{}", if negative { "-" } else { "" }, magnitude)
+            }
+            (Value::Fp(a), Radix::Hex) => {
+                let (negative, magnitude) = balanced(a);
+                format!("{}{:#x}", if negative { "-" } else { "" }, magnitude)
+            }
+            (Value::Bool(b), _) => b.to_string(),
+        }
+    }
+}
+
+/// Returns the balanced representative of `a`, as a sign and a magnitude.
+fn balanced(a: &DynField) -> (bool, BigUint) {
+    if *a.value() > a.modulus() >> 1usize {
+        (true, a.modulus() - a.value())
+    } else {
+        (false, a.value().clone())
+    }
+}
+
+/// Formats an element of `F_{q^2}` as `c0 + c1*u`.
+fn fq2(a: &Fq2) -> String {
+    format!("{} + {}*u", FieldFmt(a.c0), FieldFmt(a.c1))
+}
+
+/// A token of the calculator's language.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    /// An integer literal, as written.
+    Number(String),
+    /// A variable or function name.
+    Ident(String),
+    /// One of `+ - * / ^ ( ) , =`.
+    Op(char),
+    /// `==`.
+    Equals,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Number(n) => write!(f, "`{}`", n),
+            Token::Ident(name) => write!(f, "`{}`", name),
+            Token::Op(c) => write!(f, "`{}`", c),
+            Token::Equals => write!(f, "`==`"),
+        }
+    }
+}
+
+/// Splits a line into tokens.
+fn tokenize(line: &str) -> Result<Vec<Token>, ReplError> {
+    let mut tokens = Vec::new();
+    let mut chars = line.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_alphanumeric() || c == '_' {
+            let mut word = String::new();
+            while let Some(&c) = chars.peek() {
+                if !(c.is_ascii_alphanumeric() || c == '_') {
+                    break;
+                }
+                word.push(c);
+                chars.next();
+            }
+            if word.starts_with(|c: char| c.is_ascii_digit()) {
+                tokens.push(Token::Number(word));
+            } else {
+                tokens.push(Token::Ident(word));
+            }
+        } else if c == '=' {
+            chars.next();
+            if chars.peek() == Some(&'=') {
+                chars.next();
+                tokens.push(Token::Equals);
+            } else {
+                tokens.push(Token::Op('='));
+            }
+        } else if "+-*/^(),".contains(c) {
+            chars.next();
+            tokens.push(Token::Op(c));
+        } else {
+            return Err(ReplError::Syntax(format!("unexpected character {:?}", c)));
+        }
+    }
+    Ok(tokens)
+}
+
+/// A recursive-descent parser that evaluates as it parses, with the grammar
+///
+/// ```text
+/// expression = sum ["==" sum]
+/// sum        = product {("+" | "-") product}
+/// product    = unary {("*" | "/") unary}
+/// unary      = "-" unary | power
+/// power      = atom ["^" unary]
+/// atom       = number | name | name "(" [expression {"," expression}] ")" | "(" expression ")"
+/// ```
+struct Parser<'a> {
+    tokens: &'a [Token],
+    position: usize,
+    variables: &'a BTreeMap<String, Value>,
+    /// The number of nested calls of [`Parser::unary`], which every level of nesting
+    /// goes through.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<&'a Token> {
+        let token = self.tokens.get(self.position);
+        self.position += 1;
+        token
+    }
+
+    fn expect(&mut self, op: char) -> Result<(), ReplError> {
+        match self.next() {
+            Some(Token::Op(c)) if *c == op => Ok(()),
+            Some(token) => Err(ReplError::Syntax(format!(
+                "expected `{}`, found {}",
+                op, token
+            ))),
+            None => Err(ReplError::Syntax(format!("expected `{}`", op))),
+        }
+    }
+
+    fn expression(&mut self) -> Result<Value, ReplError> {
+        let lhs = self.sum()?;
+        if self.peek() == Some(&Token::Equals) {
+            self.next();
+            let rhs = self.sum()?;
+            return equals(lhs, rhs);
+        }
+        Ok(lhs)
+    }
+
+    fn sum(&mut self) -> Result<Value, ReplError> {
+        let mut value = self.product()?;
+        while let Some(Token::Op(op @ ('+' | '-'))) = self.peek() {
+            self.next();
+            let rhs = self.product()?;
+            value = if *op == '+' {
+                add(value, rhs)?
+            } else {
+                sub(value, rhs)?
+            };
+        }
+        Ok(value)
+    }
+
+    fn product(&mut self) -> Result<Value, ReplError> {
+        let mut value = self.unary()?;
+        while let Some(Token::Op(op @ ('*' | '/'))) = self.peek() {
+            self.next();
+            let rhs = self.unary()?;
+            value = if *op == '*' {
+                mul(value, rhs)?
+            } else {
+                div(value, rhs)?
+            };
+        }
+        Ok(value)
+    }
+
+    fn unary(&mut self) -> Result<Value, ReplError> {
+        // The parser recurses once per level, so without a limit a long enough line
+        // would overflow the stack.
+        if self.depth == MAX_DEPTH {
+            return Err(ReplError::TooDeep);
+        }
+        self.depth += 1;
+        let value = if self.peek() == Some(&Token::Op('-')) {
+            self.next();
+            self.unary().and_then(neg)
+        } else {
+            self.power()
+        };
+        self.depth -= 1;
+        value
+    }
+
+    fn power(&mut self) -> Result<Value, ReplError> {
+        let base = self.atom()?;
+        if self.peek() == Some(&Token::Op('^')) {
+            self.next();
+            // Binds tighter than unary minus on the left, but allows `a^-1`.
+            let exponent = self.unary()?;
+            return pow(base, exponent);
+        }
+        Ok(base)
+    }
+
+    fn atom(&mut self) -> Result<Value, ReplError> {
+        match self.next() {
+            Some(Token::Number(n)) => Ok(Value::Fr(number(n)?)),
+            Some(Token::Op('(')) => {
+                let value = self.expression()?;
+                self.expect(')')?;
+                Ok(value)
+            }
+            Some(Token::Ident(name)) if self.peek() == Some(&Token::Op('(')) => {
+                self.next();
+                self.call(name)
+            }
+            Some(Token::Ident(name)) => self
+                .variables
+                .get(name)
+                .cloned()
+                .ok_or_else(|| ReplError::UnknownVariable(name.clone())),
+            Some(token) => Err(ReplError::Syntax(format!("unexpected {}", token))),
+            None => Err(ReplError::Syntax("unexpected end of line".to_string())),
+        }
+    }
+
+    /// Evaluates a function call, after its opening parenthesis.
+    fn call(&mut self, name: &str) -> Result<Value, ReplError> {
+        if name == "fp" {
+            return self.fp();
+        }
+        // Literal arguments are read directly, so that `fq` accepts integers larger than
+        // `r`, and `g1` and `g2` accept encodings.
+        if let [Some(Token::Number(literal)), Some(Token::Op(')'))] = [
+            self.tokens.get(self.position),
+            self.tokens.get(self.position + 1),
+        ] {
+            let value = match name {
+                "fq" => Some(Value::Fq(number(literal)?)),
+                "g1" => Some(Value::G1(point(literal, zcash_format::decode_g1)?)),
+                "g2" => Some(Value::G2(point(literal, zcash_format::decode_g2)?)),
+                _ => None,
+            };
+            if let Some(value) = value {
+                self.position += 2;
+                return Ok(value);
+            }
+        }
+        let mut arguments = Vec::new();
+        if self.peek() != Some(&Token::Op(')')) {
+            arguments.push(self.expression()?);
+            while self.peek() == Some(&Token::Op(',')) {
+                self.next();
+                arguments.push(self.expression()?);
+            }
+        }
+        self.expect(')')?;
+        match (name, arguments.as_slice()) {
+            ("pairing", [Value::G1(p), Value::G2(q)]) => Ok(Value::Gt(Bls12_381::pairing(*p, *q))),
+            ("inv", [a]) => div(one_like(a)?, a.clone()),
+            ("fq", [Value::Fr(a)]) => {
+                let value: BigUint = (*a).into();
+                Ok(Value::Fq(Fq::from(value)))
+            }
+            ("pairing", _) | ("inv", _) | ("fq", _) => Err(ReplError::Type(format!(
+                "wrong arguments for `{}`: {}",
+                name,
+                arguments
+                    .iter()
+                    .map(Value::type_name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))),
+            ("g1", _) | ("g2", _) => Err(ReplError::Type(format!(
+                "`{}` takes a hexadecimal encoding",
+                name
+            ))),
+            _ => Err(ReplError::UnknownFunction(name.to_string())),
+        }
+    }
+
+    /// Evaluates the arguments of `fp(a, p)`, after its opening parenthesis.
+    fn fp(&mut self) -> Result<Value, ReplError> {
+        // A literal `a` is read directly, so that it can be larger than `r`.
+        let value = match self.tokens.get(self.position..self.position + 2) {
+            Some([Token::Number(literal), Token::Op(',')]) => {
+                self.position += 1;
+                (false, integer(literal)?)
+            }
+            _ => match self.expression()? {
+                Value::Fr(a) => balanced_scalar(&a),
+                other => {
+                    return Err(ReplError::Type(format!(
+                        "`fp` takes a scalar, not {}",
+                        other.type_name()
+                    )))
+                }
+            },
+        };
+        self.expect(',')?;
+        let modulus = match self.next() {
+            Some(Token::Number(literal)) => integer(literal)?,
+            _ => {
+                return Err(ReplError::Type(
+                    "`fp` takes an integer literal as its modulus".to_string(),
+                ))
+            }
+        };
+        self.expect(')')?;
+        let field = DynPrimeField::new(modulus.clone())
+            .map_err(|_| ReplError::NotPrime(modulus.to_string()))?;
+        Ok(Value::Fp(signed_element(&field, value)))
+    }
+}
+
+/// Parses an integer literal, without reducing it.
+fn integer(literal: &str) -> Result<BigUint, ReplError> {
+    let parsed = match literal.strip_prefix("0x") {
+        Some(digits) => BigUint::parse_bytes(digits.as_bytes(), 16),
+        None => BigUint::parse_bytes(literal.as_bytes(), 10),
+    };
+    parsed.ok_or_else(|| ReplError::Syntax(format!("invalid number `{}`", literal)))
+}
+
+/// Returns the balanced representative of the scalar `a`, as a sign and a magnitude.
+fn balanced_scalar(a: &Fr) -> (bool, BigUint) {
+    let value: BigUint = (*a).into();
+    let modulus: BigUint = <Fr as PrimeField>::Params::MODULUS.into();
+    if value > (&modulus >> 1usize) {
+        (true, modulus - value)
+    } else {
+        (false, value)
+    }
+}
+
+/// Returns the element of `field` with the given sign and magnitude.
+fn signed_element(field: &DynPrimeField, (negative, magnitude): (bool, BigUint)) -> DynField {
+    let element = field.element(magnitude);
+    if negative {
+        -element
+    } else {
+        element
+    }
+}
+
+/// Returns the element of the field of `x` with the balanced representative of `a`.
+fn scalar_in(x: &DynField, a: &Fr) -> DynField {
+    signed_element(&x.field(), balanced_scalar(a))
+}
+
+/// Parses an integer literal, reducing it modulo the order of `F`.
+fn number<F: PrimeField>(literal: &str) -> Result<F, ReplError> {
+    let parsed = if literal.starts_with("0x") {
+        parse_hex_mod_order(literal)
+    } else {
+        parse_decimal_mod_order(literal)
+    };
+    parsed.map_err(|e| ReplError::Syntax(format!("invalid number `{}`: {}", literal, e)))
+}
+
+/// Decodes a point literal.
+fn point<G>(literal: &str, decode: fn(&[u8]) -> Result<G, DecodeError>) -> Result<G, ReplError> {
+    let bytes = from_hex(literal)
+        .ok_or_else(|| ReplError::Syntax(format!("invalid encoding `{}`", literal)))?;
+    decode(&bytes).map_err(ReplError::InvalidPoint)
+}
+
+/// Returns the multiplicative identity of the type of `a`.
+fn one_like(a: &Value) -> Result<Value, ReplError> {
+    match a {
+        Value::Fr(_) => Ok(Value::Fr(Fr::one())),
+        Value::Fq(_) => Ok(Value::Fq(Fq::one())),
+        Value::Gt(_) => Ok(Value::Gt(Fq12::one())),
+        Value::Fp(x) => Ok(Value::Fp(x.field().one())),
+        _ => Err(ReplError::Type(format!("cannot invert {}", a.type_name()))),
+    }
+}
+
+fn type_error(verb: &str, a: &Value, b: &Value) -> ReplError {
+    ReplError::Type(format!(
+        "cannot {} {} and {}",
+        verb,
+        a.type_name(),
+        b.type_name()
+    ))
+}
+
+/// Returns an error unless `x` and `y` are elements of the same field.
+fn same_field(verb: &str, x: &DynField, y: &DynField) -> Result<(), ReplError> {
+    if x.modulus() != y.modulus() {
+        return Err(ReplError::Type(format!(
+            "cannot {} elements of the fields of order {} and {}",
+            verb,
+            x.modulus(),
+            y.modulus()
+        )));
+    }
+    Ok(())
+}
+
+fn add(a: Value, b: Value) -> Result<Value, ReplError> {
+    match (&a, &b) {
+        (Value::Fr(x), Value::Fr(y)) => Ok(Value::Fr(*x + y)),
+        (Value::Fq(x), Value::Fq(y)) => Ok(Value::Fq(*x + y)),
+        (Value::Fp(x), Value::Fp(y)) => {
+            same_field("add", x, y)?;
+            Ok(Value::Fp(x + y))
+        }
+        (Value::Fp(x), Value::Fr(k)) | (Value::Fr(k), Value::Fp(x)) => {
+            Ok(Value::Fp(x + &scalar_in(x, k)))
+        }
+        (Value::G1(p), Value::G1(q)) => Ok(Value::G1(*p + *q)),
+        (Value::G2(p), Value::G2(q)) => Ok(Value::G2(*p + *q)),
+        _ => Err(type_error("add", &a, &b)),
+    }
+}
+
+fn sub(a: Value, b: Value) -> Result<Value, ReplError> {
+    match (&a, &b) {
+        (Value::Fr(_), Value::Fr(_))
+        | (Value::Fq(_), Value::Fq(_))
+        | (Value::G1(_), Value::G1(_))
+        | (Value::G2(_), Value::G2(_))
+        | (Value::Fp(_), Value::Fp(_) | Value::Fr(_))
+        | (Value::Fr(_), Value::Fp(_)) => add(a, neg(b)?),
+        _ => Err(type_error("subtract", &a, &b)),
+    }
+}
+
+fn neg(a: Value) -> Result<Value, ReplError> {
+    match a {
+        Value::Fr(x) => Ok(Value::Fr(-x)),
+        Value::Fq(x) => Ok(Value::Fq(-x)),
+        Value::G1(p) => Ok(Value::G1(-p)),
+        Value::G2(p) => Ok(Value::G2(-p)),
+        Value::Fp(x) => Ok(Value::Fp(-x)),
+        _ => Err(ReplError::Type(format!("cannot negate {}", a.type_name()))),
+    }
+}
+
+fn mul(a: Value, b: Value) -> Result<Value, ReplError> {
+    match (&a, &b) {
+        (Value::Fr(x), Value::Fr(y)) => Ok(Value::Fr(*x * y)),
+        (Value::Fq(x), Value::Fq(y)) => Ok(Value::Fq(*x * y)),
+        (Value::Fr(k), Value::G1(p)) | (Value::G1(p), Value::Fr(k)) => {
+            Ok(Value::G1(p.mul(*k).into()))
+        }
+        (Value::Fr(k), Value::G2(p)) | (Value::G2(p), Value::Fr(k)) => {
+            Ok(Value::G2(p.mul(*k).into()))
+        }
+        (Value::Gt(x), Value::Gt(y)) => Ok(Value::Gt(*x * y)),
+        (Value::Fp(x), Value::Fp(y)) => {
+            same_field("multiply", x, y)?;
+            Ok(Value::Fp(x * y))
+        }
+        (Value::Fp(x), Value::Fr(k)) | (Value::Fr(k), Value::Fp(x)) => {
+            Ok(Value::Fp(x * &scalar_in(x, k)))
+        }
+        _ => Err(type_error("multiply", &a, &b)),
+    }
+}
+
+fn div(a: Value, b: Value) -> Result<Value, ReplError> {
+    if let (Value::Fp(x), Value::Fp(y)) = (&a, &b) {
+        same_field("divide", x, y)?;
+    }
+    let inverse = match &b {
+        Value::Fq(y) => y.inverse().map(Value::Fq),
+        Value::Gt(y) => y.inverse().map(Value::Gt),
+        Value::Fp(y) => y.inverse().ok().map(Value::Fp),
+        // A scalar divides an element of an `fp` field as an element of that field.
+        Value::Fr(k) => match &a {
+            Value::Fp(x) => scalar_in(x, k).inverse().ok().map(Value::Fp),
+            _ => k.inverse().map(Value::Fr),
+        },
+        _ => return Err(type_error("divide", &a, &b)),
+    };
+    let scalar_and_fp = matches!(
+        (&a, &b),
+        (Value::Fp(_), Value::Fr(_)) | (Value::Fr(_), Value::Fp(_))
+    );
+    if a.type_name() != b.type_name() && !scalar_and_fp {
+        return Err(type_error("divide", &a, &b));
+    }
+    mul(a, inverse.ok_or(ReplError::DivisionByZero)?)
+}
+
+/// Raises `base` to the balanced representative of a scalar exponent, inverting it first
+/// if the exponent is negative.
+fn pow(base: Value, exponent: Value) -> Result<Value, ReplError> {
+    let e = match exponent {
+        Value::Fr(e) => e,
+        _ => return Err(type_error("exponentiate", &base, &exponent)),
+    };
+    let value: BigUint = e.into();
+    let modulus: BigUint = <Fr as PrimeField>::Params::MODULUS.into();
+    let (base, magnitude) = if value > (&modulus >> 1usize) {
+        (div(one_like(&base)?, base)?, modulus - value)
+    } else {
+        (base, value)
+    };
+    let limbs = magnitude.to_u64_digits();
+    match base {
+        Value::Fr(x) => Ok(Value::Fr(x.pow(&limbs))),
+        Value::Fq(x) => Ok(Value::Fq(x.pow(&limbs))),
+        Value::Gt(x) => Ok(Value::Gt(x.pow(&limbs))),
+        Value::Fp(x) => Ok(Value::Fp(x.pow(&magnitude))),
+        _ => Err(type_error("exponentiate", &base, &Value::Fr(e))),
+    }
+}
+
+fn equals(a: Value, b: Value) -> Result<Value, ReplError> {
+    match (&a, &b) {
+        (Value::Fp(x), Value::Fp(y)) => same_field("compare", x, y)?,
+        (Value::Fp(x), Value::Fr(k)) | (Value::Fr(k), Value::Fp(x)) => {
+            return Ok(Value::Bool(*x == scalar_in(x, k)))
+        }
+        _ => {}
+    }
+    if a.type_name() != b.type_name() {
+        return Err(type_error("compare", &a, &b));
+    }
+    Ok(Value::Bool(a == b))
+}
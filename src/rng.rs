@@ -0,0 +1,43 @@
+//! Deterministic randomness for reproducible tutorial runs.
+//!
+//! The examples in the crate documentation sample their inputs with
+//! `thread_rng()`, so every run produces different numbers. When you want to
+//! compare your output against someone else's (or against a previous run),
+//! use a seeded generator instead:
+//!
+//! ```rust
+//! use ark_algebra_intro::rng::seeded;
+//! use ark_bls12_381::Fr;
+//! use ark_std::UniformRand;
+//!
+//! let a = Fr::rand(&mut seeded(42));
+//! let b = Fr::rand(&mut seeded(42));
+//! assert_eq!(a, b);
+//! assert_ne!(a, Fr::rand(&mut seeded(43)));
+//! ```
+//!
+//! These generators are *not* suitable for generating secrets: anyone who
+//! knows the seed can reproduce every value you sample.
+//!
+//! They are also the generators to use without the `std` feature, which
+//! `thread_rng()` needs.
+
+use ark_std::rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+
+/// Returns a ChaCha20-based random number generator seeded with `seed`.
+///
+/// The same seed always produces the same sequence of values, on every platform
+/// and across releases of this crate.
+///
+/// ```rust
+/// use ark_algebra_intro::rng::seeded;
+/// use ark_std::rand::Rng;
+///
+/// let mut rng = seeded(0);
+/// let first: u64 = rng.gen();
+/// assert_eq!(first, seeded(0).gen::<u64>());
+/// ```
+pub fn seeded(seed: u64) -> ChaCha20Rng {
+    ChaCha20Rng::seed_from_u64(seed)
+}
@@ -0,0 +1,132 @@
+//! Reusable samplers for commonly needed kinds of random field elements.
+//!
+//! The crate documentation samples squares and non-squares with hand-written
+//! rejection-sampling loops. The functions here package up those loops (and a
+//! few others), and document how many iterations they are expected to take.
+
+use crate::fields::FieldExt;
+use ark_ff::{BigInteger, Field, FpParameters, PrimeField};
+use ark_std::rand::{seq::index, Rng};
+
+/// Samples a uniformly random non-zero element of `F`.
+///
+/// This uses rejection sampling, which takes `|F| / (|F| - 1)` iterations in
+/// expectation; for cryptographically sized fields, that is one iteration
+/// with overwhelming probability.
+///
+/// ```rust
+/// use ark_algebra_intro::sampling::nonzero;
+/// use ark_bls12_381::Fr;
+/// use ark_ff::Field;
+/// use ark_std::{One, Zero};
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let a: Fr = nonzero(&mut rng);
+/// assert!(!a.is_zero());
+/// // ...so we can safely invert it:
+/// assert_eq!(a * a.inverse().unwrap(), Fr::one());
+/// ```
+pub fn nonzero<F: Field, R: Rng + ?Sized>(rng: &mut R) -> F {
+    loop {
+        let a = F::rand(rng);
+        if !a.is_zero() {
+            return a;
+        }
+    }
+}
+
+/// Samples a uniformly random non-zero square (i.e., *quadratic residue*) in `F`.
+///
+/// Exactly `(|F| - 1)/2` elements of a field of odd characteristic are
+/// non-zero squares, so rejection sampling takes (slightly more than) two
+/// iterations in expectation.
+///
+/// ```rust
+/// use ark_algebra_intro::{fields::FieldExt, sampling::square};
+/// use ark_bls12_381::{Fq12, Fq2};
+/// use ark_ff::{Field, SquareRootField};
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let a: Fq2 = square(&mut rng);
+/// assert!(a.legendre().is_qr());
+/// assert_eq!(a.sqrt().unwrap().square(), a);
+///
+/// // This works even for fields that don't implement `SquareRootField`:
+/// let b: Fq12 = square(&mut rng);
+/// assert!(b.is_square());
+/// ```
+pub fn square<F: Field, R: Rng + ?Sized>(rng: &mut R) -> F {
+    loop {
+        let a = F::rand(rng);
+        if !a.is_zero() && a.is_square() {
+            return a;
+        }
+    }
+}
+
+/// Samples a uniformly random non-square (i.e., *quadratic non-residue*) in `F`.
+///
+/// Exactly `(|F| - 1)/2` elements of a field of odd characteristic are
+/// non-squares, so rejection sampling takes (slightly more than) two
+/// iterations in expectation.
+///
+/// ```rust
+/// use ark_algebra_intro::sampling::nonsquare;
+/// use ark_bls12_381::Fq2;
+/// use ark_ff::SquareRootField;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let a: Fq2 = nonsquare(&mut rng);
+/// assert!(a.legendre().is_qnr());
+/// assert_eq!(a.sqrt(), None);
+/// ```
+pub fn nonsquare<F: Field, R: Rng + ?Sized>(rng: &mut R) -> F {
+    loop {
+        let a = F::rand(rng);
+        if !a.is_square() {
+            return a;
+        }
+    }
+}
+
+/// Samples an element of `F` whose canonical binary representation has
+/// exactly `weight` bits set, with the positions of those bits chosen
+/// uniformly at random.
+///
+/// Only bit positions below [`FpParameters::CAPACITY`] are used, so that
+/// every choice of bits corresponds to an integer smaller than the modulus.
+/// This never needs to reject a sample; the cost is choosing `weight`
+/// distinct positions.
+///
+/// Such elements are handy for building deliberately weak instances, e.g.,
+/// secret keys that are vulnerable to specialized discrete-log attacks.
+///
+/// # Panics
+///
+/// Panics if `weight` exceeds [`FpParameters::CAPACITY`].
+///
+/// ```rust
+/// use ark_algebra_intro::sampling::low_hamming_weight;
+/// use ark_bls12_381::Fr;
+/// use ark_ff::{BigInteger, PrimeField};
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let a: Fr = low_hamming_weight(3, &mut rng);
+/// let bits = a.into_repr().to_bits_le();
+/// assert_eq!(bits.iter().filter(|b| **b).count(), 3);
+/// ```
+pub fn low_hamming_weight<F: PrimeField, R: Rng + ?Sized>(weight: usize, rng: &mut R) -> F {
+    let capacity = F::Params::CAPACITY as usize;
+    assert!(
+        weight <= capacity,
+        "cannot set {} bits of a {}-bit integer",
+        weight,
+        capacity
+    );
+    let mut bits = vec![false; capacity];
+    for position in index::sample(rng, capacity, weight) {
+        bits[position] = true;
+    }
+    F::from_repr(F::BigInt::from_bits_le(&bits))
+        .expect("integers below the field's capacity are always in range")
+}
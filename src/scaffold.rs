@@ -0,0 +1,269 @@
+//! Starter crates for puzzles, in the style of the [ZK Hack](https://zkhack.dev) puzzles.
+//!
+//! A puzzle crate has a library that generates the puzzle *instance* (the public data
+//! handed to the solver) from a fixed seed, serializes it, and checks candidate solutions
+//! in `verify_solution`; and a binary that prints the puzzle's description, loads the
+//! instance, and calls the solver's code:
+//!
+//! ```text
+//! my-puzzle
+//! ├── Cargo.toml
+//! ├── README.md
+//! ├── .gitignore
+//! └── src
+//!     ├── lib.rs
+//!     └── bin
+//!         └── verify-my-puzzle.rs
+//! ```
+//!
+//! The generated puzzle is a small discrete logarithm, to be replaced with your own:
+//!
+//! ```rust
+//! use ark_algebra_intro::scaffold::new_puzzle;
+//!
+//! let scaffold = new_puzzle("my-puzzle").unwrap();
+//! let lib = scaffold.file("src/lib.rs").unwrap();
+//! assert!(lib.contains("pub fn verify_solution(instance: &Instance, solution: &Solution) -> bool"));
+//! assert!(scaffold.file("src/bin/verify-my-puzzle.rs").is_some());
+//!
+//! // The puzzle depends on this crate, from git unless told otherwise.
+//! assert!(scaffold.file("Cargo.toml").unwrap().contains("git = "));
+//! let local = scaffold.with_local_dependency("../algebra-intro");
+//! assert!(local.file("Cargo.toml").unwrap().contains("path = \"../algebra-intro\""));
+//!
+//! // Names must be valid crate names.
+//! assert!(new_puzzle("my puzzle").is_err());
+//! ```
+//!
+//! [`Scaffold::write`] creates the crate on disk. With the `cli` feature, so does
+//! `algebra-intro new-puzzle`:
+//!
+//! ```text
+//! $ cargo run --features cli --bin algebra-intro -- new-puzzle my-puzzle --dir ..
+//! $ cd ../my-puzzle && cargo run --release
+//! ```
+
+use ark_std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The error returned by [`new_puzzle`] for an invalid name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidName(pub String);
+
+impl fmt::Display for InvalidName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is not a valid crate name: use ASCII letters, digits, `-` and `_`, \
+             starting with a letter",
+            self.0
+        )
+    }
+}
+
+impl ark_std::error::Error for InvalidName {}
+
+/// The files of a puzzle crate, before they are written to disk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Scaffold {
+    /// The name of the crate.
+    pub name: String,
+    /// The path of each file, relative to the crate's root, and its contents.
+    pub files: Vec<(String, String)>,
+}
+
+impl Scaffold {
+    /// Returns the contents of the file at `path`, relative to the crate's root.
+    pub fn file(&self, path: &str) -> Option<&str> {
+        self.files
+            .iter()
+            .find(|(p, _)| p == path)
+            .map(|(_, contents)| contents.as_str())
+    }
+
+    /// Makes the puzzle depend on the copy of this crate at `path`, instead of the one
+    /// on GitHub. A relative `path` is relative to the puzzle crate's root.
+    pub fn with_local_dependency(mut self, path: impl AsRef<Path>) -> Self {
+        let dependency = format!("path = {:?}", path.as_ref().display().to_string());
+        for (p, contents) in &mut self.files {
+            if p == "Cargo.toml" {
+                *contents = contents.replace(GIT_DEPENDENCY, &dependency);
+            }
+        }
+        self
+    }
+
+    /// Writes the crate to a new directory named after it in `parent`, and returns the
+    /// path of that directory.
+    ///
+    /// Fails if the directory already exists, rather than overwriting anything.
+    pub fn write(&self, parent: impl AsRef<Path>) -> io::Result<PathBuf> {
+        let root = parent.as_ref().join(&self.name);
+        fs::create_dir_all(parent.as_ref())?;
+        fs::create_dir(&root)?;
+        for (path, contents) in &self.files {
+            let path = root.join(path);
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(path, contents)?;
+        }
+        Ok(root)
+    }
+}
+
+/// Returns the files of a starter crate for a puzzle called `name`.
+///
+/// `name` must be a valid crate name: ASCII letters, digits, `-` and `_`, starting with
+/// a letter.
+pub fn new_puzzle(name: &str) -> Result<Scaffold, InvalidName> {
+    let mut chars = name.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        return Err(InvalidName(name.to_string()));
+    }
+
+    let fill = |template: &str| {
+        template
+            .replace("{{name}}", name)
+            .replace("{{crate}}", &name.replace('-', "_"))
+            .replace("{{dependency}}", GIT_DEPENDENCY)
+    };
+    let files = vec![
+        ("Cargo.toml".to_string(), fill(CARGO_TOML)),
+        ("README.md".to_string(), fill(README)),
+        (".gitignore".to_string(), GITIGNORE.to_string()),
+        ("src/lib.rs".to_string(), fill(LIB_RS)),
+        (format!("src/bin/verify-{}.rs", name), fill(VERIFY_RS)),
+    ];
+    Ok(Scaffold {
+        name: name.to_string(),
+        files,
+    })
+}
+
+const GIT_DEPENDENCY: &str = "git = \"https://github.com/Pratyush/algebra-intro\"";
+
+const CARGO_TOML: &str = r#"[package]
+name = "{{name}}"
+version = "0.1.0"
+edition = "2018"
+
+[dependencies]
+ark-algebra-intro = { {{dependency}} }
+ark-bls12-381 = { version = "0.3" }
+ark-ec = { version = "0.3" }
+ark-ff = { version = "0.3" }
+ark-serialize = { version = "0.3", features = [ "derive" ] }
+ark-std = { version = "0.3" }
+
+[profile.dev]
+opt-level = 3
+"#;
+
+const GITIGNORE: &str = "/target\n/instance.bin\n";
+
+const README: &str = r#"# {{name}}
+
+Describe the puzzle here, and in `PUZZLE_DESCRIPTION` in `src/bin/verify-{{name}}.rs`.
+
+Run `cargo run --release` to see the puzzle, then write your solution in `solve`, in
+the same file.
+"#;
+
+const LIB_RS: &str = r#"//! The `{{name}}` puzzle: its instance, and a check of solutions.
+
+use ark_algebra_intro::prelude::*;
+use ark_algebra_intro::rng::seeded;
+use ark_serialize::{Read, SerializationError, Write};
+use ark_std::rand::Rng;
+
+/// The seed from which the instance is generated. Change it for a different instance.
+pub const SEED: u64 = 0;
+
+/// The file that the instance is saved to, so that solvers don't need to generate it.
+pub const INSTANCE_FILE: &str = "instance.bin";
+
+/// The public data of the puzzle.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Instance {
+    pub g: G1Affine,
+    pub h: G1Affine,
+}
+
+/// A candidate solution.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Solution {
+    pub x: Fr,
+}
+
+/// Generates the instance from [`SEED`]. Replace this with your puzzle's setup.
+///
+/// For now, the puzzle is to find `x` such that `h = x · g`, where `x` is small enough
+/// to find by brute force.
+pub fn generate() -> Instance {
+    let mut rng = seeded(SEED);
+    let g = G1Projective::rand(&mut rng);
+    let x = Fr::from(rng.gen_range(0..1u64 << 16));
+    Instance {
+        g: g.into_affine(),
+        h: g.mul(x.into_repr()).into_affine(),
+    }
+}
+
+/// Serializes `instance`, compressed.
+pub fn save(instance: &Instance) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    instance
+        .serialize(&mut bytes)
+        .expect("serializing to a vector doesn't fail");
+    bytes
+}
+
+/// Deserializes an instance, checking that its points are in the prime-order subgroup.
+pub fn load(mut bytes: &[u8]) -> Result<Instance, SerializationError> {
+    Instance::deserialize(&mut bytes)
+}
+
+/// Checks a solution to `instance`.
+pub fn verify_solution(instance: &Instance, solution: &Solution) -> bool {
+    instance.g.mul(solution.x) == instance.h.into_projective()
+}
+"#;
+
+const VERIFY_RS: &str = r#"use {{crate}}::{generate, load, save, verify_solution, Instance, Solution, INSTANCE_FILE};
+use std::fs;
+
+const PUZZLE_DESCRIPTION: &str = "\
+Alice picked a secret x, and published h = x · g. She says that x is safe, since it is
+an element of a 255-bit field, but she was in a hurry when she picked it. Find x.
+";
+
+/// Loads the instance from [`INSTANCE_FILE`], generating and saving it on the first run.
+fn instance() -> Instance {
+    match fs::read(INSTANCE_FILE) {
+        Ok(bytes) => load(&bytes).expect("the instance file is corrupted"),
+        Err(_) => {
+            let instance = generate();
+            fs::write(INSTANCE_FILE, save(&instance)).expect("failed to save the instance");
+            instance
+        }
+    }
+}
+
+/// Enter your solution here.
+fn solve(_instance: &Instance) -> Solution {
+    todo!("solve the puzzle")
+}
+
+fn main() {
+    println!("{}", PUZZLE_DESCRIPTION);
+    let instance = instance();
+    let solution = solve(&instance);
+    assert!(verify_solution(&instance, &solution), "wrong solution");
+    println!("Solved!");
+}
+"#;
@@ -0,0 +1,177 @@
+//! [`serde`] support for field elements and curve points.
+//!
+//! Arkworks types only implement [`CanonicalSerialize`], so they can't be stored in a
+//! struct that derives `serde::Serialize`. The wrappers [`SerdeField`] and
+//! [`SerdePoint`] fill that gap, with an encoding that depends on the format:
+//!
+//! * in human-readable formats (JSON, TOML, ...), a field element is its `0x`-prefixed,
+//!   big-endian [hexadecimal representation](crate::bigint::repr_to_hex), the way
+//!   specifications print them, and a point is the `0x`-prefixed hexadecimal string of its
+//!   [compressed encoding](crate::serialize::to_bytes_compressed);
+//! * in binary formats (CBOR, bincode, ...), both are their compressed canonical bytes.
+//!
+//! ```rust
+//! use ark_algebra_intro::serde_support::{SerdeField, SerdePoint};
+//! use ark_bls12_381::{Fr, G1Affine};
+//! use ark_ec::AffineCurve;
+//!
+//! let a = SerdeField(Fr::from(255u64));
+//! let json = serde_json::to_string(&a).unwrap();
+//! assert_eq!(
+//!     json,
+//!     "\"0x00000000000000000000000000000000000000000000000000000000000000ff\"",
+//! );
+//! assert_eq!(serde_json::from_str::<SerdeField<Fr>>(&json).unwrap(), a);
+//!
+//! let g = SerdePoint(G1Affine::prime_subgroup_generator());
+//! let json = serde_json::to_string(&g).unwrap();
+//! assert_eq!(json.len(), 2 + 2 + 2 * 48);
+//! assert_eq!(serde_json::from_str::<SerdePoint<G1Affine>>(&json).unwrap(), g);
+//!
+//! // Binary formats store the raw bytes instead.
+//! let cbor = serde_cbor::to_vec(&g).unwrap();
+//! assert!(cbor.len() < json.len());
+//! assert_eq!(serde_cbor::from_slice::<SerdePoint<G1Affine>>(&cbor).unwrap(), g);
+//! ```
+//!
+//! Deserialization is always [checked](crate::serialize#checked-and-unchecked-deserialization):
+//! points must be on the curve and in the prime-order subgroup, and field elements must
+//! be less than the modulus.
+//!
+//! ```rust
+//! use ark_algebra_intro::serde_support::{SerdeField, SerdePoint};
+//! use ark_bls12_381::{Fr, G1Affine};
+//!
+//! let r = "\"0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001\"";
+//! assert!(serde_json::from_str::<SerdeField<Fr>>(r).is_err());
+//! assert!(serde_json::from_str::<SerdePoint<G1Affine>>("\"0x1234\"").is_err());
+//! ```
+//!
+//! This module is only available with the `serde` feature.
+
+use crate::bigint::repr_to_hex;
+use crate::encoding::{encoded_size, from_hex, to_hex};
+use crate::fields::parse_hex;
+use crate::serialize::{ensure_consumed, to_bytes_compressed};
+use ark_ec::AffineCurve;
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::fmt;
+use ark_std::string::String;
+use ark_std::vec::Vec;
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+
+/// A field element that implements [`Serialize`] and [`Deserialize`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SerdeField<F>(pub F);
+
+/// A curve point that implements [`Serialize`] and [`Deserialize`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SerdePoint<G>(pub G);
+
+impl<F: PrimeField> Serialize for SerdeField<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&repr_to_hex(&self.0.into_repr()))
+        } else {
+            serializer.serialize_bytes(&to_bytes_compressed(&self.0))
+        }
+    }
+}
+
+impl<'de, F: PrimeField> Deserialize<'de> for SerdeField<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let hex = <String as Deserialize>::deserialize(deserializer)?;
+            // Only accept the fixed-width strings that `serialize` produces.
+            let width = 2 + 2 * encoded_size::<F>();
+            if hex.len() != width || !hex.starts_with("0x") {
+                return Err(de::Error::invalid_length(
+                    hex.len(),
+                    &"a 0x-prefixed, full-width hex string",
+                ));
+            }
+            parse_hex(&hex).map(SerdeField).map_err(de::Error::custom)
+        } else {
+            let bytes = deserializer.deserialize_bytes(BytesVisitor)?;
+            from_compressed(&bytes)
+                .map(SerdeField)
+                .map_err(de::Error::custom)
+        }
+    }
+}
+
+impl<G: AffineCurve> Serialize for SerdePoint<G> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_canonical(&self.0, serializer)
+    }
+}
+
+impl<'de, G: AffineCurve> Deserialize<'de> for SerdePoint<G> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_canonical(deserializer).map(SerdePoint)
+    }
+}
+
+/// Serializes the compressed encoding of `value`, as a hexadecimal string in
+/// human-readable formats.
+pub(crate) fn serialize_canonical<T: CanonicalSerialize, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let bytes = to_bytes_compressed(value);
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&to_hex(&bytes))
+    } else {
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+/// Deserializes what [`serialize_canonical`] produces, with all checks.
+pub(crate) fn deserialize_canonical<'de, T: CanonicalDeserialize, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    let bytes = if deserializer.is_human_readable() {
+        let hex = <String as Deserialize>::deserialize(deserializer)?;
+        from_hex(&hex).ok_or_else(|| de::Error::custom("invalid hex string"))?
+    } else {
+        deserializer.deserialize_bytes(BytesVisitor)?
+    };
+    from_compressed(&bytes).map_err(de::Error::custom)
+}
+
+/// Decodes a compressed canonical encoding, with all checks and no trailing bytes.
+fn from_compressed<T: CanonicalDeserialize>(bytes: &[u8]) -> Result<T, SerializationError> {
+    let mut reader = bytes;
+    let value = T::deserialize(&mut reader)?;
+    ensure_consumed(reader)?;
+    Ok(value)
+}
+
+/// Accepts byte strings, and sequences of bytes for formats that don't have them.
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a byte string")
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        Ok(bytes.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, bytes: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(bytes)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
@@ -0,0 +1,123 @@
+//! Serializing field elements and curve points to bytes, and back.
+//!
+//! Arkworks types implement [`CanonicalSerialize`] and [`CanonicalDeserialize`], which
+//! have two encodings for curve points:
+//!
+//! * the *compressed* encoding stores only the `x`-coordinate and one bit of `y` (which
+//!   is recovered with a square root), e.g., 48 bytes for a point of BLS12-381's G1; and
+//! * the *uncompressed* encoding stores both coordinates, e.g., 96 bytes, and is faster
+//!   to decode.
+//!
+//! Field elements have a single encoding, [little-endian](crate::encoding::to_bytes_le)
+//! and as wide as the modulus.
+//!
+//! ```rust
+//! use ark_algebra_intro::serialize::{
+//!     from_bytes_checked, to_bytes_compressed, to_bytes_uncompressed,
+//! };
+//! use ark_bls12_381::{Fr, G1Affine};
+//! use ark_ec::AffineCurve;
+//! use ark_serialize::CanonicalDeserialize;
+//!
+//! let g = G1Affine::prime_subgroup_generator();
+//! assert_eq!(to_bytes_compressed(&g).len(), 48);
+//! assert_eq!(to_bytes_uncompressed(&g).len(), 96);
+//! assert_eq!(from_bytes_checked::<G1Affine>(&to_bytes_uncompressed(&g)).unwrap(), g);
+//! // Compressed encodings decode with `CanonicalDeserialize::deserialize`.
+//! assert_eq!(G1Affine::deserialize(&to_bytes_compressed(&g)[..]).unwrap(), g);
+//!
+//! let a = Fr::from(42u64);
+//! assert_eq!(to_bytes_compressed(&a), to_bytes_uncompressed(&a));
+//! assert_eq!(from_bytes_checked::<Fr>(&to_bytes_compressed(&a)).unwrap(), a);
+//! ```
+//!
+//! # Checked and unchecked deserialization
+//!
+//! Bytes received from someone else can describe anything. Checked deserialization
+//! rejects encodings of points that are not [on the curve, or not in the prime-order
+//! subgroup](crate::curves), and field elements that are not reduced modulo `p`. Unchecked
+//! deserialization skips the point checks, which is faster (the subgroup check costs a
+//! scalar multiplication), and **only safe for trusted data**, such as parameters that
+//! this program wrote itself.
+//!
+//! Otherwise, an attacker can send a point of small order, and learn a secret scalar
+//! `k` modulo that order from `k · P` (a *small-subgroup attack*), or a point on a
+//! different curve altogether (an *invalid-curve attack*), since the addition formulas
+//! never use the curve's constant `b`:
+//!
+//! ```rust
+//! use ark_algebra_intro::curves::{point_from_xy, AffineExt};
+//! use ark_algebra_intro::serialize::{
+//!     from_bytes_checked, from_bytes_unchecked, to_bytes_uncompressed,
+//! };
+//! use ark_bls12_381::{Fq, G1Affine};
+//!
+//! // A point of order 3 on the curve.
+//! let p: G1Affine = point_from_xy(Fq::from(0u64), Fq::from(2u64)).unwrap();
+//! let bytes = to_bytes_uncompressed(&p);
+//! assert!(from_bytes_checked::<G1Affine>(&bytes).is_err());
+//! assert_eq!(from_bytes_unchecked::<G1Affine>(&bytes).unwrap(), p);
+//!
+//! // A point that is not on the curve at all.
+//! let q = G1Affine::from_xy_unchecked(Fq::from(1u64), Fq::from(1u64));
+//! let bytes = to_bytes_uncompressed(&q);
+//! assert!(from_bytes_checked::<G1Affine>(&bytes).is_err());
+//! assert!(!from_bytes_unchecked::<G1Affine>(&bytes).unwrap().is_on_curve());
+//! ```
+//!
+//! Both functions also reject trailing bytes, since an encoding that can be extended
+//! without changing the decoded value is a classic source of malleability bugs.
+
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::vec::Vec;
+
+/// Returns the compressed encoding of `value`.
+pub fn to_bytes_compressed<T: CanonicalSerialize>(value: &T) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(value.serialized_size());
+    value
+        .serialize(&mut bytes)
+        .expect("serializing to a vector never fails");
+    bytes
+}
+
+/// Returns the uncompressed encoding of `value`.
+pub fn to_bytes_uncompressed<T: CanonicalSerialize>(value: &T) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(value.uncompressed_size());
+    value
+        .serialize_uncompressed(&mut bytes)
+        .expect("serializing to a vector never fails");
+    bytes
+}
+
+/// Decodes an uncompressed encoding, checking that points are on the curve and in the
+/// prime-order subgroup.
+///
+/// Returns an error if the encoding is invalid, fails the checks, or has trailing bytes.
+pub fn from_bytes_checked<T: CanonicalDeserialize>(bytes: &[u8]) -> Result<T, SerializationError> {
+    let mut reader = bytes;
+    let value = T::deserialize_uncompressed(&mut reader)?;
+    ensure_consumed(reader)?;
+    Ok(value)
+}
+
+/// Decodes an uncompressed encoding *without* checking that points are on the curve or
+/// in the prime-order subgroup.
+///
+/// Only use this for trusted data: see the [module documentation](self).
+pub fn from_bytes_unchecked<T: CanonicalDeserialize>(
+    bytes: &[u8],
+) -> Result<T, SerializationError> {
+    let mut reader = bytes;
+    let value = T::deserialize_unchecked(&mut reader)?;
+    ensure_consumed(reader)?;
+    Ok(value)
+}
+
+/// Returns an error if `rest` is not empty.
+pub(crate) fn ensure_consumed(rest: &[u8]) -> Result<(), SerializationError> {
+    if rest.is_empty() {
+        Ok(())
+    } else {
+        Err(SerializationError::InvalidData)
+    }
+}
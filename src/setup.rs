@@ -0,0 +1,3 @@
+//! Generating the structured reference strings of pairing-based proof systems.
+
+pub mod powers_of_tau;
@@ -0,0 +1,211 @@
+//! A toy "powers of tau" ceremony.
+//!
+//! [KZG commitments](crate::commitments::kzg), and the SNARKs built on them, need a
+//! structured reference string `([τ^i]_1, [τ^i]_2)` for a secret `τ` that nobody knows.
+//! A *ceremony* computes it with many participants, such that `τ` stays secret as long
+//! as a single one of them is honest:
+//!
+//! 1. the ceremony starts from the trivial SRS with `τ = 1`;
+//! 2. each participant samples a secret `s`, multiplies the `i`-th power by `s^i`, which
+//!    turns the SRS for `τ` into the SRS for `τ · s`, and deletes `s`;
+//! 3. along with the new SRS, the participant publishes an [`UpdateProof`], which lets
+//!    anyone check that the new SRS is the old one updated with *some* `s` they know.
+//!
+//! The final `τ` is the product of all the participants' secrets, so learning it
+//! requires all of them.
+//!
+//! ```rust
+//! use ark_algebra_intro::setup::powers_of_tau::{contribute, initial, verify_contribution};
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let mut srs = initial(8);
+//! for _ in 0..3 {
+//!     let (next, proof) = contribute(&srs, &mut rng);
+//!     assert!(verify_contribution(&srs, &next, &proof, &mut rng));
+//!     srs = next;
+//! }
+//! ```
+//!
+//! # Toxic waste
+//!
+//! If every participant's secret leaks, so does `τ`, and with it the soundness of
+//! everything built on the SRS. For instance, anyone who knows `τ` can open a KZG
+//! commitment to any value they like:
+//!
+//! ```rust
+//! use ark_algebra_intro::commitments::kzg::{commit, verify, Proof};
+//! use ark_algebra_intro::poly::{DensePolynomial, Polynomial, UVPolynomial};
+//! use ark_algebra_intro::setup::powers_of_tau::{contribute_with_secret, initial};
+//! use ark_bls12_381::{Fr, G1Affine};
+//! use ark_ec::{AffineCurve, ProjectiveCurve};
+//! use ark_ff::Field;
+//! use ark_std::UniformRand;
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! // Two participants, who both keep their "toxic waste"...
+//! let (s1, s2) = (Fr::rand(&mut rng), Fr::rand(&mut rng));
+//! let (srs, _) = contribute_with_secret(&initial(4), s1, &mut rng);
+//! let (srs, _) = contribute_with_secret(&srs, s2, &mut rng);
+//!
+//! // ...and later collude to prove a false evaluation.
+//! let tau = s1 * s2;
+//! let p = DensePolynomial::<Fr>::rand(4, &mut rng);
+//! let (z, forged) = (Fr::from(7u64), Fr::from(0u64));
+//! let q_at_tau = (p.evaluate(&tau) - forged) * (tau - z).inverse().unwrap();
+//! let proof = Proof(G1Affine::prime_subgroup_generator().mul(q_at_tau).into_affine());
+//! assert!(verify(&srs, &commit(&srs, &p), z, forged, &proof));
+//! ```
+//!
+//! Real ceremonies (such as those for Zcash and Ethereum's KZG) work the same way, with
+//! the contributions chained through a public transcript.
+
+use crate::commitments::kzg;
+use crate::pairings::pairing_ratio_check;
+use crate::protocols::schnorr;
+use ark_bls12_381::{Bls12_381, Fr, G1Affine, G2Affine};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{One, UniformRand, Zero};
+use ark_std::rand::Rng;
+use ark_std::vec::Vec;
+
+/// The SRS produced by the ceremony.
+pub type Srs = kzg::Srs<Bls12_381>;
+
+/// A proof that an SRS was updated with a secret `s` known to the participant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UpdateProof {
+    /// The point `s · G`.
+    pub s_g1: G1Affine,
+    /// The point `s · H`.
+    pub s_g2: G2Affine,
+    /// A proof of knowledge of the discrete logarithm `s` of `s_g1`.
+    pub knowledge: schnorr::Signature,
+}
+
+/// Returns the starting point of the ceremony: the SRS for `τ = 1`, for polynomials of
+/// degree at most `max_degree`.
+pub fn initial(max_degree: usize) -> Srs {
+    Srs::from_tau(max_degree, Fr::one())
+}
+
+/// Updates `srs` with a fresh random secret, which is then discarded.
+pub fn contribute<R: Rng + ?Sized>(srs: &Srs, rng: &mut R) -> (Srs, UpdateProof) {
+    let secret = loop {
+        let s = Fr::rand(rng);
+        if !s.is_zero() {
+            break s;
+        }
+    };
+    contribute_with_secret(srs, secret, rng)
+}
+
+/// Updates `srs` with the given secret `s`, multiplying the `i`-th powers by `s^i`.
+///
+/// # Panics
+///
+/// Panics if `secret` is zero.
+pub fn contribute_with_secret<R: Rng + ?Sized>(
+    srs: &Srs,
+    secret: Fr,
+    rng: &mut R,
+) -> (Srs, UpdateProof) {
+    assert!(!secret.is_zero(), "the secret must be nonzero");
+    let mut power = Fr::one();
+    let mut powers_of_g = Vec::with_capacity(srs.powers_of_g.len());
+    let mut powers_of_h = Vec::with_capacity(srs.powers_of_h.len());
+    for (g, h) in srs.powers_of_g.iter().zip(&srs.powers_of_h) {
+        powers_of_g.push(g.mul(power));
+        powers_of_h.push(h.mul(power));
+        power *= secret;
+    }
+    let next = Srs {
+        powers_of_g: ProjectiveCurve::batch_normalization_into_affine(&powers_of_g),
+        powers_of_h: ProjectiveCurve::batch_normalization_into_affine(&powers_of_h),
+    };
+
+    let g = G1Affine::prime_subgroup_generator();
+    let h = G2Affine::prime_subgroup_generator();
+    let proof = UpdateProof {
+        s_g1: g.mul(secret).into_affine(),
+        s_g2: h.mul(secret).into_affine(),
+        knowledge: schnorr::prove_knowledge(&g, &secret, rng),
+    };
+    (next, proof)
+}
+
+/// Checks that `srs` is well-formed: that it consists of the powers of *some* `τ`, in
+/// both groups, starting from the generators.
+///
+/// Rather than checking `e([τ^{i+1}]_1, H) = e([τ^i]_1, [τ]_2)` for every `i`, this
+/// checks a random linear combination of those equations, with two pairings. Similarly
+/// for the powers in G2.
+pub fn verify_srs<R: Rng + ?Sized>(srs: &Srs, rng: &mut R) -> bool {
+    let g = G1Affine::prime_subgroup_generator();
+    let h = G2Affine::prime_subgroup_generator();
+    let n = srs.powers_of_g.len();
+    if n < 2 || srs.powers_of_h.len() != n || srs.powers_of_g[0] != g || srs.powers_of_h[0] != h {
+        return false;
+    }
+    let (tau_g, tau_h) = (srs.powers_of_g[1], srs.powers_of_h[1]);
+    if tau_g.is_zero() || !pairing_ratio_check::<Bls12_381>((tau_g, h), (g, tau_h)) {
+        return false;
+    }
+
+    let r: Vec<_> = (1..n).map(|_| Fr::rand(rng)).collect();
+    let msm_g1 = |powers: &[G1Affine]| crate::msm::arkworks(powers, &r).into_affine();
+    let msm_g2 = |powers: &[G2Affine]| crate::msm::arkworks(powers, &r).into_affine();
+    let (lower_g, upper_g) = (
+        msm_g1(&srs.powers_of_g[..n - 1]),
+        msm_g1(&srs.powers_of_g[1..]),
+    );
+    let (lower_h, upper_h) = (
+        msm_g2(&srs.powers_of_h[..n - 1]),
+        msm_g2(&srs.powers_of_h[1..]),
+    );
+    pairing_ratio_check::<Bls12_381>((upper_g, h), (lower_g, tau_h))
+        && pairing_ratio_check::<Bls12_381>((g, upper_h), (tau_g, lower_h))
+}
+
+/// Checks that `after` is a well-formed SRS obtained from `before` by the update that
+/// `proof` describes.
+///
+/// Besides [`verify_srs`], this checks that the participant knows `s` (with a Schnorr
+/// proof for `s · G`), that `s · G` and `s · H` are consistent, and that the new `τ` is
+/// the old one times `s`: `e([τ']_1, H) = e([τ]_1, s · H)`.
+///
+/// ```rust
+/// use ark_algebra_intro::setup::powers_of_tau::{contribute, initial, verify_contribution};
+/// use ark_bls12_381::G1Projective;
+/// use ark_ec::ProjectiveCurve;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let before = initial(4);
+/// let (mut after, proof) = contribute(&before, &mut rng);
+///
+/// // A participant who tampers with a single power is caught.
+/// after.powers_of_g[3] = G1Projective::rand(&mut rng).into_affine();
+/// assert!(!verify_contribution(&before, &after, &proof, &mut rng));
+///
+/// // So is one who tries to pass off an unrelated SRS as an update.
+/// let (other, _) = contribute(&before, &mut rng);
+/// assert!(!verify_contribution(&before, &other, &proof, &mut rng));
+/// ```
+pub fn verify_contribution<R: Rng + ?Sized>(
+    before: &Srs,
+    after: &Srs,
+    proof: &UpdateProof,
+    rng: &mut R,
+) -> bool {
+    let g = G1Affine::prime_subgroup_generator();
+    let h = G2Affine::prime_subgroup_generator();
+    before.powers_of_g.len() == after.powers_of_g.len()
+        && verify_srs(after, rng)
+        && !proof.s_g1.is_zero()
+        && schnorr::verify_knowledge(&g, &proof.s_g1, &proof.knowledge)
+        && pairing_ratio_check::<Bls12_381>((proof.s_g1, h), (g, proof.s_g2))
+        && pairing_ratio_check::<Bls12_381>(
+            (after.powers_of_g[1], h),
+            (before.powers_of_g[1], proof.s_g2),
+        )
+}
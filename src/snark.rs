@@ -0,0 +1,16 @@
+//! From constraint systems to succinct proofs.
+//!
+//! A SNARK (succinct non-interactive argument of knowledge) proves that the prover knows a
+//! witness satisfying some constraint system, with a proof that is much shorter (and
+//! much faster to check) than the witness itself. The submodules go through the steps
+//! that Groth16, the most widely deployed SNARK, takes to get there, and the
+//! arithmetization of PLONK, which replaces R1CS with gates and copy constraints.
+//!
+//! The `qap` module requires the `r1cs` feature, and `groth16_demo` the `groth16` feature.
+
+#[cfg(feature = "groth16")]
+pub mod groth16_demo;
+pub mod lookup;
+pub mod plonk_arith;
+#[cfg(feature = "r1cs")]
+pub mod qap;
@@ -0,0 +1,204 @@
+//! Groth16 proofs for the [cubic circuit](crate::r1cs::CubicCircuit), end to end.
+//!
+//! Groth16 turns an R1CS into a proof system in three steps:
+//!
+//! 1. **Setup.** A trusted party samples secret field elements (the "toxic waste"), and
+//!    publishes a *proving key* and a *verifying key*: encodings in G1 and G2 of the QAP
+//!    polynomials of the circuit, evaluated at a secret point. The keys are specific to
+//!    the circuit; anyone who learns the secrets can forge proofs.
+//! 2. **Proving.** Given the public inputs and a satisfying witness, the prover combines
+//!    the proving key into a proof of just three group elements `A`, `B` and `C`, whatever
+//!    the size of the circuit. Fresh randomness makes the proof zero-knowledge.
+//! 3. **Verification.** The verifier checks one pairing equation,
+//!    `e(A, B) = e(α, β) · e(Σ x_i · IC_i, γ) · e(C, δ)`, whose cost only depends on the
+//!    number of public inputs.
+//!
+//! ```rust
+//! use ark_algebra_intro::snark::groth16_demo::{prove, setup, verify};
+//! use ark_algebra_intro::Error;
+//! use ark_bls12_381::Fr;
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! let (pk, vk) = setup(&mut rng);
+//! let proof = prove(&pk, Fr::from(3u64), Fr::from(35u64), &mut rng).unwrap();
+//! assert!(verify(&vk, Fr::from(35u64), &proof));
+//! // The proof is for a specific public input.
+//! assert!(!verify(&vk, Fr::from(36u64), &proof));
+//!
+//! // Without a solution, there's nothing to prove.
+//! assert_eq!(
+//!     prove(&pk, Fr::from(4u64), Fr::from(35u64), &mut rng),
+//!     Err(Error::Unsatisfied)
+//! );
+//! ```
+//!
+//! The `14_groth16` example walks through this module:
+//! `cargo run --release --example 14_groth16 --features groth16`.
+//!
+//! This module is only available with the `groth16` feature.
+
+use crate::r1cs::CubicCircuit;
+use crate::Error;
+use ark_bls12_381::{Bls12_381, Fr, G1Affine, G2Affine};
+use ark_ec::AffineCurve;
+use ark_serialize::CanonicalSerialize;
+use ark_std::fmt;
+use ark_std::rand::Rng;
+
+/// A Groth16 proving key over BLS12-381.
+pub type ProvingKey = ark_groth16::ProvingKey<Bls12_381>;
+
+/// A Groth16 verifying key over BLS12-381.
+pub type VerifyingKey = ark_groth16::VerifyingKey<Bls12_381>;
+
+/// A Groth16 proof over BLS12-381.
+pub type Proof = ark_groth16::Proof<Bls12_381>;
+
+/// Generates keys for the cubic circuit.
+///
+/// The circuit has no assignment during setup: only the shape of its constraints matters.
+pub fn setup<R: Rng>(rng: &mut R) -> (ProvingKey, VerifyingKey) {
+    let circuit = CubicCircuit { x: None, out: None };
+    let pk = ark_groth16::generate_random_parameters::<Bls12_381, _, _>(circuit, rng)
+        .expect("the cubic circuit synthesizes without an assignment");
+    let vk = pk.vk.clone();
+    (pk, vk)
+}
+
+/// Proves that `x³ + x + 5 = out`, with `x` as the secret witness.
+///
+/// Returns [`Error::Unsatisfied`] if `x` is not a solution. (`ark-groth16` asserts that the witness
+/// satisfies the constraints in debug builds, and in release builds produces a proof
+/// that [`verify`] rejects.)
+pub fn prove<R: Rng>(pk: &ProvingKey, x: Fr, out: Fr, rng: &mut R) -> Result<Proof, Error> {
+    if x * x * x + x + Fr::from(5u64) != out {
+        return Err(Error::Unsatisfied);
+    }
+    let circuit = CubicCircuit {
+        x: Some(x),
+        out: Some(out),
+    };
+    let proof = ark_groth16::create_random_proof(circuit, pk, rng)
+        .expect("the cubic circuit synthesizes with an assignment");
+    Ok(proof)
+}
+
+/// Checks `proof` against the public output `out`.
+pub fn verify(vk: &VerifyingKey, out: Fr, proof: &Proof) -> bool {
+    let pvk = ark_groth16::prepare_verifying_key(vk);
+    ark_groth16::verify_proof(&pvk, proof, &[out]).unwrap_or(false)
+}
+
+/// The sizes of the parts of a proving key, and of the serialized keys and proofs.
+///
+/// ```rust
+/// use ark_algebra_intro::snark::groth16_demo::{setup, KeySummary};
+///
+/// let (pk, _) = setup(&mut ark_std::rand::thread_rng());
+/// let summary = KeySummary::new(&pk);
+/// // One public input (`out`), and 1 + 1 + 3 variables in total.
+/// assert_eq!(summary.public_inputs, 1);
+/// assert_eq!(summary.a_query, 5);
+/// // Three witness variables, each with an `L` term.
+/// assert_eq!(summary.l_query, 3);
+/// // A proof is two compressed G1 points and one compressed G2 point.
+/// assert_eq!(summary.proof_bytes, 48 + 96 + 48);
+/// println!("{}", summary);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeySummary {
+    /// The number of public inputs, excluding the constant `1`.
+    pub public_inputs: usize,
+    /// The number of G1 elements `A_i(τ)`, one per variable.
+    pub a_query: usize,
+    /// The number of G1 elements `B_i(τ)`, one per variable.
+    pub b_g1_query: usize,
+    /// The number of G2 elements `B_i(τ)`, one per variable.
+    pub b_g2_query: usize,
+    /// The number of G1 elements `τ^i · Z(τ) / δ`, for the quotient polynomial.
+    pub h_query: usize,
+    /// The number of G1 elements for the witness variables.
+    pub l_query: usize,
+    /// The size of the compressed proving key, in bytes.
+    pub proving_key_bytes: usize,
+    /// The size of the compressed verifying key, in bytes.
+    pub verifying_key_bytes: usize,
+    /// The size of a compressed proof, in bytes.
+    pub proof_bytes: usize,
+}
+
+impl KeySummary {
+    /// Summarizes `pk`, and the verifying key it contains.
+    pub fn new(pk: &ProvingKey) -> Self {
+        let proof = Proof {
+            a: G1Affine::prime_subgroup_generator(),
+            b: G2Affine::prime_subgroup_generator(),
+            c: G1Affine::prime_subgroup_generator(),
+        };
+        Self {
+            public_inputs: pk.vk.gamma_abc_g1.len() - 1,
+            a_query: pk.a_query.len(),
+            b_g1_query: pk.b_g1_query.len(),
+            b_g2_query: pk.b_g2_query.len(),
+            h_query: pk.h_query.len(),
+            l_query: pk.l_query.len(),
+            proving_key_bytes: pk.serialized_size(),
+            verifying_key_bytes: pk.vk.serialized_size(),
+            proof_bytes: proof.serialized_size(),
+        }
+    }
+}
+
+impl fmt::Display for KeySummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "public inputs: {}", self.public_inputs)?;
+        writeln!(
+            f,
+            "proving key: {} bytes ({} A, {} B in G1, {} B in G2, {} H, {} L)",
+            self.proving_key_bytes,
+            self.a_query,
+            self.b_g1_query,
+            self.b_g2_query,
+            self.h_query,
+            self.l_query
+        )?;
+        writeln!(f, "verifying key: {} bytes", self.verifying_key_bytes)?;
+        write!(f, "proof: {} bytes", self.proof_bytes)
+    }
+}
+
+/// One of the three elements of a proof.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProofElement {
+    /// The G1 element `A`.
+    A,
+    /// The G2 element `B`.
+    B,
+    /// The G1 element `C`.
+    C,
+}
+
+/// Returns a copy of `proof` with the generator added to `element`.
+///
+/// Any change to any element breaks the pairing equation:
+///
+/// ```rust
+/// use ark_algebra_intro::snark::groth16_demo::{corrupt, prove, setup, verify, ProofElement};
+/// use ark_bls12_381::Fr;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let (pk, vk) = setup(&mut rng);
+/// let proof = prove(&pk, Fr::from(3u64), Fr::from(35u64), &mut rng).unwrap();
+/// for element in [ProofElement::A, ProofElement::B, ProofElement::C].iter() {
+///     assert!(!verify(&vk, Fr::from(35u64), &corrupt(&proof, *element)));
+/// }
+/// ```
+pub fn corrupt(proof: &Proof, element: ProofElement) -> Proof {
+    let mut corrupted = proof.clone();
+    match element {
+        ProofElement::A => corrupted.a = proof.a + G1Affine::prime_subgroup_generator(),
+        ProofElement::B => corrupted.b = proof.b + G2Affine::prime_subgroup_generator(),
+        ProofElement::C => corrupted.c = proof.c + G1Affine::prime_subgroup_generator(),
+    }
+    corrupted
+}
@@ -0,0 +1,396 @@
+//! A plookup-style lookup argument: proving that every value in a committed vector lies
+//! in a public table.
+//!
+//! Range checks, bit decompositions and S-boxes are expensive to express with gates, but
+//! easy to express as a *lookup*: "each of these values appears in the table `T`". The
+//! plookup argument of Gabizon and Williamson proves that with a grand product, much like
+//! the [copy constraints of PLONK](super::plonk_arith).
+//!
+//! # The idea
+//!
+//! Let `f` be the `n` looked-up values, and `t` the `d` table entries. Let `s` be `f` and
+//! `t` together, *sorted by `t`*: each entry of `t`, followed by the values in `f` equal to
+//! it. Then every `f_i` is in `t` exactly when `s` only "steps" between consecutive
+//! entries of `t` or repeats a value, which, for random `β` and `γ`, holds (with
+//! overwhelming probability) exactly when
+//!
+//! ```text
+//! (1 + β)^n · ∏_{i < n} (γ + f_i) · ∏_{i < d-1} (γ (1 + β) + t_i + β t_{i+1})
+//!     = ∏_{i < n+d-1} (γ (1 + β) + s_i + β s_{i+1}).
+//! ```
+//!
+//! Each repeated value `s_i = s_{i+1} = f_j` on the right contributes
+//! `(1 + β)(γ + f_j)`, matching a factor on the left, and each step between table entries
+//! matches a factor of the table product.
+//!
+//! # The protocol
+//!
+//! Over a domain `H` of size `N`, with `d = N` table entries and `n = N - 1` values, the
+//! prover splits `s` (of length `2N - 1`) into two overlapping halves `h_1` and `h_2` of
+//! length `N`, which share one element, and commits to `f`, `h_1` and `h_2` with
+//! [KZG](crate::commitments::kzg). After receiving `β` and `γ` from the
+//! [transcript](crate::transcript), it commits to the grand product `Z`, which starts at
+//! `1` and accumulates the ratio of the two sides one row `i` at a time:
+//!
+//! ```text
+//! Z(ω^{i+1}) = Z(ω^i) · (1 + β)(γ + f_i)(γ (1 + β) + t_i + β t_{i+1})
+//!                     / ((γ (1 + β) + h_1,i + β h_1,i+1)(γ (1 + β) + h_2,i + β h_2,i+1)).
+//! ```
+//!
+//! The prover then shows that four identities hold on `H`: `Z` starts at `1`, follows the
+//! recurrence on every row but the last, ends at `1`, and the halves of `s` agree where
+//! they overlap. Combined with powers of a challenge `α`, they are divisible by
+//! `Z_H(X) = X^N - 1`; the prover commits to the quotient, and opens every polynomial at a
+//! random point `ζ` (and at `ω · ζ`, for the "next row" values) so that the verifier can
+//! check the combined identity there.
+//!
+//! ```rust
+//! use ark_algebra_intro::commitments::kzg::Srs;
+//! use ark_algebra_intro::snark::lookup::{prove, verify, Table};
+//! use ark_algebra_intro::Error;
+//! use ark_bls12_381::Fr;
+//!
+//! let mut rng = ark_std::rand::thread_rng();
+//! // A table of the 3-bit values, and an SRS large enough for it.
+//! let table = Table::new(&(0..8u64).map(Fr::from).collect::<Vec<_>>());
+//! let srs = Srs::setup(table.srs_degree(), &mut rng);
+//!
+//! let values: Vec<_> = [3u64, 1, 4, 1, 5].iter().map(|v| Fr::from(*v)).collect();
+//! let (commitment, proof) = prove(&srs, &table, &values).unwrap();
+//! assert!(verify(&srs, &table, &commitment, &proof));
+//!
+//! // Changing any opened value breaks the KZG proof of that opening.
+//! let mut tampered = proof.clone();
+//! tampered.openings[3].at_zeta += Fr::from(1u64);
+//! assert!(!verify(&srs, &table, &commitment, &tampered));
+//!
+//! // 9 does not fit in three bits, so there is no proof to make.
+//! let values: Vec<_> = [3u64, 1, 4, 1, 9].iter().map(|v| Fr::from(*v)).collect();
+//! assert_eq!(prove(&srs, &table, &values).err(), Some(Error::Unsatisfied));
+//!
+//! // The proof is bound to the commitment to the values.
+//! let other: Vec<_> = [2u64, 7].iter().map(|v| Fr::from(*v)).collect();
+//! let (other_commitment, _) = prove(&srs, &table, &other).unwrap();
+//! assert!(!verify(&srs, &table, &other_commitment, &proof));
+//! ```
+//!
+//! For simplicity, this omits the blinding that makes the argument zero-knowledge, so the
+//! openings leak information about the values.
+
+use crate::commitments::kzg::{self, commit, open_batch, verify_batch, Commitment};
+use crate::poly::domains::{interpolate_on_domain, EvaluationDomain, Radix2EvaluationDomain};
+use crate::poly::{divide_with_remainder, vanishing_polynomial, DensePolynomial, Polynomial};
+use crate::transcript::Transcript;
+use crate::Error;
+use ark_bls12_381::{Bls12_381, Fr};
+use ark_ff::{FftField, Field, One, Zero};
+use ark_std::vec::Vec;
+
+/// The transcript label.
+const PROOF_LABEL: &[u8] = b"ark-algebra-intro/lookup";
+
+/// A KZG structured reference string over BLS12-381.
+pub type Srs = kzg::Srs<Bls12_381>;
+
+/// A public lookup table, padded to a power of two.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Table {
+    /// The entries, padded by repeating the last one (which doesn't change which values
+    /// are in the table).
+    pub entries: Vec<Fr>,
+}
+
+impl Table {
+    /// Builds a table with the given entries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `entries` is empty.
+    pub fn new(entries: &[Fr]) -> Self {
+        let last = *entries.last().expect("the table must not be empty");
+        let mut entries = entries.to_vec();
+        entries.resize(entries.len().next_power_of_two().max(2), last);
+        Self { entries }
+    }
+
+    /// Returns the size `N` of the domain, which is the size of the padded table.
+    pub fn domain_size(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the maximum number of values a single proof can look up, `N - 1`.
+    pub fn capacity(&self) -> usize {
+        self.domain_size() - 1
+    }
+
+    /// Returns the maximum degree the SRS must support, that of the quotient polynomial.
+    pub fn srs_degree(&self) -> usize {
+        2 * self.domain_size()
+    }
+}
+
+/// The values of a committed polynomial at `ζ` and `ω · ζ`, with a KZG proof of both.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Opening {
+    /// The value at `ζ`.
+    pub at_zeta: Fr,
+    /// The value at `ω · ζ`.
+    pub at_next: Fr,
+    /// The proof of both values.
+    pub proof: kzg::Proof<Bls12_381>,
+}
+
+/// A lookup proof.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proof {
+    /// The commitment to the first half `h_1` of the sorted vector.
+    pub h1: Commitment<Bls12_381>,
+    /// The commitment to the second half `h_2`.
+    pub h2: Commitment<Bls12_381>,
+    /// The commitment to the grand product `Z`.
+    pub z: Commitment<Bls12_381>,
+    /// The commitment to the quotient of the combined identities by `Z_H`.
+    pub quotient: Commitment<Bls12_381>,
+    /// The openings of `f`, `h_1`, `h_2`, `Z` and the quotient, in this order.
+    pub openings: [Opening; 5],
+}
+
+/// The random challenges of the protocol.
+struct Challenges {
+    beta: Fr,
+    gamma: Fr,
+    alpha: Fr,
+}
+
+/// The values of every polynomial at one point `x`, and at `ω · x`.
+struct Row {
+    f: Fr,
+    t: Fr,
+    t_next: Fr,
+    h1: Fr,
+    h1_next: Fr,
+    h2: Fr,
+    h2_next: Fr,
+    z: Fr,
+    z_next: Fr,
+    /// The Lagrange polynomial of the first row of `H`, at `x`.
+    l_first: Fr,
+    /// The Lagrange polynomial of the last row of `H`, at `x`.
+    l_last: Fr,
+    /// `x - ω^{N-1}`, which switches off the recurrence on the last row.
+    not_last: Fr,
+}
+
+/// Combines the four identities, which all vanish on `H` for an honest prover.
+fn combined_identity(c: &Challenges, row: &Row) -> Fr {
+    let one_plus_beta = Fr::one() + c.beta;
+    let gamma_beta = c.gamma * one_plus_beta;
+    let starts_at_one = row.l_first * (row.z - Fr::one());
+    let recurrence = row.not_last
+        * (row.z * one_plus_beta * (c.gamma + row.f) * (gamma_beta + row.t + c.beta * row.t_next)
+            - row.z_next
+                * (gamma_beta + row.h1 + c.beta * row.h1_next)
+                * (gamma_beta + row.h2 + c.beta * row.h2_next));
+    let halves_overlap = row.l_last * (row.h1 - row.h2_next);
+    let ends_at_one = row.l_last * (row.z - Fr::one());
+    starts_at_one + c.alpha * (recurrence + c.alpha * (halves_overlap + c.alpha * ends_at_one))
+}
+
+/// Commits to `values`, and proves that every one of them is in `table`.
+///
+/// Returns [`Error::Unsatisfied`] if some value is not in the table.
+///
+/// # Panics
+///
+/// Panics if there are more values than the [capacity](Table::capacity) of the table, or
+/// if the SRS doesn't support [`Table::srs_degree`].
+pub fn prove(
+    srs: &Srs,
+    table: &Table,
+    values: &[Fr],
+) -> Result<(Commitment<Bls12_381>, Proof), Error> {
+    let n = table.domain_size();
+    assert!(
+        values.len() <= table.capacity(),
+        "the table only supports {} values",
+        table.capacity()
+    );
+    // Pad the values with a table entry, and add an unconstrained last row.
+    let mut f = values.to_vec();
+    f.resize(n, table.entries[0]);
+
+    // Sort the values by the table: each entry, followed by its copies in `f`.
+    let mut copies = vec![0; n];
+    for value in &f[..n - 1] {
+        let position = table.entries.iter().position(|t| t == value);
+        copies[position.ok_or(Error::Unsatisfied)?] += 1;
+    }
+    let mut sorted = Vec::with_capacity(2 * n - 1);
+    for (entry, count) in table.entries.iter().zip(&copies) {
+        sorted.extend(ark_std::iter::repeat_n(*entry, count + 1));
+    }
+    let (h1, h2) = (sorted[..n].to_vec(), sorted[n - 1..].to_vec());
+
+    let f_poly = interpolate_on_domain(&f);
+    let t_poly = interpolate_on_domain(&table.entries);
+    let h1_poly = interpolate_on_domain(&h1);
+    let h2_poly = interpolate_on_domain(&h2);
+    let commitment = commit(srs, &f_poly);
+    let (h1_commitment, h2_commitment) = (commit(srs, &h1_poly), commit(srs, &h2_poly));
+
+    let mut transcript = start_transcript(table, &commitment);
+    transcript.append_point(b"h1", &h1_commitment.0);
+    transcript.append_point(b"h2", &h2_commitment.0);
+    let beta: Fr = transcript.challenge_scalar(b"beta");
+    let gamma: Fr = transcript.challenge_scalar(b"gamma");
+
+    let gamma_beta = gamma * (Fr::one() + beta);
+    let mut z = vec![Fr::one()];
+    for i in 0..n - 1 {
+        let t = &table.entries;
+        let numerator = (Fr::one() + beta) * (gamma + f[i]) * (gamma_beta + t[i] + beta * t[i + 1]);
+        let denominator =
+            (gamma_beta + h1[i] + beta * h1[i + 1]) * (gamma_beta + h2[i] + beta * h2[i + 1]);
+        let ratio = numerator * denominator.inverse().expect("factors are nonzero");
+        z.push(*z.last().unwrap() * ratio);
+    }
+    let z_poly = interpolate_on_domain(&z);
+    let z_commitment = commit(srs, &z_poly);
+    transcript.append_point(b"z", &z_commitment.0);
+    let alpha: Fr = transcript.challenge_scalar(b"alpha");
+    let challenges = Challenges { beta, gamma, alpha };
+
+    // The combined identity has degree less than 4N, so it is determined by its values on
+    // a domain of size 4N, which contains H. On that domain, `ω · x` is four steps ahead.
+    let big = Radix2EvaluationDomain::<Fr>::new(4 * n).expect("the domain exists");
+    let evaluate = |p: &DensePolynomial<Fr>| big.fft(&p.coeffs);
+    let (f_e, t_e, h1_e, h2_e, z_e) = (
+        evaluate(&f_poly),
+        evaluate(&t_poly),
+        evaluate(&h1_poly),
+        evaluate(&h2_poly),
+        evaluate(&z_poly),
+    );
+    let (l_first, l_last) = lagrange_first_and_last(n);
+    let (l_first_e, l_last_e) = (evaluate(&l_first), evaluate(&l_last));
+    let last = Fr::get_root_of_unity(n).unwrap().pow([n as u64 - 1]);
+    let identity: Vec<_> = big
+        .elements()
+        .enumerate()
+        .map(|(k, x)| {
+            let next = (k + 4) % (4 * n);
+            let row = Row {
+                f: f_e[k],
+                t: t_e[k],
+                t_next: t_e[next],
+                h1: h1_e[k],
+                h1_next: h1_e[next],
+                h2: h2_e[k],
+                h2_next: h2_e[next],
+                z: z_e[k],
+                z_next: z_e[next],
+                l_first: l_first_e[k],
+                l_last: l_last_e[k],
+                not_last: x - last,
+            };
+            combined_identity(&challenges, &row)
+        })
+        .collect();
+    let (quotient, remainder) =
+        divide_with_remainder(&interpolate_on_domain(&identity), &vanishing_polynomial(n));
+    debug_assert!(remainder.is_zero());
+    let quotient_commitment = commit(srs, &quotient);
+    transcript.append_point(b"quotient", &quotient_commitment.0);
+    let zeta: Fr = transcript.challenge_scalar(b"zeta");
+
+    let points = [zeta, zeta * Fr::get_root_of_unity(n).unwrap()];
+    let open = |p: &DensePolynomial<Fr>| {
+        let (values, proof) = open_batch(srs, p, &points);
+        Opening {
+            at_zeta: values[0],
+            at_next: values[1],
+            proof,
+        }
+    };
+    let proof = Proof {
+        h1: h1_commitment,
+        h2: h2_commitment,
+        z: z_commitment,
+        quotient: quotient_commitment,
+        openings: [
+            open(&f_poly),
+            open(&h1_poly),
+            open(&h2_poly),
+            open(&z_poly),
+            open(&quotient),
+        ],
+    };
+    Ok((commitment, proof))
+}
+
+/// Checks that `proof` proves that every value committed to in `commitment` is in `table`.
+///
+/// The verifier evaluates the table polynomial and the Lagrange polynomials itself; in a
+/// real system, the table would be committed to in a preprocessing step instead.
+pub fn verify(srs: &Srs, table: &Table, commitment: &Commitment<Bls12_381>, proof: &Proof) -> bool {
+    let n = table.domain_size();
+    let mut transcript = start_transcript(table, commitment);
+    transcript.append_point(b"h1", &proof.h1.0);
+    transcript.append_point(b"h2", &proof.h2.0);
+    let beta: Fr = transcript.challenge_scalar(b"beta");
+    let gamma: Fr = transcript.challenge_scalar(b"gamma");
+    transcript.append_point(b"z", &proof.z.0);
+    let alpha: Fr = transcript.challenge_scalar(b"alpha");
+    transcript.append_point(b"quotient", &proof.quotient.0);
+    let zeta: Fr = transcript.challenge_scalar(b"zeta");
+
+    let omega = Fr::get_root_of_unity(n).unwrap();
+    let points = [zeta, zeta * omega];
+    let commitments = [commitment, &proof.h1, &proof.h2, &proof.z, &proof.quotient];
+    let openings_valid = commitments
+        .iter()
+        .zip(&proof.openings)
+        .all(|(c, o)| verify_batch(srs, c, &points, &[o.at_zeta, o.at_next], &o.proof));
+    if !openings_valid {
+        return false;
+    }
+
+    let [f, h1, h2, z, quotient] = proof.openings;
+    let t_poly = interpolate_on_domain(&table.entries);
+    let (l_first, l_last) = lagrange_first_and_last(n);
+    let row = Row {
+        f: f.at_zeta,
+        t: t_poly.evaluate(&zeta),
+        t_next: t_poly.evaluate(&points[1]),
+        h1: h1.at_zeta,
+        h1_next: h1.at_next,
+        h2: h2.at_zeta,
+        h2_next: h2.at_next,
+        z: z.at_zeta,
+        z_next: z.at_next,
+        l_first: l_first.evaluate(&zeta),
+        l_last: l_last.evaluate(&zeta),
+        not_last: zeta - omega.pow([n as u64 - 1]),
+    };
+    let challenges = Challenges { beta, gamma, alpha };
+    combined_identity(&challenges, &row)
+        == quotient.at_zeta * vanishing_polynomial(n).evaluate(&zeta)
+}
+
+/// Starts the transcript with the statement: the table and the commitment to the values.
+fn start_transcript(table: &Table, commitment: &Commitment<Bls12_381>) -> Transcript {
+    let mut transcript = Transcript::new(PROOF_LABEL);
+    transcript.append_serializable(b"table", &table.entries);
+    transcript.append_point(b"values", &commitment.0);
+    transcript
+}
+
+/// Returns the Lagrange polynomials of the first and last elements of the domain of size
+/// `n`.
+fn lagrange_first_and_last<F: FftField>(n: usize) -> (DensePolynomial<F>, DensePolynomial<F>) {
+    let mut unit = vec![F::zero(); n];
+    unit[0] = F::one();
+    let first = interpolate_on_domain(&unit);
+    unit.swap(0, n - 1);
+    (first, interpolate_on_domain(&unit))
+}
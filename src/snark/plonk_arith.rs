@@ -0,0 +1,455 @@
+//! The PLONK arithmetization: gates, copy constraints, and the grand-product argument.
+//!
+//! Where an R1CS constrains arbitrary linear combinations of variables, PLONK lays the
+//! computation out as a table with three columns `a`, `b` and `c` (the left input, right
+//! input and output wires), and one row per gate. Each row satisfies the *gate
+//! constraint*
+//!
+//! ```text
+//! q_L · a + q_R · b + q_O · c + q_M · a · b + q_C = 0,
+//! ```
+//!
+//! where the *selectors* `q_L, ..., q_C` fix the gate's type: `q_L = q_R = 1, q_O = -1` is
+//! an addition, `q_M = 1, q_O = -1` a multiplication, and so on. Gates alone don't say how
+//! they are wired together; *copy constraints* do, by requiring some cells of the table
+//! to be equal.
+//!
+//! # An example
+//!
+//! [`cubic_circuit`] proves `x³ + x + 5 = 35`, the running example of the `r1cs` module,
+//! with four gates:
+//!
+//! ```text
+//! row |  a      b     c    | gate
+//! ----+--------------------+-------------------
+//!  0  |  x      x     x²   | a · b - c = 0
+//!  1  |  x²     x     x³   | a · b - c = 0
+//!  2  |  x³     x     t    | a + b - c = 0
+//!  3  |  t      -     -    | a + 5 - 35 = 0
+//! ```
+//!
+//! and copy constraints `a_0 = b_0 = b_1 = b_2`, `c_0 = a_1`, `c_1 = a_2` and `c_2 = a_3`.
+//!
+//! ```rust
+//! use ark_algebra_intro::snark::plonk_arith::{cubic_circuit, cubic_witness};
+//! use ark_bls12_381::Fr;
+//!
+//! let circuit = cubic_circuit::<Fr>();
+//! let mut rng = ark_std::rand::thread_rng();
+//! assert!(circuit.is_satisfied(&cubic_witness(Fr::from(3u64)), &mut rng));
+//! assert!(!circuit.is_satisfied(&cubic_witness(Fr::from(4u64)), &mut rng));
+//!
+//! // Each gate can be satisfied on its own with inconsistent wires: here row 2 uses 4
+//! // for `x`, which row 3 notices...
+//! let mut witness = cubic_witness(Fr::from(3u64));
+//! witness.b[2] = Fr::from(4u64);
+//! witness.c[2] = Fr::from(31u64);
+//! witness.a[3] = Fr::from(31u64);
+//! assert_eq!(circuit.failing_gate(&witness), Some(3));
+//! // ...unless it ignores row 2's output, which only the copy constraints catch.
+//! witness.a[3] = Fr::from(30u64);
+//! assert_eq!(circuit.failing_gate(&witness), None);
+//! assert!(circuit.check_gates(&witness));
+//! assert!(!circuit.is_satisfied(&witness, &mut rng));
+//! ```
+//!
+//! # From tables to polynomials
+//!
+//! Over a [domain](crate::poly::domains) `H = {1, ω, ..., ω^{n-1}}` with one element per
+//! row, each column becomes the polynomial that takes the column's values on `H`, and
+//! the gate constraint becomes a polynomial identity, which holds on all of `H` exactly
+//! when the vanishing polynomial `Z_H(X) = X^n - 1` divides it ([`Circuit::check_gates`]).
+//!
+//! Copy constraints take more work. Label each cell with a distinct field element: cell
+//! `(a, i)` with `ω^i`, `(b, i)` with `k_1 · ω^i` and `(c, i)` with `k_2 · ω^i`, for
+//! constants `k_1, k_2` that put the three columns in disjoint cosets of `H`. The copy
+//! constraints partition the cells into classes of equal cells; a permutation `σ` cycles
+//! through each class ([`Circuit::permutation`]). The wires then satisfy the copy
+//! constraints exactly when permuting the cells by `σ` doesn't change their values, and
+//! for random `β` and `γ`, this holds (with overwhelming probability) exactly when
+//!
+//! ```text
+//! ∏_{cells x} (w(x) + β · label(x) + γ) = ∏_{cells x} (w(x) + β · label(σ(x)) + γ).
+//! ```
+//!
+//! The *grand product* `Z` accumulates the ratio of the two sides row by row
+//! ([`Circuit::grand_product`]): `Z(1) = 1` and
+//! `Z(ω^{i+1}) = Z(ω^i) · f(ω^i) / g(ω^i)`, where `f` and `g` are the products of the
+//! factors of row `i` of either side. The argument checks two polynomial identities on `H`
+//! ([`Circuit::check_copies`]):
+//!
+//! ```text
+//! L_1(X) · (Z(X) - 1) = 0,     Z(X) · f(X) - Z(ω · X) · g(X) = 0,
+//! ```
+//!
+//! where `L_1` is the Lagrange polynomial of the first row. The second wraps around from
+//! the last row to the first, so it forces the full product of ratios to be `1`.
+//!
+//! A PLONK prover commits to the wire polynomials and to `Z` with a [polynomial
+//! commitment](crate::commitments::kzg), and proves these identities at a random point;
+//! this module only checks them directly.
+
+use crate::poly::domains::interpolate_on_domain;
+use crate::poly::{divide_with_remainder, vanishing_polynomial, DensePolynomial, UVPolynomial};
+use ark_ff::{FftField, Field, Zero};
+use ark_std::rand::Rng;
+use ark_std::vec::Vec;
+
+/// A column of the table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Column {
+    /// The left input `a`.
+    A,
+    /// The right input `b`.
+    B,
+    /// The output `c`.
+    C,
+}
+
+impl Column {
+    const ALL: [Column; 3] = [Column::A, Column::B, Column::C];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// A cell of the table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cell {
+    /// The column of the cell.
+    pub column: Column,
+    /// The row of the cell.
+    pub row: usize,
+}
+
+impl Cell {
+    /// Returns the cell in `column` and `row`.
+    pub fn new(column: Column, row: usize) -> Self {
+        Self { column, row }
+    }
+}
+
+/// The selectors of one gate, `q_L · a + q_R · b + q_O · c + q_M · a · b + q_C = 0`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Gate<F> {
+    /// The coefficient of `a`.
+    pub q_l: F,
+    /// The coefficient of `b`.
+    pub q_r: F,
+    /// The coefficient of `c`.
+    pub q_o: F,
+    /// The coefficient of `a · b`.
+    pub q_m: F,
+    /// The constant term.
+    pub q_c: F,
+}
+
+impl<F: Field> Gate<F> {
+    /// The addition gate `a + b = c`.
+    pub fn add() -> Self {
+        Self {
+            q_l: F::one(),
+            q_r: F::one(),
+            q_o: -F::one(),
+            q_m: F::zero(),
+            q_c: F::zero(),
+        }
+    }
+
+    /// The multiplication gate `a · b = c`.
+    pub fn mul() -> Self {
+        Self {
+            q_l: F::zero(),
+            q_r: F::zero(),
+            q_o: -F::one(),
+            q_m: F::one(),
+            q_c: F::zero(),
+        }
+    }
+
+    /// The gate `a = value`, which ignores `b` and `c`.
+    pub fn constant(value: F) -> Self {
+        Self {
+            q_l: F::one(),
+            q_r: F::zero(),
+            q_o: F::zero(),
+            q_m: F::zero(),
+            q_c: -value,
+        }
+    }
+
+    /// Evaluates the left-hand side of the gate constraint on the wires of a row.
+    pub fn evaluate(&self, a: F, b: F, c: F) -> F {
+        self.q_l * a + self.q_r * b + self.q_o * c + self.q_m * a * b + self.q_c
+    }
+}
+
+/// The values in the three columns of the table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Witness<F> {
+    /// The left inputs.
+    pub a: Vec<F>,
+    /// The right inputs.
+    pub b: Vec<F>,
+    /// The outputs.
+    pub c: Vec<F>,
+}
+
+impl<F: Copy> Witness<F> {
+    /// Returns the value in `cell`.
+    pub fn get(&self, cell: Cell) -> F {
+        self.column(cell.column)[cell.row]
+    }
+
+    fn column(&self, column: Column) -> &[F] {
+        match column {
+            Column::A => &self.a,
+            Column::B => &self.b,
+            Column::C => &self.c,
+        }
+    }
+}
+
+/// A circuit: one gate per row, and the copy constraints between cells.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Circuit<F> {
+    /// The gates, one per row.
+    pub gates: Vec<Gate<F>>,
+    /// The pairs of cells that must hold equal values.
+    pub copies: Vec<(Cell, Cell)>,
+}
+
+impl<F: FftField> Circuit<F> {
+    /// Builds a circuit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of gates is not a power of two, or if a copy constraint refers
+    /// to a row that doesn't exist.
+    pub fn new(gates: Vec<Gate<F>>, copies: Vec<(Cell, Cell)>) -> Self {
+        assert!(
+            gates.len().is_power_of_two(),
+            "the number of gates must be a power of two"
+        );
+        assert!(
+            copies
+                .iter()
+                .all(|(x, y)| x.row < gates.len() && y.row < gates.len()),
+            "copy constraints must refer to existing rows"
+        );
+        Self { gates, copies }
+    }
+
+    /// Returns the number of rows `n`.
+    pub fn num_rows(&self) -> usize {
+        self.gates.len()
+    }
+
+    /// Returns the index of the first row whose gate constraint `witness` violates.
+    pub fn failing_gate(&self, witness: &Witness<F>) -> Option<usize> {
+        self.gates.iter().enumerate().position(|(i, gate)| {
+            !gate
+                .evaluate(witness.a[i], witness.b[i], witness.c[i])
+                .is_zero()
+        })
+    }
+
+    /// Checks the gate constraints as a polynomial identity: `Z_H` divides
+    /// `q_L · a + q_R · b + q_O · c + q_M · a · b + q_C`.
+    pub fn check_gates(&self, witness: &Witness<F>) -> bool {
+        let selector = |q: fn(&Gate<F>) -> F| {
+            interpolate_on_domain(&self.gates.iter().map(q).collect::<Vec<_>>())
+        };
+        let [a, b, c] = self.wire_polynomials(witness);
+        let terms = [
+            selector(|g| g.q_l).naive_mul(&a),
+            selector(|g| g.q_r).naive_mul(&b),
+            selector(|g| g.q_o).naive_mul(&c),
+            selector(|g| g.q_m).naive_mul(&a).naive_mul(&b),
+            selector(|g| g.q_c),
+        ];
+        let gate = terms
+            .iter()
+            .fold(DensePolynomial::zero(), |sum, term| &sum + term);
+        self.vanishes_on_domain(&gate)
+    }
+
+    /// Returns the labels `σ_a(ω^i)`, `σ_b(ω^i)` and `σ_c(ω^i)` of the images of the
+    /// cells under the permutation `σ`.
+    ///
+    /// Each class of cells that the copy constraints make equal becomes one cycle of `σ`,
+    /// so cells without copy constraints are fixed, and keep their own labels.
+    pub fn permutation(&self) -> [Vec<F>; 3] {
+        let n = self.num_rows();
+        let index = |cell: Cell| cell.column.index() * n + cell.row;
+        let cells: Vec<_> = Column::ALL
+            .iter()
+            .flat_map(|&column| (0..n).map(move |row| Cell::new(column, row)))
+            .collect();
+
+        // Merge the classes of each pair of cells: `class[i]` is the class of cell `i`.
+        let mut class: Vec<_> = (0..cells.len()).collect();
+        for (x, y) in &self.copies {
+            let (from, to) = (class[index(*x)], class[index(*y)]);
+            for c in class.iter_mut().filter(|c| **c == from) {
+                *c = to;
+            }
+        }
+        // Map each cell to the next one in its class, wrapping around.
+        let mut sigma = [Vec::new(), Vec::new(), Vec::new()];
+        for (i, cell) in cells.iter().enumerate() {
+            let next = (1..=cells.len())
+                .map(|offset| (i + offset) % cells.len())
+                .find(|&j| class[j] == class[i])
+                .unwrap();
+            sigma[cell.column.index()].push(self.label(cells[next]));
+        }
+        sigma
+    }
+
+    /// Returns the values `Z(ω^0), ..., Z(ω^n)` of the grand product, for the challenges
+    /// `β` and `γ`. The wires satisfy the copy constraints (with overwhelming probability
+    /// over the challenges) if and only if the last value, the full product, is `1`.
+    ///
+    /// ```rust
+    /// use ark_algebra_intro::snark::plonk_arith::{cubic_circuit, cubic_witness};
+    /// use ark_bls12_381::Fr;
+    /// use ark_ff::One;
+    ///
+    /// let circuit = cubic_circuit::<Fr>();
+    /// let (beta, gamma) = (Fr::from(7u64), Fr::from(11u64));
+    /// let z = circuit.grand_product(&cubic_witness(Fr::from(3u64)), beta, gamma);
+    /// assert_eq!(z.len(), 5);
+    /// assert!(z[0].is_one() && z[4].is_one());
+    /// // The partial products in between are not 1: the factors only cancel out overall.
+    /// assert!(!z[1].is_one());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a factor `w(x) + β · label(σ(x)) + γ` is zero, which happens with
+    /// negligible probability for random challenges.
+    pub fn grand_product(&self, witness: &Witness<F>, beta: F, gamma: F) -> Vec<F> {
+        let sigma = self.permutation();
+        let mut z = vec![F::one()];
+        for row in 0..self.num_rows() {
+            let (mut numerator, mut denominator) = (F::one(), F::one());
+            for column in Column::ALL.iter() {
+                let cell = Cell::new(*column, row);
+                let w = witness.get(cell);
+                numerator *= w + beta * self.label(cell) + gamma;
+                denominator *= w + beta * sigma[cell.column.index()][cell.row] + gamma;
+            }
+            let ratio = numerator
+                * denominator
+                    .inverse()
+                    .expect("the challenges should make every factor nonzero");
+            z.push(*z.last().unwrap() * ratio);
+        }
+        z
+    }
+
+    /// Checks the copy constraints with the polynomial identities of the grand-product
+    /// argument, for the challenges `β` and `γ`.
+    pub fn check_copies(&self, witness: &Witness<F>, beta: F, gamma: F) -> bool {
+        let n = self.num_rows();
+        let omega = F::get_root_of_unity(n).expect("the field has no domain of this size");
+        let wires = self.wire_polynomials(witness);
+        let sigma = self.permutation();
+
+        let mut z = self.grand_product(witness, beta, gamma);
+        z.pop();
+        let z = interpolate_on_domain(&z);
+        // Z(ω · X) has coefficients z_i · ω^i.
+        let mut power = F::one();
+        let z_shifted = DensePolynomial::from_coefficients_vec(
+            z.coeffs
+                .iter()
+                .map(|coeff| {
+                    let shifted = *coeff * power;
+                    power *= omega;
+                    shifted
+                })
+                .collect(),
+        );
+
+        let (mut f, mut g) = (constant(F::one()), constant(F::one()));
+        for column in Column::ALL.iter() {
+            let w = &wires[column.index()];
+            let k = self.label(Cell::new(*column, 0));
+            let identity = DensePolynomial::from_coefficients_vec(vec![gamma, beta * k]);
+            let permuted = &interpolate_on_domain(&sigma[column.index()]) * beta;
+            f = f.naive_mul(&(w + &identity));
+            g = g.naive_mul(&(&(w + &permuted) + &constant(gamma)));
+        }
+
+        let mut first_row = vec![F::zero(); n];
+        first_row[0] = F::one();
+        let l_1 = interpolate_on_domain(&first_row);
+        self.vanishes_on_domain(&l_1.naive_mul(&(&z - &constant(F::one()))))
+            && self.vanishes_on_domain(&(&z.naive_mul(&f) - &z_shifted.naive_mul(&g)))
+    }
+
+    /// Checks the gate constraints and, for random challenges, the copy constraints.
+    pub fn is_satisfied<R: Rng + ?Sized>(&self, witness: &Witness<F>, rng: &mut R) -> bool {
+        let (beta, gamma) = (F::rand(rng), F::rand(rng));
+        self.check_gates(witness) && self.check_copies(witness, beta, gamma)
+    }
+
+    /// Returns the label `k_j · ω^i` of `cell`, with `k_a = 1`, `k_b = g` and `k_c = g²`
+    /// for the multiplicative generator `g` of `F`. Neither `g` nor `g²` lies in `H`, so
+    /// the cosets `H`, `g · H` and `g² · H` are disjoint.
+    fn label(&self, cell: Cell) -> F {
+        let omega =
+            F::get_root_of_unity(self.num_rows()).expect("the field has no domain of this size");
+        let k = F::multiplicative_generator().pow([cell.column.index() as u64]);
+        k * omega.pow([cell.row as u64])
+    }
+
+    fn wire_polynomials(&self, witness: &Witness<F>) -> [DensePolynomial<F>; 3] {
+        [
+            interpolate_on_domain(&witness.a),
+            interpolate_on_domain(&witness.b),
+            interpolate_on_domain(&witness.c),
+        ]
+    }
+
+    fn vanishes_on_domain(&self, p: &DensePolynomial<F>) -> bool {
+        let (_, remainder) = divide_with_remainder(p, &vanishing_polynomial(self.num_rows()));
+        remainder.is_zero()
+    }
+}
+
+fn constant<F: Field>(c: F) -> DensePolynomial<F> {
+    DensePolynomial::from_coefficients_vec(vec![c])
+}
+
+/// The circuit for `x³ + x + 5 = 35` from the [module documentation](self).
+pub fn cubic_circuit<F: FftField>() -> Circuit<F> {
+    let (a, b, c) = (Column::A, Column::B, Column::C);
+    let copies = vec![
+        (Cell::new(a, 0), Cell::new(b, 0)),
+        (Cell::new(b, 0), Cell::new(b, 1)),
+        (Cell::new(b, 1), Cell::new(b, 2)),
+        (Cell::new(c, 0), Cell::new(a, 1)),
+        (Cell::new(c, 1), Cell::new(a, 2)),
+        (Cell::new(c, 2), Cell::new(a, 3)),
+    ];
+    let mut last = Gate::constant(F::from(35u64));
+    last.q_c += F::from(5u64);
+    Circuit::new(vec![Gate::mul(), Gate::mul(), Gate::add(), last], copies)
+}
+
+/// Returns the table that computes `x³ + x + 5` in [`cubic_circuit`], with zeros in the
+/// unused cells.
+pub fn cubic_witness<F: Field>(x: F) -> Witness<F> {
+    let x2 = x * x;
+    let x3 = x2 * x;
+    let t = x3 + x;
+    Witness {
+        a: vec![x, x2, x3, t],
+        b: vec![x, x, x, F::zero()],
+        c: vec![x2, x3, t, F::zero()],
+    }
+}
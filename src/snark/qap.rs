@@ -0,0 +1,178 @@
+//! Quadratic arithmetic programs (QAPs): an R1CS as a single polynomial identity.
+//!
+//! An [R1CS](crate::r1cs) with `m` constraints over `n` variables checks `m` equations
+//! `<a_i, z> · <b_i, z> = <c_i, z>`. A QAP packs them into polynomials: pick `m` distinct
+//! points `ω_0, ..., ω_{m-1}` (here, the elements of a [radix-2
+//! domain](crate::poly::domains)), and for each variable `j`, interpolate the column `j`
+//! of each matrix to get polynomials `A_j`, `B_j` and `C_j` with
+//!
+//! ```text
+//! A_j(ω_i) = A[i][j],   B_j(ω_i) = B[i][j],   C_j(ω_i) = C[i][j].
+//! ```
+//!
+//! For an assignment `z`, the combinations `A(X) = Σ z_j · A_j(X)` (and likewise `B` and
+//! `C`) then evaluate to `<a_i, z>`, `<b_i, z>` and `<c_i, z>` at `ω_i`. So `z` satisfies
+//! every constraint if and only if `A(X) · B(X) - C(X)` vanishes at every `ω_i`, i.e., if
+//! and only if it is divisible by the *target polynomial* `Z(X) = ∏ (X - ω_i)`:
+//!
+//! ```text
+//! A(X) · B(X) - C(X) = H(X) · Z(X).
+//! ```
+//!
+//! ```rust
+//! use ark_algebra_intro::r1cs::{cubic_assignment, cubic_r1cs};
+//! use ark_algebra_intro::snark::qap::Qap;
+//! use ark_algebra_intro::Error;
+//! use ark_bls12_381::Fr;
+//!
+//! let qap = Qap::from_r1cs(&cubic_r1cs::<Fr>());
+//! // Three constraints, padded to a domain of size 4.
+//! assert_eq!(qap.domain_size(), 4);
+//!
+//! let z = cubic_assignment(Fr::from(3u64));
+//! assert!(qap.quotient(&z).is_ok());
+//!
+//! // With `x = 4` and `out = 35`, only the last constraint fails, but that's enough to
+//! // leave a remainder.
+//! let mut z = cubic_assignment(Fr::from(4u64));
+//! z[1] = Fr::from(35u64);
+//! assert_eq!(qap.quotient(&z), Err(Error::Unsatisfied));
+//! ```
+//!
+//! # Checking at a single point
+//!
+//! Checking a polynomial identity doesn't require the polynomials: by the Schwartz–Zippel
+//! lemma, two distinct polynomials of degree `d` agree on at most `d` points, so checking
+//! the identity at one random point `τ` suffices with overwhelming probability. This is
+//! what Groth16 does, "in the exponent": the [setup](super::groth16_demo::setup) publishes
+//! encodings of `A_j(τ)`, `B_j(τ)`, `C_j(τ)` and `τ^i · Z(τ)`, and the pairing checks
+//! that `A(τ) · B(τ) - C(τ) = H(τ) · Z(τ)` without anyone learning `τ`.
+//!
+//! ```rust
+//! use ark_algebra_intro::poly::Polynomial;
+//! use ark_algebra_intro::r1cs::{cubic_assignment, cubic_r1cs};
+//! use ark_algebra_intro::snark::qap::Qap;
+//! use ark_bls12_381::Fr;
+//! use ark_std::UniformRand;
+//!
+//! let qap = Qap::from_r1cs(&cubic_r1cs::<Fr>());
+//! let z = cubic_assignment(Fr::from(3u64));
+//! let h = qap.quotient(&z).unwrap();
+//! let (a, b, c) = qap.combine(&z);
+//!
+//! let tau = Fr::rand(&mut ark_std::rand::thread_rng());
+//! assert_eq!(
+//!     a.evaluate(&tau) * b.evaluate(&tau) - c.evaluate(&tau),
+//!     h.evaluate(&tau) * qap.target.evaluate(&tau),
+//! );
+//! ```
+//!
+//! Padding the constraints to a power of two makes `Z(X) = X^N - 1`, which is cheap to
+//! evaluate, and lets the prover compute `H` with FFTs; the extra rows are all zero, so
+//! they are satisfied by every assignment (`0 · 0 = 0`).
+//!
+//! The `13_qap` example walks through this module:
+//! `cargo run --example 13_qap --features r1cs`.
+
+use crate::poly::domains::{EvaluationDomain, Radix2EvaluationDomain};
+use crate::poly::{
+    divide_with_remainder, lagrange_interpolate, vanishing_polynomial, DensePolynomial, Polynomial,
+    UVPolynomial,
+};
+use crate::r1cs::R1CS;
+use crate::Error;
+use ark_ff::{FftField, Zero};
+
+/// The QAP of an R1CS: one polynomial per variable and matrix, and the target polynomial.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Qap<F: FftField> {
+    /// The polynomials `A_j`, one per variable.
+    pub a: Vec<DensePolynomial<F>>,
+    /// The polynomials `B_j`.
+    pub b: Vec<DensePolynomial<F>>,
+    /// The polynomials `C_j`.
+    pub c: Vec<DensePolynomial<F>>,
+    /// The target polynomial `Z(X) = X^N - 1`, which vanishes on the domain.
+    pub target: DensePolynomial<F>,
+}
+
+impl<F: FftField> Qap<F> {
+    /// Interpolates the columns of the matrices of `r1cs` over the smallest radix-2 domain
+    /// with at least one point per constraint.
+    ///
+    /// This uses Lagrange interpolation for clarity, at a cost of `O(N²)` per column; see
+    /// [`interpolate_on_domain`](crate::poly::domains::interpolate_on_domain) for the FFT.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `F` has no domain large enough.
+    pub fn from_r1cs(r1cs: &R1CS<F>) -> Self {
+        let domain = Radix2EvaluationDomain::<F>::new(r1cs.num_constraints().max(1))
+            .expect("the field has no domain of this size");
+        let points: Vec<_> = domain.elements().collect();
+        let interpolate_columns = |matrix: &[Vec<F>]| {
+            (0..r1cs.num_variables())
+                .map(|j| {
+                    let column: Vec<_> = points
+                        .iter()
+                        .enumerate()
+                        .map(|(i, x)| (*x, matrix.get(i).map_or(F::zero(), |row| row[j])))
+                        .collect();
+                    DensePolynomial::from_coefficients_vec(lagrange_interpolate(&column))
+                })
+                .collect()
+        };
+        Self {
+            a: interpolate_columns(&r1cs.a),
+            b: interpolate_columns(&r1cs.b),
+            c: interpolate_columns(&r1cs.c),
+            target: vanishing_polynomial(domain.size()),
+        }
+    }
+
+    /// Returns the size `N` of the domain, i.e., the number of constraints rounded up to a
+    /// power of two.
+    pub fn domain_size(&self) -> usize {
+        self.target.degree()
+    }
+
+    /// Returns the combinations `A(X) = Σ z_j · A_j(X)`, `B(X)` and `C(X)` for the
+    /// assignment `z`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `z` has the wrong length.
+    pub fn combine(&self, z: &[F]) -> (DensePolynomial<F>, DensePolynomial<F>, DensePolynomial<F>) {
+        assert_eq!(z.len(), self.a.len(), "wrong number of variables");
+        let combine = |polys: &[DensePolynomial<F>]| {
+            polys
+                .iter()
+                .zip(z)
+                .fold(DensePolynomial::zero(), |sum, (p, z_j)| &sum + &(p * *z_j))
+        };
+        (combine(&self.a), combine(&self.b), combine(&self.c))
+    }
+
+    /// Returns the quotient `H(X) = (A(X) · B(X) - C(X)) / Z(X)` if `z` satisfies the
+    /// QAP, and [`Error::Unsatisfied`] if the division leaves a remainder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `z` has the wrong length.
+    pub fn quotient(&self, z: &[F]) -> Result<DensePolynomial<F>, Error> {
+        let (a, b, c) = self.combine(z);
+        let numerator = &a.naive_mul(&b) - &c;
+        let (quotient, remainder) = divide_with_remainder(&numerator, &self.target);
+        if remainder.is_zero() {
+            Ok(quotient)
+        } else {
+            Err(Error::Unsatisfied)
+        }
+    }
+
+    /// Checks whether `z` satisfies the QAP, which is the case exactly when it satisfies
+    /// every constraint of the R1CS it came from.
+    pub fn is_satisfied(&self, z: &[F]) -> bool {
+        self.quotient(z).is_ok()
+    }
+}
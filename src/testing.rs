@@ -0,0 +1,282 @@
+//! Property-based testing of code that uses fields and curves, with [`proptest`].
+//!
+//! A property-based test checks that a property holds for many random inputs, and, when
+//! it finds one for which it doesn't, shrinks that input to a simpler one before
+//! reporting it. The strategies here generate the inputs: [field elements](field), with
+//! the edge cases `0`, `1` and `-1` more often than chance would;
+//! [scalars](scalar); curve points [in](point_in_subgroup) and
+//! [outside](point_not_in_subgroup) the prime-order subgroup; and
+//! [polynomials](polynomial). Wrapping a type in [`Arb`] makes it an argument of the
+//! [`proptest!`](proptest::proptest) macro:
+//!
+//! ```rust
+//! use ark_algebra_intro::testing::Arb;
+//! use ark_bls12_381::{Fr, G1Projective};
+//! use ark_ec::ProjectiveCurve;
+//! use ark_ff::{PrimeField, Zero};
+//! use proptest::prelude::*;
+//!
+//! /// The code under test: a scalar multiplication with the bits the wrong way round.
+//! fn buggy_mul(p: G1Projective, k: Fr) -> G1Projective {
+//!     let mut result = G1Projective::zero();
+//!     for bit in ark_ff::BitIteratorLE::new(k.into_repr()) {
+//!         result.double_in_place();
+//!         if bit {
+//!             result += p;
+//!         }
+//!     }
+//!     result
+//! }
+//!
+//! let mut runner = proptest::test_runner::TestRunner::default();
+//! let result = runner.run(&(any::<Arb<G1Projective>>(), any::<Arb<Fr>>()), |(p, k)| {
+//!     prop_assert_eq!(buggy_mul(p.0, k.0), p.0.mul(k.0.into_repr()));
+//!     Ok(())
+//! });
+//! // The bug is found, for any scalar whose bits aren't a palindrome.
+//! assert!(result.is_err());
+//! ```
+//!
+//! In a test suite, the same property is a `proptest! { ... }` block. The ready-made
+//! suites [`field_axioms`] and [`group_axioms`] check that a type behaves like a field
+//! or a group, which is useful when implementing one, like the fields of
+//! [`toy_fields`](crate::toy_fields):
+//!
+//! ```rust
+//! use ark_algebra_intro::testing::{field_axioms, group_axioms};
+//! use ark_algebra_intro::toy_fields::F101;
+//! use ark_bls12_381::{Fq12, G2Projective};
+//!
+//! field_axioms::<F101>().unwrap();
+//! field_axioms::<Fq12>().unwrap();
+//! group_axioms::<G2Projective>().unwrap();
+//! ```
+//!
+//! This module needs the `testing` feature.
+
+use crate::curves::{random_not_in_subgroup, AffineExt};
+use crate::poly::{DensePolynomial, UVPolynomial};
+use crate::rng::seeded;
+use crate::Error;
+use ark_bls12_381::Fr;
+use ark_ec::short_weierstrass_jacobian::{GroupAffine, GroupProjective};
+use ark_ec::{AffineCurve, ProjectiveCurve, SWModelParameters};
+use ark_ff::fields::{Fp256, Fp256Parameters, Fp384, Fp384Parameters, Fp64, Fp64Parameters};
+use ark_ff::{Field, FpParameters, PrimeField};
+use proptest::arbitrary::Arbitrary;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::test_runner::{Config, TestError, TestRunner};
+
+/// The number of cases that [`field_axioms`] and [`group_axioms`] try.
+pub const CASES: u32 = 64;
+
+/// Returns a strategy for elements of `F`: `0`, `1` and `-1` each a tenth of the time,
+/// and uniformly random elements otherwise.
+///
+/// For prime fields, [`prime_field`] generates elements that shrink better.
+pub fn field<F: Field>() -> BoxedStrategy<F> {
+    let uniform = any::<u64>().prop_map(|seed| F::rand(&mut seeded(seed)));
+    prop_oneof![
+        1 => Just(F::zero()),
+        1 => Just(F::one()),
+        1 => Just(-F::one()),
+        7 => uniform,
+    ]
+    .boxed()
+}
+
+/// Returns a strategy for elements of the prime field `F`, with the same edge cases as
+/// [`field`].
+///
+/// The other elements are close to uniform, and shrink towards small integers, which
+/// makes counterexamples easier to read.
+pub fn prime_field<F: PrimeField>() -> BoxedStrategy<F> {
+    // Twice as many bytes as the modulus, so that the reduction is close to uniform.
+    let bytes = 2 * (F::Params::MODULUS_BITS as usize).div_ceil(8);
+    let uniform = vec(any::<u8>(), 0..=bytes).prop_map(|b| F::from_le_bytes_mod_order(&b));
+    prop_oneof![
+        1 => Just(F::zero()),
+        1 => Just(F::one()),
+        1 => Just(-F::one()),
+        7 => uniform,
+    ]
+    .boxed()
+}
+
+/// Returns a strategy for scalars of BLS12-381, i.e., elements of `Fr`.
+pub fn scalar() -> BoxedStrategy<Fr> {
+    prime_field::<Fr>()
+}
+
+/// Returns a strategy for points of the prime-order subgroup of `G`, as multiples of
+/// its generator, which shrink towards the identity.
+pub fn point_in_subgroup<G: ProjectiveCurve>() -> BoxedStrategy<G> {
+    prime_field::<G::ScalarField>()
+        .prop_map(|k| G::prime_subgroup_generator().mul(k.into_repr()))
+        .boxed()
+}
+
+/// Returns a strategy for points of `G` that are on the curve but outside the
+/// prime-order subgroup, or [`Error::NoSuchPoint`] if `G` has cofactor 1 and so has no
+/// such points.
+///
+/// ```rust
+/// use ark_algebra_intro::curves::{is_in_correct_subgroup, is_on_curve};
+/// use ark_algebra_intro::testing::point_not_in_subgroup;
+/// use ark_bls12_381::G1Affine;
+/// use proptest::prelude::*;
+///
+/// let strategy = point_not_in_subgroup::<G1Affine>().unwrap();
+/// proptest::test_runner::TestRunner::default()
+///     .run(&strategy, |p| {
+///         prop_assert!(is_on_curve(&p) && !is_in_correct_subgroup(&p));
+///         Ok(())
+///     })
+///     .unwrap();
+/// ```
+pub fn point_not_in_subgroup<G: AffineExt>() -> Result<BoxedStrategy<G>, Error> {
+    random_not_in_subgroup::<G, _>(&mut seeded(0))?;
+    let strategy = any::<u64>()
+        .prop_map(|seed| random_not_in_subgroup(&mut seeded(seed)).expect("the cofactor is not 1"));
+    Ok(strategy.boxed())
+}
+
+/// Returns a strategy for polynomials over `F` of degree at most `max_degree`, with
+/// coefficients from [`prime_field`].
+pub fn polynomial<F: PrimeField>(max_degree: usize) -> BoxedStrategy<DensePolynomial<F>> {
+    vec(prime_field::<F>(), 0..=max_degree + 1)
+        .prop_map(DensePolynomial::from_coefficients_vec)
+        .boxed()
+}
+
+/// A field element or curve point, with an [`Arbitrary`] implementation so that it can
+/// be generated with [`any`].
+///
+/// `arkworks` types can't implement `Arbitrary` themselves, since neither the trait nor
+/// the types are defined in this crate. Prime field elements come from
+/// [`prime_field`], and points of short Weierstrass curves from
+/// [`point_in_subgroup`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Arb<T>(pub T);
+
+macro_rules! arbitrary_prime_field {
+    ($field:ident, $params:ident) => {
+        impl<P: $params> Arbitrary for Arb<$field<P>> {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: ()) -> Self::Strategy {
+                prime_field::<$field<P>>().prop_map(Arb).boxed()
+            }
+        }
+    };
+}
+
+arbitrary_prime_field!(Fp64, Fp64Parameters);
+arbitrary_prime_field!(Fp256, Fp256Parameters);
+arbitrary_prime_field!(Fp384, Fp384Parameters);
+
+impl<P: SWModelParameters> Arbitrary for Arb<GroupProjective<P>> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        point_in_subgroup::<GroupProjective<P>>()
+            .prop_map(Arb)
+            .boxed()
+    }
+}
+
+impl<P: SWModelParameters> Arbitrary for Arb<GroupAffine<P>> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        point_in_subgroup::<GroupProjective<P>>()
+            .prop_map(|p| Arb(p.into_affine()))
+            .boxed()
+    }
+}
+
+/// Checks the field axioms, and the consistency of the other operations of [`Field`]
+/// with them, on [`CASES`] random triples of elements of `F`.
+///
+/// Returns the (shrunk) triple for which a check fails, if any.
+pub fn field_axioms<F: Field>() -> Result<(), TestError<(F, F, F)>> {
+    let mut runner = TestRunner::new(Config::with_cases(CASES));
+    runner.run(&(field::<F>(), field::<F>(), field::<F>()), |(a, b, c)| {
+        prop_assert_eq!(a + b, b + a, "addition is not commutative");
+        prop_assert_eq!((a + b) + c, a + (b + c), "addition is not associative");
+        prop_assert_eq!(a + F::zero(), a, "0 is not the additive identity");
+        prop_assert_eq!(a + (-a), F::zero(), "-a is not the additive inverse of a");
+        prop_assert_eq!(a - b, a + (-b), "subtraction is not adding the negation");
+        prop_assert_eq!(a * b, b * a, "multiplication is not commutative");
+        prop_assert_eq!(
+            (a * b) * c,
+            a * (b * c),
+            "multiplication is not associative"
+        );
+        prop_assert_eq!(
+            a * (b + c),
+            a * b + a * c,
+            "multiplication does not distribute"
+        );
+        prop_assert_eq!(a * F::one(), a, "1 is not the multiplicative identity");
+        prop_assert_eq!(a.double(), a + a, "doubling is not a + a");
+        prop_assert_eq!(a.square(), a * a, "squaring is not a · a");
+        match a.inverse() {
+            Some(inverse) => prop_assert_eq!(a * inverse, F::one(), "bad inverse"),
+            None => prop_assert!(a.is_zero(), "a nonzero element has no inverse"),
+        }
+        Ok(())
+    })
+}
+
+/// The inputs of a case of [`group_axioms`]: three points and two scalars.
+pub type GroupCase<G> = (
+    G,
+    G,
+    G,
+    <G as ProjectiveCurve>::ScalarField,
+    <G as ProjectiveCurve>::ScalarField,
+);
+
+/// Checks the group axioms, and the consistency of doubling, scalar multiplication and
+/// conversions to affine coordinates with them, on [`CASES`] random triples of points of
+/// the prime-order subgroup of `G` and pairs of scalars.
+///
+/// Returns the (shrunk) points and scalars for which a check fails, if any.
+pub fn group_axioms<G: ProjectiveCurve>() -> Result<(), TestError<GroupCase<G>>> {
+    let mut runner = TestRunner::new(Config::with_cases(CASES));
+    let points = || point_in_subgroup::<G>();
+    let scalars = || prime_field::<G::ScalarField>();
+    let strategy = (points(), points(), points(), scalars(), scalars());
+    runner.run(&strategy, |(p, q, r, a, b)| {
+        let mul = |p: G, k: G::ScalarField| p.mul(k.into_repr());
+        prop_assert_eq!(p + q, q + p, "addition is not commutative");
+        prop_assert_eq!((p + q) + r, p + (q + r), "addition is not associative");
+        prop_assert_eq!(p + G::zero(), p, "0 is not the identity");
+        prop_assert_eq!(p + (-p), G::zero(), "-p is not the inverse of p");
+        prop_assert_eq!(p - q, p + (-q), "subtraction is not adding the negation");
+        prop_assert_eq!(p.double(), p + p, "doubling is not p + p");
+        prop_assert_eq!(
+            mul(p, a + b),
+            mul(p, a) + mul(p, b),
+            "(a + b) · p != a · p + b · p"
+        );
+        prop_assert_eq!(
+            mul(p, a * b),
+            mul(mul(p, a), b),
+            "(a · b) · p != b · (a · p)"
+        );
+        let order = <G::ScalarField as PrimeField>::Params::MODULUS;
+        prop_assert!(p.mul(order).is_zero(), "the order of p does not divide r");
+        prop_assert_eq!(
+            p.into_affine().into_projective(),
+            p,
+            "bad affine round trip"
+        );
+        Ok(())
+    })
+}
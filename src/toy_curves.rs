@@ -0,0 +1,271 @@
+//! Elliptic curves over prime fields chosen at runtime, small enough to attack.
+//!
+//! The curves of `arkworks` are fixed at compile time, and large enough to be secure.
+//! A [`ToyCurve`] is the curve `y² = x³ + a · x + b` over a [`DynPrimeField`], for any
+//! coefficients: close enough to a real curve to show how points add, and small
+//! enough that its points can be counted and its discrete logarithms computed, as in
+//! [`attacks`](crate::attacks):
+//!
+//! ```rust
+//! use ark_algebra_intro::discrete_log::DlogGroup;
+//! use ark_algebra_intro::dynamic::DynPrimeField;
+//! use ark_algebra_intro::toy_curves::ToyCurve;
+//! use num_bigint::BigUint;
+//!
+//! // y² = x³ + 2x + 3 over F97.
+//! let f = DynPrimeField::new(BigUint::from(97u64)).unwrap();
+//! let curve = ToyCurve::new(f.element(2u64), f.element(3u64)).unwrap();
+//! let p = curve.point(3u64, 6u64).unwrap();
+//! assert_eq!(p.double(), curve.point(80u64, 10u64).unwrap());
+//! assert_eq!(p.to_string(), "(3, 6)");
+//! // The group of points of this curve has order 100, and p has order 5.
+//! assert_eq!(curve.order(), 100);
+//! assert!(p.times(5).is_infinity());
+//! assert_eq!(&p + &-&p, curve.infinity());
+//!
+//! assert!(curve.point(3u64, 7u64).is_err());
+//! ```
+//!
+//! **These curves are far too small to be secure**; use them for learning only.
+//!
+//! Like those of `arkworks`, the addition formulas use `a` but not `b`, so every point
+//! remembers `a`, and the sum of two points is only meaningful if they are on the same
+//! curve. Points are checked when they are created with [`ToyCurve::point`], but the
+//! arithmetic doesn't stop a point of one curve from being added to a point of another
+//! with the same `a`, which is what the
+//! [invalid-curve attack](crate::attacks::invalid_curve) exploits.
+
+use crate::discrete_log::DlogGroup;
+use crate::dynamic::{DynField, DynPrimeField};
+use crate::Error;
+use ark_std::convert::TryFrom;
+use ark_std::fmt;
+use ark_std::ops::{Add, Neg, Sub};
+use ark_std::vec::Vec;
+use num_bigint::BigUint;
+
+/// The elliptic curve `y² = x³ + a · x + b` over a prime field.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ToyCurve {
+    a: DynField,
+    b: DynField,
+}
+
+impl ToyCurve {
+    /// Returns the curve `y² = x³ + a · x + b`, or [`Error::SingularCurve`] if
+    /// `4a³ + 27b² = 0`, in which case the cubic has a repeated root.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` are elements of different fields.
+    pub fn new(a: DynField, b: DynField) -> Result<Self, Error> {
+        let f = a.field();
+        let discriminant = &f.element(4u64) * &(&a.square() * &a) + f.element(27u64) * b.square();
+        if discriminant.is_zero() {
+            return Err(Error::SingularCurve);
+        }
+        Ok(Self { a, b })
+    }
+
+    /// Returns the coefficient `a`.
+    pub fn a(&self) -> &DynField {
+        &self.a
+    }
+
+    /// Returns the coefficient `b`.
+    pub fn b(&self) -> &DynField {
+        &self.b
+    }
+
+    /// Returns the field of the coordinates.
+    pub fn field(&self) -> DynPrimeField {
+        self.a.field()
+    }
+
+    /// Returns the point `(x, y)`, or [`Error::NotOnCurve`] if it is not on the curve.
+    pub fn point(&self, x: impl Into<BigUint>, y: impl Into<BigUint>) -> Result<ToyPoint, Error> {
+        let f = self.field();
+        let point = ToyPoint {
+            xy: Some((f.element(x), f.element(y))),
+            a: self.a.clone(),
+        };
+        if !self.is_on_curve(&point) {
+            return Err(Error::NotOnCurve);
+        }
+        Ok(point)
+    }
+
+    /// Returns a point with the coordinate `x`, or [`Error::NoSuchPoint`] if there is none.
+    ///
+    /// There are two such points, `(x, y)` and `(x, -y)`, unless `y = 0`; this returns
+    /// one of them.
+    pub fn lift_x(&self, x: impl Into<BigUint>) -> Result<ToyPoint, Error> {
+        let x = self.field().element(x);
+        let y = (&(&x.square() + &self.a) * &x + &self.b)
+            .sqrt()
+            .map_err(|_| Error::NoSuchPoint)?;
+        Ok(ToyPoint {
+            xy: Some((x, y)),
+            a: self.a.clone(),
+        })
+    }
+
+    /// Returns the number of points of the curve, including the point at infinity.
+    ///
+    /// This counts the points with each coordinate `x`, by the Legendre symbol of
+    /// `x³ + a · x + b`, which takes a few seconds for fields of about `2^20` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus doesn't fit in a `u64`.
+    pub fn order(&self) -> u64 {
+        let p = u64::try_from(self.field().modulus()).expect("the modulus fits in a u64");
+        let f = self.field();
+        let mut x = f.zero();
+        let mut order = 1;
+        for _ in 0..p {
+            let rhs = &(&x.square() + &self.a) * &x + &self.b;
+            // `0` has one square root, the other squares two, and the rest none.
+            order += (1 + rhs.legendre()) as u64;
+            x = &x + &f.one();
+        }
+        order
+    }
+
+    /// Returns the point at infinity, the identity of the group.
+    pub fn infinity(&self) -> ToyPoint {
+        ToyPoint {
+            xy: None,
+            a: self.a.clone(),
+        }
+    }
+
+    /// Returns whether `point` satisfies the equation of the curve.
+    ///
+    /// The point at infinity is on every curve.
+    pub fn is_on_curve(&self, point: &ToyPoint) -> bool {
+        match &point.xy {
+            None => true,
+            Some((x, y)) => {
+                point.a == self.a && y.square() == &(&x.square() + &self.a) * x + &self.b
+            }
+        }
+    }
+}
+
+/// A point of a [`ToyCurve`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ToyPoint {
+    /// The coordinates, or `None` for the point at infinity.
+    xy: Option<(DynField, DynField)>,
+    /// The coefficient `a` of the curve, which the addition formulas need.
+    a: DynField,
+}
+
+impl ToyPoint {
+    /// Returns the coordinate `x`, or `None` for the point at infinity.
+    pub fn x(&self) -> Option<&DynField> {
+        self.xy.as_ref().map(|(x, _)| x)
+    }
+
+    /// Returns the coordinate `y`, or `None` for the point at infinity.
+    pub fn y(&self) -> Option<&DynField> {
+        self.xy.as_ref().map(|(_, y)| y)
+    }
+
+    /// Returns whether `self` is the point at infinity.
+    pub fn is_infinity(&self) -> bool {
+        self.xy.is_none()
+    }
+
+    /// Returns `self + self`.
+    pub fn double(&self) -> Self {
+        self + self
+    }
+
+    /// Returns the point with the coordinates `xy`, on the same curve as `self`.
+    fn with_xy(&self, xy: Option<(DynField, DynField)>) -> Self {
+        Self {
+            xy,
+            a: self.a.clone(),
+        }
+    }
+}
+
+impl fmt::Display for ToyPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.xy {
+            None => write!(f, "infinity"),
+            Some((x, y)) => write!(f, "({}, {})", x.value(), y.value()),
+        }
+    }
+}
+
+impl Add for &ToyPoint {
+    type Output = ToyPoint;
+
+    /// Adds the points with the chord-and-tangent rule.
+    fn add(self, other: Self) -> ToyPoint {
+        let ((x1, y1), (x2, y2)) = match (&self.xy, &other.xy) {
+            (None, _) => return other.clone(),
+            (_, None) => return self.clone(),
+            (Some(p), Some(q)) => (p, q),
+        };
+        let slope = if x1 != x2 {
+            &(y2 - y1) / &(x2 - x1)
+        } else if y1 == y2 && !y1.is_zero() {
+            // The tangent at (x1, y1).
+            let f = x1.field();
+            &(&f.element(3u64) * &x1.square() + &self.a) / &y1.double()
+        } else {
+            // p + (-p), or the double of a point with y = 0.
+            return self.with_xy(None);
+        };
+        let x3 = &(&slope.square() - x1) - x2;
+        let y3 = &(&slope * &(x1 - &x3)) - y1;
+        self.with_xy(Some((x3, y3)))
+    }
+}
+
+impl Sub for &ToyPoint {
+    type Output = ToyPoint;
+
+    fn sub(self, other: Self) -> ToyPoint {
+        self + &-other
+    }
+}
+
+impl Neg for &ToyPoint {
+    type Output = ToyPoint;
+
+    fn neg(self) -> ToyPoint {
+        self.with_xy(self.xy.as_ref().map(|(x, y)| (x.clone(), -y)))
+    }
+}
+
+impl DlogGroup for ToyPoint {
+    fn combine(&self, other: &Self) -> Self {
+        self + other
+    }
+
+    fn negate(&self) -> Self {
+        -self
+    }
+
+    /// Returns the coordinates, padded to the length of the modulus, or nothing for the
+    /// point at infinity.
+    fn encode(&self) -> Vec<u8> {
+        let (x, y) = match &self.xy {
+            None => return Vec::new(),
+            Some(xy) => xy,
+        };
+        let length = (x.modulus().bits() as usize).div_ceil(8);
+        let mut bytes = Vec::with_capacity(2 * length);
+        for coordinate in [x, y] {
+            let mut digits = coordinate.value().to_bytes_le();
+            digits.resize(length, 0);
+            bytes.extend_from_slice(&digits);
+        }
+        bytes
+    }
+}
@@ -0,0 +1,206 @@
+//! Tiny prime fields for pencil-and-paper checks.
+//!
+//! Before working with 381-bit numbers, it can help to do arithmetic that you can
+//! verify by hand. This module defines the prime fields of order 7, 13, and 101
+//! using the same machinery (`Fp64` with Montgomery-form parameters) that
+//! `arkworks` uses for real-world fields, so every trait and helper in this crate
+//! works with them too.
+//!
+//! **These fields are far too small to be secure**; use them for learning only.
+//! [`define_prime_field!`](crate::define_prime_field) defines fields like these for
+//! other primes, computing the parameters below at compile time.
+//!
+//! Here are the full addition and multiplication tables of [`F7`]:
+//!
+//! ```rust
+//! use ark_algebra_intro::toy_fields::F7;
+//! use ark_ff::{BigInteger, PrimeField};
+//!
+//! let to_int = |x: F7| x.into_repr().as_ref()[0];
+//! let table = |op: fn(F7, F7) -> F7| -> Vec<Vec<u64>> {
+//!     (0..7u64)
+//!         .map(|i| (0..7u64).map(|j| to_int(op(F7::from(i), F7::from(j)))).collect())
+//!         .collect()
+//! };
+//!
+//! assert_eq!(
+//!     table(|a, b| a + b),
+//!     vec![
+//!         vec![0, 1, 2, 3, 4, 5, 6],
+//!         vec![1, 2, 3, 4, 5, 6, 0],
+//!         vec![2, 3, 4, 5, 6, 0, 1],
+//!         vec![3, 4, 5, 6, 0, 1, 2],
+//!         vec![4, 5, 6, 0, 1, 2, 3],
+//!         vec![5, 6, 0, 1, 2, 3, 4],
+//!         vec![6, 0, 1, 2, 3, 4, 5],
+//!     ]
+//! );
+//!
+//! assert_eq!(
+//!     table(|a, b| a * b),
+//!     vec![
+//!         vec![0, 0, 0, 0, 0, 0, 0],
+//!         vec![0, 1, 2, 3, 4, 5, 6],
+//!         vec![0, 2, 4, 6, 1, 3, 5],
+//!         vec![0, 3, 6, 2, 5, 1, 4],
+//!         vec![0, 4, 1, 5, 2, 6, 3],
+//!         vec![0, 5, 3, 1, 6, 4, 2],
+//!         vec![0, 6, 5, 4, 3, 2, 1],
+//!     ]
+//! );
+//! ```
+//!
+//! Every other field operation can be checked by hand just as easily:
+//!
+//! ```rust
+//! use ark_algebra_intro::toy_fields::{F101, F13, F7};
+//! use ark_ff::{Field, SquareRootField};
+//!
+//! // 3 · 5 = 15 = 1 (mod 7), so 3 and 5 are inverses:
+//! assert_eq!(F7::from(3u64).inverse(), Some(F7::from(5u64)));
+//! // 4 = 2^2 (mod 7), but 3 is not a square:
+//! let root = F7::from(4u64).sqrt().unwrap();
+//! assert!(root == F7::from(2u64) || root == F7::from(5u64));
+//! assert_eq!(F7::from(3u64).sqrt(), None);
+//! // Fermat's little theorem: a^(p - 1) = 1.
+//! assert_eq!(F13::from(5u64).pow([12]), F13::from(1u64));
+//! // Integers wrap around at the modulus:
+//! assert_eq!(F101::from(100u64) + F101::from(2u64), F101::from(1u64));
+//! ```
+
+use ark_ff::{
+    biginteger::BigInteger64 as BigInteger,
+    fields::{FftParameters, Fp64, Fp64Parameters, FpParameters},
+};
+
+/// The prime field of order 7.
+pub type F7 = Fp64<F7Parameters>;
+
+/// Parameters for [`F7`].
+pub struct F7Parameters;
+
+impl Fp64Parameters for F7Parameters {}
+impl FftParameters for F7Parameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 1;
+
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^T = 6
+    /// Encoded in Montgomery form, so the value here is 6 * R % p = 5
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([5]);
+}
+impl FpParameters for F7Parameters {
+    const MODULUS: BigInteger = BigInteger([7]);
+
+    const MODULUS_BITS: u32 = 3;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 61;
+
+    /// R = 2^64 % p
+    const R: BigInteger = BigInteger([2]);
+
+    const R2: BigInteger = BigInteger([4]);
+
+    const INV: u64 = 0x9249249249249249;
+
+    /// GENERATOR = 3
+    /// Encoded in Montgomery form, so the value here is 3 * R % p = 6
+    const GENERATOR: BigInteger = BigInteger([6]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([3]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+    const T: BigInteger = BigInteger([3]);
+
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([1]);
+}
+
+/// The prime field of order 13.
+pub type F13 = Fp64<F13Parameters>;
+
+/// Parameters for [`F13`].
+pub struct F13Parameters;
+
+impl Fp64Parameters for F13Parameters {}
+impl FftParameters for F13Parameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 2;
+
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^T = 8
+    /// Encoded in Montgomery form, so the value here is 8 * R % p = 11
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([11]);
+}
+impl FpParameters for F13Parameters {
+    const MODULUS: BigInteger = BigInteger([13]);
+
+    const MODULUS_BITS: u32 = 4;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 60;
+
+    /// R = 2^64 % p
+    const R: BigInteger = BigInteger([3]);
+
+    const R2: BigInteger = BigInteger([9]);
+
+    const INV: u64 = 0xb13b13b13b13b13b;
+
+    /// GENERATOR = 2
+    /// Encoded in Montgomery form, so the value here is 2 * R % p = 6
+    const GENERATOR: BigInteger = BigInteger([6]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([6]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+    const T: BigInteger = BigInteger([3]);
+
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([1]);
+}
+
+/// The prime field of order 101.
+pub type F101 = Fp64<F101Parameters>;
+
+/// Parameters for [`F101`].
+pub struct F101Parameters;
+
+impl Fp64Parameters for F101Parameters {}
+impl FftParameters for F101Parameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 2;
+
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^T = 10
+    /// Encoded in Montgomery form, so the value here is 10 * R % p = 83
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([83]);
+}
+impl FpParameters for F101Parameters {
+    const MODULUS: BigInteger = BigInteger([101]);
+
+    const MODULUS_BITS: u32 = 7;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 57;
+
+    /// R = 2^64 % p
+    const R: BigInteger = BigInteger([79]);
+
+    const R2: BigInteger = BigInteger([80]);
+
+    const INV: u64 = 0xc5b3f5dc83cd4e93;
+
+    /// GENERATOR = 2
+    /// Encoded in Montgomery form, so the value here is 2 * R % p = 57
+    const GENERATOR: BigInteger = BigInteger([57]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([50]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+    const T: BigInteger = BigInteger([25]);
+
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([12]);
+}
@@ -0,0 +1,430 @@
+//! Counting the field and group operations that a computation performs.
+//!
+//! [`Traced<T>`](Traced) wraps a field element or a curve point, and records every
+//! arithmetic operation performed on it (the operation, its operands and its result)
+//! into a per-thread trace. [`report`] then summarizes the trace with a count of each
+//! operation, and [`start`] clears it, optionally keeping a full log of the operations:
+//!
+//! ```rust
+//! use ark_algebra_intro::trace::{self, Op, Traced};
+//! use ark_bls12_381::Fr;
+//!
+//! trace::start(true);
+//! let (a, b) = (Traced(Fr::from(2u64)), Traced(Fr::from(3u64)));
+//! let c = (a + b) * a - b.square();
+//! assert_eq!(c.0, Fr::from(1u64));
+//!
+//! let report = trace::report();
+//! assert_eq!(report.count(Op::Mul), 1);
+//! assert_eq!(report.count(Op::Square), 1);
+//! assert_eq!(report.total(), 4);
+//!
+//! // The log shows operands in their `Display` format, which is hexadecimal for `Fr`.
+//! let log = report.log.unwrap();
+//! assert_eq!(log.len(), 4);
+//! assert_eq!(log[0].op, Op::Add);
+//! assert!(log[0].result.ends_with("05)\""));
+//! for entry in &log {
+//!     println!("{}", entry);
+//! }
+//! ```
+//!
+//! The compound operations [`Traced::pow`] and [`Traced::mul_bits`] are implemented with
+//! square-and-multiply and double-and-add on traced values, so the trace shows what
+//! they cost. For example, the hard part of the BLS12-381 [final
+//! exponentiation](crate::pairings::final_exp_parts) raises an element of `Fq12` to a
+//! 1270-bit power, which takes 1269 squarings and, with plain square-and-multiply, one
+//! multiplication per set bit of the exponent:
+//!
+//! ```rust
+//! use ark_algebra_intro::trace::{self, Op, Traced};
+//! use ark_bls12_381::{Fq, Fq12, Fr};
+//! use ark_ff::{FpParameters, PrimeField};
+//! use ark_std::UniformRand;
+//! use num_bigint::BigUint;
+//!
+//! let q: BigUint = <Fq as PrimeField>::Params::MODULUS.into();
+//! let r: BigUint = <Fr as PrimeField>::Params::MODULUS.into();
+//! let q2 = &q * &q;
+//! let exponent = (&q2 * &q2 - &q2 + 1u64) / r * 3u64;
+//! assert_eq!(exponent.bits(), 1270);
+//!
+//! trace::start(false);
+//! let f = Traced(Fq12::rand(&mut ark_std::rand::thread_rng()));
+//! f.pow(exponent.to_u64_digits());
+//! let report = trace::report();
+//! assert_eq!(report.count(Op::Square), 1269);
+//! assert_eq!(report.count(Op::Mul), exponent.count_ones() as usize - 1);
+//! println!("{}", report);
+//! ```
+//!
+//! Only operations on `Traced` values are recorded: `arkworks` functions that take
+//! the underlying types (such as [`PairingEngine::pairing`](ark_ec::PairingEngine::pairing))
+//! run untraced. The trace is per thread, so tracing a computation that spawns threads
+//! only records the operations on the calling thread.
+//!
+//! To see what a whole pairing costs, [`miller_loop`] and [`final_exponentiation`] run
+//! the readable versions of its two halves from [`pairings`](crate::pairings) on
+//! `Traced<Fq12>`:
+//!
+//! ```rust
+//! use ark_algebra_intro::trace::{self, Op};
+//! use ark_bls12_381::{Bls12_381, G1Affine, G2Affine};
+//! use ark_ec::{AffineCurve, PairingEngine};
+//!
+//! let p = G1Affine::prime_subgroup_generator();
+//! let q = G2Affine::prime_subgroup_generator();
+//!
+//! trace::start(false);
+//! let f = trace::miller_loop(&p, &q);
+//! let miller_loop = trace::report();
+//! // One inversion for the slope of each of the 63 tangents and 5 chords.
+//! assert_eq!(miller_loop.count(Op::Inverse), 68);
+//! println!("Miller loop:\n{}", miller_loop);
+//!
+//! trace::start(false);
+//! let result = trace::final_exponentiation(f).unwrap();
+//! let final_exponentiation = trace::report();
+//! assert_eq!(final_exponentiation.count(Op::Inverse), 1);
+//! assert_eq!(final_exponentiation.count(Op::Square), 1269);
+//! println!("final exponentiation:\n{}", final_exponentiation);
+//!
+//! assert_eq!(result.0, Bls12_381::pairing(p, q));
+//! ```
+//!
+//! These count operations in `Fq12`. `arkworks` does much better than these counts
+//! suggest, with sparse multiplications by the lines, no inversions in the loop, and
+//! cyclotomic squarings in the hard part; the trace shows what those optimizations save.
+
+use crate::pairings::{hard_part_exponent, miller_loop_in, LoopField};
+use ark_bls12_381::{Fq12, G1Affine, G2Affine};
+use ark_ff::{BitIteratorBE, Field, One, Zero};
+use ark_std::fmt;
+use ark_std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+/// An arithmetic operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Op {
+    /// `a + b`.
+    Add,
+    /// `a - b`.
+    Sub,
+    /// `-a`.
+    Neg,
+    /// `2 · a`, or `a + a`.
+    Double,
+    /// `a · b`, in a field.
+    Mul,
+    /// `a²`, in a field.
+    Square,
+    /// `a / b`, in a field.
+    Div,
+    /// `1 / a`, in a field.
+    Inverse,
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Op::Add => "add",
+            Op::Sub => "sub",
+            Op::Neg => "neg",
+            Op::Double => "double",
+            Op::Mul => "mul",
+            Op::Square => "square",
+            Op::Div => "div",
+            Op::Inverse => "inverse",
+        };
+        f.pad(name)
+    }
+}
+
+/// An operation in the full log of a trace.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    /// The operation.
+    pub op: Op,
+    /// Its operands, formatted with `Display`.
+    pub operands: Vec<String>,
+    /// Its result, formatted with `Display`.
+    pub result: String,
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}({}) = {}",
+            self.op,
+            self.operands.join(", "),
+            self.result
+        )
+    }
+}
+
+/// A summary of the operations recorded since the last call to [`start`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    /// The number of times each operation was performed.
+    pub counts: BTreeMap<Op, usize>,
+    /// Every operation, in order, if [`start`] was asked to log them.
+    pub log: Option<Vec<Entry>>,
+}
+
+impl Report {
+    /// Returns the number of times `op` was performed.
+    pub fn count(&self, op: Op) -> usize {
+        self.counts.get(&op).copied().unwrap_or(0)
+    }
+
+    /// Returns the total number of operations.
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (op, count) in &self.counts {
+            writeln!(f, "{:<8} {:>8}", op, count)?;
+        }
+        write!(f, "{:<8} {:>8}", "total", self.total())
+    }
+}
+
+thread_local! {
+    static TRACE: RefCell<Report> = RefCell::new(Report::default());
+}
+
+/// Clears this thread's trace, and starts a new one that also logs every operation if
+/// `log` is true.
+///
+/// Logging formats every operand, so it is much slower than counting.
+pub fn start(log: bool) {
+    TRACE.with(|trace| {
+        *trace.borrow_mut() = Report {
+            counts: BTreeMap::new(),
+            log: if log { Some(Vec::new()) } else { None },
+        }
+    });
+}
+
+/// Returns a summary of the operations recorded on this thread since the last call to
+/// [`start`].
+pub fn report() -> Report {
+    TRACE.with(|trace| trace.borrow().clone())
+}
+
+/// Records `op` with `operands` in this thread's trace, and returns `result`.
+fn record<T: fmt::Display>(op: Op, operands: &[&T], result: T) -> Traced<T> {
+    TRACE.with(|trace| {
+        let mut trace = trace.borrow_mut();
+        *trace.counts.entry(op).or_insert(0) += 1;
+        if let Some(log) = &mut trace.log {
+            log.push(Entry {
+                op,
+                operands: operands.iter().map(|a| a.to_string()).collect(),
+                result: result.to_string(),
+            });
+        }
+    });
+    Traced(result)
+}
+
+/// A field element or curve point whose arithmetic is recorded in this thread's trace.
+///
+/// Comparisons and conversions are not recorded.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Traced<T>(pub T);
+
+impl<T: fmt::Display> fmt::Display for Traced<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: Copy + fmt::Display + Add<Output = T>> Traced<T> {
+    /// Returns `self + self`, recorded as a doubling.
+    pub fn double(self) -> Self {
+        record(Op::Double, &[&self.0], self.0 + self.0)
+    }
+}
+
+impl<T: Copy + fmt::Display + Zero> Traced<T> {
+    /// Computes `k · self` by double-and-add, where `k` is given by its little-endian
+    /// 64-bit limbs (e.g., from [`PrimeField::into_repr`](ark_ff::PrimeField::into_repr)).
+    ///
+    /// For a curve point, this is a scalar multiplication:
+    ///
+    /// ```rust
+    /// use ark_algebra_intro::trace::{self, Op, Traced};
+    /// use ark_bls12_381::{Fr, G1Projective};
+    /// use ark_ec::ProjectiveCurve;
+    /// use ark_ff::PrimeField;
+    ///
+    /// let g = G1Projective::prime_subgroup_generator();
+    /// let k = Fr::from(0b1011u64);
+    /// trace::start(false);
+    /// assert_eq!(Traced(g).mul_bits(k.into_repr()).0, g.mul(k.into_repr()));
+    /// // One doubling per bit after the leading one, and one addition per set bit after it.
+    /// let report = trace::report();
+    /// assert_eq!((report.count(Op::Double), report.count(Op::Add)), (3, 2));
+    /// ```
+    pub fn mul_bits(self, k: impl AsRef<[u64]>) -> Self {
+        let mut bits = BitIteratorBE::without_leading_zeros(k);
+        if bits.next().is_none() {
+            return Traced(T::zero());
+        }
+        let mut result = self;
+        for bit in bits {
+            result = result.double();
+            if bit {
+                result += self;
+            }
+        }
+        result
+    }
+}
+
+impl<F: Field> Traced<F> {
+    /// Returns `self²`.
+    pub fn square(self) -> Self {
+        record(Op::Square, &[&self.0], self.0.square())
+    }
+
+    /// Returns `1 / self`, or `None` if `self` is zero.
+    pub fn inverse(self) -> Option<Self> {
+        let inverse = self.0.inverse()?;
+        Some(record(Op::Inverse, &[&self.0], inverse))
+    }
+
+    /// Computes `self^e` by square-and-multiply, where `e` is given by its little-endian
+    /// 64-bit limbs.
+    pub fn pow(self, e: impl AsRef<[u64]>) -> Self {
+        let mut bits = BitIteratorBE::without_leading_zeros(e);
+        if bits.next().is_none() {
+            return Traced(F::one());
+        }
+        let mut result = self;
+        for bit in bits {
+            result = result.square();
+            if bit {
+                result *= self;
+            }
+        }
+        result
+    }
+}
+
+impl<T: Zero> Zero for Traced<T>
+where
+    Traced<T>: Add<Output = Traced<T>>,
+{
+    fn zero() -> Self {
+        Traced(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<T: One + PartialEq> One for Traced<T>
+where
+    Traced<T>: Mul<Output = Traced<T>>,
+{
+    fn one() -> Self {
+        Traced(T::one())
+    }
+}
+
+/// Implements a binary operator on `Traced<T>`, and its assigning version.
+macro_rules! traced_binary_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:expr) => {
+        impl<T: Copy + fmt::Display + $trait<Output = T>> $trait for Traced<T> {
+            type Output = Self;
+
+            fn $method(self, other: Self) -> Self {
+                record($op, &[&self.0, &other.0], self.0.$method(other.0))
+            }
+        }
+
+        impl<T: Copy + fmt::Display + $trait<Output = T>> $assign_trait for Traced<T> {
+            fn $assign_method(&mut self, other: Self) {
+                *self = self.$method(other);
+            }
+        }
+    };
+}
+
+traced_binary_op!(Add, add, AddAssign, add_assign, Op::Add);
+traced_binary_op!(Sub, sub, SubAssign, sub_assign, Op::Sub);
+traced_binary_op!(Mul, mul, MulAssign, mul_assign, Op::Mul);
+
+impl<T: Copy + fmt::Display + Div<Output = T>> Div for Traced<T> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        record(Op::Div, &[&self.0, &other.0], self.0 / other.0)
+    }
+}
+
+impl<T: Copy + fmt::Display + Neg<Output = T>> Neg for Traced<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        record(Op::Neg, &[&self.0], -self.0)
+    }
+}
+
+impl LoopField for Traced<Fq12> {
+    fn from_fq12(a: Fq12) -> Self {
+        Traced(a)
+    }
+
+    fn squared(self) -> Self {
+        Traced::square(self)
+    }
+
+    fn doubled(self) -> Self {
+        Traced::double(self)
+    }
+
+    fn inverted(self) -> Option<Self> {
+        Traced::inverse(self)
+    }
+
+    fn conjugated(self) -> Self {
+        let mut a = self.0;
+        a.conjugate();
+        Traced(a)
+    }
+}
+
+/// Computes the Miller loop of [`miller_loop_naive`](crate::pairings::miller_loop_naive)
+/// on traced values, recording its operations.
+///
+/// The conjugation at the end, which only negates half of the coefficients, is not
+/// recorded.
+pub fn miller_loop(p: &G1Affine, q: &G2Affine) -> Traced<Fq12> {
+    miller_loop_in(p, q, |_, _: Traced<Fq12>| {})
+}
+
+/// Computes the final exponentiation of the BLS12-381 pairing, as in
+/// [`final_exp_parts`](crate::pairings::final_exp_parts), on traced values, or returns
+/// `None` if `f` is zero.
+///
+/// The hard part uses [`Traced::pow`], with general squarings rather than cyclotomic
+/// ones. The conjugation and the Frobenius map of the easy part, which are much cheaper
+/// than a multiplication, are not recorded.
+pub fn final_exponentiation(f: Traced<Fq12>) -> Option<Traced<Fq12>> {
+    // Easy part: f^(q^6 - 1) (q^2 + 1).
+    let g = f.conjugated() * f.inverse()?;
+    let mut g_q2 = g;
+    g_q2.0.frobenius_map(2);
+    let easy = g_q2 * g;
+    // Hard part: 3 (q^4 - q^2 + 1) / r.
+    Some(easy.pow(hard_part_exponent().to_u64_digits()))
+}
@@ -0,0 +1,178 @@
+//! Fiat–Shamir transcripts.
+//!
+//! The Fiat–Shamir transform replaces a verifier's random challenges by hashes of the
+//! conversation so far. To be sound, the hash has to cover *everything* the verifier
+//! would have seen before sending the challenge: the statement, the public parameters,
+//! and all of the prover's messages. A [`Transcript`] makes this systematic: both parties
+//! absorb the same labelled messages in the same order, and squeeze challenges out of
+//! the result.
+//!
+//! ```rust
+//! use ark_algebra_intro::transcript::Transcript;
+//! use ark_bls12_381::{Fr, G1Affine};
+//! use ark_ec::AffineCurve;
+//!
+//! let mut prover = Transcript::new(b"my-protocol");
+//! prover.append_point(b"statement", &G1Affine::prime_subgroup_generator());
+//! prover.append_field(b"claim", &Fr::from(5u64));
+//! let challenge: Fr = prover.challenge_scalar(b"challenge");
+//!
+//! // The verifier recomputes the same challenge...
+//! let mut verifier = Transcript::new(b"my-protocol");
+//! verifier.append_point(b"statement", &G1Affine::prime_subgroup_generator());
+//! verifier.append_field(b"claim", &Fr::from(5u64));
+//! assert_eq!(verifier.challenge_scalar::<Fr>(b"challenge"), challenge);
+//!
+//! // ...but any difference in the messages, or in the protocol label, changes it.
+//! let mut other = Transcript::new(b"another-protocol");
+//! other.append_point(b"statement", &G1Affine::prime_subgroup_generator());
+//! other.append_field(b"claim", &Fr::from(5u64));
+//! assert_ne!(other.challenge_scalar::<Fr>(b"challenge"), challenge);
+//! ```
+//!
+//! Every message is absorbed together with its label and its length, so different
+//! sequences of messages can never produce the same hash input. Each challenge is
+//! absorbed back into the transcript, so successive challenges are independent.
+//!
+//! # Weak Fiat–Shamir
+//!
+//! Forgetting to absorb part of the statement is a classic mistake, known as the *weak*
+//! Fiat–Shamir transform. Take a [Schnorr proof](crate::protocols::schnorr) that the
+//! prover knows `x` with `X = x · G`, whose challenge only covers the commitment `R`.
+//! Then a cheater can fix an arbitrary `R` and response `s` first, compute the challenge
+//! `e`, and only *then* choose the statement `X = e⁻¹ · (s · G - R)`, whose discrete
+//! logarithm it does not know:
+//!
+//! ```rust
+//! use ark_algebra_intro::hash_to_curve::hash_to_g1;
+//! use ark_algebra_intro::transcript::Transcript;
+//! use ark_bls12_381::{Fr, G1Affine};
+//! use ark_ec::{AffineCurve, ProjectiveCurve};
+//! use ark_ff::{Field, Zero};
+//!
+//! // The weak transcript forgets the statement.
+//! let weak_challenge = |commitment: &G1Affine, _statement: &G1Affine| {
+//!     let mut transcript = Transcript::new(b"weak-schnorr");
+//!     transcript.append_point(b"commitment", commitment);
+//!     transcript.challenge_scalar::<Fr>(b"challenge")
+//! };
+//! let g = G1Affine::prime_subgroup_generator();
+//! let verify = |statement: &G1Affine, commitment: &G1Affine, response: Fr| {
+//!     let e = weak_challenge(commitment, statement);
+//!     g.mul(response) == commitment.into_projective() + statement.mul(e)
+//! };
+//!
+//! // A commitment with unknown discrete logarithm, and an arbitrary response...
+//! let commitment = hash_to_g1(b"anything", b"DST");
+//! let response = Fr::from(12345u64);
+//! // ...determine the challenge, and the statement is solved for last.
+//! let e = weak_challenge(&commitment, &G1Affine::zero());
+//! let statement = (g.mul(response) - commitment.into_projective())
+//!     .into_affine()
+//!     .mul(e.inverse().unwrap())
+//!     .into_affine();
+//! assert!(verify(&statement, &commitment, response));
+//! ```
+//!
+//! Absorbing the statement before squeezing the challenge, as
+//! [`schnorr::verify_knowledge`](crate::protocols::schnorr::verify_knowledge) does,
+//! prevents this: changing `X` afterwards would change `e`.
+//!
+//! The `10_fiat_shamir` example walks through this module:
+//! `cargo run --example 10_fiat_shamir`.
+
+use ark_ff::PrimeField;
+use ark_serialize::CanonicalSerialize;
+use ark_std::vec::Vec;
+use sha2::{Digest, Sha256};
+
+/// A Fiat–Shamir transcript, backed by the hash function `D`.
+#[derive(Clone, Debug)]
+pub struct Transcript<D: Digest + Clone = Sha256> {
+    hasher: D,
+}
+
+impl Transcript {
+    /// Starts a SHA-256 transcript for the protocol with the given label.
+    ///
+    /// The label provides domain separation: transcripts of different protocols never
+    /// produce the same challenges.
+    pub fn new(label: &[u8]) -> Self {
+        Self::with_hash(label)
+    }
+}
+
+impl<D: Digest + Clone> Transcript<D> {
+    /// Starts a transcript for the protocol with the given label, backed by the hash
+    /// function `D`.
+    ///
+    /// ```rust
+    /// use ark_algebra_intro::transcript::Transcript;
+    /// use ark_bls12_381::Fr;
+    /// use sha2::Sha512;
+    ///
+    /// let mut sha256 = Transcript::new(b"label");
+    /// let mut sha512 = Transcript::<Sha512>::with_hash(b"label");
+    /// assert_ne!(sha256.challenge_scalar::<Fr>(b"c"), sha512.challenge_scalar::<Fr>(b"c"));
+    /// ```
+    pub fn with_hash(label: &[u8]) -> Self {
+        let mut transcript = Self { hasher: D::new() };
+        transcript.append_bytes(b"protocol", label);
+        transcript
+    }
+
+    /// Absorbs a labelled byte string.
+    pub fn append_bytes(&mut self, label: &[u8], bytes: &[u8]) {
+        for part in [label, bytes] {
+            self.hasher.update((part.len() as u64).to_be_bytes());
+            self.hasher.update(part);
+        }
+    }
+
+    /// Absorbs a labelled value, in its compressed serialization.
+    pub fn append_serializable<T: CanonicalSerialize>(&mut self, label: &[u8], value: &T) {
+        let mut bytes = Vec::new();
+        value
+            .serialize(&mut bytes)
+            .expect("serializing to a vector never fails");
+        self.append_bytes(label, &bytes);
+    }
+
+    /// Absorbs a labelled field element.
+    pub fn append_field<F: ark_ff::Field>(&mut self, label: &[u8], element: &F) {
+        self.append_serializable(label, element);
+    }
+
+    /// Absorbs a labelled curve point.
+    pub fn append_point<G: ark_ec::AffineCurve>(&mut self, label: &[u8], point: &G) {
+        self.append_serializable(label, point);
+    }
+
+    /// Squeezes `len` bytes out of the transcript, and absorbs them back in.
+    pub fn challenge_bytes(&mut self, label: &[u8], len: usize) -> Vec<u8> {
+        self.append_bytes(b"challenge", label);
+        let mut output = Vec::with_capacity(len);
+        let mut counter = 0u64;
+        while output.len() < len {
+            let block = self
+                .hasher
+                .clone()
+                .chain_update(counter.to_be_bytes())
+                .finalize();
+            output.extend_from_slice(&block);
+            counter += 1;
+        }
+        output.truncate(len);
+        self.append_bytes(b"output", &output);
+        output
+    }
+
+    /// Squeezes a field element out of the transcript.
+    ///
+    /// This squeezes 128 bits more than the size of the field, so that reducing them
+    /// modulo the field size gives a (statistically close to) uniform element.
+    pub fn challenge_scalar<F: PrimeField>(&mut self, label: &[u8]) -> F {
+        let len = (F::size_in_bits() + 128).div_ceil(8);
+        F::from_be_bytes_mod_order(&self.challenge_bytes(label, len))
+    }
+}
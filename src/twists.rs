@@ -0,0 +1,140 @@
+//! Where G2 "really" lives: BLS12-381's twisted curve.
+//!
+//! The pairing `e: G1 × G2 → Gt` needs two *different* subgroups of order `r` of
+//! the BLS12-381 curve `E: y^2 = x^3 + 4`. G1 consists of points with coordinates in
+//! `Fq`, but there are no other subgroups of order `r` in `E(Fq)`: the next one only
+//! appears once we allow coordinates in `Fq12`. Working with points over `Fq12` would be
+//! painfully slow, so instead `arkworks` (like every other library) represents G2
+//! as points on the *twist*
+//!
+//! `E': y^2 = x^3 + 4(u + 1)`,
+//!
+//! whose coordinates only live in `Fq2`. The two curves are related by the map
+//! `ψ(x, y) = (x / w^2, y / w^3)`, where `w` is the generator of
+//! [`Fq12` over `Fq6`](crate::extensions): since `w^6 = v^3 = u + 1`, it turns the
+//! equation of `E'` into that of `E`. Because `w^6` lies in `Fq2` but no smaller
+//! power of `w` does, `E'` is called a *sextic* twist of `E` (rather than, say, a
+//! quadratic one, for which the corresponding element would satisfy `w^2 ∈ Fq2`).
+//!
+//! [`untwist`] computes `ψ`, and [`twist`] computes its inverse:
+//!
+//! ```rust
+//! use ark_algebra_intro::twists::{twist, untwist};
+//! use ark_bls12_381::{Fq12, G2Affine};
+//! use ark_ec::AffineCurve;
+//! use ark_ff::Field;
+//!
+//! let q = G2Affine::prime_subgroup_generator();
+//! let (x, y) = untwist(&q);
+//!
+//! // The untwisted point lies on the original curve `y^2 = x^3 + 4`, but over `Fq12`...
+//! assert_eq!(y.square(), x.square() * x + Fq12::from(4u64));
+//! // ...while the twisted one doesn't:
+//! assert_ne!(q.y.square(), q.x.square() * q.x + ark_bls12_381::Fq2::from(4u64));
+//!
+//! // Twisting recovers the original point.
+//! assert_eq!(twist(x, y), Ok(q));
+//! ```
+
+use crate::extensions::{lift_to_fq12, lift_to_fq6};
+use crate::Error;
+use ark_bls12_381::{Fq12, Fq2, Fq6, G2Affine};
+use ark_ff::Field;
+use ark_std::{One, Zero};
+
+/// Returns `w`, the generator of `Fq12` over `Fq6`.
+fn w() -> Fq12 {
+    Fq12::new(Fq6::zero(), Fq6::one())
+}
+
+/// Embeds an element of `Fq2` into `Fq12`.
+fn fq2_to_fq12(a: Fq2) -> Fq12 {
+    lift_to_fq12(lift_to_fq6(a))
+}
+
+/// Returns `a` as an element of `Fq2`, if it lies in that subfield of `Fq12`.
+fn fq12_to_fq2(a: Fq12) -> Option<Fq2> {
+    if a.c1.is_zero() && a.c0.c1.is_zero() && a.c0.c2.is_zero() {
+        Some(a.c0.c0)
+    } else {
+        None
+    }
+}
+
+/// Maps a point of G2, which is represented on the twist `E'(Fq2)`, to the
+/// corresponding point `(x / w^2, y / w^3)` on the BLS12-381 curve over `Fq12`.
+///
+/// This map is a group homomorphism, so it is compatible with the group law: for example,
+/// doubling a point and then untwisting it is the same as untwisting it and then
+/// doubling it with the usual tangent rule.
+///
+/// ```rust
+/// use ark_algebra_intro::twists::untwist;
+/// use ark_bls12_381::{Fq12, G2Affine, G2Projective};
+/// use ark_ec::ProjectiveCurve;
+/// use ark_ff::Field;
+/// use ark_std::UniformRand;
+///
+/// let mut rng = ark_std::rand::thread_rng();
+/// let q = G2Projective::rand(&mut rng);
+/// let (x, y) = untwist(&q.into_affine());
+///
+/// // Double (x, y) on `y^2 = x^3 + 4` with the tangent rule...
+/// let slope = (x.square() * Fq12::from(3u64)) * y.double().inverse().unwrap();
+/// let x2 = slope.square() - x.double();
+/// let y2 = slope * (x - x2) - y;
+///
+/// // ...which agrees with doubling on the twist.
+/// assert_eq!(untwist(&q.double().into_affine()), (x2, y2));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `point` is the point at infinity, which has no affine coordinates.
+pub fn untwist(point: &G2Affine) -> (Fq12, Fq12) {
+    assert!(
+        !point.infinity,
+        "the point at infinity has no affine coordinates"
+    );
+    let w = w();
+    let w2 = w.square();
+    let w3 = w2 * w;
+    let x = fq2_to_fq12(point.x) * w2.inverse().unwrap();
+    let y = fq2_to_fq12(point.y) * w3.inverse().unwrap();
+    (x, y)
+}
+
+/// Maps a point `(x, y)` on the BLS12-381 curve over `Fq12` to the point
+/// `(x · w^2, y · w^3)` on the twist, if the latter has coordinates in `Fq2` and
+/// lies on the twist: otherwise, this returns [`Error::NotInSubfield`] or
+/// [`Error::NotOnCurve`].
+///
+/// Only points in the image of [`untwist`] can be twisted back; for example, points
+/// of G1 can't:
+///
+/// ```rust
+/// use ark_algebra_intro::extensions::{lift_to_fq12, lift_to_fq2, lift_to_fq6};
+/// use ark_algebra_intro::twists::twist;
+/// use ark_algebra_intro::Error;
+/// use ark_bls12_381::{Bls12_381, G1Affine, G2Affine};
+/// use ark_ec::{AffineCurve, PairingEngine};
+///
+/// let p = G1Affine::prime_subgroup_generator();
+/// let lift = |a| lift_to_fq12(lift_to_fq6(lift_to_fq2(a)));
+/// assert_eq!(twist(lift(p.x), lift(p.y)), Err(Error::NotInSubfield));
+///
+/// // Pairings don't care which representation of a point we started with,
+/// // since twisting and untwisting are inverse to each other:
+/// let q = G2Affine::prime_subgroup_generator();
+/// let (x, y) = ark_algebra_intro::twists::untwist(&q);
+/// let q_again = twist(x, y).unwrap();
+/// assert_eq!(Bls12_381::pairing(p, q_again), Bls12_381::pairing(p, q));
+/// ```
+pub fn twist(x: Fq12, y: Fq12) -> Result<G2Affine, Error> {
+    let w = w();
+    let w2 = w.square();
+    let w3 = w2 * w;
+    let x = fq12_to_fq2(x * w2).ok_or(Error::NotInSubfield)?;
+    let y = fq12_to_fq2(y * w3).ok_or(Error::NotInSubfield)?;
+    crate::curves::point_from_xy(x, y)
+}
@@ -0,0 +1,272 @@
+//! Test vectors for BLS12-381, in a documented JSON format.
+//!
+//! A *test vector* records the inputs of an operation and its expected output, so that
+//! another implementation (in another language, or a new version of this one) can check
+//! that it computes the same thing. This module reads and writes named vectors, and
+//! bundles a set of [known-answer vectors](known_answer_vectors) computed with arkworks.
+//!
+//! # Schema
+//!
+//! A file is a JSON object with the name of the curve and a list of vectors:
+//!
+//! ```text
+//! {
+//!   "curve": "BLS12-381",
+//!   "vectors": [
+//!     {
+//!       "name": "g1_mul/generator_by_2",
+//!       "operation": "g1_mul",
+//!       "inputs": [
+//!         { "type": "g1", "value": "0xbbc622db...f117" },
+//!         { "type": "fr", "value": "0x00...02" }
+//!       ],
+//!       "output": { "type": "g1", "value": "0x4e0fbf29...7285" }
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Each value is tagged with its `type`:
+//!
+//! | `type` | Value                         | Encoding of `value`                                  |
+//! |--------|-------------------------------|------------------------------------------------------|
+//! | `fr`   | element of the scalar field   | `0x`-prefixed big-endian hex, 64 digits              |
+//! | `fq`   | element of the base field     | `0x`-prefixed big-endian hex, 96 digits              |
+//! | `g1`   | point of G1                   | `0x`-prefixed hex of the 48-byte compressed encoding |
+//! | `g2`   | point of G2                   | `0x`-prefixed hex of the 96-byte compressed encoding |
+//! | `gt`   | element of `F_{q^12}`         | `0x`-prefixed hex of the 576-byte arkworks encoding  |
+//!
+//! Points use the [compressed encoding](crate::serialize) of arkworks: the little-endian
+//! `x`-coordinate, with flags in the top bits of the *last* byte. This is *not* the ZCash
+//! format that most other BLS12-381 libraries use (big-endian, with flags in the top
+//! bits of the *first* byte), so other implementations need to convert.
+//! The operations, and the types of their inputs and output, are listed in [`Operation`].
+//!
+//! ```rust
+//! use ark_algebra_intro::vectors::{from_json, to_json, Operation, TestVector, Value};
+//! use ark_bls12_381::{Fr, G1Affine};
+//! use ark_ec::AffineCurve;
+//!
+//! let g = G1Affine::prime_subgroup_generator();
+//! let vector = TestVector::new(
+//!     "g1_mul/generator_by_2",
+//!     Operation::G1Mul,
+//!     vec![Value::G1(g), Value::Fr(Fr::from(2u64))],
+//! )
+//! .unwrap();
+//! assert_eq!(vector.output, Value::G1((g + g).into()));
+//!
+//! let json = to_json(&[vector.clone()]);
+//! assert!(json.contains("\"operation\": \"g1_mul\""));
+//! assert_eq!(from_json(&json).unwrap(), vec![vector]);
+//! ```
+//!
+//! This module is only available with the `vectors` feature.
+
+use crate::serde_support::{deserialize_canonical, serialize_canonical, SerdeField, SerdePoint};
+use ark_bls12_381::{Bls12_381, Fq, Fq12, Fr, G1Affine, G2Affine};
+use ark_ec::{AffineCurve, PairingEngine};
+use ark_ff::{Field, SquareRootField};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The name of the curve in the files this module writes.
+pub const CURVE: &str = "BLS12-381";
+
+/// The bundled known-answer vectors.
+const KNOWN_ANSWERS: &str = include_str!("vectors/bls12_381.json");
+
+/// A value of one of the types that operations take and return.
+// Target group elements are much larger than the others, but values are never stored in
+// bulk, so boxing them would only make the enum harder to use.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "RawValue", from = "RawValue")]
+pub enum Value {
+    /// An element of the scalar field.
+    Fr(Fr),
+    /// An element of the base field.
+    Fq(Fq),
+    /// A point of G1.
+    G1(G1Affine),
+    /// A point of G2.
+    G2(G2Affine),
+    /// An element of the target group, in `F_{q^12}`.
+    Gt(Fq12),
+}
+
+/// The operations that test vectors can record.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Operation {
+    /// `fr, fr -> fr`: addition.
+    FrAdd,
+    /// `fr, fr -> fr`: multiplication.
+    FrMul,
+    /// `fr -> fr`: inversion (of a nonzero element).
+    FrInverse,
+    /// `fq, fq -> fq`: multiplication.
+    FqMul,
+    /// `fq -> fq`: the square root that arkworks returns (of a square).
+    FqSqrt,
+    /// `g1, g1 -> g1`: addition.
+    G1Add,
+    /// `g1, fr -> g1`: scalar multiplication.
+    G1Mul,
+    /// `g2, g2 -> g2`: addition.
+    G2Add,
+    /// `g2, fr -> g2`: scalar multiplication.
+    G2Mul,
+    /// `g1, g2 -> gt`: the optimal ate pairing.
+    Pairing,
+}
+
+impl Operation {
+    /// Applies the operation to `inputs`, or returns `None` if they have the wrong number
+    /// or types, or are outside the domain of the operation.
+    pub fn apply(self, inputs: &[Value]) -> Option<Value> {
+        use Value::*;
+        let output = match (self, inputs) {
+            (Operation::FrAdd, [Fr(a), Fr(b)]) => Fr(*a + b),
+            (Operation::FrMul, [Fr(a), Fr(b)]) => Fr(*a * b),
+            (Operation::FrInverse, [Fr(a)]) => Fr(a.inverse()?),
+            (Operation::FqMul, [Fq(a), Fq(b)]) => Fq(*a * b),
+            (Operation::FqSqrt, [Fq(a)]) => Fq(a.sqrt()?),
+            (Operation::G1Add, [G1(p), G1(q)]) => G1(*p + *q),
+            (Operation::G1Mul, [G1(p), Fr(k)]) => G1(p.mul(*k).into()),
+            (Operation::G2Add, [G2(p), G2(q)]) => G2(*p + *q),
+            (Operation::G2Mul, [G2(p), Fr(k)]) => G2(p.mul(*k).into()),
+            (Operation::Pairing, [G1(p), G2(q)]) => Gt(Bls12_381::pairing(*p, *q)),
+            _ => return None,
+        };
+        Some(output)
+    }
+}
+
+/// A named operation, with its inputs and expected output.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestVector {
+    /// A unique name, conventionally `<operation>/<description>`.
+    pub name: String,
+    /// The operation.
+    pub operation: Operation,
+    /// The inputs.
+    pub inputs: Vec<Value>,
+    /// The expected output.
+    pub output: Value,
+}
+
+impl TestVector {
+    /// Returns the vector with the output that this crate computes for `inputs`, or `None`
+    /// if the operation doesn't apply to them.
+    pub fn new(name: &str, operation: Operation, inputs: Vec<Value>) -> Option<Self> {
+        let output = operation.apply(&inputs)?;
+        Some(Self {
+            name: name.to_string(),
+            operation,
+            inputs,
+            output,
+        })
+    }
+
+    /// Checks that this crate computes the expected output.
+    pub fn check(&self) -> bool {
+        self.operation.apply(&self.inputs).as_ref() == Some(&self.output)
+    }
+}
+
+/// The top-level object of a file.
+#[derive(Serialize, Deserialize)]
+struct VectorFile {
+    curve: String,
+    vectors: Vec<TestVector>,
+}
+
+/// Writes `vectors` as a pretty-printed JSON file.
+pub fn to_json(vectors: &[TestVector]) -> String {
+    let file = VectorFile {
+        curve: CURVE.to_string(),
+        vectors: vectors.to_vec(),
+    };
+    serde_json::to_string_pretty(&file).expect("test vectors always serialize")
+}
+
+/// Reads the vectors of a JSON file.
+///
+/// Returns an error if the JSON doesn't follow the [schema](self#schema), if a value is
+/// not a valid (checked) encoding, or if the file is for another curve.
+pub fn from_json(json: &str) -> Result<Vec<TestVector>, serde_json::Error> {
+    let file: VectorFile = serde_json::from_str(json)?;
+    if file.curve != CURVE {
+        return Err(serde::de::Error::custom(format!(
+            "expected vectors for {}, found {}",
+            CURVE, file.curve
+        )));
+    }
+    Ok(file.vectors)
+}
+
+/// Returns the bundled known-answer vectors for BLS12-381.
+///
+/// They cover every [`Operation`], and were computed with this crate.
+///
+/// ```rust
+/// use ark_algebra_intro::vectors::{known_answer_vectors, Operation};
+///
+/// let vectors = known_answer_vectors();
+/// assert!(vectors.iter().all(|v| v.check()));
+/// assert!(vectors.iter().any(|v| v.operation == Operation::Pairing));
+/// ```
+pub fn known_answer_vectors() -> Vec<TestVector> {
+    from_json(KNOWN_ANSWERS).expect("the bundled vectors are valid")
+}
+
+/// The serialized form of a [`Value`], with the wrappers that implement serde.
+#[derive(Serialize, Deserialize)]
+#[allow(clippy::large_enum_variant)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+enum RawValue {
+    Fr(SerdeField<Fr>),
+    Fq(SerdeField<Fq>),
+    G1(SerdePoint<G1Affine>),
+    G2(SerdePoint<G2Affine>),
+    Gt(SerdeGt),
+}
+
+/// An element of the target group, with the compressed encoding of a [`SerdePoint`].
+struct SerdeGt(Fq12);
+
+impl Serialize for SerdeGt {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_canonical(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SerdeGt {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_canonical(deserializer).map(SerdeGt)
+    }
+}
+
+impl From<Value> for RawValue {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Fr(a) => RawValue::Fr(SerdeField(a)),
+            Value::Fq(a) => RawValue::Fq(SerdeField(a)),
+            Value::G1(p) => RawValue::G1(SerdePoint(p)),
+            Value::G2(p) => RawValue::G2(SerdePoint(p)),
+            Value::Gt(a) => RawValue::Gt(SerdeGt(a)),
+        }
+    }
+}
+
+impl From<RawValue> for Value {
+    fn from(value: RawValue) -> Self {
+        match value {
+            RawValue::Fr(a) => Value::Fr(a.0),
+            RawValue::Fq(a) => Value::Fq(a.0),
+            RawValue::G1(p) => Value::G1(p.0),
+            RawValue::G2(p) => Value::G2(p.0),
+            RawValue::Gt(a) => Value::Gt(a.0),
+        }
+    }
+}
@@ -0,0 +1,140 @@
+//! JavaScript bindings for interactive browser pages.
+//!
+//! With the `wasm` feature, this module exports a small set of BLS12-381 operations
+//! through [`wasm-bindgen`](https://rustwasm.github.io/docs/wasm-bindgen/), so that a web
+//! page can compute with the same arkworks types as the rest of the tutorial. Values cross
+//! the boundary as strings:
+//!
+//! * scalars (elements of `Fr`) as decimal integers, optionally negative;
+//! * points as `0x`-prefixed hexadecimal strings of their [ZCash
+//!   encoding](crate::interop::zcash_format), which is what most other BLS12-381 libraries
+//!   (and test vectors) use.
+//!
+//! Invalid inputs throw a JavaScript `Error`. The bindings are ordinary Rust functions, so
+//! they can also be called (and tested) natively:
+//!
+//! ```rust
+//! use ark_algebra_intro::wasm::{fr_add, fr_inverse, fr_mul, g1_add, g1_generator, g1_mul};
+//!
+//! assert_eq!(fr_add("2", "-5").unwrap(), "-3");
+//! assert_eq!(fr_mul(&fr_inverse("7").unwrap(), "7").unwrap(), "1");
+//!
+//! let g = g1_generator();
+//! assert!(g.starts_with("0x97f1d3a7"));
+//! assert_eq!(g1_mul(&g, "2").unwrap(), g1_add(&g, &g).unwrap());
+//! ```
+//!
+//! See `examples/wasm` in the repository for a page that uses them. Randomness (e.g.,
+//! `thread_rng`) works in browsers through `crypto.getRandomValues`.
+
+use crate::display::FieldFmt;
+use crate::encoding::{from_hex, to_hex};
+use crate::fields::parse_decimal;
+use crate::interop::zcash_format;
+use ark_bls12_381::{Bls12_381, Fr, G1Affine, G2Affine};
+use ark_ec::{AffineCurve, PairingEngine};
+use ark_ff::Field;
+use wasm_bindgen::prelude::*;
+
+/// Parses a decimal scalar.
+fn scalar(s: &str) -> Result<Fr, JsError> {
+    parse_decimal(s).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Formats a scalar in balanced representation, so that small negative values stay small.
+fn scalar_to_string(a: Fr) -> String {
+    FieldFmt(a).balanced().to_string()
+}
+
+/// Parses a hexadecimal ZCash encoding of a G1 point.
+fn g1(s: &str) -> Result<G1Affine, JsError> {
+    let bytes = from_hex(s).ok_or_else(|| JsError::new("invalid hex string"))?;
+    zcash_format::decode_g1(&bytes).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Parses a hexadecimal ZCash encoding of a G2 point.
+fn g2(s: &str) -> Result<G2Affine, JsError> {
+    let bytes = from_hex(s).ok_or_else(|| JsError::new("invalid hex string"))?;
+    zcash_format::decode_g2(&bytes).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Returns `a + b`.
+#[wasm_bindgen]
+pub fn fr_add(a: &str, b: &str) -> Result<String, JsError> {
+    Ok(scalar_to_string(scalar(a)? + scalar(b)?))
+}
+
+/// Returns `a - b`.
+#[wasm_bindgen]
+pub fn fr_sub(a: &str, b: &str) -> Result<String, JsError> {
+    Ok(scalar_to_string(scalar(a)? - scalar(b)?))
+}
+
+/// Returns `a · b`.
+#[wasm_bindgen]
+pub fn fr_mul(a: &str, b: &str) -> Result<String, JsError> {
+    Ok(scalar_to_string(scalar(a)? * scalar(b)?))
+}
+
+/// Returns `1 / a`, or throws if `a` is zero.
+#[wasm_bindgen]
+pub fn fr_inverse(a: &str) -> Result<String, JsError> {
+    let inverse = scalar(a)?
+        .inverse()
+        .ok_or_else(|| JsError::new("zero has no inverse"))?;
+    Ok(scalar_to_string(inverse))
+}
+
+/// Returns the generator of G1.
+#[wasm_bindgen]
+pub fn g1_generator() -> String {
+    to_hex(&zcash_format::encode_g1(
+        &G1Affine::prime_subgroup_generator(),
+    ))
+}
+
+/// Returns `p + q` in G1.
+#[wasm_bindgen]
+pub fn g1_add(p: &str, q: &str) -> Result<String, JsError> {
+    let sum = g1(p)? + g1(q)?;
+    Ok(to_hex(&zcash_format::encode_g1(&sum)))
+}
+
+/// Returns `k · p` in G1.
+#[wasm_bindgen]
+pub fn g1_mul(p: &str, k: &str) -> Result<String, JsError> {
+    let product = g1(p)?.mul(scalar(k)?).into();
+    Ok(to_hex(&zcash_format::encode_g1(&product)))
+}
+
+/// Returns the generator of G2.
+#[wasm_bindgen]
+pub fn g2_generator() -> String {
+    to_hex(&zcash_format::encode_g2(
+        &G2Affine::prime_subgroup_generator(),
+    ))
+}
+
+/// Returns `k · q` in G2.
+#[wasm_bindgen]
+pub fn g2_mul(q: &str, k: &str) -> Result<String, JsError> {
+    let product = g2(q)?.mul(scalar(k)?).into();
+    Ok(to_hex(&zcash_format::encode_g2(&product)))
+}
+
+/// Checks whether `e(p1, q1) = e(p2, q2)`.
+///
+/// ```rust
+/// use ark_algebra_intro::wasm::{g1_generator, g1_mul, g2_generator, g2_mul, pairing_check};
+///
+/// let (g, h) = (g1_generator(), g2_generator());
+/// // e(6g, h) = e(2g, 3h)
+/// let lhs = (g1_mul(&g, "6").unwrap(), h.clone());
+/// let rhs = (g1_mul(&g, "2").unwrap(), g2_mul(&h, "3").unwrap());
+/// assert!(pairing_check(&lhs.0, &lhs.1, &rhs.0, &rhs.1).unwrap());
+/// assert!(!pairing_check(&lhs.0, &lhs.1, &g, &h).unwrap());
+/// ```
+#[wasm_bindgen]
+pub fn pairing_check(p1: &str, q1: &str, p2: &str, q2: &str) -> Result<bool, JsError> {
+    Ok(Bls12_381::pairing(g1(p1)?, g2(q1)?) == Bls12_381::pairing(g1(p2)?, g2(q2)?))
+}